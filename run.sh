@@ -19,13 +19,28 @@ echo ""
 if [ "$1" == "--debug" ] || [ "$1" == "-d" ]; then
     echo -e "${YELLOW}Running in debug mode with full logging...${NC}"
     cargo run -- --debug
+elif [ "$1" == "--plain" ] || [ "$1" == "-p" ]; then
+    cargo run -- --plain
+elif [ "$1" == "--liveview" ] || [ "$1" == "-l" ]; then
+    cargo run -- --liveview
+elif [ "$1" == "--slow-camera" ] || [ "$1" == "-s" ]; then
+    cargo run -- --slow-camera
+elif [ "$1" == "--setup" ]; then
+    cargo run -- --setup
+elif [ "$1" == "--protocol-log" ]; then
+    cargo run -- --protocol-log
 elif [ "$1" == "--help" ] || [ "$1" == "-h" ]; then
     echo -e "Usage:"
     echo -e "  ${GREEN}./run.sh${NC}               - Run in normal mode"
     echo -e "  ${GREEN}./run.sh --debug${NC}       - Run with full debug logs"
+    echo -e "  ${GREEN}./run.sh --plain${NC}       - Run with a line-based interface for screen readers"
+    echo -e "  ${GREEN}./run.sh --liveview${NC}    - Start streaming live view right away"
+    echo -e "  ${GREEN}./run.sh --slow-camera${NC} - Wait longer between init steps for slow firmware"
+    echo -e "  ${GREEN}./run.sh --setup${NC}       - Ask the first-run setup questions again"
+    echo -e "  ${GREEN}./run.sh --protocol-log${NC} - Log full camera requests and responses to the logs directory"
     echo -e "  ${GREEN}./run.sh --help${NC}        - Show this help"
     echo ""
-    echo -e "Camera IP is configured in src/main.rs"
+    echo -e "Camera URL is configured in olympus_config.json (camera_url)"
     echo -e "Default is http://192.168.0.10"
 else
     echo -e "${GREEN}Running in normal mode...${NC}"
@@ -0,0 +1,616 @@
+use anyhow::{Context, Result, anyhow};
+use log::{info, warn};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::image::formats::UrlFormatGenerator;
+use crate::utils::atomic;
+
+/// Name of the capability cache in the cache directory
+pub const CAPABILITIES_FILE: &str = "capabilities.json";
+
+/// Stands for the image file name in a remembered URL format
+const FILE_PLACEHOLDER: &str = "{file}";
+
+/// Stands for the image folder in a remembered URL format
+const FOLDER_PLACEHOLDER: &str = "{folder}";
+
+/// Pause between probe requests so the camera isn't flooded
+const PROBE_PAUSE: Duration = Duration::from_millis(200);
+
+/// Endpoints checked by the probe
+const PROBE_ENDPOINTS: [&str; 12] = [
+    "",
+    "get_state.cgi",
+    "get_imglist.cgi?DIR=/DCIM/100OLYMP",
+    "get_capability.cgi",
+    "get_connectmode.cgi",
+    "exec_takemisc.cgi?com=getdevicestatus",
+    "DCIM",
+    "DCIM/100OLYMP",
+    "DCIM/",
+    "/DCIM/100OLYMP",
+    "get_imglist.cgi",
+    "get_imglist.cgi?DIR=/DCIM",
+];
+
+/// Command the camera deletes images with
+pub const DELETE_COMMAND: &str = "exec_erase";
+
+/// Command that switches modes and sets the live view size
+const MODE_COMMAND: &str = "switch_cameramode";
+
+/// Command that reads camera properties
+const PROPERTY_COMMAND: &str = "get_camprop";
+
+/// Image operations whose working URL format is remembered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageOperation {
+    /// Loading a preview for the image viewer
+    Preview,
+    /// Downloading the full image
+    Download,
+    /// Deleting the image on the camera
+    Delete,
+}
+
+/// What one camera supports, found by probing and by requests that worked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Capabilities {
+    /// Model reported by the camera when it was probed
+    pub model: Option<String>,
+    /// Whether each probed endpoint answered successfully
+    pub endpoints: BTreeMap<String, bool>,
+    /// URL format that works for previews, relative to the camera URL
+    pub preview: Option<String>,
+    /// URL format that works for downloads, relative to the camera URL
+    pub download: Option<String>,
+    /// URL format that works for deleting, relative to the camera URL
+    pub delete: Option<String>,
+    /// What the camera listed in `get_caplist.cgi`, if it answered
+    pub caplist: Option<CapabilityList>,
+}
+
+impl Capabilities {
+    /// Whether the camera has been probed
+    pub fn probed(&self) -> bool {
+        !self.endpoints.is_empty()
+    }
+
+    /// Number of probed endpoints that answered successfully
+    pub fn supported_endpoints(&self) -> usize {
+        self.endpoints
+            .values()
+            .filter(|supported| **supported)
+            .count()
+    }
+
+    /// Whether the camera answers `command`, such as `exec_erase`
+    ///
+    /// Cameras without a capability list are assumed to answer everything.
+    pub fn allows_command(&self, command: &str) -> bool {
+        self.caplist
+            .as_ref()
+            .is_none_or(|list| list.commands.contains(command))
+    }
+
+    /// Whether `property` can be read, assumed when the camera lists no properties
+    pub fn allows_property(&self, property: &str) -> bool {
+        self.caplist.as_ref().is_none_or(|list| {
+            list.properties.is_empty()
+                || list
+                    .properties
+                    .iter()
+                    .any(|listed| listed.eq_ignore_ascii_case(property))
+        })
+    }
+
+    /// Live view sizes the camera lists, or `None` if it didn't list any
+    #[cfg(feature = "video")]
+    pub fn liveview_sizes(&self) -> Option<&[String]> {
+        self.caplist
+            .as_ref()
+            .map(|list| list.liveview_sizes.as_slice())
+            .filter(|sizes| !sizes.is_empty())
+    }
+
+    /// URL format known to work for `operation`
+    pub fn format(&self, operation: ImageOperation) -> Option<&str> {
+        match operation {
+            ImageOperation::Preview => self.preview.as_deref(),
+            ImageOperation::Download => self.download.as_deref(),
+            ImageOperation::Delete => self.delete.as_deref(),
+        }
+    }
+
+    /// Add what `other` knows and this doesn't, returning the number of entries added
+    ///
+    /// What this camera found itself always wins over what is merged in.
+    pub fn merge(&mut self, other: &Capabilities) -> usize {
+        let mut added = 0;
+        if self.model.is_none() && other.model.is_some() {
+            self.model = other.model.clone();
+        }
+        if self.caplist.is_none() && other.caplist.is_some() {
+            self.caplist = other.caplist.clone();
+            added += 1;
+        }
+        for (endpoint, supported) in &other.endpoints {
+            if !self.endpoints.contains_key(endpoint) {
+                self.endpoints.insert(endpoint.clone(), *supported);
+                added += 1;
+            }
+        }
+        for operation in [
+            ImageOperation::Preview,
+            ImageOperation::Download,
+            ImageOperation::Delete,
+        ] {
+            let format = self.format_mut(operation);
+            if format.is_none() && other.format(operation).is_some() {
+                *format = other.format(operation).map(str::to_string);
+                added += 1;
+            }
+        }
+        added
+    }
+
+    fn format_mut(&mut self, operation: ImageOperation) -> &mut Option<String> {
+        match operation {
+            ImageOperation::Preview => &mut self.preview,
+            ImageOperation::Download => &mut self.download,
+            ImageOperation::Delete => &mut self.delete,
+        }
+    }
+}
+
+/// Commands, live view sizes and properties a camera lists in `get_caplist.cgi`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CapabilityList {
+    /// Version of the camera's protocol
+    pub version: Option<String>,
+    /// Functions such as `remote` or `gps`
+    pub functions: BTreeSet<String>,
+    /// Commands the camera answers, without `.cgi`
+    pub commands: BTreeSet<String>,
+    /// Live view sizes `switch_cameramode.cgi` accepts, in the camera's order
+    pub liveview_sizes: Vec<String>,
+    /// Properties `get_camprop.cgi` can read
+    pub properties: BTreeSet<String>,
+}
+
+impl CapabilityList {
+    /// Parse a `get_caplist.cgi` response, or `None` if it lists no commands
+    pub fn parse(text: &str) -> Option<Self> {
+        let cgi = Regex::new(r#"(?s)<cgi\s+name="([^"]+)"\s*(?:/>|>(.*?)</cgi>)"#).ok()?;
+        let param = Regex::new(r#"<param(\d*)\s+name="([^"]+)""#).ok()?;
+        let function = Regex::new(r#"<support\s+func="([^"]+)""#).ok()?;
+        let version = Regex::new(r"<version>([^<]*)</version>").ok()?;
+
+        let mut list = CapabilityList {
+            version: version
+                .captures(text)
+                .map(|caps| caps[1].trim().to_string()),
+            functions: function
+                .captures_iter(text)
+                .map(|caps| caps[1].to_string())
+                .collect(),
+            ..CapabilityList::default()
+        };
+
+        for caps in cgi.captures_iter(text) {
+            let command = caps[1].trim_end_matches(".cgi");
+            let body = caps.get(2).map_or("", |body| body.as_str());
+            for param in param.captures_iter(body) {
+                let (level, name) = (&param[1], &param[2]);
+                match command {
+                    MODE_COMMAND
+                        if is_liveview_size(name)
+                            && !list.liveview_sizes.iter().any(|size| size == name) =>
+                    {
+                        list.liveview_sizes.push(name.to_string());
+                    }
+                    // Property names are the second level, under `com=desc` or `com=get`
+                    PROPERTY_COMMAND if level == "2" && name != "desclist" => {
+                        list.properties.insert(name.to_string());
+                    }
+                    _ => {}
+                }
+            }
+            list.commands.insert(command.to_string());
+        }
+
+        (!list.commands.is_empty()).then_some(list)
+    }
+}
+
+/// Whether `name` is a live view size such as `0640x0480`
+fn is_liveview_size(name: &str) -> bool {
+    name.split_once('x').is_some_and(|(width, height)| {
+        [width, height]
+            .iter()
+            .all(|side| !side.is_empty() && side.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+/// Capabilities of the configured camera, kept between sessions
+#[derive(Debug, Default)]
+pub struct CapabilityCache {
+    /// Where the cache is saved; an unsaved cache only lasts the session
+    path: Option<PathBuf>,
+    capabilities: Capabilities,
+}
+
+impl CapabilityCache {
+    /// Load the capabilities saved by a previous session, or start empty
+    pub fn load(path: &Path) -> Self {
+        let capabilities = match fs::read_to_string(path) {
+            Ok(text) => match serde_json::from_str::<Capabilities>(&text) {
+                Ok(capabilities) => {
+                    info!("Loaded camera capabilities from {:?}", path);
+                    capabilities
+                }
+                Err(e) => {
+                    warn!("Ignoring invalid capability cache {:?}: {}", path, e);
+                    Capabilities::default()
+                }
+            },
+            Err(_) => Capabilities::default(),
+        };
+
+        Self {
+            path: Some(path.to_path_buf()),
+            capabilities,
+        }
+    }
+
+    /// Cached capabilities
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
+    /// Replace the cache with the results of a new probe
+    pub fn replace(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+        self.save();
+    }
+
+    /// Write the capabilities to a file that can be shared with owners of the same model
+    pub fn export(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string_pretty(&self.capabilities)?;
+        atomic::write_atomic(path, text.as_bytes())
+            .with_context(|| format!("Failed to export capabilities to {:?}", path))?;
+        info!("Exported camera capabilities to {:?}", path);
+        Ok(())
+    }
+
+    /// Merge capabilities exported from another camera, returning the number of entries added
+    ///
+    /// Files from a different model are refused, since its endpoints and formats may not apply.
+    pub fn import(&mut self, path: &Path) -> Result<usize> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+        let imported: Capabilities = serde_json::from_str(&text)
+            .with_context(|| format!("{:?} is not a capability file", path))?;
+
+        if let (Some(ours), Some(theirs)) = (&self.capabilities.model, &imported.model)
+            && ours != theirs
+        {
+            return Err(anyhow!(
+                "{:?} is for the {}, not this {}",
+                path,
+                theirs,
+                ours
+            ));
+        }
+
+        let added = self.capabilities.merge(&imported);
+        info!("Imported {} capability entries from {:?}", added, path);
+        if added > 0 {
+            self.save();
+        }
+        Ok(added)
+    }
+
+    /// Note whether `endpoint` answered, as found outside a probe
+    pub fn record_endpoint(&mut self, endpoint: &str, supported: bool) {
+        if self.capabilities.endpoints.get(endpoint) != Some(&supported) {
+            info!("Endpoint {} supported: {}", endpoint, supported);
+            self.capabilities
+                .endpoints
+                .insert(endpoint.to_string(), supported);
+            self.save();
+        }
+    }
+
+    /// Order `urls` so the format known to work for `operation` comes first
+    pub fn known_first(
+        &self,
+        operation: ImageOperation,
+        base_url: &str,
+        folder: &str,
+        image_name: &str,
+        mut urls: Vec<String>,
+    ) -> Vec<String> {
+        let Some(known) = self.capabilities.format(operation) else {
+            return urls;
+        };
+        if let Some(index) = urls
+            .iter()
+            .position(|url| url_template(base_url, folder, image_name, url) == known)
+        {
+            let url = urls.remove(index);
+            urls.insert(0, url);
+        }
+        urls
+    }
+
+    /// Remember the URL that worked for `operation`
+    pub fn remember(
+        &mut self,
+        operation: ImageOperation,
+        base_url: &str,
+        folder: &str,
+        image_name: &str,
+        url: &str,
+    ) {
+        let template = url_template(base_url, folder, image_name, url);
+        let format = self.capabilities.format_mut(operation);
+        if format.as_deref() != Some(template.as_str()) {
+            info!("Remembering {:?} format {}", operation, template);
+            *format = Some(template);
+            self.save();
+        }
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = serde_json::to_string_pretty(&self.capabilities)
+            .map_err(anyhow::Error::from)
+            .and_then(|text| atomic::write_atomic(path, text.as_bytes()))
+            .with_context(|| format!("Failed to save capability cache {:?}", path));
+        if let Err(e) = result {
+            warn!("{:#}", e);
+        }
+    }
+}
+
+/// File name offered for exporting the capabilities of `model`
+pub fn export_file_name(model: Option<&str>) -> String {
+    let model: String = model
+        .unwrap_or("camera")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("capabilities_{}.json", model.trim_matches('_'))
+}
+
+/// URL relative to the camera URL with the image name and folder replaced by placeholders
+///
+/// Only the folder's own name is replaced, so a format found in `/DCIM/100OLYMP`
+/// still matches when `/DCIM/101OLYMP` is open.
+pub fn url_template(base_url: &str, folder: &str, image_name: &str, url: &str) -> String {
+    let folder_name = folder.rsplit('/').next().unwrap_or(folder);
+    url.strip_prefix(base_url)
+        .unwrap_or(url)
+        .replace(image_name, FILE_PLACEHOLDER)
+        .replace(folder_name, FOLDER_PLACEHOLDER)
+}
+
+/// Structured probe of the endpoints and URL formats a camera supports
+pub trait CapabilityProbe: ClientOperations {
+    /// Probe the camera and cache the results, trying image formats on `sample_image`
+    fn probe_capabilities(&self, sample_image: Option<&str>) -> Capabilities {
+        info!("🔍 Probing camera capabilities");
+
+        let mut capabilities = Capabilities {
+            model: self
+                .get_text("get_caminfo.cgi")
+                .ok()
+                .and_then(|text| parse_model(&text)),
+            caplist: self
+                .get_text("get_caplist.cgi")
+                .ok()
+                .and_then(|text| CapabilityList::parse(&text)),
+            ..Capabilities::default()
+        };
+        match &capabilities.caplist {
+            Some(list) => info!(
+                "Camera lists {} commands, {} live view sizes and {} properties",
+                list.commands.len(),
+                list.liveview_sizes.len(),
+                list.properties.len()
+            ),
+            None => info!("Camera has no capability list, assuming every feature works"),
+        }
+
+        for endpoint in PROBE_ENDPOINTS {
+            let supported = match self.get_page(endpoint) {
+                Ok(_) => {
+                    info!("✅ Endpoint {} succeeded", endpoint);
+                    true
+                }
+                Err(e) => {
+                    info!("❌ Endpoint {} failed: {}", endpoint, e);
+                    false
+                }
+            };
+            capabilities
+                .endpoints
+                .insert(endpoint.to_string(), supported);
+            thread::sleep(PROBE_PAUSE);
+        }
+
+        if let Some(image) = sample_image.map(str::trim) {
+            info!("Testing image access for: {}", image);
+            let base_url = self.base_url();
+            let folder = self.image_folder();
+            capabilities.preview = self.probe_format(
+                image,
+                &UrlFormatGenerator::generate_url_formats(base_url, &folder, image),
+            );
+            capabilities.download = self.probe_format(
+                image,
+                &UrlFormatGenerator::download_url_formats(base_url, &folder, image),
+            );
+        }
+
+        // Deleting can't be tried out, so keep the format the last delete found
+        let mut cache = self.capabilities().lock().unwrap();
+        capabilities.delete = cache.capabilities().delete.clone();
+        // Endpoints found from the command console aren't probed, so they are kept too
+        for (endpoint, supported) in &cache.capabilities().endpoints {
+            if !PROBE_ENDPOINTS.contains(&endpoint.as_str()) {
+                capabilities
+                    .endpoints
+                    .entry(endpoint.clone())
+                    .or_insert(*supported);
+            }
+        }
+        cache.replace(capabilities.clone());
+
+        info!(
+            "🔍 Camera probe complete: {} of {} endpoints supported",
+            capabilities.supported_endpoints(),
+            capabilities.endpoints.len()
+        );
+        capabilities
+    }
+
+    /// Template of the first URL in `urls` that returns JPEG data
+    fn probe_format(&self, image_name: &str, urls: &[String]) -> Option<String> {
+        let working = urls.iter().enumerate().find(|(i, url)| {
+            let result = self.get_binary(url);
+            thread::sleep(PROBE_PAUSE);
+            match result {
+                Ok(data) if data.starts_with(&[0xFF, 0xD8]) => {
+                    info!("✅ URL format #{} returned a valid JPEG", i + 1);
+                    true
+                }
+                Ok(data) => {
+                    info!(
+                        "⚠️ URL format #{} returned {} bytes that aren't a JPEG",
+                        i + 1,
+                        data.len()
+                    );
+                    false
+                }
+                Err(e) => {
+                    info!("❌ URL format #{} failed: {}", i + 1, e);
+                    false
+                }
+            }
+        });
+        working.map(|(_, url)| url_template(self.base_url(), &self.image_folder(), image_name, url))
+    }
+}
+
+/// Model name from a `get_caminfo.cgi` response
+fn parse_model(text: &str) -> Option<String> {
+    let re = Regex::new(r"<model>([^<]*)</model>").ok()?;
+    re.captures(text).map(|caps| caps[1].trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAPLIST: &str = r#"<?xml version="1.0"?>
+<oishare>
+<version>4.20</version>
+<support func="web"/>
+<support func="remote"/>
+<cgi name="get_connectmode">
+<http_method type="get"/>
+</cgi>
+<cgi name="switch_cameramode">
+<http_method type="get">
+<cmd1 name="mode">
+<param1 name="rec">
+<cmd2 name="lvqty">
+<param2 name="0320x0240"/>
+<param2 name="0640x0480"/>
+</cmd2>
+</param1>
+<param1 name="play"/>
+</cmd1>
+</http_method>
+</cgi>
+<cgi name="get_camprop">
+<http_method type="get">
+<cmd1 name="com">
+<param1 name="desc">
+<cmd2 name="propname">
+<param2 name="desclist"/>
+<param2 name="isospeedvalue"/>
+</cmd2>
+</param1>
+</cmd1>
+</http_method>
+</cgi>
+</oishare>"#;
+
+    #[test]
+    fn caplist_is_parsed() {
+        let list = CapabilityList::parse(CAPLIST).unwrap();
+        assert_eq!(list.version.as_deref(), Some("4.20"));
+        assert!(list.functions.contains("remote"));
+        assert_eq!(list.commands.len(), 3);
+        assert_eq!(list.liveview_sizes, ["0320x0240", "0640x0480"]);
+        assert_eq!(list.properties.len(), 1);
+        assert!(CapabilityList::parse("<html>404</html>").is_none());
+    }
+
+    #[test]
+    fn unlisted_features_are_refused_only_with_a_list() {
+        let mut capabilities = Capabilities::default();
+        assert!(capabilities.allows_command(DELETE_COMMAND));
+        #[cfg(feature = "video")]
+        assert!(capabilities.liveview_sizes().is_none());
+
+        capabilities.caplist = CapabilityList::parse(CAPLIST);
+        assert!(!capabilities.allows_command(DELETE_COMMAND));
+        assert!(capabilities.allows_command("get_connectmode"));
+        assert!(capabilities.allows_property("ISOSPEEDVALUE"));
+        assert!(!capabilities.allows_property("WB"));
+        #[cfg(feature = "video")]
+        assert_eq!(capabilities.liveview_sizes().map(<[_]>::len), Some(2));
+    }
+
+    #[test]
+    fn remembered_formats_match_in_every_folder() {
+        let base_url = "http://192.168.0.10/";
+        let template = |folder, image| {
+            url_template(
+                base_url,
+                folder,
+                image,
+                &format!("{}get_img.cgi?DIR={}&FILE={}", base_url, folder, image),
+            )
+        };
+        assert_eq!(
+            template("/DCIM/100OLYMP", "P1010001.JPG"),
+            "get_img.cgi?DIR=/DCIM/{folder}&FILE={file}"
+        );
+        assert_eq!(
+            template("/DCIM/100OLYMP", "P1010001.JPG"),
+            template("/DCIM/101OLYMP", "P1020001.JPG")
+        );
+    }
+}
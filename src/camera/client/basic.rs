@@ -1,8 +1,19 @@
 use anyhow::{Result, anyhow};
 use log::{error, info, warn};
+use regex::Regex;
 use reqwest::StatusCode;
-use reqwest::blocking::{Client, Response};
-use std::time::Duration;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::camera::capabilities::{Capabilities, CapabilityCache, ImageOperation};
+use crate::camera::client::breaker::{BreakerState, CircuitBreaker};
+use crate::camera::client::error::{ReadOnlyError, RequestError};
+use crate::camera::client::protocol::ProtocolLog;
+use crate::camera::client::stats::{RequestRecord, RequestStats};
+use crate::camera::client::timeouts::TimeoutConfig;
+use crate::utils::audit::{self, AuditAction};
 
 /// Trait for basic client operations
 pub trait ClientOperations {
@@ -12,25 +23,253 @@ pub trait ClientOperations {
     /// Get the base URL
     fn base_url(&self) -> &str;
 
+    /// Get the per-operation timeouts
+    fn timeouts(&self) -> &TimeoutConfig;
+
+    /// Get the request statistics shared by all clones of the client
+    fn request_stats(&self) -> &Arc<Mutex<RequestStats>>;
+
+    /// Get the cached capabilities of the camera
+    fn capabilities(&self) -> &Arc<Mutex<CapabilityCache>>;
+
+    /// Get the circuit breaker shared by all clones of the client
+    fn breaker(&self) -> &Arc<Mutex<CircuitBreaker>>;
+
+    /// Get the read-only flag shared by all clones of the client
+    fn read_only(&self) -> &Arc<AtomicBool>;
+
+    /// Get the open image folder shared by all clones of the client
+    fn folder(&self) -> &Arc<Mutex<String>>;
+
+    /// Folder on the card images are listed, viewed and downloaded from
+    fn image_folder(&self) -> String {
+        self.folder().lock().unwrap().clone()
+    }
+
+    /// Make `folder` the one images are listed, viewed and downloaded from
+    fn open_folder(&self, folder: &str) {
+        info!("Opening image folder {}", folder);
+        *self.folder().lock().unwrap() = folder.to_string();
+    }
+
+    /// Whether capture, deletion and settings changes are refused
+    fn is_read_only(&self) -> bool {
+        self.read_only().load(Ordering::Relaxed)
+    }
+
+    /// Allow or refuse requests that change the camera
+    fn set_read_only(&self, read_only: bool) {
+        info!("Read-only mode {}", if read_only { "on" } else { "off" });
+        self.read_only().store(read_only, Ordering::Relaxed);
+    }
+
+    /// Fail with a read-only error if `operation` would change the camera in read-only mode
+    fn ensure_writable(&self, operation: &'static str) -> Result<()> {
+        if self.is_read_only() {
+            return Err(ReadOnlyError(operation).into());
+        }
+        Ok(())
+    }
+
+    /// Whether the camera stopped answering and the breaker is in its cool-down
+    fn breaker_state(&self) -> BreakerState {
+        self.breaker().lock().unwrap().state()
+    }
+
+    /// Whether automatic requests should hold off because the camera isn't answering
+    fn camera_unresponsive(&self) -> bool {
+        self.breaker().lock().unwrap().is_open()
+    }
+
+    /// What is known about the camera's capabilities so far
+    fn known_capabilities(&self) -> Capabilities {
+        self.capabilities().lock().unwrap().capabilities().clone()
+    }
+
+    /// Order image URLs so the format known to work for `operation` is tried first
+    fn known_first(
+        &self,
+        operation: ImageOperation,
+        image_name: &str,
+        urls: Vec<String>,
+    ) -> Vec<String> {
+        self.capabilities().lock().unwrap().known_first(
+            operation,
+            self.base_url(),
+            &self.image_folder(),
+            image_name,
+            urls,
+        )
+    }
+
+    /// Remember the image URL that worked for `operation`
+    fn remember_format(&self, operation: ImageOperation, image_name: &str, url: &str) {
+        self.capabilities().lock().unwrap().remember(
+            operation,
+            self.base_url(),
+            &self.image_folder(),
+            image_name,
+            url,
+        );
+    }
+
+    /// Get the protocol log, if full requests and responses are being logged
+    fn protocol_log(&self) -> Option<&ProtocolLog> {
+        None
+    }
+
+    /// Send a request and record its endpoint, status and duration
+    fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let request = request.build()?;
+        let method = request.method().to_string();
+        let endpoint = request.url().path().trim_start_matches('/').to_string();
+
+        if let Some(log) = self.protocol_log() {
+            log.request(&request);
+        }
+
+        let start = Instant::now();
+        let result = self.client().execute(request);
+        let duration = start.elapsed();
+        let record = RequestRecord {
+            method,
+            endpoint,
+            status: result.as_ref().ok().map(|r| r.status().as_u16()),
+            duration,
+        };
+        self.request_stats().lock().unwrap().record(record);
+        // Any answer short of a server error shows the camera is alive
+        let answered = result
+            .as_ref()
+            .is_ok_and(|response| !response.status().is_server_error());
+        self.breaker().lock().unwrap().record(answered);
+
+        match (self.protocol_log(), result) {
+            (Some(log), Ok(response)) => log.response(response, duration),
+            (Some(log), Err(e)) => {
+                log.failure(&e, duration);
+                Err(e.into())
+            }
+            (None, result) => Ok(result?),
+        }
+    }
+
     /// Make a simple GET request to the camera
     fn get_page(&self, endpoint: &str) -> Result<()> {
+        self.get_page_with_timeout(endpoint, self.timeouts().request())
+    }
+
+    /// Make a simple GET request to the camera with a specific timeout
+    fn get_page_with_timeout(&self, endpoint: &str, timeout: Duration) -> Result<()> {
         let url = format!("{}{}", self.base_url(), endpoint);
         info!("Request: {}", url);
 
         // Send request with exact headers that work
-        let response = self
-            .client()
-            .get(&url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()?;
+        let response = self.send(
+            self.client()
+                .get(&url)
+                .timeout(timeout)
+                .header("user-agent", "OlympusCameraKit")
+                .header("content-length", "4096"),
+        )?;
 
         // Log but don't validate status code
         self.log_response_info(&response, "Page request");
 
         // If status is not successful, return an error
         if !response.status().is_success() {
-            return Err(anyhow!("Request failed with status: {}", response.status()));
+            return Err(RequestError::from_status(response.status()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Time a HEAD request to the camera; any answer counts, whatever its status
+    fn ping(&self, timeout: Duration) -> Result<Duration> {
+        let start = Instant::now();
+        self.send(
+            self.client()
+                .head(self.base_url())
+                .timeout(timeout)
+                .header("user-agent", "OlympusCameraKit"),
+        )?;
+        Ok(start.elapsed())
+    }
+
+    /// Make a GET request and return the response body as text
+    fn get_text(&self, endpoint: &str) -> Result<String> {
+        let url = format!("{}{}", self.base_url(), endpoint);
+        info!("Text request: {}", url);
+
+        let response = self.send(
+            self.client()
+                .get(&url)
+                .timeout(self.timeouts().request())
+                .header("user-agent", "OlympusCameraKit")
+                .header("content-length", "4096"),
+        )?;
+
+        self.log_response_info(&response, "Text request");
+
+        if !response.status().is_success() {
+            return Err(RequestError::from_status(response.status()).into());
+        }
+
+        Ok(response.text()?)
+    }
+
+    /// Read the current value of a camera property such as `ISO` or `WB`
+    fn get_camprop(&self, property: &str) -> Result<String> {
+        let text = self.get_text(&format!("get_camprop.cgi?com=get&propname={}", property))?;
+
+        let re = Regex::new(r"<value>([^<]*)</value>")?;
+        re.captures(&text)
+            .map(|caps| caps[1].trim().to_string())
+            .ok_or_else(|| anyhow!("No value for {} in camera response", property))
+    }
+
+    /// Set a camera property such as `FOCUS_STILL` or `ISO`, recorded in the audit log
+    fn set_camprop(&self, property: &str, value: &str) -> Result<()> {
+        let result = self.write_camprop(property, value);
+        audit::record(
+            AuditAction::Setting,
+            &format!("{}={}", property, value),
+            &result,
+        );
+        result
+    }
+
+    /// Send a new property value to the camera
+    fn write_camprop(&self, property: &str, value: &str) -> Result<()> {
+        self.ensure_writable("Changing camera settings")?;
+        let url = format!(
+            "{}set_camprop.cgi?com=set&propname={}",
+            self.base_url(),
+            property
+        );
+        info!("Setting camera property {} = {}", property, value);
+
+        let body = format!(
+            "<?xml version=\"1.0\"?>\r\n<set>\r\n<value>{}</value>\r\n</set>\r\n",
+            value
+        );
+
+        let response = self.send(
+            self.client()
+                .post(&url)
+                .timeout(self.timeouts().request())
+                .header("user-agent", "OlympusCameraKit")
+                .header("content-type", "text/plain;charset=utf-8")
+                .body(body),
+        )?;
+
+        self.log_response_info(&response, "Set property");
+
+        if !response.status().is_success() {
+            return Err(
+                anyhow::Error::new(RequestError::from_status(response.status()))
+                    .context(format!("Failed to set {}", property)),
+            );
         }
 
         Ok(())
@@ -46,15 +285,15 @@ pub trait ClientOperations {
 
         info!("Binary request: {}", url);
 
-        // Send request with proper headers and longer timeout
-        let response = self
-            .client()
-            .get(&url)
-            .timeout(Duration::from_secs(30)) // Longer timeout for image data
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .header("accept", "image/jpeg,*/*")
-            .send()?;
+        // Send request with proper headers and the download timeout
+        let response = self.send(
+            self.client()
+                .get(&url)
+                .timeout(self.timeouts().download())
+                .header("user-agent", "OlympusCameraKit")
+                .header("content-length", "4096")
+                .header("accept", "image/jpeg,*/*"),
+        )?;
 
         self.log_response_info(&response, "Binary request");
 
@@ -111,20 +350,18 @@ pub trait ClientOperations {
                                 String::from_utf8_lossy(&bytes_vec[0..bytes_vec.len().min(100)]);
                             error!("404 response content: {}", text);
                         }
-                        Err(anyhow!("404 Not Found: URL doesn't exist on camera"))
+                        Err(RequestError::NotFound.into())
                     }
-                    Err(_) => Err(anyhow!("404 Not Found: URL doesn't exist on camera")),
+                    Err(_) => Err(RequestError::NotFound.into()),
                 }
             }
             status if status.as_u16() == 520 => {
                 error!("520 Unknown Status error for URL: {}", url);
-                Err(anyhow!(
-                    "520 Unknown Status: Camera returned unexpected status code"
-                ))
+                Err(RequestError::Busy(520).into())
             }
             other => {
                 error!("Request failed with status: {} for URL: {}", other, url);
-                Err(anyhow!("Request failed with status code: {}", other))
+                Err(RequestError::from_status(other).into())
             }
         }
     }
@@ -0,0 +1,99 @@
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Circuit breaker settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreakerConfig {
+    /// Whether repeated failures pause automatic requests
+    pub enabled: bool,
+    /// Consecutive failed requests that open the breaker
+    pub failure_threshold: u32,
+    /// Seconds automatic requests are paused once the breaker opens
+    pub cooldown_secs: u64,
+}
+
+impl Default for BreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: 5,
+            cooldown_secs: 30,
+        }
+    }
+}
+
+/// Whether automatic requests may go to the camera
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// The camera is answering
+    Closed,
+    /// The camera stopped answering; automatic requests wait out the cool-down
+    Open { retry_in: Duration },
+    /// The cool-down is over and the next request decides whether the camera is back
+    HalfOpen,
+}
+
+/// Counts consecutive failed requests and opens after too many
+///
+/// While open, background polling and automatic retries hold off instead of
+/// piling more requests onto a camera that isn't answering. Requests the user
+/// makes still go through, and any answer closes the breaker again.
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: BreakerConfig,
+    failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker
+    pub fn new(config: &BreakerConfig) -> Self {
+        Self {
+            config: config.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Count the outcome of a request
+    pub fn record(&mut self, success: bool) {
+        if success {
+            if self.opened_at.take().is_some() {
+                info!("Camera is answering again, resuming automatic requests");
+            }
+            self.failures = 0;
+            return;
+        }
+
+        self.failures += 1;
+        if !self.config.enabled || self.failures < self.config.failure_threshold {
+            return;
+        }
+        // A failure while half-open starts the cool-down over
+        if self.opened_at.is_none() {
+            warn!(
+                "Camera unresponsive after {} failed requests, pausing automatic requests for {}s",
+                self.failures, self.config.cooldown_secs
+            );
+        }
+        self.opened_at = Some(Instant::now());
+    }
+
+    /// Current state of the breaker
+    pub fn state(&self) -> BreakerState {
+        let Some(opened_at) = self.opened_at else {
+            return BreakerState::Closed;
+        };
+        let cooldown = Duration::from_secs(self.config.cooldown_secs);
+        match cooldown.checked_sub(opened_at.elapsed()) {
+            Some(retry_in) if !retry_in.is_zero() => BreakerState::Open { retry_in },
+            _ => BreakerState::HalfOpen,
+        }
+    }
+
+    /// Whether automatic requests should hold off
+    pub fn is_open(&self) -> bool {
+        matches!(self.state(), BreakerState::Open { .. })
+    }
+}
@@ -1,6 +1,103 @@
 use anyhow::{Result, anyhow};
 use log::{error, info};
+use reqwest::StatusCode;
 use reqwest::blocking::Response;
+use thiserror::Error;
+
+/// Error status the camera answered a request with
+#[derive(Debug, Error)]
+pub enum RequestError {
+    /// The camera is working on something else, e.g. writing to the card
+    #[error("Camera is busy (status {0})")]
+    Busy(u16),
+    /// The URL doesn't exist on the camera
+    #[error("404 Not Found: URL doesn't exist on camera")]
+    NotFound,
+    #[error("Request failed with status: {0}")]
+    Status(StatusCode),
+}
+
+impl RequestError {
+    /// Error for a response with an unsuccessful status
+    pub fn from_status(status: StatusCode) -> Self {
+        match status.as_u16() {
+            404 => Self::NotFound,
+            // The Air answers 520 while it is busy, 503 when it is starting up
+            code @ (503 | 520) => Self::Busy(code),
+            _ => Self::Status(status),
+        }
+    }
+}
+
+/// A request that would change the camera, refused in read-only mode
+#[derive(Debug, Error)]
+#[error("{0} is disabled in read-only mode")]
+pub struct ReadOnlyError(pub &'static str);
+
+/// What a failed request says about whether trying again can help
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Busy camera or timed out request; likely to work a moment later
+    Transient,
+    /// The URL doesn't exist on the camera
+    NotFound,
+    /// Nothing answered at the camera's address
+    Unreachable,
+    /// Any other failure
+    Permanent,
+}
+
+impl FailureKind {
+    /// Classify an error from a camera request
+    pub fn classify(error: &anyhow::Error) -> Self {
+        for cause in error.chain() {
+            if let Some(error) = cause.downcast_ref::<RequestError>() {
+                return match error {
+                    RequestError::Busy(_) => Self::Transient,
+                    RequestError::NotFound => Self::NotFound,
+                    RequestError::Status(_) => Self::Permanent,
+                };
+            }
+            if let Some(error) = cause.downcast_ref::<reqwest::Error>() {
+                // Connect errors first, since a connect timeout is also a timeout
+                if error.is_connect() {
+                    return Self::Unreachable;
+                }
+                if error.is_timeout() {
+                    return Self::Transient;
+                }
+                return match error.status() {
+                    Some(status) => Self::from_status(status),
+                    None => Self::Permanent,
+                };
+            }
+        }
+        Self::Permanent
+    }
+
+    fn from_status(status: StatusCode) -> Self {
+        match RequestError::from_status(status) {
+            RequestError::Busy(_) => Self::Transient,
+            RequestError::NotFound => Self::NotFound,
+            RequestError::Status(_) => Self::Permanent,
+        }
+    }
+
+    /// Whether the request is worth retrying
+    pub fn is_transient(self) -> bool {
+        self == Self::Transient
+    }
+
+    /// Short explanation for messages about the failure
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Transient => "the camera is busy",
+            Self::NotFound => "not found on the camera",
+            Self::Unreachable => "the camera can't be reached, check its Wi-Fi connection",
+            Self::Permanent => "the camera rejected the request",
+        }
+    }
+}
 
 /// Helper for extracting error information
 pub trait ErrorHandler {
@@ -1,3 +1,7 @@
 // Export client submodules
 pub mod basic;
+pub mod breaker;
 pub mod error;
+pub mod protocol;
+pub mod stats;
+pub mod timeouts;
@@ -0,0 +1,166 @@
+use anyhow::Result;
+use log::{info, warn};
+use regex::Regex;
+use reqwest::blocking::{Request, Response};
+use reqwest::header::{CONTENT_TYPE, HeaderMap};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::utils::paths::{self, DataDir};
+
+/// Protocol log settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtocolLogConfig {
+    /// Write every request and response to a protocol log for this session
+    pub enabled: bool,
+    /// Longest text body written in full; longer and binary bodies are only sized
+    pub max_body_bytes: usize,
+}
+
+impl Default for ProtocolLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_body_bytes: 64 * 1024,
+        }
+    }
+}
+
+/// Per-session log of the raw CGI traffic with the camera
+pub struct ProtocolLog {
+    path: PathBuf,
+    file: Mutex<File>,
+    max_body_bytes: usize,
+}
+
+impl ProtocolLog {
+    /// Open a new protocol log in the logs directory
+    pub fn open(config: &ProtocolLogConfig) -> Result<Self> {
+        let session = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let path = paths::dir(DataDir::Logs).join(format!("protocol_{}.log", session));
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        info!("Writing the camera protocol log to {:?}", path);
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+            max_body_bytes: config.max_body_bytes,
+        })
+    }
+
+    /// Where the log is written
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Write a request about to be sent
+    pub fn request(&self, request: &Request) {
+        let mut entry = format!(
+            "==> {} {}\n",
+            request.method(),
+            redact(request.url().as_str())
+        );
+        push_headers(&mut entry, request.headers());
+        match request.body().and_then(|body| body.as_bytes()) {
+            Some(body) => self.push_body(&mut entry, request.headers(), body),
+            None => entry.push('\n'),
+        }
+        self.write(&entry);
+    }
+
+    /// Write a response, reading its body and handing back an equivalent response
+    pub fn response(&self, response: Response, elapsed: Duration) -> Result<Response> {
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes()?;
+
+        let mut entry = format!(
+            "<== {:?} {} in {} ms\n",
+            version,
+            status,
+            elapsed.as_millis()
+        );
+        push_headers(&mut entry, &headers);
+        self.push_body(&mut entry, &headers, &body);
+        self.write(&entry);
+
+        let mut rebuilt = http::Response::builder().status(status).version(version);
+        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+            *rebuilt_headers = headers;
+        }
+        Ok(Response::from(rebuilt.body(body)?))
+    }
+
+    /// Write a request that got no response
+    pub fn failure(&self, error: &reqwest::Error, elapsed: Duration) {
+        self.write(&format!(
+            "<== no response after {} ms: {}\n\n",
+            elapsed.as_millis(),
+            redact(&error.to_string())
+        ));
+    }
+
+    /// Append a body, in full if it is short text
+    fn push_body(&self, entry: &mut String, headers: &HeaderMap, body: &[u8]) {
+        let text = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_none_or(|value| value.starts_with("text/") || value.contains("xml"));
+
+        entry.push('\n');
+        match std::str::from_utf8(body) {
+            Ok(body) if text && body.len() <= self.max_body_bytes => {
+                let body = redact(body);
+                entry.push_str(&body);
+                if !body.ends_with('\n') {
+                    entry.push('\n');
+                }
+            }
+            _ => entry.push_str(&format!("[{} bytes not shown]\n", body.len())),
+        }
+        entry.push('\n');
+    }
+
+    fn write(&self, entry: &str) {
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = file.write_all(entry.as_bytes()) {
+            warn!("Failed to write the protocol log {:?}: {}", self.path, e);
+        }
+    }
+}
+
+/// `text` with access point passwords, as sent to `set_wifisetting` and read
+/// back from `get_wifisetting`, replaced by `***`
+fn redact(text: &str) -> String {
+    let patterns = [
+        (r"(?i)(password=)[^&\s]*", "${1}***"),
+        (r"(?i)(<password>)[^<]*", "${1}***"),
+    ];
+    patterns.iter().fold(
+        text.to_string(),
+        |text, (pattern, replacement)| match Regex::new(pattern) {
+            Ok(re) => re.replace_all(&text, *replacement).into_owned(),
+            Err(_) => text,
+        },
+    )
+}
+
+/// Append headers as `name: value` lines
+fn push_headers(entry: &mut String, headers: &HeaderMap) {
+    for (name, value) in headers {
+        entry.push_str(&format!(
+            "{}: {}\n",
+            name,
+            String::from_utf8_lossy(value.as_bytes())
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+use log::debug;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+/// Requests kept in the recent request log
+const RECENT_LIMIT: usize = 50;
+
+/// One finished request to the camera
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestRecord {
+    /// HTTP method, e.g. `GET`
+    pub method: String,
+    /// CGI endpoint without the query string, e.g. `get_imglist.cgi`
+    pub endpoint: String,
+    /// HTTP status, or `None` if no response arrived
+    pub status: Option<u16>,
+    pub duration: Duration,
+}
+
+/// Totals for one endpoint
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EndpointStats {
+    pub count: u64,
+    /// Requests without a response or with an error status
+    pub failures: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl EndpointStats {
+    /// Mean request duration
+    pub fn average(&self) -> Duration {
+        self.total / self.count.max(1) as u32
+    }
+}
+
+/// Timing and outcome of every request made through a camera handle and its clones
+#[derive(Debug, Default)]
+pub struct RequestStats {
+    recent: VecDeque<RequestRecord>,
+    endpoints: BTreeMap<String, EndpointStats>,
+}
+
+impl RequestStats {
+    /// Add a finished request
+    pub fn record(&mut self, record: RequestRecord) {
+        debug!(
+            "{} {} -> {:?} in {:?}",
+            record.method, record.endpoint, record.status, record.duration
+        );
+
+        let totals = self.endpoints.entry(record.endpoint.clone()).or_default();
+        totals.count += 1;
+        if record.status.is_none_or(|status| status >= 400) {
+            totals.failures += 1;
+        }
+        totals.total += record.duration;
+        totals.max = totals.max.max(record.duration);
+
+        if self.recent.len() == RECENT_LIMIT {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(record);
+    }
+
+    /// Most recent requests, oldest first
+    pub fn recent(&self) -> impl Iterator<Item = &RequestRecord> {
+        self.recent.iter()
+    }
+
+    /// Totals per endpoint, sorted by endpoint
+    pub fn endpoints(&self) -> impl Iterator<Item = (&str, &EndpointStats)> {
+        self.endpoints
+            .iter()
+            .map(|(endpoint, stats)| (endpoint.as_str(), stats))
+    }
+}
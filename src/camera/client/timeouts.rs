@@ -0,0 +1,99 @@
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How much longer init step delays are with the slow camera profile
+const SLOW_CAMERA_FACTOR: u32 = 3;
+
+/// Per-operation request timeouts and init pacing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeoutConfig {
+    /// Timeout for control requests, in seconds
+    pub request_secs: u64,
+    /// Timeout for fetching the image list, in seconds
+    pub list_secs: u64,
+    /// Timeout for downloading image data, in seconds
+    pub download_secs: u64,
+    /// Timeout for downloading a whole movie, in seconds
+    pub movie_secs: u64,
+    /// Timeout for each live view initialization request, in seconds
+    pub liveview_init_secs: u64,
+    /// Longest wait for a shot to reach the card, covering long exposures and noise reduction, in seconds
+    pub capture_secs: u64,
+    /// Lengthen the delays between init steps for slow or flaky firmware
+    pub slow_camera: bool,
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            request_secs: 30,
+            list_secs: 30,
+            download_secs: 30,
+            movie_secs: 900,
+            liveview_init_secs: 30,
+            capture_secs: 150,
+            slow_camera: false,
+        }
+    }
+}
+
+impl TimeoutConfig {
+    /// Raise timeouts set to 0, which would fail every request at once, to one second
+    pub fn clamp_zero(&mut self) {
+        for (name, secs) in [
+            ("request_secs", &mut self.request_secs),
+            ("list_secs", &mut self.list_secs),
+            ("download_secs", &mut self.download_secs),
+            ("movie_secs", &mut self.movie_secs),
+            ("liveview_init_secs", &mut self.liveview_init_secs),
+            ("capture_secs", &mut self.capture_secs),
+        ] {
+            if *secs == 0 {
+                warn!("Timeout {} can't be 0, using 1 second", name);
+                *secs = 1;
+            }
+        }
+    }
+
+    /// Timeout for control requests
+    pub fn request(&self) -> Duration {
+        Duration::from_secs(self.request_secs)
+    }
+
+    /// Timeout for fetching the image list
+    pub fn list(&self) -> Duration {
+        Duration::from_secs(self.list_secs)
+    }
+
+    /// Timeout for downloading image data
+    pub fn download(&self) -> Duration {
+        Duration::from_secs(self.download_secs)
+    }
+
+    /// Timeout for downloading a whole movie
+    pub fn movie(&self) -> Duration {
+        Duration::from_secs(self.movie_secs)
+    }
+
+    /// Timeout for each live view initialization request
+    #[cfg(feature = "video")]
+    pub fn liveview_init(&self) -> Duration {
+        Duration::from_secs(self.liveview_init_secs)
+    }
+
+    /// Longest wait for a shot to reach the card
+    pub fn capture(&self) -> Duration {
+        Duration::from_secs(self.capture_secs)
+    }
+
+    /// Delay between init steps, stretched for the slow camera profile
+    pub fn step_delay(&self, base: Duration) -> Duration {
+        if self.slow_camera {
+            base * SLOW_CAMERA_FACTOR
+        } else {
+            base
+        }
+    }
+}
@@ -1,26 +1,56 @@
 use anyhow::{Result, anyhow};
 use log::{error, info};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::client::error::FailureKind;
+use crate::camera::connection::state::{CameraMode, CameraState, ConnectionState};
 
 /// Helper for camera connection management
 pub trait ConnectionManager: ClientOperations {
-    /// Get connection state
-    fn connected(&self) -> &Arc<AtomicBool>;
+    /// Get the shared connection state
+    fn connection(&self) -> &Arc<Mutex<ConnectionState>>;
+
+    /// Snapshot of the current connection state
+    fn connection_state(&self) -> ConnectionState {
+        self.connection()
+            .lock()
+            .map(|state| state.clone())
+            .unwrap_or(ConnectionState::Disconnected)
+    }
+
+    /// Move to a new connection state
+    fn set_connection_state(&self, new_state: ConnectionState) {
+        if let Ok(mut state) = self.connection().lock()
+            && *state != new_state
+        {
+            info!("Connection state: {:?} -> {:?}", *state, new_state);
+            *state = new_state;
+        }
+    }
+
+    /// Whether the camera is connected
+    fn is_connected(&self) -> bool {
+        self.connection_state().is_connected()
+    }
+
+    /// Read and decode what the camera reports about itself
+    fn get_state(&self) -> Result<CameraState> {
+        Ok(CameraState::parse(&self.get_text("get_state.cgi")?))
+    }
 
     /// Connect to camera with required initialization steps
     fn connect(&self) -> Result<()> {
         // If already connected, don't reconnect
-        if self.connected().load(Ordering::Relaxed) {
+        if self.is_connected() {
             info!("Camera already connected");
             return Ok(());
         }
 
         info!("Connecting to camera at {}", self.base_url());
+        self.set_connection_state(ConnectionState::Connecting);
 
         // More robust connection sequence with timeouts between steps
         let steps = [
@@ -33,8 +63,9 @@ pub trait ConnectionManager: ClientOperations {
         for (i, step) in steps.iter().enumerate() {
             info!("Connection step {}/{}: {}", i + 1, steps.len(), step);
 
-            // Try each step with multiple attempts
+            // Try each step with multiple attempts, as long as the failures are transient
             let mut success = false;
+            let mut failure = None;
             for attempt in 1..=3 {
                 info!("Attempt {} for step '{}'", attempt, step);
 
@@ -43,7 +74,9 @@ pub trait ConnectionManager: ClientOperations {
                         info!("✅ Step successful: {}", step);
                         success = true;
                         // Add increasing delay between successful steps
-                        let delay = Duration::from_millis(500 * (i as u64 + 1));
+                        let delay = self
+                            .timeouts()
+                            .step_delay(Duration::from_millis(500 * (i as u64 + 1)));
                         info!("Waiting {:?} before next step", delay);
                         thread::sleep(delay);
                         break;
@@ -53,10 +86,22 @@ pub trait ConnectionManager: ClientOperations {
                             "❌ Connection step '{}' failed (attempt {}/3): {}",
                             step, attempt, e
                         );
+                        let kind = FailureKind::classify(&e);
+                        failure = Some(kind);
+                        if !kind.is_transient() {
+                            info!("Not retrying step '{}': {}", step, kind.describe());
+                            break;
+                        }
+                        if self.camera_unresponsive() {
+                            info!("Not retrying step '{}': camera unresponsive", step);
+                            break;
+                        }
 
                         // Add backoff delay between attempts
                         if attempt < 3 {
-                            let delay = Duration::from_millis(500 * attempt as u64);
+                            let delay = self
+                                .timeouts()
+                                .step_delay(Duration::from_millis(500 * attempt as u64));
                             info!("Retrying in {:?}...", delay);
                             thread::sleep(delay);
                         }
@@ -69,28 +114,45 @@ pub trait ConnectionManager: ClientOperations {
                     "Failed to complete connection step '{}' after 3 attempts",
                     step
                 );
-                return Err(anyhow!(
-                    "Failed to connect: step '{}' failed after multiple attempts",
+                self.set_connection_state(ConnectionState::Error(format!(
+                    "step '{}' failed",
                     step
-                ));
+                )));
+                return Err(match failure {
+                    Some(kind) if !kind.is_transient() => anyhow!(
+                        "Failed to connect: step '{}' failed, {}",
+                        step,
+                        kind.describe()
+                    ),
+                    _ => anyhow!(
+                        "Failed to connect: step '{}' failed after multiple attempts",
+                        step
+                    ),
+                });
             }
         }
 
         // Add final delay after all steps complete
-        thread::sleep(Duration::from_secs(1));
+        thread::sleep(self.timeouts().step_delay(Duration::from_secs(1)));
 
         // Verify connection with a state check
         info!("Verifying camera connection with state check");
-        match self.get_page("get_state.cgi") {
-            Ok(_) => {
-                info!("✅ Connection verification successful");
-                // Mark as connected
-                self.connected().store(true, Ordering::Relaxed);
+        match self.get_state() {
+            Ok(state) => {
+                info!(
+                    "✅ Connection verification successful, camera mode {:?}",
+                    state.mode
+                );
+                // Mark as connected; the sequence leaves the camera in rec mode
+                self.set_connection_state(ConnectionState::Connected {
+                    mode: CameraMode::Rec,
+                });
                 info!("Camera connected successfully");
                 Ok(())
             }
             Err(e) => {
                 error!("❌ Connection verification failed: {}", e);
+                self.set_connection_state(ConnectionState::Error(e.to_string()));
                 Err(anyhow!("Failed to verify camera connection: {}", e))
             }
         }
@@ -1,5 +1,9 @@
 // Export connection submodules
 pub mod init;
+pub mod mode;
+pub mod state;
 
 // Re-export key components
 pub use init::ConnectionManager;
+pub use mode::ModeManager;
+pub use state::{CameraMode, CameraState, ConnectionState};
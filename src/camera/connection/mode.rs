@@ -0,0 +1,41 @@
+use anyhow::Result;
+use log::info;
+use std::thread;
+use std::time::Duration;
+
+use crate::camera::connection::init::ConnectionManager;
+use crate::camera::connection::state::{CameraMode, ConnectionState};
+
+/// Time the camera needs to settle after a mode switch
+const MODE_SWITCH_SETTLE: Duration = Duration::from_secs(1);
+
+/// Tracks whether the camera is in rec or play mode and switches only when needed
+pub trait ModeManager: ConnectionManager {
+    /// Mode the camera was last switched to, if connected
+    fn current_mode(&self) -> Option<CameraMode> {
+        self.connection_state().mode()
+    }
+
+    /// Record that the camera was switched to another mode
+    fn set_mode(&self, mode: CameraMode) {
+        if self.is_connected() {
+            self.set_connection_state(ConnectionState::Connected { mode });
+        }
+    }
+
+    /// Switch the camera to the given mode unless it is already in it
+    fn ensure_mode(&self, mode: CameraMode) -> Result<()> {
+        if self.current_mode() == Some(mode) {
+            info!("Camera already in {} mode", mode.as_param());
+            return Ok(());
+        }
+
+        info!("Switching camera to {} mode", mode.as_param());
+        self.get_page(&format!("switch_cameramode.cgi?mode={}", mode.as_param()))?;
+        self.set_mode(mode);
+
+        // Give camera time to change modes
+        thread::sleep(self.timeouts().step_delay(MODE_SWITCH_SETTLE));
+        Ok(())
+    }
+}
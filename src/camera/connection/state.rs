@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use crate::camera::events;
+
+/// Field of `get_state.cgi` holding the camera's rec/play mode
+const MODE_FIELD: &str = "cammode";
+
+/// Field of `get_state.cgi` holding the focal length of the lens, in millimetres
+const FOCAL_LENGTH_FIELD: &str = "focallength";
+
+/// Field of `get_state.cgi` saying whether the camera is taking a picture
+const SHOOTING_FIELD: &str = "takestatus";
+
+/// Field of `get_state.cgi` describing the memory card
+const CARD_FIELD: &str = "cardinfo";
+
+/// Operating mode the camera has been switched to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+    /// Recording mode, used for capture and live view
+    Rec,
+    /// Playback mode, used for browsing and deleting images
+    Play,
+}
+
+impl CameraMode {
+    /// Value of the `mode` parameter for `switch_cameramode.cgi`
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            CameraMode::Rec => "rec",
+            CameraMode::Play => "play",
+        }
+    }
+
+    /// Mode named by `get_state.cgi` or `switch_cameramode.cgi`, in any case
+    pub fn from_param(param: &str) -> Option<Self> {
+        match param.to_lowercase().as_str() {
+            "rec" => Some(CameraMode::Rec),
+            "play" => Some(CameraMode::Play),
+            _ => None,
+        }
+    }
+}
+
+/// Connection lifecycle of the camera, shared with the UI
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState {
+    /// No connection has been made yet
+    Disconnected,
+    /// The connection sequence is running
+    Connecting,
+    /// Connected, with the mode the camera was last switched to
+    Connected { mode: CameraMode },
+    /// The last connection attempt failed
+    Error(String),
+}
+
+impl ConnectionState {
+    /// Whether the camera is connected
+    pub fn is_connected(&self) -> bool {
+        matches!(self, ConnectionState::Connected { .. })
+    }
+
+    /// Current camera mode, if connected
+    pub fn mode(&self) -> Option<CameraMode> {
+        match self {
+            ConnectionState::Connected { mode } => Some(*mode),
+            _ => None,
+        }
+    }
+}
+
+/// What the camera reports in `get_state.cgi`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CameraState {
+    /// Rec or play mode, unless the camera reports another one such as `shutter`
+    pub mode: Option<CameraMode>,
+    /// Battery level as the camera words it, e.g. `normal` or `low`
+    pub battery: Option<String>,
+    /// Focal length of the mounted lens in millimetres
+    pub focal_length: Option<f32>,
+    /// Shooting status as the camera words it, e.g. `idle`
+    pub shooting: Option<String>,
+    /// Memory card status as the camera words it
+    pub card: Option<String>,
+    /// Every field of the response by lowercase name, including those above
+    pub fields: BTreeMap<String, String>,
+}
+
+impl CameraState {
+    /// Decode a `get_state.cgi` response, leaving out fields it doesn't have
+    pub fn parse(text: &str) -> Self {
+        let fields = events::parse_state(text);
+        let field = |name: &str| fields.get(name).filter(|value| !value.is_empty()).cloned();
+
+        Self {
+            mode: field(MODE_FIELD).and_then(|mode| CameraMode::from_param(&mode)),
+            // Firmware versions name it `battery` or `batterylevel`
+            battery: fields
+                .iter()
+                .find(|(name, _)| name.contains("battery"))
+                .map(|(_, value)| value.clone()),
+            focal_length: field(FOCAL_LENGTH_FIELD).and_then(|length| {
+                length
+                    .trim_end_matches(|c: char| c.is_alphabetic() || c.is_whitespace())
+                    .parse()
+                    .ok()
+            }),
+            shooting: field(SHOOTING_FIELD),
+            card: field(CARD_FIELD),
+            fields,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_fields_are_decoded() {
+        let state = CameraState::parse(
+            "<state><cammode>rec</cammode><batterylevel>normal</batterylevel>\
+             <focallength>14mm</focallength><takestatus>idle</takestatus>\
+             <cardinfo>42 files</cardinfo></state>",
+        );
+        assert_eq!(state.mode, Some(CameraMode::Rec));
+        assert_eq!(state.battery.as_deref(), Some("normal"));
+        assert_eq!(state.focal_length, Some(14.0));
+        assert_eq!(state.shooting.as_deref(), Some("idle"));
+        assert_eq!(state.card.as_deref(), Some("42 files"));
+        assert_eq!(state.fields.len(), 5);
+    }
+
+    #[test]
+    fn missing_and_unknown_values_are_left_out() {
+        let state = CameraState::parse("<state><cammode>shutter</cammode></state>");
+        assert_eq!(state.mode, None);
+        assert_eq!(state.focal_length, None);
+        assert_eq!(
+            state.fields.get("cammode").map(String::as_str),
+            Some("shutter")
+        );
+    }
+}
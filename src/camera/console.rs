@@ -0,0 +1,180 @@
+use anyhow::Result;
+use log::{info, warn};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::camera::client::basic::ClientOperations;
+use crate::utils::audit::{self, AuditAction};
+use crate::utils::diff::{self, Change};
+use crate::utils::paths::{self, DataDir};
+use crate::utils::{atomic, session};
+
+/// Transcript of console commands and responses, in the logs directory
+pub const CONSOLE_FILE: &str = "console.txt";
+
+/// One command sent from the console and what came back
+#[derive(Debug, Clone)]
+pub struct ConsoleEntry {
+    /// Request as sent, relative to the camera URL
+    pub request: String,
+    /// When it was sent, in Unix seconds
+    pub time: u64,
+    /// HTTP status, if the camera answered
+    pub status: Option<u16>,
+    /// Time until the whole response arrived, in milliseconds
+    pub duration_ms: u128,
+    /// Response body as text, or what happened instead
+    pub response: String,
+    /// File a binary response was saved to
+    pub saved: Option<PathBuf>,
+}
+
+impl ConsoleEntry {
+    /// Whether the camera accepted the command
+    pub fn succeeded(&self) -> bool {
+        self.status
+            .is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// Response split into lines for comparing, with each XML element on a line of its own
+///
+/// The camera answers most requests with XML on a single line, which would otherwise
+/// show up as one changed line however little differs.
+pub fn comparable_lines(response: &str) -> Vec<String> {
+    response
+        .replace("><", ">\n<")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Line changes from an earlier response to a later one, or `None` if they are too long to compare
+pub fn compare(earlier: &ConsoleEntry, later: &ConsoleEntry) -> Option<Vec<(Change, String)>> {
+    let before = comparable_lines(&earlier.response);
+    let after = comparable_lines(&later.response);
+    let before: Vec<&str> = before.iter().map(String::as_str).collect();
+    let after: Vec<&str> = after.iter().map(String::as_str).collect();
+    let lines = diff::diff_lines(&before, &after)?;
+    Some(
+        lines
+            .into_iter()
+            .map(|(change, line)| (change, line.to_string()))
+            .collect(),
+    )
+}
+
+/// Whether a request may change the camera, going by the camera's naming of commands
+///
+/// `get_` commands and plain paths such as `DCIM` only read; `exec_`, `set_`, `switch_`
+/// and anything else ending in `.cgi` is treated as a change.
+pub fn changes_camera(request: &str) -> bool {
+    let command = request.split('?').next().unwrap_or_default();
+    command.ends_with(".cgi") && !command.starts_with("get_")
+}
+
+/// Sending hand-typed requests, for trying out undocumented commands
+pub trait CommandConsole: ClientOperations {
+    /// Send `request` as typed and save the response, noting a working command in the capability cache
+    fn run_command(&self, request: &str) -> ConsoleEntry {
+        let request = request.trim().trim_start_matches('/').to_string();
+        let changes = changes_camera(&request);
+        let started = Instant::now();
+
+        let result = if changes {
+            self.ensure_writable("Console commands that change the camera")
+        } else {
+            Ok(())
+        }
+        .and_then(|_| {
+            info!("Console request: {}", request);
+            let response = self.send(
+                self.client()
+                    .get(format!("{}{}", self.base_url(), request))
+                    .timeout(self.timeouts().request())
+                    .header("user-agent", "OlympusCameraKit"),
+            )?;
+            let status = response.status().as_u16();
+            Ok((status, response.bytes()?.to_vec()))
+        });
+        if changes {
+            audit::record(AuditAction::Command, &request, &result);
+        }
+
+        let mut entry = ConsoleEntry {
+            request,
+            time: session::unix_now(),
+            status: None,
+            duration_ms: started.elapsed().as_millis(),
+            response: String::new(),
+            saved: None,
+        };
+        match result {
+            Ok((status, body)) => {
+                entry.status = Some(status);
+                match String::from_utf8(body) {
+                    Ok(text) if !text.contains('\0') => entry.response = text,
+                    Ok(text) => save_binary(&mut entry, text.as_bytes()),
+                    Err(e) => save_binary(&mut entry, e.as_bytes()),
+                }
+            }
+            Err(e) => entry.response = e.to_string(),
+        }
+
+        if entry.succeeded() {
+            self.capabilities()
+                .lock()
+                .unwrap()
+                .record_endpoint(&entry.request, true);
+        }
+        if let Err(e) = append_transcript(&entry) {
+            warn!("Failed to write the console transcript: {}", e);
+        }
+        entry
+    }
+}
+
+/// Save a binary response next to the transcript and note where it went
+fn save_binary(entry: &mut ConsoleEntry, body: &[u8]) {
+    let path = paths::dir(DataDir::Logs).join(format!("console_{}_{}.bin", entry.time, body.len()));
+    match atomic::write_atomic(&path, body) {
+        Ok(()) => {
+            entry.response = format!("{} bytes of binary data", body.len());
+            entry.saved = Some(path);
+        }
+        Err(e) => {
+            entry.response = format!("{} bytes of binary data, not saved: {}", body.len(), e);
+        }
+    }
+}
+
+/// Add an entry to the console transcript
+fn append_transcript(entry: &ConsoleEntry) -> Result<()> {
+    let dir = paths::dir(DataDir::Logs);
+    fs::create_dir_all(&dir)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(CONSOLE_FILE))?;
+
+    let status = entry
+        .status
+        .map_or_else(|| "no answer".to_string(), |status| status.to_string());
+    writeln!(
+        file,
+        "{}  {}  {}  {} ms",
+        session::datetime(entry.time),
+        entry.request,
+        status,
+        entry.duration_ms
+    )?;
+    match &entry.saved {
+        Some(path) => writeln!(file, "{} ({})\n", entry.response, path.display())?,
+        None => writeln!(file, "{}\n", entry.response.trim_end())?,
+    }
+    Ok(())
+}
@@ -0,0 +1,588 @@
+use anyhow::{Context, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::{ImageBuffer, Rgb, RgbImage};
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::camera::image::list::{DCIM_FOLDER, DEFAULT_FOLDER};
+
+/// Photos on the fake camera's card when it starts
+const SAMPLE_IMAGES: usize = 42;
+
+/// Second folder on the card, so paging from folder to folder can be shown
+const SECOND_FOLDER: &str = "/DCIM/101OLYMP";
+
+/// Photos in the second folder
+const SECOND_FOLDER_IMAGES: usize = 5;
+
+/// Size of generated thumbnails
+const THUMBNAIL_SIZE: (u32, u32) = (160, 120);
+
+/// Size of generated full images
+const FULL_SIZE: (u32, u32) = (1600, 1200);
+
+/// Size and rate of the synthetic live view
+const LIVEVIEW_SIZE: (u32, u32) = (640, 480);
+const LIVEVIEW_FPS: u64 = 15;
+
+/// Largest RTP payload sent per live view packet
+const RTP_PAYLOAD: usize = 1400;
+
+/// Values the fake camera lists for the properties it describes
+const PROPERTY_CHOICES: [(&str, &str); 5] = [
+    ("ISOSPEEDVALUE", "Auto Low 200 400 800 1600 3200 6400 12800"),
+    (
+        "SHUTSPEEDVALUE",
+        "60\" 30\" 15\" 8\" 4\" 2\" 1\" 2 4 8 15 30 60 125 250 500 1000 2000 4000",
+    ),
+    (
+        "APERTUREVALUE",
+        "3.5 4.0 4.5 5.0 5.6 6.3 7.1 8.0 9.0 10 11 13 14 16 18 22",
+    ),
+    (
+        "EXPREV",
+        "-3.0 -2.7 -2.3 -2.0 -1.7 -1.3 -1.0 -0.7 -0.3 0.0 +0.3 +0.7 +1.0 +1.3 +1.7 +2.0 +2.3 +2.7 +3.0",
+    ),
+    (
+        "WBVALUE",
+        "WB_AUTO MWB_FINE MWB_SHADE MWB_CLOUD MWB_LAMP MWB_FLUORESCENCE1 MWB_WATER_1",
+    ),
+];
+
+/// Capability list of the fake camera, with the commands it answers
+const CAPLIST: &str = concat!(
+    "<oishare><version>4.20</version>",
+    "<support func=\"web\"/><support func=\"remote\"/>",
+    "<cgi name=\"get_connectmode\"/><cgi name=\"switch_connectmode\"/>",
+    "<cgi name=\"get_caminfo\"/><cgi name=\"get_state\"/>",
+    "<cgi name=\"switch_cameramode\"><http_method type=\"get\"><cmd1 name=\"mode\">",
+    "<param1 name=\"rec\"><cmd2 name=\"lvqty\">",
+    "<param2 name=\"0320x0240\"/><param2 name=\"0640x0480\"/>",
+    "</cmd2></param1><param1 name=\"play\"/></cmd1></http_method></cgi>",
+    "<cgi name=\"get_imglist\"/><cgi name=\"get_thumbnail\"/>",
+    "<cgi name=\"get_resized_img\"/><cgi name=\"get_img\"/>",
+    "<cgi name=\"exec_takemotion\"/><cgi name=\"exec_takemisc\"/><cgi name=\"exec_erase\"/>",
+    "<cgi name=\"get_camprop\"><http_method type=\"get\"><cmd1 name=\"com\">",
+    "<param1 name=\"desc\"><cmd2 name=\"propname\"><param2 name=\"desclist\"/>",
+    "<param2 name=\"isospeedvalue\"/><param2 name=\"shutspeedvalue\"/>",
+    "<param2 name=\"aperturevalue\"/><param2 name=\"wbvalue\"/>",
+    "<param2 name=\"exprev\"/></cmd2></param1>",
+    "</cmd1></http_method></cgi><cgi name=\"set_camprop\"/>",
+    "</oishare>"
+);
+
+/// What the fake camera keeps between requests
+#[derive(Default)]
+struct DemoState {
+    /// Files in the default folder, with their sizes
+    images: Vec<(String, u64)>,
+    /// Files in the second folder, with their sizes
+    second_folder: Vec<(String, u64)>,
+    /// Number of the next photo taken
+    next_number: usize,
+    mode: String,
+    connect_mode: String,
+    properties: HashMap<String, String>,
+    /// Running live view stream, stopped by clearing the flag
+    liveview: Option<Arc<AtomicBool>>,
+    /// Whether a movie is being recorded
+    recording: bool,
+}
+
+impl DemoState {
+    fn new() -> Self {
+        let mut state = Self {
+            mode: "rec".to_string(),
+            connect_mode: "OPC".to_string(),
+            next_number: 1,
+            ..Self::default()
+        };
+        for _ in 0..SAMPLE_IMAGES {
+            state.add_photo();
+        }
+        // A raw pair and a movie so every kind of file shows up in the list
+        state.images.push(("P1010001.ORF".to_string(), 15_728_640));
+        state.images.push(("P1010900.MOV".to_string(), 52_428_800));
+        state.images.sort();
+        state.second_folder = (1..=SECOND_FOLDER_IMAGES)
+            .map(|number| (format!("P1020{:03}.JPG", number), 4_194_304))
+            .collect();
+
+        for (property, value) in [
+            ("TAKEMODE", "P"),
+            ("ISOSPEEDVALUE", "Auto"),
+            ("SHUTSPEEDVALUE", "250"),
+            ("APERTUREVALUE", "5.6"),
+            ("EXPREV", "0.0"),
+            ("WBVALUE", "WB_AUTO"),
+            ("FOCUS_STILL", "FOCUS_SAF"),
+        ] {
+            state
+                .properties
+                .insert(property.to_string(), value.to_string());
+        }
+        state
+    }
+
+    /// Put a new photo on the card, returning its name
+    fn add_photo(&mut self) -> String {
+        let name = format!("P101{:04}.JPG", self.next_number);
+        self.next_number += 1;
+        self.images.push((name.clone(), 4_194_304));
+        name
+    }
+
+    /// Put a new movie on the card, returning its name
+    fn add_movie(&mut self) -> String {
+        let name = format!("P101{:04}.MOV", self.next_number);
+        self.next_number += 1;
+        self.images.push((name.clone(), 52_428_800));
+        name
+    }
+}
+
+/// Fake Olympus Air served over HTTP on the loopback interface
+///
+/// It answers the same CGI requests as the camera with a sample card,
+/// generated images and a synthetic live view, so the interface can be shown
+/// and worked on without hardware.
+pub struct DemoCamera {
+    address: SocketAddr,
+}
+
+impl DemoCamera {
+    /// Start serving on a free local port
+    pub fn start() -> Result<Self> {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").context("Failed to start the demo camera")?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(DemoState::new()));
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        let state = Arc::clone(&state);
+                        thread::spawn(move || {
+                            if let Err(e) = serve(stream, &state) {
+                                debug!("Demo camera request failed: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => warn!("Demo camera connection failed: {}", e),
+                }
+            }
+        });
+
+        info!("Demo camera listening on {}", address);
+        Ok(Self { address })
+    }
+
+    /// URL to configure as the camera URL
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+}
+
+/// A response to send back
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    fn ok() -> Self {
+        Self::text("")
+    }
+
+    fn text(text: &str) -> Self {
+        Self {
+            status: 200,
+            content_type: "text/plain",
+            body: text.as_bytes().to_vec(),
+        }
+    }
+
+    fn xml(xml: &str) -> Self {
+        Self {
+            content_type: "text/xml",
+            ..Self::text(&format!("<?xml version=\"1.0\"?>\r\n{}", xml))
+        }
+    }
+
+    fn jpeg(body: Vec<u8>) -> Self {
+        Self {
+            status: 200,
+            content_type: "image/jpeg",
+            body,
+        }
+    }
+
+    fn not_found() -> Self {
+        Self {
+            status: 404,
+            ..Self::text("")
+        }
+    }
+}
+
+/// Answer one request, closing the connection afterwards
+fn serve(stream: TcpStream, state: &Mutex<DemoState>) -> Result<()> {
+    let peer = stream.peer_addr()?.ip();
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    // GET requests to the camera carry a content length without a body, so only POST reads one
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        let header = line.split_once(':');
+        if let Some((_, value)) =
+            header.filter(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    let mut body = vec![0; if method == "POST" { content_length } else { 0 }];
+    reader.read_exact(&mut body)?;
+
+    let reply = answer(state, &target, &String::from_utf8_lossy(&body), peer);
+    debug!("Demo camera: {} {} -> {}", method, target, reply.status);
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        reply.status,
+        if reply.status == 200 {
+            "OK"
+        } else {
+            "Not Found"
+        },
+        reply.content_type,
+        reply.body.len()
+    )?;
+    stream.write_all(&reply.body)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Parameters of a request target
+fn query(target: &str) -> (&str, HashMap<&str, &str>) {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let params = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .collect();
+    (path.trim_start_matches('/'), params)
+}
+
+/// Response of the fake camera to a request
+fn answer(state: &Mutex<DemoState>, target: &str, body: &str, peer: IpAddr) -> Reply {
+    let (path, params) = query(target);
+    let mut state = state.lock().unwrap();
+
+    // Images by path, as in `DCIM/100OLYMP/P1010001.JPG`
+    if let Some(name) = path
+        .strip_prefix("DCIM/")
+        .and_then(|rest| rest.split_once('/'))
+        .map(|(_, name)| name)
+    {
+        return image_reply(&state, name, FULL_SIZE);
+    }
+
+    match path {
+        "get_connectmode.cgi" => Reply::xml(&format!(
+            "<connectmode>{}</connectmode>",
+            state.connect_mode
+        )),
+        "switch_connectmode.cgi" => {
+            if let Some(mode) = params.get("mode") {
+                state.connect_mode = mode.to_string();
+            }
+            Reply::ok()
+        }
+        "get_caplist.cgi" => Reply::xml(CAPLIST),
+        "get_caminfo.cgi" => Reply::xml("<caminfo><model>AIR-A01 (demo)</model></caminfo>"),
+        "switch_cameramode.cgi" => {
+            if let Some(mode) = params.get("mode") {
+                state.mode = mode.to_string();
+            }
+            Reply::ok()
+        }
+        "get_state.cgi" => Reply::xml(&format!(
+            "<state><cammode>{}</cammode><battery>normal</battery><focallength>14</focallength><takestatus>{}</takestatus><cardinfo>{} files</cardinfo></state>",
+            state.mode,
+            if state.recording { "recording" } else { "idle" },
+            state.images.len()
+        )),
+        "get_imglist.cgi" => image_list(&state, params.get("DIR").copied().unwrap_or("")),
+        "get_thumbnail.cgi" => file_param(&params).map_or_else(Reply::not_found, |name| {
+            image_reply(&state, name, THUMBNAIL_SIZE)
+        }),
+        "get_resized_img.cgi" => {
+            let width = params
+                .get("size")
+                .and_then(|size| size.parse().ok())
+                .unwrap_or(1024);
+            file_param(&params).map_or_else(Reply::not_found, |name| {
+                image_reply(&state, name, (width, width * 3 / 4))
+            })
+        }
+        "get_img.cgi" => file_param(&params).map_or_else(Reply::not_found, |name| {
+            image_reply(&state, name, FULL_SIZE)
+        }),
+        "exec_takemotion.cgi" => {
+            let movie_mode = state.properties.get("TAKEMODE").map(String::as_str) == Some("movie");
+            match params.get("com").copied() {
+                Some("starttake") if movie_mode => {
+                    state.recording = true;
+                    info!("Demo camera started recording");
+                }
+                Some("stoptake") if state.recording => {
+                    state.recording = false;
+                    let name = state.add_movie();
+                    info!("Demo camera recorded {}", name);
+                }
+                _ => {
+                    let name = state.add_photo();
+                    info!("Demo camera took {}", name);
+                }
+            }
+            Reply::ok()
+        }
+        "exec_erase.cgi" => match file_param(&params) {
+            Some(name) => {
+                state.images.retain(|(image, _)| image != name);
+                state.second_folder.retain(|(image, _)| image != name);
+                Reply::ok()
+            }
+            None => Reply::not_found(),
+        },
+        "exec_takemisc.cgi" => match params.get("com").copied() {
+            Some("startliveview") => {
+                let port = params
+                    .get("port")
+                    .and_then(|port| port.parse().ok())
+                    .unwrap_or(5555);
+                start_liveview(&mut state, SocketAddr::new(peer, port));
+                Reply::ok()
+            }
+            Some("stopliveview") => {
+                if let Some(running) = state.liveview.take() {
+                    running.store(false, Ordering::Relaxed);
+                }
+                Reply::ok()
+            }
+            Some("getrecview") | Some("getlastjpg") => {
+                match state
+                    .images
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| name.ends_with(".JPG"))
+                {
+                    Some((name, _)) => Reply::jpeg(render(name, FULL_SIZE)),
+                    None => Reply::not_found(),
+                }
+            }
+            Some(_) => Reply::ok(),
+            None => Reply::not_found(),
+        },
+        "get_camprop.cgi" => {
+            let property = params.get("propname").copied().unwrap_or("");
+            let choices = PROPERTY_CHOICES
+                .iter()
+                .find(|(name, _)| *name == property)
+                .map(|(_, choices)| *choices);
+            match (params.get("com").copied(), state.properties.get(property)) {
+                (Some("desc"), Some(value)) => match choices {
+                    Some(choices) => Reply::xml(&format!(
+                        "<desc><propname>{}</propname><attribute>getset</attribute><value>{}</value><enum>{}</enum></desc>",
+                        property, value, choices
+                    )),
+                    None => Reply::not_found(),
+                },
+                (_, Some(value)) => Reply::xml(&format!("<get><value>{}</value></get>", value)),
+                (_, None) => Reply::not_found(),
+            }
+        }
+        "set_camprop.cgi" => {
+            let property = params.get("propname").copied().unwrap_or("").to_string();
+            let value = body
+                .split_once("<value>")
+                .and_then(|(_, rest)| rest.split_once("</value>"))
+                .map(|(value, _)| value.trim().to_string());
+            match value {
+                Some(value) => {
+                    state.properties.insert(property, value);
+                    Reply::ok()
+                }
+                None => Reply::not_found(),
+            }
+        }
+        _ => Reply::not_found(),
+    }
+}
+
+/// File name of an image request, from `FILE` or a `DIR` or `PATH` ending in it
+fn file_param<'a>(params: &HashMap<&str, &'a str>) -> Option<&'a str> {
+    params
+        .get("FILE")
+        .or_else(|| params.get("PATH"))
+        .or_else(|| params.get("DIR"))
+        .map(|value| value.rsplit('/').next().unwrap_or(value))
+}
+
+/// `get_imglist.cgi` listing of a folder
+fn image_list(state: &DemoState, folder: &str) -> Reply {
+    let mut listing = String::from("VER_100\r\n");
+    let files = if folder.eq_ignore_ascii_case(DEFAULT_FOLDER) {
+        &state.images
+    } else if folder.eq_ignore_ascii_case(SECOND_FOLDER) {
+        &state.second_folder
+    } else if folder.eq_ignore_ascii_case(DCIM_FOLDER) {
+        listing.push_str("/DCIM,100OLYMP,0,16,20481,0\r\n");
+        listing.push_str("/DCIM,101OLYMP,0,16,20481,0\r\n");
+        return Reply::text(&listing);
+    } else {
+        return Reply::not_found();
+    };
+    for (name, size) in files {
+        listing.push_str(&format!("{},{},{},0,20481,{}\r\n", folder, name, size, 0));
+    }
+    Reply::text(&listing)
+}
+
+/// A generated picture of an image on the card, if it is there
+fn image_reply(state: &DemoState, name: &str, size: (u32, u32)) -> Reply {
+    let on_card = |(image, _): &(String, u64)| image == name;
+    if state.images.iter().any(on_card) || state.second_folder.iter().any(on_card) {
+        Reply::jpeg(render(name, size))
+    } else {
+        Reply::not_found()
+    }
+}
+
+/// Draw a picture unique to `seed`: a colour gradient with a diagonal band
+fn render(seed: &str, (width, height): (u32, u32)) -> Vec<u8> {
+    let hash = seed.bytes().fold(2_166_136_261u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(16_777_619)
+    });
+    let [r, g, b, _] = hash.to_le_bytes();
+
+    let image: RgbImage = ImageBuffer::from_fn(width, height, |x, y| {
+        let fx = x * 255 / width.max(1);
+        let fy = y * 255 / height.max(1);
+        let band = (x + y + hash % 97) % (width / 4).max(1) < width / 16;
+        if band {
+            Rgb([240, 240, 240])
+        } else {
+            Rgb([
+                ((u32::from(r) + fx) / 2) as u8,
+                ((u32::from(g) + fy) / 2) as u8,
+                ((u32::from(b) + 255 - fx) / 2) as u8,
+            ])
+        }
+    });
+    encode(&image)
+}
+
+fn encode(image: &RgbImage) -> Vec<u8> {
+    let mut data = Vec::new();
+    if let Err(e) = JpegEncoder::new_with_quality(&mut data, 80).encode_image(image) {
+        warn!("Demo camera failed to encode an image: {}", e);
+    }
+    data
+}
+
+/// Stream synthetic frames to `target` the way the camera does, until stopped
+fn start_liveview(state: &mut DemoState, target: SocketAddr) {
+    if let Some(running) = state.liveview.take() {
+        running.store(false, Ordering::Relaxed);
+    }
+    let running = Arc::new(AtomicBool::new(true));
+    state.liveview = Some(Arc::clone(&running));
+
+    thread::spawn(move || {
+        let socket = match UdpSocket::bind("127.0.0.1:0") {
+            Ok(socket) => socket,
+            Err(e) => {
+                warn!("Demo camera can't send live view: {}", e);
+                return;
+            }
+        };
+        info!("Demo camera streaming live view to {}", target);
+
+        let mut sequence: u16 = 0;
+        let mut frame: u32 = 0;
+        while running.load(Ordering::Relaxed) {
+            let jpeg = liveview_frame(frame);
+            for packet in rtp_packets(&jpeg, frame, &mut sequence) {
+                // Nobody listening yet is fine, the camera doesn't care either
+                let _ = socket.send_to(&packet, target);
+            }
+            frame = frame.wrapping_add(1);
+            thread::sleep(Duration::from_millis(1000 / LIVEVIEW_FPS));
+        }
+        info!("Demo camera stopped live view");
+    });
+}
+
+/// A frame with a bar sweeping across, so motion and stalls are visible
+fn liveview_frame(frame: u32) -> Vec<u8> {
+    let (width, height) = LIVEVIEW_SIZE;
+    let bar = (frame * 8) % width;
+    let image: RgbImage = ImageBuffer::from_fn(width, height, |x, y| {
+        if x.abs_diff(bar) < 12 {
+            Rgb([255, 200, 0])
+        } else {
+            Rgb([(x * 255 / width) as u8, (y * 255 / height) as u8, 96])
+        }
+    });
+    encode(&image)
+}
+
+/// Split a JPEG into the camera's RTP packets: the first with a header
+/// extension, the last with the marker bit, all sharing the frame number
+fn rtp_packets(jpeg: &[u8], frame: u32, sequence: &mut u16) -> Vec<Vec<u8>> {
+    // The receiver expects at least a first and a last packet
+    let chunk = RTP_PAYLOAD.min(jpeg.len().div_ceil(2)).max(1);
+    let chunks: Vec<&[u8]> = jpeg.chunks(chunk).collect();
+
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(i, payload)| {
+            let first = i == 0;
+            let last = i == chunks.len() - 1;
+            let mut packet = Vec::with_capacity(payload.len() + 16);
+            packet.push(if first { 0x90 } else { 0x80 });
+            packet.push(if last { 0x80 | 96 } else { 96 });
+            packet.extend_from_slice(&sequence.to_be_bytes());
+            packet.extend_from_slice(&frame.to_be_bytes());
+            packet.extend_from_slice(&0u32.to_be_bytes());
+            if first {
+                // Extension header without any extension words
+                packet.extend_from_slice(&[0, 0, 0, 0]);
+            }
+            packet.extend_from_slice(payload);
+            *sequence = sequence.wrapping_add(1);
+            packet
+        })
+        .collect()
+}
@@ -0,0 +1,235 @@
+use log::{debug, info, warn};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::connection::{CameraMode, CameraState, ConnectionManager, ModeManager};
+use crate::camera::image::list::ImageLister;
+use crate::camera::olympus::OlympusCamera;
+
+/// Changes noticed on the camera itself
+#[derive(Debug, Clone, PartialEq)]
+pub enum CameraEvent {
+    /// Images appeared on the card, e.g. from the physical shutter
+    NewImages(Vec<String>),
+    /// A field reported by `get_state.cgi`, such as the mode dial, changed
+    StateChanged { key: String, value: String },
+    /// The battery level reported by the camera changed
+    Battery(String),
+    /// The camera switched modes without us asking, so another app is driving it
+    ExternalControl(CameraMode),
+}
+
+/// Camera event polling settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventConfig {
+    /// Whether to poll the camera for changes
+    pub enabled: bool,
+    /// Seconds between polls
+    pub interval_secs: u64,
+}
+
+impl Default for EventConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 5,
+        }
+    }
+}
+
+/// Polls the camera in the background and queues events for the UI
+pub struct EventPoller {
+    receiver: Receiver<CameraEvent>,
+    paused: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+}
+
+impl EventPoller {
+    /// Start polling with the images already known to the app as baseline
+    pub fn start(camera: OlympusCamera, config: &EventConfig, known_images: &[String]) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let paused = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let interval = Duration::from_secs(config.interval_secs.max(1));
+        let known: HashSet<String> = known_images.iter().cloned().collect();
+        let thread_paused = Arc::clone(&paused);
+        let thread_running = Arc::clone(&running);
+
+        thread::spawn(move || {
+            poll_camera(
+                camera,
+                interval,
+                known,
+                sender,
+                thread_paused,
+                thread_running,
+            )
+        });
+
+        info!("Camera event polling started every {:?}", interval);
+        Self {
+            receiver,
+            paused,
+            running,
+        }
+    }
+
+    /// Suspend polling while another operation needs the camera to itself
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// Take all events queued since the last call
+    pub fn drain(&self) -> Vec<CameraEvent> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+impl Drop for EventPoller {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Poll loop run on the background thread
+fn poll_camera(
+    camera: OlympusCamera,
+    interval: Duration,
+    mut known_images: HashSet<String>,
+    sender: Sender<CameraEvent>,
+    paused: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+) {
+    let mut known_folder = camera.image_folder();
+    let mut last_state: Option<CameraState> = None;
+    let mut mode_mismatches = 0;
+    let mut last_poll = Instant::now();
+
+    while running.load(Ordering::Relaxed) {
+        // Sleep in short steps so stopping the poller is quick
+        thread::sleep(Duration::from_millis(200));
+        if paused.load(Ordering::Relaxed) || last_poll.elapsed() < interval {
+            continue;
+        }
+        // Leave a camera that stopped answering alone until its cool-down is over
+        if camera.camera_unresponsive() {
+            continue;
+        }
+        last_poll = Instant::now();
+
+        let mut events = Vec::new();
+
+        // Images of a folder opened since the last poll aren't new
+        let folder = camera.image_folder();
+        match camera.list_folder(&folder) {
+            Ok((_, images)) => {
+                let new_images: Vec<String> = images
+                    .iter()
+                    .filter(|image| !known_images.contains(*image))
+                    .cloned()
+                    .collect();
+                if folder == known_folder && !new_images.is_empty() {
+                    events.push(CameraEvent::NewImages(new_images));
+                }
+                known_folder = folder;
+                known_images = images.into_iter().collect();
+            }
+            Err(e) => debug!("Event poll: image list failed: {}", e),
+        }
+
+        match camera.get_state() {
+            Ok(state) => {
+                events.extend(state_events(
+                    last_state.as_ref().map(|last| &last.fields),
+                    &state.fields,
+                ));
+
+                // Only a mismatch seen twice in a row counts, so our own mode switches can finish
+                match foreign_mode(&state, camera.current_mode()) {
+                    Some(mode) => {
+                        mode_mismatches += 1;
+                        if mode_mismatches == 2 {
+                            events.push(CameraEvent::ExternalControl(mode));
+                        }
+                    }
+                    None => mode_mismatches = 0,
+                }
+                last_state = Some(state);
+            }
+            Err(e) => debug!("Event poll: state request failed: {}", e),
+        }
+
+        for event in events {
+            info!("Camera event: {:?}", event);
+            if sender.send(event).is_err() {
+                return;
+            }
+        }
+    }
+
+    info!("Camera event polling stopped");
+}
+
+/// Pattern matching a leaf element, built on first use
+static STATE_PATTERN: OnceLock<Option<Regex>> = OnceLock::new();
+
+/// Extract the leaf elements of a `get_state.cgi` XML response
+pub fn parse_state(text: &str) -> BTreeMap<String, String> {
+    let pattern = STATE_PATTERN.get_or_init(|| match Regex::new(r"<(\w+)>([^<]*)</\w+>") {
+        Ok(re) => Some(re),
+        Err(e) => {
+            warn!("Failed to build state pattern: {}", e);
+            None
+        }
+    });
+    let Some(re) = pattern else {
+        return BTreeMap::new();
+    };
+
+    re.captures_iter(text)
+        .map(|caps| (caps[1].to_lowercase(), caps[2].trim().to_string()))
+        .collect()
+}
+
+/// Mode the camera reports, if it differs from the one it was last switched to
+fn foreign_mode(state: &CameraState, tracked: Option<CameraMode>) -> Option<CameraMode> {
+    let tracked = tracked?;
+    let reported = state.mode?;
+    (reported != tracked).then_some(reported)
+}
+
+/// Compare two state snapshots and describe what changed
+fn state_events(
+    previous: Option<&BTreeMap<String, String>>,
+    current: &BTreeMap<String, String>,
+) -> Vec<CameraEvent> {
+    let mut events = Vec::new();
+
+    for (key, value) in current {
+        let old = previous.and_then(|previous| previous.get(key));
+        if old == Some(value) {
+            continue;
+        }
+
+        if key.contains("battery") {
+            // Battery is reported from the first poll so the panel can show it
+            events.push(CameraEvent::Battery(value.clone()));
+        } else if previous.is_some() {
+            events.push(CameraEvent::StateChanged {
+                key: key.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    events
+}
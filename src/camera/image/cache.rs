@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::camera::image::list::DEFAULT_FOLDER;
+use crate::utils::atomic;
+
+/// Name of the cached image list in the cache directory
+pub const IMAGE_LIST_CACHE_FILE: &str = "image_list.json";
+
+/// Images added and removed between two listings
+#[derive(Debug, Clone, Default)]
+pub struct ListChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl ListChanges {
+    /// Whether the listing is the same as before
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Last image listing, kept so a refresh only has to work out what changed
+///
+/// Only the open folder is held, so memory use doesn't grow with the card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageListCache {
+    /// Folder the images are in
+    #[serde(default = "default_folder")]
+    folder: String,
+    /// Hash of the raw response the images were parsed from
+    response_hash: u64,
+    images: Vec<String>,
+}
+
+impl Default for ImageListCache {
+    fn default() -> Self {
+        Self {
+            folder: default_folder(),
+            response_hash: 0,
+            images: Vec::new(),
+        }
+    }
+}
+
+fn default_folder() -> String {
+    DEFAULT_FOLDER.to_string()
+}
+
+impl ImageListCache {
+    /// Load the cache saved by a previous session, or start empty
+    pub fn load(path: &Path) -> Self {
+        let Ok(text) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+        match serde_json::from_str::<Self>(&text) {
+            Ok(cache) => {
+                info!(
+                    "Loaded {} cached images from {:?}",
+                    cache.images.len(),
+                    path
+                );
+                cache
+            }
+            Err(e) => {
+                warn!("Ignoring invalid image list cache {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Save the cache for the next session
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string(self)?;
+        atomic::write_atomic(path, text.as_bytes())
+            .with_context(|| format!("Failed to save image list cache {:?}", path))
+    }
+
+    /// Folder the cached images are in
+    pub fn folder(&self) -> &str {
+        &self.folder
+    }
+
+    /// Cached image names, sorted
+    pub fn images(&self) -> &[String] {
+        &self.images
+    }
+
+    /// Hold the listing of another folder instead, without comparing it to the old one
+    pub fn replace(&mut self, folder: &str, response_hash: u64, images: Vec<String>) {
+        self.folder = folder.to_string();
+        self.response_hash = response_hash;
+        self.images = images;
+    }
+
+    /// Replace the listing with a newly fetched one, returning what changed
+    pub fn update(&mut self, response_hash: u64, images: Vec<String>) -> ListChanges {
+        // An identical response can't hold any changes
+        if response_hash == self.response_hash {
+            return ListChanges::default();
+        }
+
+        let old: HashSet<&String> = self.images.iter().collect();
+        let new: HashSet<&String> = images.iter().collect();
+        let changes = ListChanges {
+            added: images
+                .iter()
+                .filter(|image| !old.contains(image))
+                .cloned()
+                .collect(),
+            removed: self
+                .images
+                .iter()
+                .filter(|image| !new.contains(image))
+                .cloned()
+                .collect(),
+        };
+
+        self.response_hash = response_hash;
+        self.images = images;
+        changes
+    }
+}
@@ -1,14 +1,21 @@
 use anyhow::{Result, anyhow};
 use log::info;
-use std::thread;
-use std::time::Duration;
 
-use crate::camera::client::basic::ClientOperations;
+use crate::camera::capabilities::{DELETE_COMMAND, ImageOperation};
+use crate::camera::connection::{CameraMode, ModeManager};
+use crate::camera::image::formats::UrlFormatGenerator;
 
 /// Image deletion functionality
-pub trait ImageDeleter: ClientOperations {
+pub trait ImageDeleter: ModeManager {
     /// Delete an image from the camera - alternative approach
     fn delete_image(&self, image_name: &str) -> Result<()> {
+        self.ensure_writable("Deleting images")?;
+        if !self.known_capabilities().allows_command(DELETE_COMMAND) {
+            return Err(anyhow!(
+                "Camera doesn't list {}, so it can't delete images over WiFi",
+                DELETE_COMMAND
+            ));
+        }
         info!("Deleting image: {}", image_name);
 
         // Make sure we're getting exactly the requested image file
@@ -18,136 +25,52 @@ pub trait ImageDeleter: ClientOperations {
 
         // APPROACH 1: Switch to playback mode before trying to delete
         info!("APPROACH 1: Switch to playback mode first");
-        let play_mode_url = format!("{}switch_cameramode.cgi?mode=play", self.base_url());
-
-        match self
-            .client()
-            .get(&play_mode_url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()
-        {
-            Ok(response) => {
-                info!("Switch to play mode response: {}", response.status());
-                thread::sleep(Duration::from_secs(1)); // Give camera time to change modes
-            }
-            Err(e) => {
-                info!("Failed to switch to play mode: {}", e);
-            }
+        if let Err(e) = self.ensure_mode(CameraMode::Play) {
+            info!("Failed to switch to play mode: {}", e);
         }
 
-        // APPROACH 2: Try standard delete URL
-        info!("APPROACH 2: Standard delete URL");
-        let delete_url = format!(
-            "{}exec_erase.cgi?DIR=/DCIM/100OLYMP&FILE={}",
-            self.base_url(),
-            image_name
+        // APPROACH 2: Try each delete URL, starting with the format known to work
+        let urls = self.known_first(
+            ImageOperation::Delete,
+            image_name,
+            UrlFormatGenerator::delete_url_formats(
+                self.base_url(),
+                &self.image_folder(),
+                image_name,
+            ),
         );
 
-        match self
-            .client()
-            .get(&delete_url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()
-        {
-            Ok(response) => {
-                info!("Delete response status: {}", response.status());
-                if response.status().is_success() {
-                    match response.text() {
-                        Ok(text) => {
-                            if !text.contains("WIFI_INTERNAL_ERROR") {
-                                info!("Delete successful with APPROACH 2");
-                                return Ok(());
-                            } else {
-                                info!("WIFI_INTERNAL_ERROR detected in APPROACH 2");
-                            }
-                        }
-                        Err(_) => {}
-                    }
-                }
-            }
-            Err(e) => {
-                info!("Delete request failed in APPROACH 2: {}", e);
-            }
-        }
+        for (i, delete_url) in urls.iter().enumerate() {
+            info!("Trying delete URL #{}: {}", i + 1, delete_url);
 
-        // APPROACH 3: Try alternative delete URL format
-        info!("APPROACH 3: Alternative delete URL format");
-        let alt_delete_url = format!(
-            "{}exec_erase.cgi?com=exec&DIR=/DCIM/100OLYMP&FILE={}",
-            self.base_url(),
-            image_name
-        );
-
-        match self
-            .client()
-            .get(&alt_delete_url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()
-        {
-            Ok(response) => {
-                info!(
-                    "Delete response status for APPROACH 3: {}",
-                    response.status()
-                );
-                if response.status().is_success() {
-                    match response.text() {
-                        Ok(text) => {
-                            if !text.contains("WIFI_INTERNAL_ERROR") {
-                                info!("Delete successful with APPROACH 3");
-                                return Ok(());
-                            } else {
-                                info!("WIFI_INTERNAL_ERROR detected in APPROACH 3");
-                            }
+            match self.send(
+                self.client()
+                    .get(delete_url)
+                    .header("user-agent", "OlympusCameraKit")
+                    .header("content-length", "4096"),
+            ) {
+                Ok(response) => {
+                    info!(
+                        "Delete response status for URL #{}: {}",
+                        i + 1,
+                        response.status()
+                    );
+                    if response.status().is_success()
+                        && let Ok(text) = response.text()
+                    {
+                        if !text.contains("WIFI_INTERNAL_ERROR") {
+                            info!("Delete successful with URL #{}", i + 1);
+                            self.remember_format(ImageOperation::Delete, image_name, delete_url);
+                            return Ok(());
+                        } else {
+                            info!("WIFI_INTERNAL_ERROR detected with URL #{}", i + 1);
                         }
-                        Err(_) => {}
                     }
                 }
-            }
-            Err(e) => {
-                info!("Delete request failed in APPROACH 3: {}", e);
-            }
-        }
-
-        // APPROACH 4: Try direct file path approach
-        info!("APPROACH 4: Try direct file path approach");
-        let direct_url = format!(
-            "{}exec_erase.cgi?DIR=/DCIM/100OLYMP/{}",
-            self.base_url(),
-            image_name
-        );
-
-        match self
-            .client()
-            .get(&direct_url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()
-        {
-            Ok(response) => {
-                info!(
-                    "Delete response status for APPROACH 4: {}",
-                    response.status()
-                );
-                if response.status().is_success() {
-                    match response.text() {
-                        Ok(text) => {
-                            if !text.contains("WIFI_INTERNAL_ERROR") {
-                                info!("Delete successful with APPROACH 4");
-                                return Ok(());
-                            } else {
-                                info!("WIFI_INTERNAL_ERROR detected in APPROACH 4");
-                            }
-                        }
-                        Err(_) => {}
-                    }
+                Err(e) => {
+                    info!("Delete request failed with URL #{}: {}", i + 1, e);
                 }
             }
-            Err(e) => {
-                info!("Delete request failed in APPROACH 4: {}", e);
-            }
         }
 
         // If all the above approaches failed, return error with guidance
@@ -1,48 +1,68 @@
 use anyhow::{Result, anyhow};
 use log::info;
-use std::fs;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::Path;
 
+use crate::camera::capabilities::ImageOperation;
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::image::formats::UrlFormatGenerator;
+use crate::camera::image::list;
+use crate::utils::atomic::{self, PartFile};
+use crate::utils::resize;
 
 /// Image downloading functionality
 pub trait ImageDownloader: ClientOperations {
     /// Download an image from the camera to the local file system
     fn download_image(&self, image_name: &str, destination: &Path) -> Result<()> {
+        if list::is_movie(image_name) {
+            return self.download_movie(image_name, destination, &mut |_, _| true);
+        }
+        let bytes = self.fetch_image(image_name)?;
+
+        // Written as a .part file so an interrupted save isn't mistaken for the image
+        atomic::write_atomic(destination, &bytes)?;
+
+        info!("Image saved to: {:?}", destination);
+        Ok(())
+    }
+
+    /// Fetch the full image from the camera without saving it
+    fn fetch_image(&self, image_name: &str) -> Result<Vec<u8>> {
         info!("Downloading image: {}", image_name);
 
         // Make sure we're getting exactly the requested image file
         let image_name = image_name.trim(); // Remove any trailing/leading whitespace
-
-        // Set of URLs to try (from most likely to least likely)
-        let urls = [
-            format!(
-                "{}get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}",
-                self.base_url(),
+        if list::is_movie(image_name) {
+            return Err(anyhow!(
+                "{} is a movie and is downloaded to a file instead",
                 image_name
-            ),
-            format!("{}DCIM/100OLYMP/{}", self.base_url(), image_name),
-            format!(
-                "{}get_img.cgi?DIR=/DCIM/100OLYMP&FILE={}",
+            ));
+        }
+
+        // Set of URLs to try, starting with the format known to work on this camera
+        let urls = self.known_first(
+            ImageOperation::Download,
+            image_name,
+            UrlFormatGenerator::download_url_formats(
                 self.base_url(),
-                image_name
+                &self.image_folder(),
+                image_name,
             ),
-        ];
+        );
 
         // Try each URL
         for (i, url) in urls.iter().enumerate() {
             info!("Trying download URL #{}: {}", i + 1, url);
 
             // Get image data
-            match self
-                .client()
-                .get(url)
-                .header("user-agent", "OlympusCameraKit")
-                .header("content-length", "4096")
-                .header("accept", "image/jpeg,*/*")
-                .send()
-            {
+            match self.send(
+                self.client()
+                    .get(url)
+                    .timeout(self.timeouts().download())
+                    .header("user-agent", "OlympusCameraKit")
+                    .header("content-length", "4096")
+                    .header("accept", "image/jpeg,*/*"),
+            ) {
                 Ok(response) => {
                     info!("Download response status: {}", response.status());
 
@@ -54,28 +74,15 @@ pub trait ImageDownloader: ClientOperations {
                                 let bytes_vec = bytes.to_vec();
 
                                 // Check if it looks like an image (JPGs start with FFD8)
-                                if bytes_vec.len() < 2
-                                    || bytes_vec[0] != 0xFF
-                                    || bytes_vec[1] != 0xD8
-                                {
+                                if !is_image_data(&bytes_vec) {
                                     info!(
-                                        "WARNING: Downloaded data doesn't appear to be a JPEG image"
+                                        "WARNING: Downloaded data doesn't appear to be a JPEG or raw image"
                                     );
                                     continue; // Try next URL
                                 }
 
-                                // Create parent directories if they don't exist
-                                if let Some(parent) = destination.parent() {
-                                    fs::create_dir_all(parent)?;
-                                }
-
-                                // Manual file writing to ensure proper handling
-                                let mut file = std::fs::File::create(destination)?;
-                                file.write_all(&bytes_vec)?;
-                                file.flush()?;
-
-                                info!("Image saved to: {:?}", destination);
-                                return Ok(());
+                                self.remember_format(ImageOperation::Download, image_name, url);
+                                return Ok(bytes_vec);
                             }
                             Err(e) => {
                                 info!("Failed to get image bytes: {}", e);
@@ -94,6 +101,63 @@ pub trait ImageDownloader: ClientOperations {
         return Err(anyhow!("Failed to download image after trying all URLs"));
     }
 
+    /// Stream a movie from the camera to `destination`
+    ///
+    /// `progress` gets the bytes received so far and the size of the movie, if
+    /// the camera sent it, and stops the download by returning false.
+    fn download_movie(
+        &self,
+        movie_name: &str,
+        destination: &Path,
+        progress: &mut dyn FnMut(u64, Option<u64>) -> bool,
+    ) -> Result<()> {
+        let movie_name = movie_name.trim();
+        info!("Downloading movie: {}", movie_name);
+
+        let mut last_error = anyhow!("No movie URL to try");
+        let folder = self.image_folder();
+        for url in UrlFormatGenerator::movie_url_formats(self.base_url(), &folder, movie_name) {
+            let response = match self.send(
+                self.client()
+                    .get(&url)
+                    .timeout(self.timeouts().movie())
+                    .header("user-agent", "OlympusCameraKit"),
+            ) {
+                Ok(response) if response.status().is_success() => response,
+                Ok(response) => {
+                    last_error = anyhow!("{} returned {}", url, response.status());
+                    continue;
+                }
+                Err(e) => {
+                    last_error = e;
+                    continue;
+                }
+            };
+
+            let total = response.content_length();
+            let mut reader = response;
+            let mut file = PartFile::create(destination)?;
+            let mut buffer = vec![0u8; 64 * 1024];
+            let mut received = 0u64;
+            loop {
+                let read = reader.read(&mut buffer)?;
+                if read == 0 {
+                    break;
+                }
+                file.write_all(&buffer[..read])?;
+                received += read as u64;
+                if !progress(received, total) {
+                    return Err(anyhow!("Movie download cancelled"));
+                }
+            }
+            file.commit()?;
+
+            info!("Movie saved to {:?} ({} bytes)", destination, received);
+            return Ok(());
+        }
+        Err(last_error)
+    }
+
     /// Get image data with enhanced error handling
     fn get_image_data(&self, image_name: &str) -> Result<Vec<u8>> {
         info!("Getting image data for: {}", image_name);
@@ -101,40 +165,48 @@ pub trait ImageDownloader: ClientOperations {
         // Make sure we're getting exactly the requested image file
         let image_name = image_name.trim(); // Remove any trailing/leading whitespace
 
+        let folder = self.image_folder();
+        let relative = folder.trim_start_matches('/');
+
         // Enhanced set of URLs to try (from most likely to least likely)
         let urls = [
             // Format 1: Get thumbnail with absolute DIR path (most common format)
             format!(
-                "{}get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
                 self.base_url(),
+                folder,
                 image_name
             ),
             // Format 2: Get thumbnail with relative DIR path
             format!(
-                "{}get_thumbnail.cgi?DIR=DCIM/100OLYMP&FILE={}&size=1024",
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
                 self.base_url(),
+                relative,
                 image_name
             ),
             // Format 3: Get thumbnail with DIR path without leading '/'
             format!(
-                "{}get_thumbnail.cgi?DIR=DCIM/100OLYMP&FILE={}&size=1024",
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
                 self.base_url(),
+                relative,
                 image_name
             ),
             // Format 4: Direct path - sometimes this works better
-            format!("{}DCIM/100OLYMP/{}", self.base_url(), image_name),
+            format!("{}{}/{}", self.base_url(), relative, image_name),
             // Format 5: Alternative direct path with leading /
-            format!("{}/DCIM/100OLYMP/{}", self.base_url(), image_name),
+            format!("{}{}/{}", self.base_url(), folder, image_name),
             // Format 6: Using get_img.cgi for full image instead
             format!(
-                "{}get_img.cgi?DIR=/DCIM/100OLYMP&FILE={}",
+                "{}get_img.cgi?DIR={}&FILE={}",
                 self.base_url(),
+                folder,
                 image_name
             ),
             // Format 7: Get resized image
             format!(
-                "{}get_resized_img.cgi?DIR=/DCIM/100OLYMP&FILE={}",
+                "{}get_resized_img.cgi?DIR={}&FILE={}",
                 self.base_url(),
+                folder,
                 image_name
             ),
         ];
@@ -144,14 +216,14 @@ pub trait ImageDownloader: ClientOperations {
             info!("📷 Trying image data URL #{}: {}", i + 1, url);
 
             // Get image data with improved error handling
-            match self
-                .client()
-                .get(url)
-                .header("user-agent", "OlympusCameraKit")
-                .header("content-length", "4096")
-                .header("accept", "image/jpeg,*/*")
-                .send()
-            {
+            match self.send(
+                self.client()
+                    .get(url)
+                    .timeout(self.timeouts().download())
+                    .header("user-agent", "OlympusCameraKit")
+                    .header("content-length", "4096")
+                    .header("accept", "image/jpeg,*/*"),
+            ) {
                 Ok(response) => {
                     let status = response.status();
                     info!("📷 Image data response status: {}", status);
@@ -236,23 +308,32 @@ pub trait ImageDownloader: ClientOperations {
         );
 
         // Build URL based on requested resolution
-        let url = match resolution.to_lowercase().as_str() {
-            "thumbnail" | "low" => {
-                format!("get_thumbnail.cgi?DIR={}&size=1024", image_path)
-            }
-            "medium" => {
-                format!("get_resized_img.cgi?DIR={}&size=2048", image_path)
-            }
-            "high" | "full" => {
-                format!("get_img.cgi?DIR={}", image_path)
-            }
-            _ => {
-                // Default to thumbnail for unknown resolution
-                format!("get_thumbnail.cgi?DIR={}&size=1024", image_path)
-            }
-        };
+        let url = UrlFormatGenerator::resolution_url(image_path, resolution);
 
         // Get the binary data
         self.get_binary(&url)
     }
+
+    /// Fetch a copy of an image resized on the camera, so only the smaller file crosses the link
+    fn fetch_resized_image(&self, image_name: &str, max_dimension: u32) -> Result<Vec<u8>> {
+        let size = resize::camera_resize_size(max_dimension);
+        info!("Requesting {} resized to {} pixels", image_name, size);
+
+        let image_path = format!("{}/{}", self.image_folder(), image_name.trim());
+        let data = self.get_binary(&UrlFormatGenerator::resized_url(&image_path, size))?;
+        if !data.starts_with(&[0xFF, 0xD8]) {
+            return Err(anyhow!("Camera did not return a resized JPEG"));
+        }
+        Ok(data)
+    }
+}
+
+/// Whether downloaded data starts like a JPEG or an Olympus raw file
+///
+/// ORF files are TIFF based, with Olympus' own `IIRO`, `IIRS` or `MMOR` signature.
+fn is_image_data(data: &[u8]) -> bool {
+    data.starts_with(&[0xFF, 0xD8])
+        || data.starts_with(b"IIRO")
+        || data.starts_with(b"IIRS")
+        || data.starts_with(b"MMOR")
 }
@@ -3,17 +3,18 @@ pub struct UrlFormatGenerator;
 
 impl UrlFormatGenerator {
     /// Generate various URL formats to try for accessing images
-    pub fn generate_url_formats(base_url: &str, image_name: &str) -> Vec<String> {
+    pub fn generate_url_formats(base_url: &str, folder: &str, image_name: &str) -> Vec<String> {
+        let relative = folder.trim_start_matches('/');
         vec![
             // Format 1: Standard thumbnail format
             format!(
-                "{}get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
-                base_url, image_name
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
+                base_url, folder, image_name
             ),
             // Format 2: Without leading slash in DIR
             format!(
-                "{}get_thumbnail.cgi?DIR=DCIM/100OLYMP&FILE={}&size=1024",
-                base_url, image_name
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
+                base_url, relative, image_name
             ),
             // Format 3: Without DIR parameter
             format!(
@@ -21,35 +22,94 @@ impl UrlFormatGenerator {
                 base_url, image_name
             ),
             // Format 4: Direct path
-            format!("{}DCIM/100OLYMP/{}", base_url, image_name),
+            format!("{}{}/{}", base_url, relative, image_name),
             // Format 5: Using get_img.cgi instead
-            format!(
-                "{}get_img.cgi?DIR=/DCIM/100OLYMP&FILE={}",
-                base_url, image_name
-            ),
+            format!("{}get_img.cgi?DIR={}&FILE={}", base_url, folder, image_name),
             // Format 6: Using get_img.cgi without leading slash
             format!(
-                "{}get_img.cgi?DIR=DCIM/100OLYMP&FILE={}",
-                base_url, image_name
+                "{}get_img.cgi?DIR={}&FILE={}",
+                base_url, relative, image_name
             ),
             // Format 7: Using get_resized_img.cgi
             format!(
-                "{}get_resized_img.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
-                base_url, image_name
+                "{}get_resized_img.cgi?DIR={}&FILE={}&size=1024",
+                base_url, folder, image_name
             ),
             // Format 8: Alternative path structure
-            format!("{}get_img.cgi?PATH=/DCIM/100OLYMP/{}", base_url, image_name),
+            format!("{}get_img.cgi?PATH={}/{}", base_url, folder, image_name),
             // Format 9: With uppercase filename
             format!(
-                "{}get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
                 base_url,
+                folder,
                 image_name.to_uppercase()
             ),
             // Format 10: With lowercase path
             format!(
-                "{}get_thumbnail.cgi?DIR=/dcim/100olymp&FILE={}&size=1024",
-                base_url, image_name
+                "{}get_thumbnail.cgi?DIR={}&FILE={}&size=1024",
+                base_url,
+                folder.to_lowercase(),
+                image_name
+            ),
+        ]
+    }
+
+    /// URL formats to try for downloading the full image, most likely first
+    pub fn download_url_formats(base_url: &str, folder: &str, image_name: &str) -> Vec<String> {
+        let relative = folder.trim_start_matches('/');
+        vec![
+            format!(
+                "{}get_thumbnail.cgi?DIR={}&FILE={}",
+                base_url, folder, image_name
             ),
+            format!("{}{}/{}", base_url, relative, image_name),
+            format!("{}get_img.cgi?DIR={}&FILE={}", base_url, folder, image_name),
         ]
     }
+
+    /// URL formats to try for downloading a movie, most likely first
+    ///
+    /// Unlike photos, movies can't fall back on the thumbnail, which is only a still of the movie.
+    pub fn movie_url_formats(base_url: &str, folder: &str, movie_name: &str) -> Vec<String> {
+        let relative = folder.trim_start_matches('/');
+        vec![
+            format!("{}{}/{}", base_url, relative, movie_name),
+            format!("{}get_img.cgi?DIR={}&FILE={}", base_url, folder, movie_name),
+        ]
+    }
+
+    /// URL formats to try for deleting an image, most likely first
+    pub fn delete_url_formats(base_url: &str, folder: &str, image_name: &str) -> Vec<String> {
+        vec![
+            // Standard delete URL
+            format!(
+                "{}exec_erase.cgi?DIR={}&FILE={}",
+                base_url, folder, image_name
+            ),
+            // Alternative format with an explicit command
+            format!(
+                "{}exec_erase.cgi?com=exec&DIR={}&FILE={}",
+                base_url, folder, image_name
+            ),
+            // Direct file path
+            format!("{}exec_erase.cgi?DIR={}/{}", base_url, folder, image_name),
+        ]
+    }
+
+    /// URL for the image at `image_path` in one of the resolutions the camera serves
+    pub fn resolution_url(image_path: &str, resolution: &str) -> String {
+        match resolution.to_lowercase().as_str() {
+            // Resized on the camera, much smaller than the original
+            "medium" => Self::resized_url(image_path, 2048),
+            // The original file
+            "high" | "full" => format!("get_img.cgi?DIR={}", image_path),
+            // Thumbnail for low and unknown resolutions
+            _ => format!("get_thumbnail.cgi?DIR={}&size=1024", image_path),
+        }
+    }
+
+    /// URL for the image at `image_path` resized on the camera to `size` pixels on its longest side
+    pub fn resized_url(image_path: &str, size: u32) -> String {
+        format!("get_resized_img.cgi?DIR={}&size={}", image_path, size)
+    }
 }
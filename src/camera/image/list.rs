@@ -1,43 +1,170 @@
 use anyhow::Result;
 use log::info;
 use regex::Regex;
+use std::io::{BufRead, BufReader};
+use xxhash_rust::xxh3::Xxh3;
 
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::image::cache::{ImageListCache, ListChanges};
+
+/// Root of the image folders on the card
+pub const DCIM_FOLDER: &str = "/DCIM";
+
+/// Folder opened when the card's folders aren't known yet
+pub const DEFAULT_FOLDER: &str = "/DCIM/100OLYMP";
+
+/// Attribute bit `get_imglist.cgi` sets on folder entries
+const DIRECTORY_ATTRIBUTE: u32 = 0x10;
+
+/// Whether a file on the card is a movie rather than a photo
+pub fn is_movie(name: &str) -> bool {
+    name.trim().to_uppercase().ends_with(".MOV")
+}
+
+/// One `folder,name,size,attribute,date,time` line of a `get_imglist.cgi` response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListEntry {
+    pub folder: String,
+    pub name: String,
+    pub size: u64,
+    pub attribute: u32,
+}
+
+impl ListEntry {
+    /// Parse a listing line; the `VER_100` header and blank lines give `None`
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.trim().split(',');
+        let folder = fields.next()?.to_string();
+        let name = fields.next()?.to_string();
+        let size = fields.next()?.parse().ok()?;
+        let attribute = fields.next()?.parse().ok()?;
+        Some(Self {
+            folder,
+            name,
+            size,
+            attribute,
+        })
+    }
+
+    /// Whether the entry is a folder rather than a file
+    pub fn is_folder(&self) -> bool {
+        self.attribute & DIRECTORY_ATTRIBUTE != 0
+    }
+
+    /// Full path of the entry on the card
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.folder.trim_end_matches('/'), self.name)
+    }
+}
+
+/// Finds image file names in listing lines
+struct ImageNameMatcher {
+    patterns: [Regex; 2],
+}
+
+impl ImageNameMatcher {
+    fn new() -> Self {
+        // Use both regex patterns to find all image files, raw files and movies included
+        Self {
+            patterns: [
+                Regex::new(r"P\w\d+\.(?:JPG|ORF|MOV)").unwrap(),
+                Regex::new(r"P.\d+\.(?:JPG|ORF|MOV)").unwrap(),
+            ],
+        }
+    }
+
+    /// Add the image names found in `line` to `filenames`
+    fn collect(&self, line: &str, filenames: &mut Vec<String>) {
+        for pattern in &self.patterns {
+            filenames.extend(pattern.find_iter(line).map(|m| m.as_str().to_string()));
+        }
+    }
+}
 
 /// Image listing functionality
 pub trait ImageLister: ClientOperations {
-    /// Get a list of images on the camera
+    /// Get a list of the images in the open folder
     fn get_image_list(&self) -> Result<Vec<String>> {
-        info!("Getting list of images");
+        let (_, filenames) = self.list_folder(&self.image_folder())?;
+        Ok(filenames)
+    }
 
-        let url = format!("{}get_imglist.cgi?DIR=/DCIM/100OLYMP", self.base_url());
+    /// Pass each line of a folder's `get_imglist.cgi` listing to `visit` as it arrives
+    ///
+    /// Listings of full cards run to megabytes, so the response is never held
+    /// in memory. Returns a hash of the whole response.
+    fn stream_image_list(&self, folder: &str, visit: &mut dyn FnMut(&str)) -> Result<u64> {
+        info!("Getting list of images in {}", folder);
 
-        let response = self
-            .client()
-            .get(&url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()?;
+        let url = format!("{}get_imglist.cgi?DIR={}", self.base_url(), folder);
 
-        self.log_response_info(&response, "Image list");
+        let response = self.send(
+            self.client()
+                .get(&url)
+                .timeout(self.timeouts().list())
+                .header("user-agent", "OlympusCameraKit")
+                .header("content-length", "4096"),
+        )?;
 
-        let text = response.text()?;
+        self.log_response_info(&response, "Image list");
 
-        // Use both regex patterns to find all image files
-        let re1 = Regex::new(r"P\w\d+\.JPG").unwrap();
-        let re2 = Regex::new(r"P.\d+\.JPG").unwrap();
+        let mut reader = BufReader::new(response);
+        let mut hasher = Xxh3::new();
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            hasher.update(&line);
+            visit(&String::from_utf8_lossy(&line));
+            line.clear();
+        }
+        Ok(hasher.digest())
+    }
 
+    /// Sorted image names in one folder, with a hash of the listing
+    fn list_folder(&self, folder: &str) -> Result<(u64, Vec<String>)> {
+        let matcher = ImageNameMatcher::new();
         let mut filenames = Vec::new();
-
-        // Add matches from both patterns
-        filenames.extend(re1.find_iter(&text).map(|m| m.as_str().to_string()));
-        filenames.extend(re2.find_iter(&text).map(|m| m.as_str().to_string()));
+        let hash =
+            self.stream_image_list(folder, &mut |line| matcher.collect(line, &mut filenames))?;
 
         // Remove duplicates
         filenames.sort();
         filenames.dedup();
 
-        info!("Found {} images", filenames.len());
-        Ok(filenames)
+        info!("Found {} images in {}", filenames.len(), folder);
+        Ok((hash, filenames))
+    }
+
+    /// Image folders on the card, such as `/DCIM/100OLYMP`, to be listed one at a time
+    fn get_image_folders(&self) -> Result<Vec<String>> {
+        let mut folders = Vec::new();
+        self.stream_image_list(DCIM_FOLDER, &mut |line| {
+            if let Some(entry) = ListEntry::parse(line).filter(ListEntry::is_folder) {
+                folders.push(entry.path());
+            }
+        })?;
+        folders.sort();
+
+        info!("Found {} image folders", folders.len());
+        Ok(folders)
+    }
+
+    /// List `folder` and open it, replacing the cached listing of the previous folder
+    fn open_image_folder(&self, folder: &str, cache: &mut ImageListCache) -> Result<()> {
+        let (hash, images) = self.list_folder(folder)?;
+        self.open_folder(folder);
+        cache.replace(folder, hash, images);
+        Ok(())
+    }
+
+    /// Bring the cached listing of the open folder up to date, returning the images added and removed
+    fn refresh_image_list(&self, cache: &mut ImageListCache) -> Result<ListChanges> {
+        let (hash, images) = self.list_folder(cache.folder())?;
+        let changes = cache.update(hash, images);
+        info!(
+            "Image list refreshed: {} new, {} removed",
+            changes.added.len(),
+            changes.removed.len()
+        );
+        Ok(changes)
     }
 }
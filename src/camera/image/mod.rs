@@ -1,8 +1,10 @@
 // Export image handling submodules
+pub mod cache;
 pub mod delete;
 pub mod download;
 pub mod formats;
 pub mod list;
+pub mod prefetch;
 
 // Re-export key components
 pub use delete::ImageDeleter;
@@ -0,0 +1,195 @@
+use anyhow::Result;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::camera::capabilities::ImageOperation;
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::image::formats::UrlFormatGenerator;
+use crate::camera::image::list;
+use crate::camera::olympus::OlympusCamera;
+use crate::utils::atomic;
+
+/// Thumbnail prefetching settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrefetchConfig {
+    /// Fetch thumbnails of the images around the selection in the background
+    pub enabled: bool,
+    /// Threads fetching thumbnails at once
+    pub workers: usize,
+    /// Most thumbnail requests sent per second, across all workers
+    pub requests_per_sec: f64,
+}
+
+impl Default for PrefetchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            workers: 2,
+            requests_per_sec: 2.0,
+        }
+    }
+}
+
+/// Spaces requests out so no more than a set number start per second
+struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_sec: f64) -> Self {
+        Self {
+            interval: Duration::from_secs_f64(1.0 / requests_per_sec.max(0.1)),
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait for the next free slot
+    fn wait(&self) {
+        // Slots are handed out in turn, so waiting workers don't all fire at once
+        let slot = {
+            let mut next = self.next.lock().unwrap();
+            let slot = (*next).max(Instant::now());
+            *next = slot + self.interval;
+            slot
+        };
+        thread::sleep(slot.saturating_duration_since(Instant::now()));
+    }
+}
+
+/// State shared by the prefetch workers
+struct Shared {
+    queue: Mutex<VecDeque<String>>,
+    limiter: RateLimiter,
+    directory: PathBuf,
+    paused: AtomicBool,
+    running: AtomicBool,
+}
+
+/// Fetches thumbnails on a few background threads and keeps them on disk
+///
+/// Requests are rate limited across all workers and stop entirely while
+/// paused, since live view initialization is easily upset by other requests.
+pub struct ThumbnailPrefetcher {
+    shared: Arc<Shared>,
+}
+
+impl ThumbnailPrefetcher {
+    /// Start the workers, saving thumbnails to `directory`
+    pub fn start(camera: &OlympusCamera, config: &PrefetchConfig, directory: &Path) -> Self {
+        if let Err(e) = fs::create_dir_all(directory) {
+            warn!(
+                "Failed to create thumbnail directory {:?}: {}",
+                directory, e
+            );
+        }
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(VecDeque::new()),
+            limiter: RateLimiter::new(config.requests_per_sec),
+            directory: directory.to_path_buf(),
+            paused: AtomicBool::new(false),
+            running: AtomicBool::new(true),
+        });
+
+        let workers = config.workers.max(1);
+        for _ in 0..workers {
+            let camera = camera.clone();
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || run_worker(&camera, &shared));
+        }
+
+        info!(
+            "Thumbnail prefetching started with {} workers at up to {} requests/s",
+            workers, config.requests_per_sec
+        );
+        Self { shared }
+    }
+
+    /// Fetch these images next, in order, dropping what was queued before
+    pub fn request(&self, images: &[String]) {
+        let mut queue = self.shared.queue.lock().unwrap();
+        queue.clear();
+        queue.extend(
+            images
+                .iter()
+                .filter(|image| !list::is_movie(image))
+                .filter(|image| !thumbnail_path(&self.shared.directory, image).exists())
+                .cloned(),
+        );
+    }
+
+    /// Stop sending requests until resumed, e.g. while live view runs
+    pub fn set_paused(&self, paused: bool) {
+        self.shared.paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// Prefetched thumbnail of an image, if it has been fetched
+    pub fn cached(&self, image: &str) -> Option<Vec<u8>> {
+        fs::read(thumbnail_path(&self.shared.directory, image)).ok()
+    }
+}
+
+impl Drop for ThumbnailPrefetcher {
+    fn drop(&mut self) {
+        self.shared.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Where the thumbnail of an image is kept
+fn thumbnail_path(directory: &Path, image: &str) -> PathBuf {
+    directory.join(image.trim()).with_extension("thumb.jpg")
+}
+
+/// Take images off the queue until the prefetcher is dropped
+fn run_worker(camera: &OlympusCamera, shared: &Shared) {
+    while shared.running.load(Ordering::Relaxed) {
+        let next = if shared.paused.load(Ordering::Relaxed) || camera.camera_unresponsive() {
+            None
+        } else {
+            shared.queue.lock().unwrap().pop_front()
+        };
+        let Some(image) = next else {
+            thread::sleep(Duration::from_millis(200));
+            continue;
+        };
+
+        let path = thumbnail_path(&shared.directory, &image);
+        if path.exists() {
+            continue;
+        }
+        shared.limiter.wait();
+        match fetch_thumbnail(camera, &image) {
+            Ok(data) => {
+                if let Err(e) = atomic::write_atomic(&path, &data) {
+                    warn!("Failed to save thumbnail of {}: {}", image, e);
+                }
+            }
+            Err(e) => debug!("Failed to prefetch thumbnail of {}: {}", image, e),
+        }
+    }
+}
+
+/// Fetch a thumbnail with the preview format known to work, or the most common one
+fn fetch_thumbnail(camera: &OlympusCamera, image: &str) -> Result<Vec<u8>> {
+    let image = image.trim();
+    let url = camera
+        .known_first(
+            ImageOperation::Preview,
+            image,
+            UrlFormatGenerator::generate_url_formats(
+                camera.base_url(),
+                &camera.image_folder(),
+                image,
+            ),
+        )
+        .remove(0);
+    camera.get_binary(&url)
+}
@@ -1,9 +1,17 @@
 // Export all submodules
+pub mod capabilities;
 pub mod client;
 pub mod connection;
+pub mod console;
+#[cfg(feature = "demo")]
+pub mod demo;
+pub mod events;
 pub mod image;
 pub mod olympus;
+pub mod pairing;
 pub mod photo;
+pub mod profiles;
+pub mod settings;
 
 // Re-export the main camera type for convenience
 pub use olympus::OlympusCamera;
@@ -1,28 +1,67 @@
 use anyhow::Result;
-use log::info;
+use log::{info, warn};
 use reqwest::blocking::Client;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use crate::camera::capabilities::{CapabilityCache, CapabilityProbe};
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::client::breaker::{BreakerConfig, CircuitBreaker};
 use crate::camera::client::error::ErrorHandler;
+use crate::camera::client::protocol::{ProtocolLog, ProtocolLogConfig};
+use crate::camera::client::stats::RequestStats;
+use crate::camera::client::timeouts::TimeoutConfig;
 use crate::camera::connection::init::ConnectionManager;
+use crate::camera::connection::mode::ModeManager;
+use crate::camera::connection::state::ConnectionState;
+use crate::camera::console::CommandConsole;
 use crate::camera::image::delete::ImageDeleter;
 use crate::camera::image::download::ImageDownloader;
-use crate::camera::image::list::ImageLister;
+use crate::camera::image::list::{DEFAULT_FOLDER, ImageLister};
+use crate::camera::pairing::PairingManager;
+use crate::camera::photo::bracket::FocusBracketing;
 use crate::camera::photo::capture::PhotoCapture;
+use crate::camera::photo::movie::MovieCapture;
+use crate::camera::photo::tether::TetheredCapture;
+use crate::camera::profiles::SettingsProfiles;
+use crate::camera::settings::CameraSettings;
+
+/// Idle connections kept open to the camera
+const IDLE_CONNECTIONS: usize = 4;
+
+/// How long an idle connection to the camera is kept open
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// Main camera client for Olympus Air
+///
+/// Clones are cheap handles to the same HTTP connection pool, connection
+/// state and request statistics, so the UI, downloads and background
+/// monitors can all use the camera at once.
+#[derive(Clone)]
 pub struct OlympusCamera {
     pub base_url: String,
-    pub client: Client,
-    pub connected: Arc<AtomicBool>,
+    /// Shared by every clone so keep-alive connections to the camera are reused
+    pub client: Arc<Client>,
+    pub connection: Arc<Mutex<ConnectionState>>,
+    pub timeouts: TimeoutConfig,
+    pub requests: Arc<Mutex<RequestStats>>,
+    /// Endpoints and URL formats known to work on this camera
+    pub capabilities: Arc<Mutex<CapabilityCache>>,
+    /// Log of the raw requests and responses, when protocol logging is on
+    pub protocol: Option<Arc<ProtocolLog>>,
+    /// Pauses automatic requests after repeated failures
+    pub breaker: Arc<Mutex<CircuitBreaker>>,
+    /// Refuses capture, deletion and settings changes while set
+    pub read_only: Arc<AtomicBool>,
+    /// Folder on the card images are listed, viewed and downloaded from
+    pub folder: Arc<Mutex<String>>,
 }
 
 impl OlympusCamera {
     /// Create a new camera client
-    pub fn new(base_url: &str) -> Self {
+    pub fn new(base_url: &str, timeouts: TimeoutConfig) -> Self {
         // Ensure URL ends with trailing slash
         let base_url = if base_url.ends_with('/') {
             base_url.to_string()
@@ -32,9 +71,14 @@ impl OlympusCamera {
 
         info!("Creating camera client with base URL: {}", base_url);
 
-        // Create HTTP client with longer timeout
+        // Create HTTP client with the configured request timeout, keeping a few
+        // idle connections open so requests from the UI, downloads and monitors
+        // skip the connection setup
         let client = Client::builder()
-            .timeout(Duration::from_secs(30)) // Increase timeout
+            .timeout(timeouts.request())
+            .pool_max_idle_per_host(IDLE_CONNECTIONS)
+            .pool_idle_timeout(IDLE_TIMEOUT)
+            .tcp_keepalive(IDLE_TIMEOUT)
             .build()
             .unwrap_or_else(|e| {
                 info!(
@@ -46,21 +90,45 @@ impl OlympusCamera {
 
         Self {
             base_url,
-            client,
-            connected: Arc::new(AtomicBool::new(false)),
+            client: Arc::new(client),
+            connection: Arc::new(Mutex::new(ConnectionState::Disconnected)),
+            timeouts,
+            requests: Arc::new(Mutex::new(RequestStats::default())),
+            capabilities: Arc::new(Mutex::new(CapabilityCache::default())),
+            protocol: None,
+            breaker: Arc::new(Mutex::new(CircuitBreaker::default())),
+            read_only: Arc::new(AtomicBool::new(false)),
+            folder: Arc::new(Mutex::new(DEFAULT_FOLDER.to_string())),
         }
     }
 
-    /// Clone the camera for thread safety
-    pub fn clone(&self) -> Self {
-        Self {
-            base_url: self.base_url.clone(),
-            client: Client::builder()
-                .timeout(Duration::from_secs(30))
-                .build()
-                .unwrap_or_else(|_| Client::new()),
-            connected: Arc::clone(&self.connected),
+    /// Keep the camera's capabilities in `path` between sessions
+    pub fn with_capability_cache(mut self, path: &Path) -> Self {
+        self.capabilities = Arc::new(Mutex::new(CapabilityCache::load(path)));
+        self
+    }
+
+    /// Pause automatic requests after repeated failures as configured
+    pub fn with_breaker(mut self, config: &BreakerConfig) -> Self {
+        self.breaker = Arc::new(Mutex::new(CircuitBreaker::new(config)));
+        self
+    }
+
+    /// Start in read-only mode, refusing requests that change the camera
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Arc::new(AtomicBool::new(read_only));
+        self
+    }
+
+    /// Log every request and response to a protocol log if enabled
+    pub fn with_protocol_log(mut self, config: &ProtocolLogConfig) -> Self {
+        if config.enabled {
+            match ProtocolLog::open(config) {
+                Ok(log) => self.protocol = Some(Arc::new(log)),
+                Err(e) => warn!("Failed to open the protocol log: {}", e),
+            }
         }
+        self
     }
 }
 
@@ -73,6 +141,34 @@ impl ClientOperations for OlympusCamera {
     fn base_url(&self) -> &str {
         &self.base_url
     }
+
+    fn timeouts(&self) -> &TimeoutConfig {
+        &self.timeouts
+    }
+
+    fn request_stats(&self) -> &Arc<Mutex<RequestStats>> {
+        &self.requests
+    }
+
+    fn capabilities(&self) -> &Arc<Mutex<CapabilityCache>> {
+        &self.capabilities
+    }
+
+    fn breaker(&self) -> &Arc<Mutex<CircuitBreaker>> {
+        &self.breaker
+    }
+
+    fn read_only(&self) -> &Arc<AtomicBool> {
+        &self.read_only
+    }
+
+    fn folder(&self) -> &Arc<Mutex<String>> {
+        &self.folder
+    }
+
+    fn protocol_log(&self) -> Option<&ProtocolLog> {
+        self.protocol.as_deref()
+    }
 }
 
 // Implement error handling
@@ -80,11 +176,14 @@ impl ErrorHandler for OlympusCamera {}
 
 // Implement connection management
 impl ConnectionManager for OlympusCamera {
-    fn connected(&self) -> &Arc<AtomicBool> {
-        &self.connected
+    fn connection(&self) -> &Arc<Mutex<ConnectionState>> {
+        &self.connection
     }
 }
 
+// Implement rec/play mode tracking
+impl ModeManager for OlympusCamera {}
+
 // Implement image listing
 impl ImageLister for OlympusCamera {}
 
@@ -102,3 +201,27 @@ impl PhotoCapture for OlympusCamera {
         ImageLister::get_image_list(self)
     }
 }
+
+// Implement movie recording
+impl MovieCapture for OlympusCamera {}
+
+// Implement tethered capture
+impl TetheredCapture for OlympusCamera {}
+
+// Implement focus bracketing
+impl FocusBracketing for OlympusCamera {}
+
+// Implement settings profiles
+impl SettingsProfiles for OlympusCamera {}
+
+// Implement exposure settings control
+impl CameraSettings for OlympusCamera {}
+
+// Implement connect-mode management
+impl PairingManager for OlympusCamera {}
+
+// Implement capability probing
+impl CapabilityProbe for OlympusCamera {}
+
+// Implement the raw command console
+impl CommandConsole for OlympusCamera {}
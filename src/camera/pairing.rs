@@ -0,0 +1,186 @@
+use anyhow::{Result, anyhow};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::client::error::{FailureKind, RequestError};
+use crate::camera::events;
+use crate::utils::audit::{self, AuditAction};
+
+/// Longest network name Wi-Fi allows, in bytes
+const MAX_SSID_BYTES: usize = 32;
+
+/// Shortest and longest WPA2 passphrase
+const PASSWORD_CHARS: std::ops::RangeInclusive<usize> = 8..=63;
+
+/// Connect-mode and access point requests
+///
+/// Firmware versions differ in which of these they accept, so the requests can be changed.
+/// `{mode}`, `{ssid}` and `{password}` stand for the values being set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PairingConfig {
+    /// Connect modes offered, in the order they are stepped through
+    pub modes: Vec<String>,
+    /// Request switching the connect mode
+    pub set_mode: String,
+    /// Request reading the access point name and password
+    pub get_network: String,
+    /// Request changing the access point name and password
+    pub set_network: String,
+}
+
+impl Default for PairingConfig {
+    fn default() -> Self {
+        Self {
+            modes: vec!["private".to_string(), "enrollment".to_string()],
+            set_mode: "switch_connectmode.cgi?mode={mode}".to_string(),
+            get_network: "get_wifisetting.cgi".to_string(),
+            set_network: "set_wifisetting.cgi?ssid={ssid}&password={password}".to_string(),
+        }
+    }
+}
+
+/// Access point the camera opens for phones and this tool
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessPoint {
+    pub ssid: Option<String>,
+    pub password: Option<String>,
+}
+
+/// How the camera accepts connections
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectSettings {
+    /// Connect mode the camera reports, such as `private`
+    pub mode: Option<String>,
+    /// Access point settings, if the camera reports them
+    pub network: Option<AccessPoint>,
+}
+
+/// Mode after `current` in the configured order, starting over after the last
+pub fn next_mode<'a>(config: &'a PairingConfig, current: Option<&str>) -> Option<&'a str> {
+    let next = current
+        .and_then(|current| config.modes.iter().position(|mode| mode == current))
+        .map_or(0, |index| index + 1);
+    config
+        .modes
+        .get(next)
+        .or_else(|| config.modes.first())
+        .map(String::as_str)
+}
+
+/// Check a new access point name and password before sending them
+pub fn validate_access_point(ssid: &str, password: &str) -> Result<()> {
+    if ssid.is_empty() || ssid.len() > MAX_SSID_BYTES {
+        return Err(anyhow!(
+            "The network name must be 1 to {} bytes long",
+            MAX_SSID_BYTES
+        ));
+    }
+    if !PASSWORD_CHARS.contains(&password.chars().count()) {
+        return Err(anyhow!(
+            "The password must be {} to {} characters long",
+            PASSWORD_CHARS.start(),
+            PASSWORD_CHARS.end()
+        ));
+    }
+    Ok(())
+}
+
+/// Request with each `{name}` replaced by its escaped value
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |request, (name, value)| {
+            request.replace(&format!("{{{}}}", name), &encode(value))
+        })
+}
+
+/// Escape a value for a query string
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Drop the request URL, which holds the password, from the error of a failed request
+fn without_url(error: anyhow::Error) -> anyhow::Error {
+    match error.downcast::<reqwest::Error>() {
+        Ok(error) => error.without_url().into(),
+        Err(error) => error,
+    }
+}
+
+/// Reading and changing how the camera accepts connections
+pub trait PairingManager: ClientOperations {
+    /// Read the connect mode and, where the camera reports them, its access point settings
+    fn read_connect_settings(&self, config: &PairingConfig) -> Result<ConnectSettings> {
+        let mode =
+            events::parse_state(&self.get_text("get_connectmode.cgi")?).remove("connectmode");
+
+        let network = match self.get_text(&config.get_network) {
+            Ok(text) => {
+                let mut values = events::parse_state(&text);
+                Some(AccessPoint {
+                    ssid: values.remove("ssid"),
+                    password: values.remove("password"),
+                })
+            }
+            Err(e) if FailureKind::classify(&e) == FailureKind::NotFound => {
+                info!("Camera doesn't report its access point settings");
+                None
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(ConnectSettings { mode, network })
+    }
+
+    /// Switch the connect mode, recorded in the audit log
+    fn set_connect_mode(&self, config: &PairingConfig, mode: &str) -> Result<()> {
+        let result = self
+            .ensure_writable("Changing the connect mode")
+            .and_then(|_| self.get_page(&fill(&config.set_mode, &[("mode", mode)])));
+        audit::record(
+            AuditAction::Setting,
+            &format!("connectmode={}", mode),
+            &result,
+        );
+        result
+    }
+
+    /// Change the access point name and password, recorded in the audit log without the password
+    fn set_access_point(&self, config: &PairingConfig, ssid: &str, password: &str) -> Result<()> {
+        validate_access_point(ssid, password)?;
+        let request = fill(
+            &config.set_network,
+            &[("ssid", ssid), ("password", password)],
+        );
+        let result = self
+            .ensure_writable("Changing the access point")
+            .and_then(|_| {
+                // Sent without `get_page`, which would log the password with the URL
+                info!("Changing the camera's access point to {}", ssid);
+                let response = self
+                    .send(
+                        self.client()
+                            .get(format!("{}{}", self.base_url(), request))
+                            .timeout(self.timeouts().request())
+                            .header("user-agent", "OlympusCameraKit"),
+                    )
+                    .map_err(without_url)?;
+                if !response.status().is_success() {
+                    return Err(RequestError::from_status(response.status()).into());
+                }
+                Ok(())
+            });
+        audit::record(AuditAction::Setting, &format!("ssid={}", ssid), &result);
+        result
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::{Result, anyhow};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::thread;
+use std::time::Duration;
+
+use crate::camera::photo::capture::PhotoCapture;
+
+/// Focus bracketing settings for stacking sequences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FocusBracketConfig {
+    /// Number of frames to capture
+    pub frames: u32,
+    /// Focus drive step between frames
+    pub step: u32,
+    /// Direction to drive focus, `near` or `far`
+    pub direction: String,
+    /// Focus drive request; `{direction}` and `{step}` are filled in
+    pub drive_command: String,
+    /// Pause after each shot and focus move, in milliseconds
+    pub settle_ms: u64,
+}
+
+impl Default for FocusBracketConfig {
+    fn default() -> Self {
+        Self {
+            frames: 5,
+            step: 10,
+            direction: "far".to_string(),
+            drive_command: "exec_takemisc.cgi?com=drivefocus&direction={direction}&step={step}"
+                .to_string(),
+            settle_ms: 500,
+        }
+    }
+}
+
+impl FocusBracketConfig {
+    /// Focus drive request for one step
+    fn drive_request(&self) -> String {
+        self.drive_command
+            .replace("{direction}", &self.direction)
+            .replace("{step}", &self.step.to_string())
+    }
+}
+
+/// Automated focus bracketing for focus stacking
+pub trait FocusBracketing: PhotoCapture {
+    /// Capture a focus bracket, calling `progress` after each frame; returns frames taken
+    fn focus_bracket(
+        &self,
+        config: &FocusBracketConfig,
+        mut progress: impl FnMut(u32, u32),
+    ) -> Result<u32> {
+        if config.frames == 0 {
+            return Err(anyhow!("Focus bracket needs at least one frame"));
+        }
+        if config.direction != "near" && config.direction != "far" {
+            return Err(anyhow!(
+                "Focus direction must be 'near' or 'far', not '{}'",
+                config.direction
+            ));
+        }
+
+        info!(
+            "Starting focus bracket: {} frames, step {} {}",
+            config.frames, config.step, config.direction
+        );
+
+        // Autofocus would undo each focus step before the shot
+        if let Err(e) = self.set_camprop("FOCUS_STILL", "FOCUS_MF") {
+            warn!("Could not switch to manual focus: {}", e);
+        }
+
+        let settle = self
+            .timeouts()
+            .step_delay(Duration::from_millis(config.settle_ms));
+        let drive_request = config.drive_request();
+
+        for frame in 1..=config.frames {
+            self.take_raw_photo()?;
+            thread::sleep(settle);
+            progress(frame, config.frames);
+
+            if frame < config.frames {
+                self.get_page(&drive_request)
+                    .map_err(|e| anyhow!("Focus drive failed after frame {}: {}", frame, e))?;
+                thread::sleep(settle);
+            }
+        }
+
+        info!("Focus bracket complete");
+        Ok(config.frames)
+    }
+}
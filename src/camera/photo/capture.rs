@@ -1,92 +1,102 @@
-use anyhow::Result;
-use log::info;
+use anyhow::{Result, anyhow};
+use log::{debug, info};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::camera::client::basic::ClientOperations;
+use crate::camera::connection::{CameraMode, ModeManager};
+use crate::utils::audit::{self, AuditAction};
+
+/// Pause between checks while the camera processes a shot
+const PROCESSING_POLL: Duration = Duration::from_millis(500);
 
 /// Photo capture functionality
-pub trait PhotoCapture: ClientOperations {
+pub trait PhotoCapture: ModeManager {
     /// Take a photo with warm-up approach
     fn take_photo(&self) -> Result<()> {
         info!("Taking a photo with warm-up sequence");
 
-        // Get existing images before starting
-        let existing_images = match self.get_image_list() {
-            Ok(images) => images,
-            Err(_) => Vec::new(),
-        };
-
         // Take a warm-up photo first
         info!("Taking warm-up photo to initialize camera state");
-        self.take_raw_photo()?;
-
-        // Wait for camera to process warm-up
-        info!("Waiting 3 seconds after warm-up photo");
-        thread::sleep(Duration::from_secs(3));
+        let warm_up = self.take_photo_and_wait()?;
 
         // Now take the actual photo
         info!("Taking actual photo");
+        let actual = self.take_photo_and_wait()?;
+
+        info!(
+            "Photo capture successful - captured {} new images (including warm-up shot)",
+            warm_up.len() + actual.len()
+        );
+        info!("Photo sequence complete");
+        Ok(())
+    }
+
+    /// Take a photo and wait until the camera has written it, returning the new images
+    fn take_photo_and_wait(&self) -> Result<Vec<String>> {
+        let existing_images = self.get_image_list()?;
         self.take_raw_photo()?;
+        self.wait_for_shot(&existing_images)
+    }
 
-        // Wait for camera to process
-        thread::sleep(Duration::from_secs(3));
-
-        // Verify if new images were captured
-        match self.get_image_list() {
-            Ok(current_images) => {
-                let new_images: Vec<_> = current_images
-                    .into_iter()
-                    .filter(|img| !existing_images.contains(img))
-                    .collect();
-
-                let expected_count = 2; // Warm-up photo + actual photo
-                if !new_images.is_empty() {
-                    info!(
-                        "Photo capture successful - captured {} new images (including warm-up shot)",
-                        new_images.len()
-                    );
-
-                    if new_images.len() != expected_count {
-                        info!(
-                            "Expected {} photos but found {}",
-                            expected_count,
-                            new_images.len()
-                        );
+    /// Wait until images not in `existing_images` reach the card
+    ///
+    /// Long exposures keep the camera busy for the exposure and again as long for noise
+    /// reduction, refusing requests meanwhile, so failed polls count as still processing.
+    fn wait_for_shot(&self, existing_images: &[String]) -> Result<Vec<String>> {
+        let started = Instant::now();
+        loop {
+            thread::sleep(PROCESSING_POLL);
+            match self.get_image_list() {
+                Ok(images) => {
+                    let new_images: Vec<String> = images
+                        .into_iter()
+                        .filter(|image| !existing_images.contains(image))
+                        .collect();
+                    if !new_images.is_empty() {
+                        info!("Shot written after {:.1}s", started.elapsed().as_secs_f64());
+                        return Ok(new_images);
                     }
-                } else {
-                    info!("No new images were detected after photo sequence");
                 }
+                Err(e) => debug!("Camera still processing: {}", e),
             }
-            Err(e) => {
-                info!("Failed to verify new images: {}", e);
+
+            if started.elapsed() >= self.timeouts().capture() {
+                return Err(anyhow!(
+                    "No new image on the card within {} seconds of the shot",
+                    self.timeouts().capture_secs
+                ));
             }
         }
-
-        info!("Photo sequence complete");
-        Ok(())
     }
 
-    /// Internal method to take a raw photo
+    /// Internal method to take a raw photo, recorded in the audit log
     fn take_raw_photo(&self) -> Result<()> {
+        let result = self.release_shutter();
+        audit::record(AuditAction::Capture, "shutter", &result);
+        result
+    }
+
+    /// Send the shutter command once the camera is ready for it
+    fn release_shutter(&self) -> Result<()> {
+        self.ensure_writable("Capture")?;
         info!("Sending direct photo command to camera");
 
         // Make sure we're in rec mode
-        self.get_page("switch_cameramode.cgi?mode=rec")?;
+        self.ensure_mode(CameraMode::Rec)?;
 
         // Get state
-        self.get_page("get_state.cgi")?;
+        self.get_state()?;
 
         // Send the photo command - exact URL that works
         let url = format!("{}exec_takemotion.cgi?com=newstarttake", self.base_url());
 
         // Send the request with exact headers from working example
-        let response = self
-            .client()
-            .get(&url)
-            .header("user-agent", "OlympusCameraKit")
-            .header("content-length", "4096")
-            .send()?;
+        let response = self.send(
+            self.client()
+                .get(&url)
+                .header("user-agent", "OlympusCameraKit")
+                .header("content-length", "4096"),
+        )?;
 
         // Log but don't check status
         info!("Photo command sent with status: {}", response.status());
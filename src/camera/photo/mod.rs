@@ -1,5 +1,10 @@
 // Export photo capture submodule
+pub mod bracket;
 pub mod capture;
+pub mod movie;
+pub mod queue;
+pub mod tether;
+pub mod timelapse;
 
 // Re-export key components
 pub use capture::PhotoCapture;
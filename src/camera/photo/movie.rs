@@ -0,0 +1,83 @@
+use anyhow::Result;
+use log::{info, warn};
+use std::time::{Duration, Instant};
+
+use crate::camera::connection::{CameraMode, ModeManager};
+use crate::utils::audit::{self, AuditAction};
+
+/// Camera property holding the shooting mode
+const TAKEMODE_PROPERTY: &str = "TAKEMODE";
+
+/// Shooting mode in which the shutter command records a movie
+const MOVIE_TAKEMODE: &str = "movie";
+
+/// A movie being recorded on the camera
+#[derive(Debug, Clone)]
+pub struct MovieRecording {
+    started: Instant,
+    /// Shooting mode before the camera was switched to movie mode, restored when recording stops
+    previous_mode: Option<String>,
+}
+
+impl MovieRecording {
+    /// Time since recording started
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Time since recording started as `m:ss`
+    pub fn length(&self) -> String {
+        let secs = self.elapsed().as_secs();
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/// Movie recording started and stopped from the app
+pub trait MovieCapture: ModeManager {
+    /// Switch the camera to movie mode and start recording
+    fn start_movie(&self) -> Result<MovieRecording> {
+        self.ensure_writable("Recording movies")?;
+        self.ensure_mode(CameraMode::Rec)?;
+
+        let previous_mode = self.get_camprop(TAKEMODE_PROPERTY).ok();
+        if previous_mode.as_deref() != Some(MOVIE_TAKEMODE) {
+            self.set_camprop(TAKEMODE_PROPERTY, MOVIE_TAKEMODE)?;
+        }
+
+        let result = self.get_page("exec_takemotion.cgi?com=starttake");
+        audit::record(AuditAction::Capture, "movie start", &result);
+        if let Err(e) = result {
+            restore_mode(self, previous_mode.as_deref());
+            return Err(e);
+        }
+
+        info!("Movie recording started");
+        Ok(MovieRecording {
+            started: Instant::now(),
+            previous_mode,
+        })
+    }
+
+    /// Stop recording and put the camera back in the shooting mode it was in
+    fn stop_movie(&self, recording: &MovieRecording) -> Result<()> {
+        let result = self.get_page("exec_takemotion.cgi?com=stoptake");
+        audit::record(AuditAction::Capture, "movie stop", &result);
+        result?;
+
+        info!(
+            "Movie recording stopped after {:.1}s",
+            recording.elapsed().as_secs_f64()
+        );
+        restore_mode(self, recording.previous_mode.as_deref());
+        Ok(())
+    }
+}
+
+/// Go back to `mode` after recording, unless it was movie mode already
+fn restore_mode<C: MovieCapture + ?Sized>(camera: &C, mode: Option<&str>) {
+    if let Some(mode) = mode.filter(|mode| *mode != MOVIE_TAKEMODE)
+        && let Err(e) = camera.set_camprop(TAKEMODE_PROPERTY, mode)
+    {
+        warn!("Failed to restore shooting mode {}: {}", mode, e);
+    }
+}
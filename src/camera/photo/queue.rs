@@ -0,0 +1,219 @@
+use anyhow::{Result, anyhow};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use crate::camera::photo::capture::PhotoCapture;
+use crate::camera::profiles::{ProfileSettings, SettingsProfiles};
+
+/// Shots taken in turn, each with its own settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CaptureQueueConfig {
+    /// Shots in the order they are taken
+    pub shots: Vec<QueuedShot>,
+    /// Put the camera back to the settings it had before the queue once it ends
+    pub restore_settings: bool,
+}
+
+impl Default for CaptureQueueConfig {
+    fn default() -> Self {
+        Self {
+            shots: Vec::new(),
+            restore_settings: true,
+        }
+    }
+}
+
+/// One entry of the capture queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueuedShot {
+    /// Camera properties set before the shot; anything left out stays as the last shot had it
+    pub settings: ProfileSettings,
+    /// Number of shots taken with these settings
+    pub count: u32,
+}
+
+impl Default for QueuedShot {
+    fn default() -> Self {
+        Self {
+            settings: ProfileSettings::new(),
+            count: 1,
+        }
+    }
+}
+
+impl QueuedShot {
+    /// Settings as shown in progress, e.g. `ISOSPEEDVALUE=200 SHUTSPEEDVALUE=60`
+    pub fn describe(&self) -> String {
+        self.settings
+            .iter()
+            .map(|(property, value)| format!("{}={}", property, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl CaptureQueueConfig {
+    /// Shots in the whole queue, counting repeats
+    pub fn total_shots(&self) -> u32 {
+        self.shots.iter().map(|shot| shot.count).sum()
+    }
+}
+
+/// Progress of a running queue, shared with the UI
+#[derive(Debug, Clone, Default)]
+pub struct QueueStatus {
+    /// Shots taken so far
+    pub shots_taken: u32,
+    /// Shots in the queue
+    pub shots_total: u32,
+    /// Settings of the entry being shot
+    pub current: Option<String>,
+    /// Whether the queue has ended
+    pub finished: bool,
+    /// Error that ended the queue early
+    pub error: Option<String>,
+}
+
+/// Works through a capture queue on a background thread
+pub struct CaptureQueue {
+    status: Arc<Mutex<QueueStatus>>,
+    running: Arc<AtomicBool>,
+}
+
+impl CaptureQueue {
+    /// Start taking the configured shots
+    pub fn start(camera: OlympusCamera, config: &CaptureQueueConfig) -> Result<Self> {
+        let total = config.total_shots();
+        if total == 0 {
+            return Err(anyhow!("The capture queue is empty"));
+        }
+        camera.ensure_writable("Capture queue")?;
+
+        let status = Arc::new(Mutex::new(QueueStatus {
+            shots_total: total,
+            ..QueueStatus::default()
+        }));
+        let running = Arc::new(AtomicBool::new(true));
+
+        info!(
+            "Capture queue started: {} shots in {} entries",
+            total,
+            config.shots.len()
+        );
+
+        let thread_status = Arc::clone(&status);
+        let thread_running = Arc::clone(&running);
+        let config = config.clone();
+        thread::spawn(move || {
+            let result = run_queue(&camera, &config, &thread_status, &thread_running);
+            if let Ok(mut status) = thread_status.lock() {
+                status.finished = true;
+                if let Err(e) = result {
+                    error!("Capture queue stopped: {}", e);
+                    status.error = Some(e.to_string());
+                }
+            }
+        });
+
+        Ok(Self { status, running })
+    }
+
+    /// Snapshot of the queue progress
+    pub fn status(&self) -> QueueStatus {
+        self.status
+            .lock()
+            .map(|status| status.clone())
+            .unwrap_or_default()
+    }
+
+    /// Ask the queue to stop after the current shot
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl Drop for CaptureQueue {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Take every shot, restoring the earlier settings afterwards if configured
+fn run_queue(
+    camera: &OlympusCamera,
+    config: &CaptureQueueConfig,
+    status: &Arc<Mutex<QueueStatus>>,
+    running: &Arc<AtomicBool>,
+) -> Result<()> {
+    let original = if config.restore_settings {
+        Some(camera.read_profile()?)
+    } else {
+        None
+    };
+
+    let result = take_shots(camera, config, status, running);
+
+    if let Some(original) = original {
+        match camera.apply_profile(&original) {
+            Ok(_) => info!("Restored the settings from before the capture queue"),
+            Err(e) => warn!("Could not restore the earlier settings: {}", e),
+        }
+    }
+    result
+}
+
+/// Apply each entry's settings and take its shots, waiting for every shot to be written
+fn take_shots(
+    camera: &OlympusCamera,
+    config: &CaptureQueueConfig,
+    status: &Arc<Mutex<QueueStatus>>,
+    running: &Arc<AtomicBool>,
+) -> Result<()> {
+    let mut taken = 0;
+    for (entry, shot) in config.shots.iter().enumerate() {
+        if shot.count == 0 {
+            continue;
+        }
+
+        // A shot with the wrong settings would spoil the comparison, so every one must apply
+        let applied = camera.apply_profile(&shot.settings)?;
+        if applied < shot.settings.len() {
+            return Err(anyhow!(
+                "Camera rejected some settings of entry {}: {}",
+                entry + 1,
+                shot.describe()
+            ));
+        }
+        if let Ok(mut status) = status.lock() {
+            status.current = Some(shot.describe());
+        }
+
+        for _ in 0..shot.count {
+            if !running.load(Ordering::Relaxed) {
+                info!("Capture queue cancelled after {} shots", taken);
+                return Ok(());
+            }
+            camera.take_photo_and_wait()?;
+            taken += 1;
+            info!(
+                "Capture queue shot {}/{} taken ({})",
+                taken,
+                config.total_shots(),
+                shot.describe()
+            );
+            if let Ok(mut status) = status.lock() {
+                status.shots_taken = taken;
+            }
+        }
+    }
+
+    info!("Capture queue complete");
+    Ok(())
+}
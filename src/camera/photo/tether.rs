@@ -0,0 +1,97 @@
+use anyhow::{Result, anyhow};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::camera::photo::capture::PhotoCapture;
+use crate::utils::atomic;
+
+/// Time the camera needs to finish writing a shot before it can be fetched
+const TETHER_SETTLE: Duration = Duration::from_millis(1500);
+
+/// Tethered capture settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TetherConfig {
+    /// Pull every captured photo straight to the host
+    pub enabled: bool,
+    /// Directory tethered photos are saved to
+    pub directory: String,
+    /// Refresh the camera image list after each tethered shot
+    pub refresh_list: bool,
+}
+
+impl Default for TetherConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: "tethered".to_string(),
+            refresh_list: false,
+        }
+    }
+}
+
+/// Capture with immediate transfer of the full-resolution file
+pub trait TetheredCapture: PhotoCapture {
+    /// Take a single photo and save it to the given directory, returning its path
+    fn capture_to_host(&self, directory: &Path) -> Result<PathBuf> {
+        info!("Taking tethered photo");
+
+        let existing_images = self.get_image_list().unwrap_or_default();
+
+        // A single shot without warm-up keeps shot-to-screen time short
+        self.take_raw_photo()?;
+        thread::sleep(self.timeouts().step_delay(TETHER_SETTLE));
+
+        fs::create_dir_all(directory)?;
+
+        // The camera can hand back the shot it just took without a card lookup
+        match self.get_binary("exec_takemisc.cgi?com=getlastjpg") {
+            Ok(data) if is_jpeg(&data) => {
+                let secs = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or_default();
+                let path = directory.join(format!("tethered_{}.jpg", secs));
+                atomic::write_atomic(&path, &data)?;
+                info!("Tethered photo saved to {:?}", path);
+                return Ok(path);
+            }
+            Ok(_) => warn!("Last JPEG request did not return an image"),
+            Err(e) => warn!("Last JPEG request failed: {}", e),
+        }
+
+        // Fall back to finding the new file on the card
+        let new_image = self
+            .get_image_list()?
+            .into_iter()
+            .filter(|image| !existing_images.contains(image))
+            .max()
+            .ok_or_else(|| anyhow!("No new image found after capture"))?;
+
+        let data = self.get_binary(&format!(
+            "get_img.cgi?DIR={}/{}",
+            self.image_folder(),
+            new_image
+        ))?;
+        if !is_jpeg(&data) {
+            return Err(anyhow!(
+                "Camera did not return image data for {}",
+                new_image
+            ));
+        }
+
+        let path = directory.join(&new_image);
+        atomic::write_atomic(&path, &data)?;
+        info!("Tethered photo saved to {:?}", path);
+        Ok(path)
+    }
+}
+
+/// Whether the data starts with a JPEG SOI marker
+fn is_jpeg(data: &[u8]) -> bool {
+    data.len() >= 2 && data[0] == 0xFF && data[1] == 0xD8
+}
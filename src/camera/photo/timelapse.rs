@@ -0,0 +1,418 @@
+use anyhow::{Result, anyhow};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use crate::camera::photo::capture::PhotoCapture;
+use crate::camera::settings::CameraSetting;
+#[cfg(feature = "ramping")]
+use crate::terminal::video_viewer::meter::LiveViewMeter;
+use crate::utils::session;
+use crate::utils::solar::{self, SECONDS_PER_DAY, SolarEvent};
+
+/// Days ahead a solar schedule looks for its next window
+const MAX_SCHEDULE_DAYS: i64 = 7;
+
+/// Time after a shot before live view shows the scene again
+const LIVE_VIEW_SETTLE: Duration = Duration::from_millis(500);
+
+/// Intervalometer settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimelapseConfig {
+    /// Seconds between shots
+    pub interval_secs: u64,
+    /// Number of shots in the sequence
+    pub frames: u32,
+    /// Automatic exposure ramping for day-to-night sequences
+    pub ramp: RampConfig,
+    /// Shooting window tied to sunrise or sunset, replacing the frame count
+    pub schedule: SolarScheduleConfig,
+}
+
+impl Default for TimelapseConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 10,
+            frames: 100,
+            ramp: RampConfig::default(),
+            schedule: SolarScheduleConfig::default(),
+        }
+    }
+}
+
+/// A time relative to sunrise or sunset
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SolarTrigger {
+    pub event: SolarEvent,
+    /// Minutes after the event, negative for before it
+    pub offset_mins: i64,
+}
+
+/// Solar schedule settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SolarScheduleConfig {
+    /// Wait for `start` and shoot until `end` instead of taking a fixed number of frames
+    pub enabled: bool,
+    /// Degrees north, negative in the southern hemisphere
+    pub latitude: f64,
+    /// Degrees east, negative west of Greenwich
+    pub longitude: f64,
+    /// When the first shot is taken
+    pub start: SolarTrigger,
+    /// When the sequence ends
+    pub end: SolarTrigger,
+}
+
+impl Default for SolarScheduleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            latitude: 0.0,
+            longitude: 0.0,
+            start: SolarTrigger {
+                event: SolarEvent::Sunset,
+                offset_mins: -30,
+            },
+            end: SolarTrigger {
+                event: SolarEvent::Sunset,
+                offset_mins: 30,
+            },
+        }
+    }
+}
+
+impl SolarScheduleConfig {
+    /// Unix time of a trigger on `day`, counted in days since the Unix epoch
+    fn time_of(&self, trigger: SolarTrigger, day: i64) -> Option<i64> {
+        solar::sun_event(day, self.latitude, self.longitude, trigger.event)
+            .map(|time| time + trigger.offset_mins * 60)
+    }
+
+    /// Start and end of the first window that hasn't ended by `now`, in Unix seconds
+    ///
+    /// A window already under way starts at `now`. The end is the first one after the start,
+    /// so a window from sunset to sunrise runs overnight.
+    pub fn next_window(&self, now: i64) -> Result<(i64, i64)> {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        for day in today - 1..=today + MAX_SCHEDULE_DAYS {
+            let Some(start) = self.time_of(self.start, day) else {
+                continue;
+            };
+            let end = (day..=day + 1)
+                .filter_map(|day| self.time_of(self.end, day))
+                .find(|end| *end > start);
+            if let Some(end) = end.filter(|end| *end > now) {
+                return Ok((start.max(now), end));
+            }
+        }
+        Err(anyhow!(
+            "The sun doesn't rise and set at {}, {} in the coming week",
+            self.latitude,
+            self.longitude
+        ))
+    }
+}
+
+/// Exposure ramping settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RampConfig {
+    /// Adjust shutter and ISO between shots to hold scene brightness
+    pub enabled: bool,
+    /// Mean brightness to aim for, from 0 to 255
+    pub target_brightness: f64,
+    /// Brightness error, in EV, that is ignored to avoid flicker
+    pub tolerance_ev: f64,
+    /// Largest change per shot, in third-stop steps
+    pub max_steps_per_frame: usize,
+    /// Shutter speeds in third stops from shortest to longest
+    pub shutter_values: Vec<String>,
+    /// ISO values in third stops from lowest to highest
+    pub iso_values: Vec<String>,
+    /// Shutter speed the sequence starts with
+    pub start_shutter: String,
+    /// Port the live view used for metering is received on
+    pub udp_port: u16,
+}
+
+impl Default for RampConfig {
+    fn default() -> Self {
+        let shutters = [
+            "1000", "800", "640", "500", "400", "320", "250", "200", "160", "125", "100", "80",
+            "60", "50", "40", "30", "25", "20", "15", "13", "10", "8", "6", "5", "4", "3", "2.5",
+            "2", "1.6", "1.3", "1\"", "1.3\"", "1.6\"", "2\"", "2.5\"", "3.2\"", "4\"", "5\"",
+            "6\"", "8\"", "10\"", "13\"", "15\"", "20\"", "25\"", "30\"",
+        ];
+        let isos = [
+            "200", "250", "320", "400", "500", "640", "800", "1000", "1250", "1600", "2000",
+            "2500", "3200",
+        ];
+
+        Self {
+            enabled: false,
+            target_brightness: 118.0,
+            tolerance_ev: 0.33,
+            max_steps_per_frame: 1,
+            shutter_values: shutters.iter().map(|s| s.to_string()).collect(),
+            iso_values: isos.iter().map(|s| s.to_string()).collect(),
+            start_shutter: "250".to_string(),
+            udp_port: 65002,
+        }
+    }
+}
+
+impl RampConfig {
+    /// Number of third-stop positions: every shutter at base ISO, then every higher ISO
+    fn ladder_len(&self) -> usize {
+        self.shutter_values.len() + self.iso_values.len().saturating_sub(1)
+    }
+
+    /// Shutter and ISO for a ladder position, from brightest scene to darkest
+    fn exposure_at(&self, index: usize) -> (&str, &str) {
+        let shutters = self.shutter_values.len();
+        if index < shutters {
+            (&self.shutter_values[index], &self.iso_values[0])
+        } else {
+            (
+                &self.shutter_values[shutters - 1],
+                &self.iso_values[index - shutters + 1],
+            )
+        }
+    }
+
+    /// Ladder position for the next shot given the measured brightness
+    fn next_index(&self, index: usize, brightness: f64) -> usize {
+        // Third stops needed to reach the target, positive when the scene is too dark
+        let error_ev = (self.target_brightness / brightness.max(1.0)).log2();
+        if error_ev.abs() <= self.tolerance_ev {
+            return index;
+        }
+
+        let steps = ((error_ev * 3.0).round().abs() as usize).min(self.max_steps_per_frame);
+        if error_ev > 0.0 {
+            (index + steps).min(self.ladder_len() - 1)
+        } else {
+            index.saturating_sub(steps)
+        }
+    }
+}
+
+/// Progress of a running sequence, shared with the UI
+#[derive(Debug, Clone, Default)]
+pub struct TimelapseStatus {
+    /// Shots taken so far
+    pub frames_taken: u32,
+    /// Shots in the sequence
+    pub frames_total: u32,
+    /// Unix time of the first shot, for a sequence waiting on its solar schedule
+    pub starts_at: Option<u64>,
+    /// Current shutter speed and ISO when ramping
+    pub exposure: Option<String>,
+    /// Mean brightness of the last live view reading, 0 to 255
+    pub brightness: Option<f64>,
+    /// Whether the sequence has ended
+    pub finished: bool,
+    /// Error that ended the sequence early
+    pub error: Option<String>,
+}
+
+/// Takes a sequence of shots at a fixed interval on a background thread
+pub struct Intervalometer {
+    status: Arc<Mutex<TimelapseStatus>>,
+    running: Arc<AtomicBool>,
+}
+
+impl Intervalometer {
+    /// Start a sequence with the given settings
+    pub fn start(camera: OlympusCamera, config: &TimelapseConfig) -> Result<Self> {
+        if config.frames == 0 {
+            return Err(anyhow!("Timelapse needs at least one frame"));
+        }
+        if config.ramp.enabled
+            && (config.ramp.shutter_values.is_empty() || config.ramp.iso_values.is_empty())
+        {
+            return Err(anyhow!("Exposure ramping needs shutter and ISO values"));
+        }
+        camera.ensure_writable("Timelapse")?;
+
+        let mut config = config.clone();
+        let mut starts_at = None;
+        if config.schedule.enabled {
+            let (start, end) = config.schedule.next_window(session::unix_now() as i64)?;
+            // A shot at the start and one every interval until the end
+            config.frames = ((end - start) as u64 / config.interval_secs.max(1)) as u32 + 1;
+            starts_at = Some(start as u64);
+            info!(
+                "Timelapse scheduled from {} to {} UTC",
+                session::datetime(start as u64),
+                session::datetime(end as u64)
+            );
+        }
+
+        let status = Arc::new(Mutex::new(TimelapseStatus {
+            frames_total: config.frames,
+            starts_at,
+            ..TimelapseStatus::default()
+        }));
+        let running = Arc::new(AtomicBool::new(true));
+
+        info!(
+            "Timelapse started: {} frames every {}s",
+            config.frames, config.interval_secs
+        );
+
+        let thread_status = Arc::clone(&status);
+        let thread_running = Arc::clone(&running);
+        thread::spawn(move || {
+            let result = run_sequence(&camera, &config, &thread_status, &thread_running);
+            if let Ok(mut status) = thread_status.lock() {
+                status.finished = true;
+                if let Err(e) = result {
+                    error!("Timelapse stopped: {}", e);
+                    status.error = Some(e.to_string());
+                }
+            }
+        });
+
+        Ok(Self { status, running })
+    }
+
+    /// Snapshot of the sequence progress
+    pub fn status(&self) -> TimelapseStatus {
+        self.status
+            .lock()
+            .map(|status| status.clone())
+            .unwrap_or_default()
+    }
+
+    /// Ask the sequence to stop after the current shot
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl Drop for Intervalometer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Take every shot of the sequence, ramping exposure between shots if enabled
+fn run_sequence(
+    camera: &OlympusCamera,
+    config: &TimelapseConfig,
+    status: &Arc<Mutex<TimelapseStatus>>,
+    running: &Arc<AtomicBool>,
+) -> Result<()> {
+    let interval = Duration::from_secs(config.interval_secs.max(1));
+    let ramp = &config.ramp;
+
+    let mut index = ramp
+        .shutter_values
+        .iter()
+        .position(|shutter| *shutter == ramp.start_shutter)
+        .unwrap_or(0);
+    let mut applied: Option<usize> = None;
+
+    let starts_at = status.lock().ok().and_then(|status| status.starts_at);
+    if let Some(starts_at) = starts_at {
+        while session::unix_now() < starts_at {
+            if !running.load(Ordering::Relaxed) {
+                info!("Scheduled timelapse cancelled before it started");
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    // Live view keeps running between shots, so the scene is metered before each one
+    let meter = if ramp.enabled {
+        let meter = LiveViewMeter::start(camera, ramp.udp_port)?;
+        // Ramping only works if the camera doesn't pick the exposure itself
+        camera.set_camprop("TAKEMODE", "M")?;
+        Some(meter)
+    } else {
+        None
+    };
+    // Live view frames from this time on show the exposure that is set
+    let mut settled = Instant::now();
+
+    for frame in 1..=config.frames {
+        if !running.load(Ordering::Relaxed) {
+            info!("Timelapse cancelled after {} frames", frame - 1);
+            return Ok(());
+        }
+        let started = Instant::now();
+
+        let mut brightness = None;
+        if let Some(meter) = &meter {
+            // The first shot is taken at the start exposure
+            if applied.is_some() {
+                brightness = meter.brightness_after(settled);
+                if let Some(brightness) = brightness {
+                    index = ramp.next_index(index, brightness);
+                }
+            }
+
+            if applied != Some(index) {
+                let (shutter, iso) = ramp.exposure_at(index);
+                camera.set_camprop(CameraSetting::Shutter.property(), shutter)?;
+                camera.set_camprop(CameraSetting::Iso.property(), iso)?;
+                applied = Some(index);
+                if let Ok(mut status) = status.lock() {
+                    status.exposure = Some(format!("{} ISO {}", shutter, iso));
+                }
+            }
+        }
+
+        // The next shot waits for this one to be written, or the camera would drop it
+        camera.take_photo_and_wait()?;
+        settled = Instant::now() + LIVE_VIEW_SETTLE;
+        info!("Timelapse frame {}/{} taken", frame, config.frames);
+
+        if let Ok(mut status) = status.lock() {
+            status.frames_taken = frame;
+            status.brightness = brightness.or(status.brightness);
+        }
+
+        // Wait out the rest of the interval, checking for cancellation
+        if frame < config.frames {
+            if started.elapsed() > interval {
+                warn!(
+                    "Frame {} took {:.1}s, longer than the {}s interval",
+                    frame,
+                    started.elapsed().as_secs_f64(),
+                    interval.as_secs()
+                );
+            }
+            while started.elapsed() < interval && running.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(100));
+            }
+        }
+    }
+
+    info!("Timelapse complete");
+    Ok(())
+}
+
+/// Without the live view receiver and an image decoder there is nothing to meter
+#[cfg(not(feature = "ramping"))]
+struct LiveViewMeter;
+
+#[cfg(not(feature = "ramping"))]
+impl LiveViewMeter {
+    fn start(_camera: &OlympusCamera, _udp_port: u16) -> Result<Self> {
+        Err(anyhow!("Exposure ramping needs the ramping feature"))
+    }
+
+    fn brightness_after(&self, _since: Instant) -> Option<f64> {
+        None
+    }
+}
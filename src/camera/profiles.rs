@@ -0,0 +1,162 @@
+use anyhow::{Context, Result, anyhow};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::camera::connection::{CameraMode, ModeManager};
+
+/// Properties saved in a profile, in the order they are applied
+///
+/// The exposure mode goes first since it decides which of the others the camera accepts.
+pub const PROFILE_PROPERTIES: [&str; 7] = [
+    "TAKEMODE",
+    "TAKE_DRIVE",
+    "APERTUREVALUE",
+    "SHUTSPEEDVALUE",
+    "ISOSPEEDVALUE",
+    "EXPREV",
+    "WBVALUE",
+];
+
+/// Settings profile names and storage location
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileConfig {
+    /// File the saved profiles are kept in
+    pub file: String,
+    /// Profile names offered even before anything is saved under them
+    pub names: Vec<String>,
+}
+
+impl Default for ProfileConfig {
+    fn default() -> Self {
+        Self {
+            file: "olympus_profiles.json".to_string(),
+            names: vec![
+                "studio".to_string(),
+                "timelapse".to_string(),
+                "street".to_string(),
+            ],
+        }
+    }
+}
+
+/// Camera property values by property name
+pub type ProfileSettings = BTreeMap<String, String>;
+
+/// Saved settings profiles by name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileStore {
+    pub profiles: BTreeMap<String, ProfileSettings>,
+}
+
+impl ProfileStore {
+    /// Load saved profiles, starting empty if the file is missing or invalid
+    pub fn load(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let loaded = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))
+            .and_then(|text| {
+                serde_json::from_str(&text)
+                    .with_context(|| format!("Failed to parse {}", path.display()))
+            });
+
+        match loaded {
+            Ok(store) => {
+                info!("Loaded settings profiles from {}", path.display());
+                store
+            }
+            Err(e) => {
+                warn!("Failed to load settings profiles: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write all profiles to the given file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+        fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+        info!("Saved settings profiles to {}", path.display());
+        Ok(())
+    }
+
+    /// Settings saved under a name
+    pub fn get(&self, name: &str) -> Option<&ProfileSettings> {
+        self.profiles.get(name)
+    }
+
+    /// Save settings under a name, replacing any existing profile
+    pub fn insert(&mut self, name: &str, settings: ProfileSettings) {
+        self.profiles.insert(name.to_string(), settings);
+    }
+
+    /// Configured names followed by any other saved profiles
+    pub fn names(&self, configured: &[String]) -> Vec<String> {
+        let mut names = configured.to_vec();
+        for name in self.profiles.keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    }
+}
+
+/// Reading and re-applying named groups of camera settings
+pub trait SettingsProfiles: ModeManager {
+    /// Read the profile properties from the camera, skipping any it doesn't report
+    fn read_profile(&self) -> Result<ProfileSettings> {
+        self.ensure_mode(CameraMode::Rec)?;
+
+        let mut settings = ProfileSettings::new();
+        for property in PROFILE_PROPERTIES {
+            match self.get_camprop(property) {
+                Ok(value) => {
+                    settings.insert(property.to_string(), value);
+                }
+                Err(e) => warn!("Could not read {}: {}", property, e),
+            }
+        }
+
+        if settings.is_empty() {
+            return Err(anyhow!("Camera did not report any settings"));
+        }
+        Ok(settings)
+    }
+
+    /// Apply saved settings, returning how many the camera accepted
+    fn apply_profile(&self, settings: &ProfileSettings) -> Result<usize> {
+        self.ensure_mode(CameraMode::Rec)?;
+
+        // Known properties in their dependency order, then anything added by hand
+        let ordered = PROFILE_PROPERTIES
+            .iter()
+            .filter_map(|property| settings.get_key_value(*property))
+            .chain(
+                settings
+                    .iter()
+                    .filter(|(property, _)| !PROFILE_PROPERTIES.contains(&property.as_str())),
+            );
+
+        let mut applied = 0;
+        for (property, value) in ordered {
+            // Some values are rejected in the wrong exposure mode; keep going with the rest
+            match self.set_camprop(property, value) {
+                Ok(_) => applied += 1,
+                Err(e) => warn!("Could not apply {} = {}: {}", property, value, e),
+            }
+        }
+
+        if applied == 0 && !settings.is_empty() {
+            return Err(anyhow!("Camera rejected every setting in the profile"));
+        }
+        Ok(applied)
+    }
+}
@@ -0,0 +1,252 @@
+use anyhow::{Result, anyhow};
+use log::{info, warn};
+use regex::Regex;
+
+use crate::camera::client::error::FailureKind;
+use crate::camera::connection::{CameraMode, ModeManager};
+use crate::utils::i18n::tr;
+
+/// Exposure compensation values the camera accepts, in third stops
+pub const EV_VALUES: [&str; 19] = [
+    "-3.0", "-2.7", "-2.3", "-2.0", "-1.7", "-1.3", "-1.0", "-0.7", "-0.3", "0.0", "+0.3", "+0.7",
+    "+1.0", "+1.3", "+1.7", "+2.0", "+2.3", "+2.7", "+3.0",
+];
+
+/// White balance presets as (camera value, label)
+pub const WB_PRESETS: [(&str, &str); 8] = [
+    ("0", "Auto"),
+    ("18", "Daylight"),
+    ("16", "Shade"),
+    ("17", "Cloudy"),
+    ("20", "Tungsten"),
+    ("35", "Fluorescent"),
+    ("64", "Flash"),
+    ("23", "Underwater"),
+];
+
+/// White balance names some firmware reports instead of numbers, as (camera value, label)
+const WB_NAMES: [(&str, &str); 8] = [
+    ("WB_AUTO", "Auto"),
+    ("MWB_FINE", "Daylight"),
+    ("MWB_SHADE", "Shade"),
+    ("MWB_CLOUD", "Cloudy"),
+    ("MWB_LAMP", "Tungsten"),
+    ("MWB_FLUORESCENCE1", "Fluorescent"),
+    ("MWB_FLASH", "Flash"),
+    ("MWB_WATER_1", "Underwater"),
+];
+
+/// ISO values offered when the camera doesn't list its own
+const ISO_VALUES: [&str; 20] = [
+    "Auto", "Low", "200", "250", "320", "400", "500", "640", "800", "1000", "1250", "1600", "2000",
+    "2500", "3200", "4000", "5000", "6400", "8000", "12800",
+];
+
+/// Shutter speeds offered when the camera doesn't list its own
+///
+/// Values ending in `"` are whole seconds, the others the denominator of a fraction.
+const SHUTTER_VALUES: [&str; 36] = [
+    "60\"", "30\"", "15\"", "8\"", "4\"", "2\"", "1\"", "2", "4", "8", "10", "13", "15", "20",
+    "25", "30", "40", "50", "60", "80", "100", "125", "160", "200", "250", "320", "400", "500",
+    "640", "800", "1000", "1250", "1600", "2000", "3200", "4000",
+];
+
+/// Apertures offered when the camera doesn't list its own, which depend on the lens
+const APERTURE_VALUES: [&str; 22] = [
+    "1.8", "2.0", "2.2", "2.5", "2.8", "3.2", "3.5", "4.0", "4.5", "5.0", "5.6", "6.3", "7.1",
+    "8.0", "9.0", "10", "11", "13", "14", "16", "18", "22",
+];
+
+/// Exposure settings that can be changed from the settings screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraSetting {
+    Iso,
+    Shutter,
+    Aperture,
+    WhiteBalance,
+    ExposureCompensation,
+}
+
+/// Settings in the order they are shown
+pub const CAMERA_SETTINGS: [CameraSetting; 5] = [
+    CameraSetting::Iso,
+    CameraSetting::Shutter,
+    CameraSetting::Aperture,
+    CameraSetting::WhiteBalance,
+    CameraSetting::ExposureCompensation,
+];
+
+impl CameraSetting {
+    /// Camera property holding the setting, as `get_camprop.cgi` names it
+    pub fn property(&self) -> &'static str {
+        match self {
+            CameraSetting::Iso => "ISOSPEEDVALUE",
+            CameraSetting::Shutter => "SHUTSPEEDVALUE",
+            CameraSetting::Aperture => "APERTUREVALUE",
+            CameraSetting::WhiteBalance => "WBVALUE",
+            CameraSetting::ExposureCompensation => "EXPREV",
+        }
+    }
+
+    /// Untranslated name
+    pub fn label(&self) -> &'static str {
+        match self {
+            CameraSetting::Iso => "ISO",
+            CameraSetting::Shutter => "Shutter speed",
+            CameraSetting::Aperture => "Aperture",
+            CameraSetting::WhiteBalance => "White balance",
+            CameraSetting::ExposureCompensation => "Exposure compensation",
+        }
+    }
+
+    /// Values offered when the camera doesn't describe the property
+    fn fallback_values(&self) -> Vec<String> {
+        let values: &[&str] = match self {
+            CameraSetting::Iso => &ISO_VALUES,
+            CameraSetting::Shutter => &SHUTTER_VALUES,
+            CameraSetting::Aperture => &APERTURE_VALUES,
+            CameraSetting::ExposureCompensation => &EV_VALUES,
+            CameraSetting::WhiteBalance => {
+                return WB_PRESETS
+                    .iter()
+                    .map(|(value, _)| value.to_string())
+                    .collect();
+            }
+        };
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    /// Camera value written the way photographers read it, e.g. `1/250` or `f/5.6`
+    pub fn display_value(&self, value: &str) -> String {
+        match self {
+            CameraSetting::Shutter => match value.strip_suffix('"') {
+                Some(seconds) => format!("{}s", seconds),
+                None => format!("1/{}", value),
+            },
+            CameraSetting::Aperture => format!("f/{}", value),
+            CameraSetting::WhiteBalance => WB_PRESETS
+                .iter()
+                .chain(WB_NAMES.iter())
+                .find(|(wb, _)| *wb == value)
+                .map_or(value.to_string(), |(_, label)| tr(label).to_string()),
+            CameraSetting::ExposureCompensation => format!("{} EV", value),
+            CameraSetting::Iso => value.to_string(),
+        }
+    }
+}
+
+/// A setting as the camera reported it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingState {
+    pub setting: CameraSetting,
+    /// Current value, if the camera reported it
+    pub value: Option<String>,
+    /// Values that can be chosen, in the camera's order
+    pub choices: Vec<String>,
+    /// False when the exposure mode fixes the setting, e.g. the aperture in shutter priority
+    pub writable: bool,
+}
+
+impl SettingState {
+    /// Value `steps` places away from the current one, stopping at either end
+    ///
+    /// Returns `None` when there is nothing to move to. A value missing from
+    /// the choices counts as the first one.
+    pub fn step(&self, steps: isize) -> Option<&str> {
+        let current = self
+            .value
+            .as_ref()
+            .and_then(|value| self.choices.iter().position(|choice| choice == value));
+        let index = current
+            .unwrap_or(0)
+            .saturating_add_signed(steps)
+            .min(self.choices.len().checked_sub(1)?);
+        (Some(index) != current).then(|| self.choices[index].as_str())
+    }
+}
+
+/// First `<tag>` in a camera response
+fn element(text: &str, tag: &str) -> Option<String> {
+    let re = Regex::new(&format!(r"<{0}>([^<]*)</{0}>", tag)).ok()?;
+    re.captures(text).map(|caps| caps[1].trim().to_string())
+}
+
+/// Reading and changing ISO, shutter speed, aperture, white balance and exposure compensation
+pub trait CameraSettings: ModeManager {
+    /// Read a setting with the values the camera accepts for it
+    ///
+    /// Cameras that don't answer `com=desc` are read with `com=get` and
+    /// offered the built-in values.
+    fn describe_setting(&self, setting: CameraSetting) -> Result<SettingState> {
+        let property = setting.property();
+        match self.get_text(&format!("get_camprop.cgi?com=desc&propname={}", property)) {
+            Ok(text) => {
+                let choices: Vec<String> = element(&text, "enum")
+                    .map(|values| values.split_whitespace().map(str::to_string).collect())
+                    .unwrap_or_default();
+                let value = element(&text, "value");
+                if !choices.is_empty() && value.is_some() {
+                    return Ok(SettingState {
+                        setting,
+                        value,
+                        choices,
+                        writable: element(&text, "attribute").is_none_or(|a| a.contains("set")),
+                    });
+                }
+                info!("Camera didn't list the values of {}", property);
+            }
+            Err(e) if FailureKind::classify(&e) == FailureKind::NotFound => {
+                info!("Camera doesn't describe {}", property);
+            }
+            Err(e) => return Err(e),
+        }
+
+        Ok(SettingState {
+            setting,
+            value: Some(self.get_camprop(property)?),
+            choices: setting.fallback_values(),
+            writable: true,
+        })
+    }
+
+    /// Read every setting, leaving out the value of any the camera doesn't report
+    ///
+    /// Settings whose property the camera's capability list leaves out aren't offered.
+    fn read_settings(&self) -> Result<Vec<SettingState>> {
+        self.ensure_mode(CameraMode::Rec)?;
+
+        let capabilities = self.known_capabilities();
+        let mut settings = Vec::new();
+        for setting in CAMERA_SETTINGS {
+            if !capabilities.allows_property(setting.property()) {
+                info!("Camera doesn't list {}, leaving it out", setting.property());
+                continue;
+            }
+            match self.describe_setting(setting) {
+                Ok(state) => settings.push(state),
+                Err(e) => {
+                    warn!("Could not read {}: {}", setting.property(), e);
+                    settings.push(SettingState {
+                        setting,
+                        value: None,
+                        choices: setting.fallback_values(),
+                        writable: true,
+                    });
+                }
+            }
+        }
+
+        if settings.iter().all(|state| state.value.is_none()) {
+            return Err(anyhow!("Camera did not report any settings"));
+        }
+        Ok(settings)
+    }
+
+    /// Send a new value for a setting, recorded in the audit log
+    fn change_setting(&self, setting: CameraSetting, value: &str) -> Result<()> {
+        self.ensure_mode(CameraMode::Rec)?;
+        self.set_camprop(setting.property(), value)?;
+        info!("{} set to {}", setting.label(), value);
+        Ok(())
+    }
+}
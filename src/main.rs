@@ -1,5 +1,7 @@
 // src/main.rs
 mod camera;
+mod net;
+mod plugins;
 mod terminal;
 mod utils;
 
@@ -12,6 +14,27 @@ fn main() {
     // Check for debug mode argument
     let debug_mode = env::args().any(|arg| arg == "--debug");
 
+    // Check for screen-reader friendly plain mode
+    let plain_mode = env::args().any(|arg| arg == "--plain");
+
+    // Check for starting straight into live view
+    let liveview_mode = env::args().any(|arg| arg == "--liveview");
+
+    // Check for the slow camera profile
+    let slow_camera = env::args().any(|arg| arg == "--slow-camera");
+
+    // Check for running the setup questions again
+    let setup_mode = env::args().any(|arg| arg == "--setup");
+
+    // Check for logging the full camera protocol
+    let protocol_log = env::args().any(|arg| arg == "--protocol-log");
+
+    // Check for the read-only monitor mode
+    let read_only = env::args().any(|arg| arg == "--read-only");
+
+    // Check for running against the built-in fake camera
+    let demo_mode = env::args().any(|arg| arg == "--demo");
+
     // Initialize logging only if in debug mode
     if debug_mode {
         utils::logging::init();
@@ -25,6 +48,30 @@ fn main() {
     }
 
     // Print welcome message
+    if plain_mode {
+        println!("Olympus Air camera and video control");
+    } else {
+        print_banner();
+    }
+
+    // Run the application with proper error handling
+    if let Err(e) = run(
+        plain_mode,
+        liveview_mode,
+        slow_camera,
+        setup_mode,
+        protocol_log,
+        read_only,
+        demo_mode,
+    ) {
+        eprintln!("{} {}", "ERROR:".red().bold(), e);
+        eprintln!("{}", "Application terminated with errors.".red());
+        process::exit(1);
+    }
+}
+
+/// Print the boxed welcome banner
+fn print_banner() {
     println!(
         "{}",
         "╔════════════════════════════════════════╗".bright_cyan()
@@ -39,21 +86,88 @@ fn main() {
         "{}",
         "╚════════════════════════════════════════╝".bright_cyan()
     );
+}
 
-    // Run the application with proper error handling
-    if let Err(e) = run() {
-        eprintln!("{} {}", "ERROR:".red().bold(), e);
-        eprintln!("{}", "Application terminated with errors.".red());
-        process::exit(1);
+/// Start the fake camera and point the configuration at it
+#[cfg(feature = "demo")]
+fn start_demo(config: &mut utils::config::Config) -> Result<camera::demo::DemoCamera> {
+    let demo = camera::demo::DemoCamera::start()?;
+    config.camera_url = demo.url();
+    config.paths.camera = "demo".to_string();
+    Ok(demo)
+}
+
+#[cfg(not(feature = "demo"))]
+fn start_demo(_config: &mut utils::config::Config) -> Result<()> {
+    anyhow::bail!("This build has no demo camera; rebuild with the \"demo\" feature")
+}
+
+/// Wake the camera over Bluetooth if it is asleep, so the connection attempt finds it
+#[cfg(feature = "ble")]
+fn wake_camera(config: &utils::config::Config) {
+    use utils::i18n::{tr, trf};
+
+    if !config.ble_wake.enabled {
+        return;
+    }
+    println!("{}", tr("Checking whether the camera is awake...").cyan());
+    match net::ble::wake_if_asleep(&config.camera_url, &config.ble_wake, &config.timeouts) {
+        Ok(Some(name)) => println!("{}", trf("Woke {} over Bluetooth", &[&name]).cyan()),
+        Ok(None) => {}
+        Err(e) => println!("{}", trf("Bluetooth wake failed: {}", &[&e]).yellow()),
     }
 }
 
-fn run() -> Result<()> {
-    // Define camera URL
-    let camera_url = "http://192.168.0.10";
+fn run(
+    plain_mode: bool,
+    liveview_mode: bool,
+    slow_camera: bool,
+    setup_mode: bool,
+    protocol_log: bool,
+    read_only: bool,
+    demo_mode: bool,
+) -> Result<()> {
+    // Load configuration (camera URL, alerts, ...), asking for it on the first run
+    let mut config = if setup_mode || (!demo_mode && terminal::wizard::is_first_run()) {
+        terminal::wizard::run()?
+    } else {
+        utils::config::Config::load()
+    };
+    if liveview_mode {
+        config.auto_liveview = true;
+    }
+    if slow_camera {
+        config.timeouts.slow_camera = true;
+    }
+    if protocol_log {
+        config.protocol_log.enabled = true;
+    }
+    if read_only {
+        config.read_only = true;
+    }
+    // Kept alive for the whole run; the demo data lives in its own camera directory
+    let _demo_camera = if demo_mode {
+        Some(start_demo(&mut config)?)
+    } else {
+        None
+    };
+    utils::i18n::init(&config.locale);
+    utils::paths::init(&config.paths);
+    utils::atomic::remove_stale_parts(&utils::paths::camera_root());
+
+    // A sleeping camera has no Wi-Fi to connect to until it is woken
+    #[cfg(feature = "ble")]
+    if !demo_mode {
+        wake_camera(&config);
+    }
+
+    // The plain interface replaces the TUI entirely
+    if plain_mode {
+        return terminal::plain::run(config);
+    }
 
     // Create and run application, handling any errors
-    let app = terminal::app::App::new(camera_url)?;
+    let app = terminal::app::App::new(config)?;
     app.run()?;
 
     Ok(())
@@ -0,0 +1,85 @@
+// src/net/address.rs
+// Addresses on the camera link. The camera sends live view packets back to
+// whichever address asked for them, so the receiver listens on the local
+// address the system routes the camera through, on any subnet and over IPv6.
+// Local addresses are only needed by the live view receiver
+#![cfg_attr(not(feature = "video"), allow(dead_code))]
+use anyhow::{Result, anyhow};
+use log::{info, warn};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket};
+
+/// Port used only to ask the system for a route; nothing is sent to it
+const ROUTE_PROBE_PORT: u16 = 80;
+
+/// Host of a camera URL, without the brackets around an IPv6 literal
+pub fn camera_host(camera_url: &str) -> Result<String> {
+    let url = reqwest::Url::parse(camera_url)
+        .map_err(|e| anyhow!("Invalid camera URL {}: {}", camera_url, e))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("Camera URL {} has no host", camera_url))?;
+    Ok(host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string())
+}
+
+/// Address of the camera, resolving a host name if needed
+pub fn camera_ip(camera_url: &str) -> Result<IpAddr> {
+    let host = camera_host(camera_url)?;
+    if let Ok(ip) = host.parse() {
+        return Ok(ip);
+    }
+    (host.as_str(), ROUTE_PROBE_PORT)
+        .to_socket_addrs()?
+        .next()
+        .map(|addr| addr.ip())
+        .ok_or_else(|| anyhow!("{} did not resolve to an address", host))
+}
+
+/// Local address the system uses to reach `camera`
+pub fn local_address_for(camera: IpAddr) -> Result<SocketAddr> {
+    let unspecified = match camera {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+    };
+
+    // Connecting a UDP socket only selects a route; no packet is sent
+    let socket = UdpSocket::bind(SocketAddr::new(unspecified, 0))?;
+    socket.connect(SocketAddr::new(camera, ROUTE_PROBE_PORT))?;
+    let local = socket.local_addr()?;
+    info!(
+        "Camera {} is reached from local address {}",
+        camera,
+        local.ip()
+    );
+    Ok(local)
+}
+
+/// Address for the live view receiver to listen on, all interfaces if the route is unknown
+pub fn receiver_address(camera_url: &str, port: u16) -> SocketAddr {
+    let mut address = camera_ip(camera_url)
+        .and_then(local_address_for)
+        .unwrap_or_else(|e| {
+            warn!(
+                "Could not find the local address for {}: {}. Listening on all interfaces.",
+                camera_url, e
+            );
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)
+        });
+    address.set_port(port);
+    address
+}
+
+/// Host and port joined for display, with an IPv6 literal in brackets
+pub fn host_port(host: &str, port: u16) -> String {
+    format!("{}:{}", url_host(host), port)
+}
+
+/// Host as written in a URL, with an IPv6 literal in brackets
+pub fn url_host(host: &str) -> String {
+    match host.parse::<Ipv6Addr>() {
+        Ok(_) => format!("[{}]", host),
+        Err(_) => host.to_string(),
+    }
+}
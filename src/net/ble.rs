@@ -0,0 +1,182 @@
+// src/net/ble.rs
+// Waking a sleeping camera over Bluetooth LE. The Air keeps listening on
+// Bluetooth while it is off and turns Wi-Fi on when the official app writes its
+// wake command, so a camera that doesn't answer at startup is woken the same way
+// and waited for, rather than the app starting offline.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::client::timeouts::TimeoutConfig;
+use crate::camera::olympus::OlympusCamera;
+use anyhow::{Result, anyhow};
+use btleplug::api::{Central, Manager as _, Peripheral as _, ScanFilter, WriteType};
+use btleplug::platform::{Adapter, Manager, Peripheral};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time the camera has to answer before it counts as asleep
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Pause between checks while scanning for the camera or waiting for its Wi-Fi
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Bluetooth wake settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BleWakeConfig {
+    /// Wake the camera over Bluetooth when it doesn't answer at startup
+    pub enabled: bool,
+    /// Start of the Bluetooth name the camera advertises
+    pub name_prefix: String,
+    /// UUID of the characteristic the wake command is written to
+    pub characteristic: String,
+    /// Wake command as hex bytes, spaces allowed
+    pub command: String,
+    /// Seconds to look for the camera
+    pub scan_secs: u64,
+    /// Seconds to wait for the camera's Wi-Fi after waking it
+    pub boot_secs: u64,
+}
+
+impl Default for BleWakeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            name_prefix: "AIR-A01".to_string(),
+            characteristic: String::new(),
+            command: String::new(),
+            scan_secs: 10,
+            boot_secs: 20,
+        }
+    }
+}
+
+/// Wake the camera at `camera_url` if it doesn't answer, returning the name of the camera woken
+pub fn wake_if_asleep(
+    camera_url: &str,
+    config: &BleWakeConfig,
+    timeouts: &TimeoutConfig,
+) -> Result<Option<String>> {
+    let camera = OlympusCamera::new(camera_url, timeouts.clone());
+    if !config.enabled || answers(&camera) {
+        return Ok(None);
+    }
+
+    let command = parse_hex(&config.command)?;
+    if config.characteristic.is_empty() || command.is_empty() {
+        return Err(anyhow!(
+            "ble_wake.characteristic and ble_wake.command must both be set"
+        ));
+    }
+
+    info!("Camera not answering, waking it over Bluetooth");
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let name = runtime.block_on(send_wake(config, &command))?;
+
+    // Wi-Fi takes a while to come up after the camera wakes
+    let deadline = Instant::now() + Duration::from_secs(config.boot_secs);
+    while !answers(&camera) {
+        if Instant::now() >= deadline {
+            return Err(anyhow!(
+                "{} woke up but didn't answer over Wi-Fi within {} seconds",
+                name,
+                config.boot_secs
+            ));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+    info!("{} is awake", name);
+    Ok(Some(name))
+}
+
+/// Whether the camera answers over Wi-Fi
+fn answers(camera: &OlympusCamera) -> bool {
+    camera
+        .get_page_with_timeout("get_connectmode.cgi", PROBE_TIMEOUT)
+        .is_ok()
+}
+
+/// Bytes of a hex string such as `01 02 ff`
+fn parse_hex(text: &str) -> Result<Vec<u8>> {
+    let digits: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) || !digits.iter().all(u8::is_ascii_hexdigit) {
+        return Err(anyhow!("Wake command is not a list of hex bytes: {}", text));
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair)?;
+            Ok(u8::from_str_radix(pair, 16)?)
+        })
+        .collect()
+}
+
+/// Find the camera, write the wake command and disconnect, returning the camera's name
+async fn send_wake(config: &BleWakeConfig, command: &[u8]) -> Result<String> {
+    let manager = Manager::new().await?;
+    let central = manager
+        .adapters()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No Bluetooth adapter found"))?;
+
+    central.start_scan(ScanFilter::default()).await?;
+    let found = find_camera(&central, config).await;
+    let _ = central.stop_scan().await;
+    let (peripheral, name) = found?;
+
+    info!("Waking {} over Bluetooth", name);
+    peripheral.connect().await?;
+    let result = write_command(&peripheral, config, command).await;
+    let _ = peripheral.disconnect().await;
+    result.map(|_| name)
+}
+
+/// First device advertising a name that starts with the configured prefix
+async fn find_camera(central: &Adapter, config: &BleWakeConfig) -> Result<(Peripheral, String)> {
+    let deadline = Instant::now() + Duration::from_secs(config.scan_secs);
+    while Instant::now() < deadline {
+        for peripheral in central.peripherals().await? {
+            let name = peripheral
+                .properties()
+                .await?
+                .and_then(|properties| properties.local_name)
+                .filter(|name| name.starts_with(&config.name_prefix));
+            if let Some(name) = name {
+                return Ok((peripheral, name));
+            }
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+    Err(anyhow!(
+        "No Bluetooth device named {}... found within {} seconds",
+        config.name_prefix,
+        config.scan_secs
+    ))
+}
+
+/// Write the wake command to the configured characteristic
+async fn write_command(
+    peripheral: &Peripheral,
+    config: &BleWakeConfig,
+    command: &[u8],
+) -> Result<()> {
+    peripheral.discover_services().await?;
+    let characteristic = peripheral
+        .characteristics()
+        .into_iter()
+        .find(|characteristic| {
+            characteristic
+                .uuid
+                .to_string()
+                .eq_ignore_ascii_case(&config.characteristic)
+        })
+        .ok_or_else(|| anyhow!("Camera has no characteristic {}", config.characteristic))?;
+    peripheral
+        .write(&characteristic, command, WriteType::WithResponse)
+        .await?;
+    Ok(())
+}
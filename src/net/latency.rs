@@ -0,0 +1,132 @@
+// src/net/latency.rs
+// Round-trip time to the camera, measured with small HEAD requests. A stream
+// that stalls while the camera still answers quickly points at the camera;
+// one that stalls while requests are slow or lost points at the network.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Round trips averaged for the reported latency
+const SAMPLES: usize = 5;
+
+/// Latency monitoring settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LatencyConfig {
+    /// Whether to measure the round-trip time to the camera
+    pub enabled: bool,
+    /// Seconds between measurements
+    pub interval_secs: u64,
+    /// Milliseconds after which a measurement counts as lost
+    pub timeout_ms: u64,
+    /// Average round trip in milliseconds above which the network counts as congested
+    pub slow_ms: u64,
+}
+
+impl Default for LatencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 3,
+            timeout_ms: 1000,
+            slow_ms: 250,
+        }
+    }
+}
+
+/// Latest round-trip measurements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Latency {
+    /// Last round trip, or `None` if it timed out or failed
+    pub last: Option<Duration>,
+    /// Average of the recent successful round trips
+    pub average: Option<Duration>,
+    /// The last round trip was lost or the average is above the configured limit
+    pub slow: bool,
+}
+
+/// Measures the round-trip time in the background and keeps the latest figures
+pub struct LatencyMonitor {
+    latest: Arc<Mutex<Option<Latency>>>,
+    running: Arc<AtomicBool>,
+}
+
+impl LatencyMonitor {
+    /// Start measuring the round-trip time to `camera`
+    pub fn start(camera: OlympusCamera, config: &LatencyConfig) -> Self {
+        let latest = Arc::new(Mutex::new(None));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let config = config.clone();
+        let interval = Duration::from_secs(config.interval_secs.max(1));
+        let thread_latest = Arc::clone(&latest);
+        let thread_running = Arc::clone(&running);
+
+        thread::spawn(move || {
+            let mut samples = VecDeque::with_capacity(SAMPLES);
+            let mut last_ping: Option<Instant> = None;
+            while thread_running.load(Ordering::Relaxed) {
+                // The first ping after a cool-down tells whether the camera is back
+                let due = last_ping.is_none_or(|time| time.elapsed() >= interval);
+                if due && !camera.camera_unresponsive() {
+                    last_ping = Some(Instant::now());
+                    let latency = measure(&camera, &config, &mut samples);
+                    *thread_latest.lock().unwrap() = Some(latency);
+                }
+                // Sleep in short steps so stopping the monitor is quick
+                thread::sleep(Duration::from_millis(200));
+            }
+        });
+
+        info!("Latency monitoring started every {:?}", interval);
+        Self { latest, running }
+    }
+
+    /// Latest measurements, once the first round trip has finished
+    pub fn latest(&self) -> Option<Latency> {
+        *self.latest.lock().unwrap()
+    }
+}
+
+impl Drop for LatencyMonitor {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Take one measurement and fold it into the recent samples
+fn measure(
+    camera: &OlympusCamera,
+    config: &LatencyConfig,
+    samples: &mut VecDeque<Duration>,
+) -> Latency {
+    let last = match camera.ping(Duration::from_millis(config.timeout_ms)) {
+        Ok(rtt) => {
+            if samples.len() == SAMPLES {
+                samples.pop_front();
+            }
+            samples.push_back(rtt);
+            Some(rtt)
+        }
+        Err(e) => {
+            debug!("Camera ping failed: {}", e);
+            None
+        }
+    };
+
+    let average =
+        (!samples.is_empty()).then(|| samples.iter().sum::<Duration>() / samples.len() as u32);
+    let slow = last.is_none()
+        || average.is_some_and(|average| average > Duration::from_millis(config.slow_ms));
+    Latency {
+        last,
+        average,
+        slow,
+    }
+}
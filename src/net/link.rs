@@ -0,0 +1,184 @@
+// src/net/link.rs
+// Wireless link quality of the interface the camera is reached through. Linux
+// reports it in /proc/net/wireless and macOS through the airport tool; on other
+// systems, or over a wired link, there is no reading and no indicator is shown.
+use crate::net::address;
+use crate::net::wifi::{self, AIRPORT_PATH};
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Where Linux reports wireless link quality
+const PROC_WIRELESS: &str = "/proc/net/wireless";
+
+/// Highest link quality value in /proc/net/wireless for most drivers
+const PROC_QUALITY_MAX: f64 = 70.0;
+
+/// Signal bars, weakest first
+const BARS: [char; 4] = ['▂', '▄', '▆', '█'];
+
+/// Link quality polling settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LinkConfig {
+    /// Whether to poll the wireless link quality
+    pub enabled: bool,
+    /// Seconds between readings
+    pub interval_secs: u64,
+    /// Quality in percent below which live view is likely to stutter
+    pub weak_percent: u8,
+}
+
+impl Default for LinkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 5,
+            weak_percent: 40,
+        }
+    }
+}
+
+/// One reading of the wireless link quality
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkQuality {
+    /// Interface the reading was taken on
+    pub interface: String,
+    /// Quality from 0 to 100
+    pub percent: u8,
+    /// Below the configured threshold for live view
+    pub weak: bool,
+}
+
+impl LinkQuality {
+    /// Signal bars and percentage, e.g. `▂▄▆· 62%`
+    pub fn indicator(&self) -> String {
+        let lit = usize::from(self.percent).div_ceil(25);
+        let bars: String = BARS
+            .iter()
+            .enumerate()
+            .map(|(i, bar)| if i < lit { *bar } else { '·' })
+            .collect();
+        format!("{} {}%", bars, self.percent)
+    }
+}
+
+/// Reads the link quality in the background and keeps the latest reading
+pub struct LinkMonitor {
+    latest: Arc<Mutex<Option<LinkQuality>>>,
+    running: Arc<AtomicBool>,
+}
+
+impl LinkMonitor {
+    /// Start reading the link used to reach `camera_url`
+    pub fn start(camera_url: &str, config: &LinkConfig) -> Self {
+        let latest = Arc::new(Mutex::new(None));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let host = address::camera_host(camera_url).unwrap_or_default();
+        let interval = Duration::from_secs(config.interval_secs.max(1));
+        let weak_percent = config.weak_percent;
+        let thread_latest = Arc::clone(&latest);
+        let thread_running = Arc::clone(&running);
+
+        thread::spawn(move || {
+            let mut last_read: Option<Instant> = None;
+            while thread_running.load(Ordering::Relaxed) {
+                if last_read.is_none_or(|time| time.elapsed() >= interval) {
+                    last_read = Some(Instant::now());
+                    let reading = read_link_quality(&host, weak_percent);
+                    *thread_latest.lock().unwrap() = reading;
+                }
+                // Sleep in short steps so stopping the monitor is quick
+                thread::sleep(Duration::from_millis(200));
+            }
+        });
+
+        info!("Link quality monitoring started every {:?}", interval);
+        Self { latest, running }
+    }
+
+    /// Latest reading, if the link quality can be read on this system
+    pub fn latest(&self) -> Option<LinkQuality> {
+        self.latest.lock().unwrap().clone()
+    }
+}
+
+impl Drop for LinkMonitor {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Read the quality of the wireless link towards `host`
+fn read_link_quality(host: &str, weak_percent: u8) -> Option<LinkQuality> {
+    let reading = if Path::new(PROC_WIRELESS).exists() {
+        read_proc_wireless(host)
+    } else if Path::new(AIRPORT_PATH).exists() {
+        read_airport()
+    } else {
+        None
+    };
+
+    reading.map(|(interface, percent)| LinkQuality {
+        interface,
+        percent,
+        weak: percent < weak_percent,
+    })
+}
+
+/// Read /proc/net/wireless for the interface routing to `host`
+fn read_proc_wireless(host: &str) -> Option<(String, u8)> {
+    let text = fs::read_to_string(PROC_WIRELESS).ok()?;
+    let interface = route_interface(host);
+
+    // Two header lines, then `wlan0: 0000   54.  -56.  -256 ...`
+    text.lines().skip(2).find_map(|line| {
+        let (name, fields) = line.split_once(':')?;
+        let name = name.trim();
+        if interface.as_deref().is_some_and(|wanted| wanted != name) {
+            return None;
+        }
+        let quality: f64 = fields
+            .split_whitespace()
+            .nth(1)?
+            .trim_end_matches('.')
+            .parse()
+            .ok()?;
+        let percent = (quality * 100.0 / PROC_QUALITY_MAX).clamp(0.0, 100.0) as u8;
+        Some((name.to_string(), percent))
+    })
+}
+
+/// Interface the system routes `host` through, if `ip` can tell
+fn route_interface(host: &str) -> Option<String> {
+    let output = Command::new("ip")
+        .args(["route", "get", host])
+        .output()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut words = text.split_whitespace();
+    words.find(|word| *word == "dev")?;
+    let interface = words.next().map(str::to_string);
+    debug!("Camera {} is routed through {:?}", host, interface);
+    interface
+}
+
+/// Read the signal level of the Wi-Fi interface with `airport -I`
+fn read_airport() -> Option<(String, u8)> {
+    let output = Command::new(AIRPORT_PATH).arg("-I").output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let rssi: i32 = text
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("agrCtlRSSI:"))?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(("Wi-Fi".to_string(), wifi::rssi_to_percent(rssi)))
+}
@@ -0,0 +1,9 @@
+// src/net/mod.rs
+// The host side of the camera link: the wireless network the camera is
+// reached over, as opposed to the camera's own HTTP and UDP protocols.
+pub mod address;
+#[cfg(feature = "ble")]
+pub mod ble;
+pub mod latency;
+pub mod link;
+pub mod wifi;
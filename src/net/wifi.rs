@@ -0,0 +1,234 @@
+// src/net/wifi.rs
+// Joining the camera's access point from inside the app. NetworkManager's nmcli
+// is used on Linux and the airport and networksetup tools on macOS; where
+// neither is available the network has to be joined by hand as before.
+use crate::utils::exec;
+use anyhow::{Result, anyhow};
+use log::info;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Location of the macOS airport tool, which is not on the PATH
+pub const AIRPORT_PATH: &str =
+    "/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport";
+
+/// Settings for finding and joining the camera's network
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WifiConfig {
+    /// Start of the network names the camera broadcasts
+    pub ssid_prefix: String,
+    /// Password of the camera's network; `None` relies on one the system remembers
+    pub password: Option<String>,
+}
+
+impl Default for WifiConfig {
+    fn default() -> Self {
+        Self {
+            ssid_prefix: "AIR-A01".to_string(),
+            password: None,
+        }
+    }
+}
+
+/// A wireless network found by a scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    pub ssid: String,
+    /// Signal strength from 0 to 100, if the tool reports one
+    pub signal: Option<u8>,
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.signal {
+            Some(signal) => write!(f, "{} ({}%)", self.ssid, signal),
+            None => write!(f, "{}", self.ssid),
+        }
+    }
+}
+
+/// System tools used to scan and join networks
+enum Tool {
+    /// NetworkManager's command line client
+    Nmcli(PathBuf),
+    /// airport scans and networksetup joins on macOS
+    Airport {
+        airport: PathBuf,
+        networksetup: PathBuf,
+    },
+}
+
+impl Tool {
+    /// Find the tools available on this system
+    fn find() -> Result<Self> {
+        if let Some(nmcli) = exec::find_program("nmcli") {
+            return Ok(Tool::Nmcli(nmcli));
+        }
+
+        let airport = Path::new(AIRPORT_PATH);
+        let networksetup = airport
+            .exists()
+            .then(|| exec::find_program("networksetup"))
+            .flatten();
+        if let Some(networksetup) = networksetup {
+            return Ok(Tool::Airport {
+                airport: airport.to_path_buf(),
+                networksetup,
+            });
+        }
+
+        Err(anyhow!(
+            "Joining Wi-Fi networks needs nmcli (NetworkManager) or the macOS airport tool"
+        ))
+    }
+}
+
+/// Scan for the camera's networks, strongest first
+pub fn scan(config: &WifiConfig) -> Result<Vec<Network>> {
+    let networks = match Tool::find()? {
+        Tool::Nmcli(nmcli) => parse_nmcli(&run(Command::new(nmcli).args([
+            "-t",
+            "-f",
+            "SSID,SIGNAL",
+            "device",
+            "wifi",
+            "list",
+            "--rescan",
+            "yes",
+        ]))?),
+        Tool::Airport { airport, .. } => parse_airport(&run(Command::new(airport).arg("-s"))?),
+    };
+
+    let mut networks: Vec<Network> = networks
+        .into_iter()
+        .filter(|network| network.ssid.starts_with(&config.ssid_prefix))
+        .collect();
+
+    // A network heard on several channels is listed once per channel; keep the strongest
+    networks.sort_by_key(|network| Reverse(network.signal));
+    let mut seen = HashSet::new();
+    networks.retain(|network| seen.insert(network.ssid.clone()));
+
+    info!("Found {} camera Wi-Fi networks", networks.len());
+    Ok(networks)
+}
+
+/// Join a camera network, using the configured password if there is one
+pub fn connect(network: &Network, config: &WifiConfig) -> Result<()> {
+    info!("Joining Wi-Fi network {}", network.ssid);
+
+    match Tool::find()? {
+        Tool::Nmcli(nmcli) => {
+            let mut command = Command::new(nmcli);
+            command.args(["device", "wifi", "connect", &network.ssid]);
+            if let Some(password) = &config.password {
+                command.args(["password", password]);
+            }
+            run(&mut command)?;
+        }
+        Tool::Airport { networksetup, .. } => {
+            let device = wifi_device(&networksetup)?;
+            let mut command = Command::new(&networksetup);
+            command.args(["-setairportnetwork", &device, &network.ssid]);
+            if let Some(password) = &config.password {
+                command.arg(password);
+            }
+
+            // networksetup exits successfully even when joining fails, printing why instead
+            let output = run(&mut command)?;
+            if !output.trim().is_empty() {
+                return Err(anyhow!(
+                    "Failed to join {}: {}",
+                    network.ssid,
+                    output.trim()
+                ));
+            }
+        }
+    }
+
+    info!("Joined Wi-Fi network {}", network.ssid);
+    Ok(())
+}
+
+/// Run a tool and return its output, failing with its error message
+fn run(command: &mut Command) -> Result<String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command
+        .output()
+        .map_err(|e| anyhow!("Failed to run {}: {}", program, e))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse `nmcli -t -f SSID,SIGNAL device wifi list`
+fn parse_nmcli(output: &str) -> Vec<Network> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields = split_terse(line);
+            let ssid = fields.first().filter(|ssid| !ssid.is_empty())?;
+            Some(Network {
+                ssid: ssid.clone(),
+                signal: fields.get(1).and_then(|signal| signal.parse().ok()),
+            })
+        })
+        .collect()
+}
+
+/// Split a line of nmcli's terse output, where `:` separates fields and `\` escapes
+fn split_terse(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => fields.last_mut().unwrap().extend(chars.next()),
+            ':' => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// Parse `airport -s`, whose SSID column is right-aligned and may contain spaces
+fn parse_airport(output: &str) -> Vec<Network> {
+    let row = Regex::new(r"^\s*(.+?)\s+[0-9a-f]{2}(?::[0-9a-f]{2}){5}\s+(-?\d+)\s").unwrap();
+    output
+        .lines()
+        .filter_map(|line| row.captures(line))
+        .map(|captures| Network {
+            ssid: captures[1].to_string(),
+            signal: captures[2].parse().ok().map(rssi_to_percent),
+        })
+        .collect()
+}
+
+/// Convert a signal level in dBm to a percentage, -50 dBm and above being full strength
+pub fn rssi_to_percent(rssi: i32) -> u8 {
+    (2 * (rssi + 100)).clamp(0, 100) as u8
+}
+
+/// Name of the Wi-Fi interface on macOS, such as `en0`
+fn wifi_device(networksetup: &Path) -> Result<String> {
+    let output = run(Command::new(networksetup).arg("-listallhardwareports"))?;
+    let lines: Vec<&str> = output.lines().collect();
+    lines
+        .windows(2)
+        .find_map(|pair| {
+            let is_wifi = pair[0].ends_with("Wi-Fi") || pair[0].ends_with("AirPort");
+            pair[1].strip_prefix("Device: ").filter(|_| is_wifi)
+        })
+        .map(|device| device.trim().to_string())
+        .ok_or_else(|| anyhow!("No Wi-Fi interface found"))
+}
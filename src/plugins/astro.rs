@@ -0,0 +1,183 @@
+// src/plugins/astro.rs
+// Astro stacking trigger: a run of exposures taken back to back for stacking,
+// each waited for until it is on the card, with an optional pause between
+// them to let the sensor cool.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use crate::camera::photo::capture::PhotoCapture;
+use crate::plugins::{Plugin, PluginEntry};
+use anyhow::{Result, anyhow};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Astro stacking settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AstroStackConfig {
+    /// Exposures in a stack
+    pub frames: u32,
+    /// Seconds to wait between exposures
+    pub pause_secs: u64,
+}
+
+impl Default for AstroStackConfig {
+    fn default() -> Self {
+        Self {
+            frames: 20,
+            pause_secs: 0,
+        }
+    }
+}
+
+/// Progress of a running stack, shared with the UI
+#[derive(Debug, Clone, Default)]
+struct StackStatus {
+    frames_taken: u32,
+    finished: bool,
+    error: Option<String>,
+}
+
+/// Stack being shot on a background thread
+struct RunningStack {
+    status: Arc<Mutex<StackStatus>>,
+    running: Arc<AtomicBool>,
+}
+
+/// Takes a stack of exposures with one key
+pub struct AstroStack {
+    config: AstroStackConfig,
+    stack: Option<RunningStack>,
+}
+
+impl AstroStack {
+    pub fn new(config: &AstroStackConfig) -> Self {
+        Self {
+            config: config.clone(),
+            stack: None,
+        }
+    }
+
+    /// Start shooting a stack
+    fn start(&mut self, camera: &OlympusCamera) -> Result<String> {
+        if self.config.frames == 0 {
+            return Err(anyhow!("plugins.astro.frames is 0"));
+        }
+        camera.ensure_writable("Astro stack")?;
+
+        let status = Arc::new(Mutex::new(StackStatus::default()));
+        let running = Arc::new(AtomicBool::new(true));
+        info!("Astro stack started: {} frames", self.config.frames);
+
+        let thread_status = Arc::clone(&status);
+        let thread_running = Arc::clone(&running);
+        let camera = camera.clone();
+        let config = self.config.clone();
+        thread::spawn(move || {
+            let result = shoot_stack(&camera, &config, &thread_status, &thread_running);
+            if let Ok(mut status) = thread_status.lock() {
+                status.finished = true;
+                if let Err(e) = result {
+                    error!("Astro stack stopped: {}", e);
+                    status.error = Some(e.to_string());
+                }
+            }
+        });
+
+        self.stack = Some(RunningStack { status, running });
+        Ok(format!(
+            "Astro stack started: {} frames",
+            self.config.frames
+        ))
+    }
+
+    fn status(&self) -> Option<StackStatus> {
+        let stack = self.stack.as_ref()?;
+        stack.status.lock().ok().map(|status| status.clone())
+    }
+}
+
+impl Plugin for AstroStack {
+    fn name(&self) -> &'static str {
+        "Astro stacking"
+    }
+
+    fn entries(&self) -> Vec<PluginEntry> {
+        vec![PluginEntry {
+            key: 's',
+            label: "Start or stop a stack",
+        }]
+    }
+
+    fn run(&mut self, key: char, camera: &OlympusCamera) -> Result<String> {
+        match key {
+            's' if self.stack.is_some() => {
+                self.stop();
+                Ok("Stopping the astro stack after this frame".to_string())
+            }
+            's' => self.start(camera),
+            _ => Err(anyhow!("Astro stacking has no action on {}", key)),
+        }
+    }
+
+    fn poll(&mut self) -> Option<String> {
+        let status = self.status().filter(|status| status.finished)?;
+        self.stack = None;
+
+        let total = self.config.frames;
+        Some(match status.error {
+            Some(e) => format!(
+                "Astro stack stopped after {}/{} frames: {}",
+                status.frames_taken, total, e
+            ),
+            None if status.frames_taken < total => format!(
+                "Astro stack cancelled after {}/{} frames",
+                status.frames_taken, total
+            ),
+            None => format!("Astro stack complete: {} frames", total),
+        })
+    }
+
+    fn activity(&self) -> Option<String> {
+        let status = self.status()?;
+        Some(format!(
+            "Stack {}/{}",
+            status.frames_taken, self.config.frames
+        ))
+    }
+
+    fn stop(&mut self) {
+        if let Some(stack) = &self.stack {
+            stack.running.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Take the configured exposures one after another
+fn shoot_stack(
+    camera: &OlympusCamera,
+    config: &AstroStackConfig,
+    status: &Arc<Mutex<StackStatus>>,
+    running: &Arc<AtomicBool>,
+) -> Result<()> {
+    for frame in 1..=config.frames {
+        if !running.load(Ordering::Relaxed) {
+            info!("Astro stack cancelled after {} frames", frame - 1);
+            return Ok(());
+        }
+        if frame > 1 && config.pause_secs > 0 {
+            thread::sleep(Duration::from_secs(config.pause_secs));
+        }
+        camera.take_photo_and_wait()?;
+        info!("Astro stack frame {}/{} taken", frame, config.frames);
+        if let Ok(mut status) = status.lock() {
+            status.frames_taken = frame;
+        }
+    }
+
+    info!("Astro stack complete");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// src/plugins/mod.rs
+// Niche features that don't belong in the core. Each plugin is compiled in
+// with a cargo feature of its own and listed in `registered`; the plugins
+// screen offers their entries and the main loop polls their background work,
+// so nothing else in the app needs to know which plugins exist.
+#[cfg(feature = "astro")]
+pub mod astro;
+
+use crate::camera::olympus::OlympusCamera;
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Plugin settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginsConfig {
+    /// Names of compiled-in plugins to leave out
+    pub disabled: Vec<String>,
+    /// Astro stacking trigger
+    #[cfg(feature = "astro")]
+    pub astro: astro::AstroStackConfig,
+}
+
+/// Action a plugin offers on the plugins screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginEntry {
+    /// Key that runs the action; `q` is taken by the screen itself
+    pub key: char,
+    /// Untranslated label
+    pub label: &'static str,
+}
+
+/// Feature kept outside the core
+pub trait Plugin {
+    /// Name shown on the plugins screen and used in `plugins.disabled`
+    fn name(&self) -> &'static str;
+
+    /// Actions offered on the plugins screen
+    fn entries(&self) -> Vec<PluginEntry>;
+
+    /// Run the action bound to `key`, returning a status message
+    fn run(&mut self, key: char, camera: &OlympusCamera) -> Result<String>;
+
+    /// Check on background work, returning a status message when something ended
+    fn poll(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Progress of background work for the status bar, while it runs
+    fn activity(&self) -> Option<String> {
+        None
+    }
+
+    /// Stop background work before the app quits
+    fn stop(&mut self) {}
+}
+
+/// Plugins compiled into this build, minus the disabled ones
+#[cfg_attr(not(feature = "astro"), allow(unused_mut))]
+pub fn registered(config: &PluginsConfig) -> Vec<Box<dyn Plugin>> {
+    let mut plugins: Vec<Box<dyn Plugin>> = Vec::new();
+    #[cfg(feature = "astro")]
+    plugins.push(Box::new(astro::AstroStack::new(&config.astro)));
+
+    plugins.retain(|plugin| !config.disabled.iter().any(|name| name == plugin.name()));
+    for plugin in &plugins {
+        info!("Plugin loaded: {}", plugin.name());
+    }
+    plugins
+}
@@ -1,5 +1,9 @@
 // src/terminal/app.rs
-use crate::terminal::{handlers, image_viewer, state::AppState, video_viewer};
+use crate::net::wifi::{self, Network};
+use crate::terminal::events::{self, AppMessage};
+use crate::terminal::{handlers, renderer, shell, state::AppState};
+use crate::utils::config::Config;
+use crate::utils::i18n::{tr, trf};
 use anyhow::Result;
 use colored::*;
 use crossterm::{
@@ -12,7 +16,7 @@ use std::io;
 use tui::{
     Terminal,
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
     widgets::{Block, Borders, Paragraph},
@@ -21,69 +25,109 @@ use tui::{
 /// The main application struct
 pub struct App {
     state: Option<AppState>,
-    camera_url: String,
+    config: Config,
     connection_error: Option<String>,
+    /// Camera Wi-Fi networks found from the offline screen
+    networks: Vec<Network>,
+    /// Outcome of the last Wi-Fi scan or join
+    wifi_message: Option<String>,
 }
 
 impl App {
     /// Create a new App instance
-    pub fn new(camera_url: &str) -> Result<Self> {
+    pub fn new(config: Config) -> Result<Self> {
         info!("Initializing application");
 
         // Print initial connection message
-        println!("{}", "Connecting to Olympus camera...".cyan().bold());
+        println!("{}", tr("Connecting to Olympus camera...").cyan().bold());
 
         // Initialize the application state
-        let state_result = AppState::new(camera_url);
+        let state_result = AppState::new(&config);
         let has_error = state_result.is_err();
 
         let state = match state_result {
-            Ok(state) => {
+            Ok(mut state) => {
                 println!(
                     "{}",
-                    format!("Found {} images on camera", state.images.len()).cyan()
+                    trf("Found {} images on camera", &[&state.images.len()]).cyan()
                 );
+                handlers::auto_start_live_view(&mut state);
                 Some(state)
             }
             Err(e) => {
                 println!(
                     "{}",
-                    format!("Error connecting to camera: {}", e).red().bold()
+                    trf("Error connecting to camera: {}", &[&e]).red().bold()
                 );
                 println!(
                     "{}",
-                    "Starting in offline mode. Press any key to continue.".yellow()
+                    tr("Starting in offline mode. Press any key to continue.").yellow()
                 );
                 None
             }
         };
 
-        println!("{}", "Starting terminal interface...".cyan().italic());
+        println!("{}", tr("Starting terminal interface...").cyan().italic());
 
         Ok(Self {
             state,
-            camera_url: camera_url.to_string(),
+            config,
             connection_error: if has_error {
-                Some("Failed to connect to camera".to_string())
+                Some(tr("Failed to connect to camera").to_string())
             } else {
                 None
             },
+            networks: Vec::new(),
+            wifi_message: None,
         })
     }
 
+    /// Look for the camera's Wi-Fi networks to offer on the offline screen
+    fn scan_wifi(&mut self) {
+        match wifi::scan(&self.config.wifi) {
+            Ok(networks) => {
+                self.wifi_message = networks
+                    .is_empty()
+                    .then(|| tr("No camera Wi-Fi network found").to_string());
+                self.networks = networks;
+            }
+            Err(e) => {
+                self.networks.clear();
+                self.wifi_message = Some(trf("Wi-Fi scan failed: {}", &[&e]));
+            }
+        }
+    }
+
+    /// Join a network listed on the offline screen, then try the camera again
+    fn join_wifi(&mut self, index: usize) {
+        let Some(network) = self.networks.get(index).cloned() else {
+            return;
+        };
+        match wifi::connect(&network, &self.config.wifi) {
+            Ok(()) => {
+                self.wifi_message = Some(trf("Joined {}", &[&network.ssid]));
+                let _ = self.attempt_reconnect();
+            }
+            Err(e) => {
+                self.wifi_message = Some(trf("Failed to join {}: {}", &[&network.ssid, &e]));
+            }
+        }
+    }
+
     /// Attempt to reconnect to the camera
     fn attempt_reconnect(&mut self) -> Result<bool> {
         info!("Attempting to reconnect to camera");
 
-        match AppState::new(&self.camera_url) {
-            Ok(state) => {
+        match AppState::new(&self.config) {
+            Ok(mut state) => {
+                handlers::auto_start_live_view(&mut state);
                 self.state = Some(state);
                 self.connection_error = None;
                 info!("Successfully reconnected to camera");
                 Ok(true)
             }
             Err(e) => {
-                self.connection_error = Some(format!("Failed to connect: {}", e));
+                self.connection_error = Some(trf("Failed to connect: {}", &[&e]));
                 info!("Reconnection failed: {}", e);
                 Ok(false)
             }
@@ -106,6 +150,9 @@ impl App {
 
         // Run the application loop
         let result = self.run_app(&mut terminal);
+        if let Some(state) = &mut self.state {
+            handlers::shutdown(state);
+        }
 
         // Restore terminal
         disable_raw_mode()?;
@@ -118,58 +165,77 @@ impl App {
 
         // Check for errors
         if let Err(err) = result {
-            println!("{}", format!("Error: {}", err).red().bold());
+            println!("{}", trf("Error: {}", &[&err]).red().bold());
             return Err(err);
         }
 
         // Show exit message
         println!(
             "{}",
-            "Olympus Camera Control terminated successfully."
+            tr("Olympus Camera Control terminated successfully.")
                 .green()
                 .bold()
         );
-        println!("{}", "Thank you for using the application!".cyan());
+        println!("{}", tr("Thank you for using the application!").cyan());
 
         info!("Application shutdown");
 
         Ok(())
     }
 
+    /// Leave the interface for a shell in the downloads directory and restore it afterwards
+    fn suspend_to_shell<B: tui::backend::Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+    ) -> Result<()> {
+        disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+        terminal.show_cursor()?;
+
+        let result = shell::run_in_downloads();
+
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        terminal.clear()?;
+
+        if let Err(e) = result {
+            match &mut self.state {
+                Some(state) => state.set_status(&e.to_string()),
+                None => self.connection_error = Some(e.to_string()),
+            }
+        }
+        Ok(())
+    }
+
     fn run_app<B: tui::backend::Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
         // Set up a buffer to prevent excessive screen redraws
         let mut last_screen_refresh = std::time::Instant::now();
         let refresh_rate = std::time::Duration::from_millis(50); // 50ms refresh rate (20 FPS)
+        // Set after a resize so the new layout doesn't wait for the next refresh
+        let mut force_redraw = true;
 
         loop {
+            // Apply what the camera, background jobs and timers have to report
+            if let Some(state) = &mut self.state
+                && events::dispatch(state, AppMessage::Tick)?
+            {
+                return Ok(());
+            }
+
             // Only redraw if enough time has passed
             let now = std::time::Instant::now();
-            if now.duration_since(last_screen_refresh) >= refresh_rate {
+            if force_redraw || now.duration_since(last_screen_refresh) >= refresh_rate {
                 terminal.draw(|f| {
                     let size = f.size(); // Get the area for rendering
 
+                    // Layouts garble below a minimum size, so ask for a bigger terminal instead
+                    if renderer::is_too_small(size) {
+                        renderer::render_too_small(f, size);
+                        return;
+                    }
+
                     if let Some(state) = &self.state {
-                        // If we have a state, render the appropriate UI based on mode
-                        match state.mode {
-                            crate::terminal::state::AppMode::ViewingImage => {
-                                // In image viewer mode, use the image viewer renderer
-                                if let Some(viewer_state) = &state.image_viewer {
-                                    // Pass the viewer_state, frame, and area to the render function
-                                    image_viewer::renderer::ui::render(viewer_state, f, size);
-                                }
-                            }
-                            crate::terminal::state::AppMode::ViewingVideo => {
-                                // In video viewer mode, use the video viewer renderer
-                                if let Some(viewer_state) = &state.video_viewer {
-                                    // Pass the viewer_state, frame, and area to the render function
-                                    video_viewer::renderer::render(viewer_state, f, size);
-                                }
-                            }
-                            _ => {
-                                // For all other modes, use the main renderer
-                                crate::terminal::renderer::render_app(state, f);
-                            }
-                        }
+                        renderer::render_screen(state, f);
                     } else {
                         // If we don't have a state, render the offline mode UI
                         let size = f.size();
@@ -190,7 +256,7 @@ impl App {
 
                         // Title
                         let title = Paragraph::new(vec![Spans::from(vec![Span::styled(
-                            "Olympus Camera Control - OFFLINE MODE",
+                            tr("Olympus Camera Control - OFFLINE MODE"),
                             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
                         )])])
                         .block(Block::default().borders(Borders::ALL));
@@ -198,30 +264,51 @@ impl App {
                         f.render_widget(title, chunks[0]);
 
                         // Error message
-                        let error_text = vec![
+                        let mut error_text = vec![
                             Spans::from(vec![Span::styled(
-                                "Camera Connection Error",
+                                tr("Camera Connection Error"),
                                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
                             )]),
                             Spans::from(vec![Span::raw("")]),
                             Spans::from(vec![Span::raw(
-                                self.connection_error.as_deref().unwrap_or("Unknown error"),
+                                self.connection_error
+                                    .as_deref()
+                                    .unwrap_or(tr("Unknown error")),
                             )]),
                             Spans::from(vec![Span::raw("")]),
-                            Spans::from(vec![Span::raw("Please check:")]),
-                            Spans::from(vec![Span::raw("1. Camera is powered on")]),
-                            Spans::from(vec![Span::raw("2. WiFi connection is active")]),
-                            Spans::from(vec![Span::raw("3. Camera IP address is correct")]),
-                            Spans::from(vec![Span::raw("")]),
-                            Spans::from(vec![Span::styled(
-                                "Press 'r' to attempt reconnection or 'q' to quit",
-                                Style::default().fg(Color::Yellow),
-                            )]),
+                            Spans::from(vec![Span::raw(tr("Please check:"))]),
+                            Spans::from(vec![Span::raw(tr("1. Camera is powered on"))]),
+                            Spans::from(vec![Span::raw(tr("2. WiFi connection is active"))]),
+                            Spans::from(vec![Span::raw(tr("3. Camera IP address is correct"))]),
                         ];
 
+                        if !self.networks.is_empty() {
+                            error_text.push(Spans::from(vec![Span::raw("")]));
+                            error_text.push(Spans::from(vec![Span::raw(tr(
+                                "Camera Wi-Fi networks:",
+                            ))]));
+                            for (i, network) in self.networks.iter().take(9).enumerate() {
+                                error_text.push(Spans::from(vec![Span::raw(format!(
+                                    "{}. {}",
+                                    i + 1,
+                                    network
+                                ))]));
+                            }
+                        }
+                        if let Some(message) = &self.wifi_message {
+                            error_text.push(Spans::from(vec![Span::raw("")]));
+                            error_text.push(Spans::from(vec![Span::raw(message.as_str())]));
+                        }
+
+                        error_text.push(Spans::from(vec![Span::raw("")]));
+                        error_text.push(Spans::from(vec![Span::styled(
+                            tr("Press 'r' to attempt reconnection, 'w' to find the camera's Wi-Fi or 'q' to quit"),
+                            Style::default().fg(Color::Yellow),
+                        )]));
+
                         let error_msg = Paragraph::new(error_text).block(
                             Block::default()
-                                .title("Connection Status")
+                                .title(tr("Connection Status"))
                                 .borders(Borders::ALL),
                         );
 
@@ -230,11 +317,12 @@ impl App {
                         // Controls
                         let controls = Paragraph::new(vec![Spans::from(vec![
                             Span::styled(
-                                "Controls: ",
+                                tr("Controls: "),
                                 Style::default().add_modifier(Modifier::BOLD),
                             ),
-                            Span::raw("r - Attempt reconnection   "),
-                            Span::raw("q - Quit application"),
+                            Span::raw(tr("r - Attempt reconnection   ")),
+                            Span::raw(tr("w - Find camera Wi-Fi   ")),
+                            Span::raw(tr("q - Quit application")),
                         ])])
                         .block(Block::default().borders(Borders::ALL));
 
@@ -243,27 +331,50 @@ impl App {
                 })?;
 
                 last_screen_refresh = now;
+                force_redraw = false;
             }
 
             // Handle events with a timeout to prevent UI blocking
             if crossterm::event::poll(std::time::Duration::from_millis(10))? {
-                if let Event::Key(key) = event::read()? {
-                    if let Some(state) = &mut self.state {
-                        // Normal mode - pass events to the handler
-                        if handlers::handle_input(state, key.code)? {
-                            return Ok(());
-                        }
-                    } else {
-                        // Offline mode - limited options
-                        match key.code {
-                            KeyCode::Char('q') => return Ok(()),
-                            KeyCode::Char('r') => {
-                                // Try to reconnect
-                                let _ = self.attempt_reconnect();
+                match event::read()? {
+                    // Ctrl-q and Ctrl-c leave from any screen, dialogs included
+                    Event::Key(key) if handlers::is_hard_exit(&key) => {
+                        info!("Hard exit requested");
+                        return Ok(());
+                    }
+                    // Ctrl-z drops to a shell, keeping the camera connection
+                    Event::Key(key) if shell::is_suspend(&key) => {
+                        self.suspend_to_shell(terminal)?;
+                        force_redraw = true;
+                    }
+                    Event::Key(key) => {
+                        if let Some(state) = &mut self.state {
+                            // Normal mode - pass events to the handler
+                            if events::dispatch(state, AppMessage::UserInput(key))? {
+                                return Ok(());
+                            }
+                        } else {
+                            // Offline mode - limited options
+                            match key.code {
+                                KeyCode::Char('q') => return Ok(()),
+                                KeyCode::Char('r') => {
+                                    // Try to reconnect
+                                    let _ = self.attempt_reconnect();
+                                }
+                                KeyCode::Char('w') => self.scan_wifi(),
+                                KeyCode::Char(c @ '1'..='9') => {
+                                    self.join_wifi(c as usize - '1' as usize)
+                                }
+                                _ => {}
                             }
-                            _ => {}
                         }
                     }
+                    Event::Resize(width, height) => {
+                        info!("Terminal resized to {}x{}", width, height);
+                        terminal.resize(Rect::new(0, 0, width, height))?;
+                        force_redraw = true;
+                    }
+                    _ => {}
                 }
             }
 
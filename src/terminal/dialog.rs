@@ -0,0 +1,190 @@
+// src/terminal/dialog.rs
+// Modal dialogs drawn over whichever screen is showing. While a dialog is open
+// it receives every key, and confirming it hands its action back to the
+// handlers to run.
+use crate::terminal::input::{InputEvent, InputField};
+use crossterm::event::KeyCode;
+
+/// Operation run when a dialog is confirmed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogAction {
+    /// Download the named image
+    Download(String),
+    /// Delete the named image
+    Delete(String),
+    /// Replace the tags of the named image with the comma separated ones entered
+    Tag(String),
+    /// Select the image whose 1-based number was entered
+    GoTo,
+    /// Start a shoot session labelled with the text entered
+    StartSession,
+    /// Quit even though work is still running
+    Quit,
+    /// Take control of the camera from another instance or app
+    TakeOver,
+    /// Switch the camera to the named connect mode
+    SwitchConnectMode(String),
+    /// Ask for the password of the access point name entered
+    SetAccessPointName,
+    /// Give the named access point the password entered
+    SetAccessPointPassword(String),
+    /// Send the request entered from the command console
+    RunCommand,
+    /// Save the recorded keys as a macro under the name entered
+    SaveMacro(Vec<KeyCode>),
+    /// Play the macro named
+    PlayMacro,
+    /// Write the capability cache to the file entered
+    ExportCapabilities,
+    /// Merge in the capability file entered
+    ImportCapabilities,
+    /// Replace UDP live view with the snapshot loop
+    #[cfg(feature = "video")]
+    SnapshotFallback,
+}
+
+/// Background operation a progress dialog follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressSource {
+    Timelapse,
+    CaptureQueue,
+    DngConversion,
+    MovieDownload,
+}
+
+/// What the dialog asks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogKind {
+    /// Information or an error, closed with Enter, Esc or Space
+    Message,
+    /// Yes/no question; Enter runs the action and Esc cancels
+    Confirm(DialogAction),
+    /// A line of text handed to the action once it is valid
+    Input {
+        field: InputField,
+        action: DialogAction,
+    },
+    /// Progress of a background operation; Esc hides it while it keeps running
+    Progress(ProgressSource),
+}
+
+/// Result of passing a key to a dialog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogOutcome {
+    /// The dialog stays open
+    Open,
+    /// The dialog was dismissed or cancelled
+    Closed,
+    /// The dialog was confirmed; `input` holds the text of an input dialog
+    Confirmed { action: DialogAction, input: String },
+}
+
+/// A modal dialog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dialog {
+    pub title: String,
+    pub message: String,
+    pub kind: DialogKind,
+    /// Drawn in red, for errors and destructive actions
+    pub warning: bool,
+}
+
+impl Dialog {
+    fn new(title: &str, message: &str, kind: DialogKind) -> Self {
+        Self {
+            title: title.to_string(),
+            message: message.to_string(),
+            kind,
+            warning: false,
+        }
+    }
+
+    /// Information to acknowledge
+    pub fn message(title: &str, message: &str) -> Self {
+        Self::new(title, message, DialogKind::Message)
+    }
+
+    /// An error to acknowledge
+    pub fn error(title: &str, message: &str) -> Self {
+        Self::message(title, message).with_warning()
+    }
+
+    /// A question that runs `action` when confirmed
+    pub fn confirm(title: &str, message: &str, action: DialogAction) -> Self {
+        Self::new(title, message, DialogKind::Confirm(action))
+    }
+
+    /// A prompt for a line of text that is handed to `action`
+    pub fn input(title: &str, message: &str, field: InputField, action: DialogAction) -> Self {
+        Self::new(title, message, DialogKind::Input { field, action })
+    }
+
+    /// Progress of a background operation
+    pub fn progress(title: &str, message: &str, source: ProgressSource) -> Self {
+        Self::new(title, message, DialogKind::Progress(source))
+    }
+
+    /// Draw the dialog as a warning
+    pub fn with_warning(mut self) -> Self {
+        self.warning = true;
+        self
+    }
+
+    /// Handle a key pressed while the dialog is open
+    pub fn handle_key(&mut self, key: KeyCode) -> DialogOutcome {
+        match &mut self.kind {
+            DialogKind::Message => match key {
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Char(' ') => DialogOutcome::Closed,
+                _ => DialogOutcome::Open,
+            },
+            DialogKind::Confirm(action) => match key {
+                KeyCode::Enter | KeyCode::Char('y') => DialogOutcome::Confirmed {
+                    action: action.clone(),
+                    input: String::new(),
+                },
+                KeyCode::Esc | KeyCode::Char('n') => DialogOutcome::Closed,
+                _ => DialogOutcome::Open,
+            },
+            DialogKind::Input { field, action } => match field.handle_key(key) {
+                InputEvent::Editing => DialogOutcome::Open,
+                InputEvent::Cancelled => DialogOutcome::Closed,
+                InputEvent::Submitted(input) => DialogOutcome::Confirmed {
+                    action: action.clone(),
+                    input,
+                },
+            },
+            DialogKind::Progress(_) => match key {
+                KeyCode::Esc | KeyCode::Enter => DialogOutcome::Closed,
+                _ => DialogOutcome::Open,
+            },
+        }
+    }
+
+    /// Text field of an input dialog
+    pub fn input_field(&self) -> Option<&InputField> {
+        match &self.kind {
+            DialogKind::Input { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Text field of an input dialog, for filling it in directly
+    pub fn input_field_mut(&mut self) -> Option<&mut InputField> {
+        match &mut self.kind {
+            DialogKind::Input { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Key hint shown at the bottom of the dialog
+    pub fn hint(&self) -> &'static str {
+        match self.kind {
+            DialogKind::Message => "Press Enter to close",
+            DialogKind::Confirm(_) => "Press Enter to confirm or Esc to cancel",
+            DialogKind::Input { .. } => {
+                "Type, then press Enter to confirm or Esc to cancel; Up/Down recall earlier values"
+            }
+            DialogKind::Progress(_) => "Press Esc to hide; it keeps running in the background",
+        }
+    }
+}
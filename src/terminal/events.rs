@@ -0,0 +1,94 @@
+// src/terminal/events.rs
+// Everything that changes the app arrives as a message and is applied by
+// `update`: keys typed, changes the camera reported, background jobs ending,
+// and the clock ticking. Work that finds news, such as the camera poller or a
+// finished job, posts messages instead of changing the state itself, so both
+// interfaces apply things in the same order and tests can send messages
+// directly instead of staging them.
+use crate::camera::events::CameraEvent;
+use crate::camera::photo::queue::QueueStatus;
+use crate::camera::photo::timelapse::TimelapseStatus;
+use crate::terminal::handlers;
+use crate::terminal::movie::MovieProgress;
+use crate::terminal::state::AppState;
+use crate::utils::dng::DngProgress;
+use crate::utils::ffmpeg::TranscodeProgress;
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+
+/// Something that happened, for `update` to apply
+#[derive(Debug, Clone)]
+pub enum AppMessage {
+    /// A key typed by the user
+    UserInput(KeyEvent),
+    /// A change the camera poller noticed
+    Camera(CameraEvent),
+    /// A background job ended
+    JobProgress(JobUpdate),
+    /// Time passed: monitors, timers and sources of messages are checked
+    Tick,
+}
+
+/// Final state of a background job
+#[derive(Debug, Clone)]
+pub enum JobUpdate {
+    Timelapse(TimelapseStatus),
+    CaptureQueue(QueueStatus),
+    /// Message of a plugin whose background work ended
+    Plugin(String),
+    Transcode(TranscodeProgress),
+    DngConversion(DngProgress),
+    MovieDownload(MovieProgress),
+}
+
+/// Apply `message` and everything it posts in turn, returning true if the app should quit
+pub fn dispatch(state: &mut AppState, message: AppMessage) -> Result<bool> {
+    state.post(message);
+    let mut quit = false;
+    while let Some(message) = state.next_message() {
+        quit |= update(state, message)?;
+    }
+    Ok(quit)
+}
+
+/// Apply a single message, returning true if the app should quit
+fn update(state: &mut AppState, message: AppMessage) -> Result<bool> {
+    match message {
+        AppMessage::UserInput(key) => handlers::handle_key_event(state, &key),
+        AppMessage::Camera(event) => {
+            state.apply_camera_event(event);
+            Ok(false)
+        }
+        AppMessage::JobProgress(update) => {
+            state.apply_job_update(update);
+            Ok(false)
+        }
+        AppMessage::Tick => tick(state),
+    }
+}
+
+/// Check the monitors and timers, posting whatever the camera and jobs have to report
+fn tick(state: &mut AppState) -> Result<bool> {
+    for event in state.poll_camera_events() {
+        state.post(AppMessage::Camera(event));
+    }
+    for update in state.finished_jobs() {
+        state.post(AppMessage::JobProgress(update));
+    }
+
+    state.process_list_refresh();
+    state.process_prefetch();
+    state.process_instance_lock();
+    state.process_link_quality();
+    state.process_latency();
+    #[cfg(feature = "video")]
+    state.process_stream_fallback();
+    #[cfg(feature = "player")]
+    state.process_player_exit();
+    #[cfg(feature = "video")]
+    state.process_stream_quality();
+    #[cfg(feature = "video")]
+    state.process_freeze_alert();
+    state.process_image_fetch();
+    handlers::process_macro(state)
+}
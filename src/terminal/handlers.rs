@@ -1,91 +1,378 @@
 // src/terminal/handlers.rs
+use crate::camera::capabilities::{self, DELETE_COMMAND};
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::console;
 use crate::camera::image::delete::ImageDeleter;
 use crate::camera::image::download::ImageDownloader;
+use crate::camera::image::formats::UrlFormatGenerator;
+use crate::camera::image::list;
+use crate::camera::pairing::{self, AccessPoint, PairingManager};
+use crate::camera::photo::bracket::FocusBracketing;
 use crate::camera::photo::capture::PhotoCapture;
+use crate::camera::photo::movie::MovieCapture;
+use crate::camera::photo::queue::CaptureQueue;
+use crate::camera::photo::tether::TetheredCapture;
+use crate::camera::photo::timelapse::Intervalometer;
+use crate::camera::profiles::SettingsProfiles;
+use crate::camera::settings::CameraSettings;
+use crate::terminal::dialog::{Dialog, DialogAction, DialogOutcome, ProgressSource};
+use crate::terminal::input::{InputField, Validation};
+use crate::terminal::macros::{self, MacroPlayback};
+use crate::terminal::menu::{self, MenuAction};
+use crate::terminal::movie::MovieFetch;
 use crate::terminal::state::{AppMode, AppState};
+#[cfg(feature = "video")]
 use crate::terminal::video_viewer;
-use anyhow::Result;
-use crossterm::event::KeyCode;
-use log::info;
-use std::path::Path;
+use crate::utils::alert::{self, AlertEvent};
+use crate::utils::atomic;
+use crate::utils::audit::{self, AuditAction};
+use crate::utils::catalog::{self, Catalog};
+use crate::utils::diff::Change;
+use crate::utils::disk::{self, SpaceCheck};
+use crate::utils::dng::{self, DngConverter, DngJob};
+use crate::utils::i18n::{tr, trf};
+use crate::utils::notes;
+use crate::utils::open;
+use crate::utils::paths::{self, DataDir};
+use crate::utils::resize::{self, WebSizeConfig};
+use crate::utils::session::{self, ShootSession};
+use crate::utils::xmp;
+use anyhow::{Result, anyhow};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use log::{info, warn};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Handle input based on the current application mode
 pub fn handle_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
-    // Handle error dialog if it's showing
-    if state.show_error_dialog {
-        match key {
-            KeyCode::Enter | KeyCode::Esc | KeyCode::Char(' ') => {
-                state.dismiss_error_dialog();
-                return Ok(false);
+    // An open dialog takes every key until it closes
+    if let Some(dialog) = &mut state.dialog {
+        match dialog.handle_key(key) {
+            DialogOutcome::Open => {}
+            DialogOutcome::Closed => state.close_dialog(),
+            DialogOutcome::Confirmed { action, input } => {
+                if let Some(field) = dialog.input_field() {
+                    state.input_history.record(field.name(), &input);
+                }
+                state.close_dialog();
+                return Ok(run_dialog_action(state, action, &input));
             }
-            _ => return Ok(false),
         }
+        return Ok(false);
     }
 
     // Normal input handling
     match state.mode {
         AppMode::Main => handle_main_input(state, key),
         AppMode::ImageList => handle_image_list_input(state, key),
-        AppMode::Downloading => handle_download_input(state, key),
-        AppMode::Deleting => handle_delete_input(state, key),
         AppMode::ViewingImage => {
             crate::terminal::image_viewer::handlers::handle_image_viewer_input(state, key)
         }
+        #[cfg(feature = "video")]
         AppMode::ViewingVideo => {
             crate::terminal::video_viewer::handlers::handle_video_viewer_input(state, key)
         }
+        #[cfg(not(feature = "video"))]
+        AppMode::ViewingVideo => Ok(false),
+        #[cfg(feature = "video")]
+        AppMode::Transcoding => handle_transcode_input(state, key),
+        #[cfg(not(feature = "video"))]
+        AppMode::Transcoding => Ok(false),
+        AppMode::AuditLog => handle_audit_log_input(state, key),
+        #[cfg(feature = "video")]
+        AppMode::Dashboard => handle_dashboard_input(state, key),
+        #[cfg(not(feature = "video"))]
+        AppMode::Dashboard => Ok(false),
+        AppMode::Pairing => handle_pairing_input(state, key),
+        AppMode::Settings => handle_settings_input(state, key),
+        AppMode::Console => handle_console_input(state, key),
+        AppMode::Plugins => handle_plugins_input(state, key),
+    }
+}
+
+/// Handle a key typed in the full interface: the macro keys, then recording and `handle_input`
+pub fn handle_key_event(state: &mut AppState, key: &KeyEvent) -> Result<bool> {
+    if macros::is_record(key) {
+        toggle_macro_recording(state);
+        return Ok(false);
+    }
+    if macros::is_replay(key) {
+        match state.last_macro.clone() {
+            Some(name) => play_macro(state, &name),
+            None => ask_macro_to_play(state),
+        }
+        return Ok(false);
+    }
+    if macros::is_choose(key) {
+        ask_macro_to_play(state);
+        return Ok(false);
+    }
+
+    // A typed key takes over from a playing macro
+    if let Some(playback) = state.macro_playback.take() {
+        state.set_status(&trf(
+            "Macro {} stopped after {}/{} keys",
+            &[&playback.name, &playback.played(), &playback.total],
+        ));
+        return Ok(false);
+    }
+
+    if let Some(keys) = &mut state.macro_recording {
+        keys.push(key.code);
+    }
+    handle_input(state, key.code)
+}
+
+/// Send the next key of a playing macro once it is due, returning true if it quit the app
+pub fn process_macro(state: &mut AppState) -> Result<bool> {
+    let step = Duration::from_millis(state.config.macros.step_millis);
+    let Some(playback) = &mut state.macro_playback else {
+        return Ok(false);
+    };
+    let Some(key) = playback.next_key(step) else {
+        if playback.keys.is_empty() {
+            let message = trf("Macro {} finished", &[&playback.name]);
+            state.macro_playback = None;
+            state.set_status(&message);
+        }
+        return Ok(false);
+    };
+
+    match handle_input(state, key) {
+        Ok(quit) => Ok(quit),
+        Err(e) => {
+            if let Some(playback) = state.macro_playback.take() {
+                state.set_status(&trf("Macro {} stopped: {}", &[&playback.name, &e]));
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// Play a whole macro straight away, for the plain interface, returning true if it quit the app
+pub fn run_macro(state: &mut AppState, name: &str) -> Result<bool> {
+    let keys = state.macros.keys(name)?;
+    state.last_macro = Some(name.to_string());
+    for key in keys {
+        if handle_input(state, key)? {
+            return Ok(true);
+        }
+    }
+    state.set_status(&trf("Macro {} finished", &[&name]));
+    Ok(false)
+}
+
+/// Whether a key exits straight away from any screen, without asking
+pub fn is_hard_exit(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('q'))
+}
+
+/// Quit, asking first if that would cut running work short
+///
+/// Returns true when the application should quit right away.
+pub fn request_quit(state: &mut AppState) -> bool {
+    let jobs = state.active_jobs();
+    if jobs.is_empty() {
+        return true;
+    }
+
+    let jobs: Vec<&str> = jobs.into_iter().map(tr).collect();
+    state.open_dialog(
+        Dialog::confirm(
+            tr("Quit"),
+            &trf(
+                "Still running: {}\n\nQuitting now stops them. Quit anyway?",
+                &[&jobs.join(", ")],
+            ),
+            DialogAction::Quit,
+        )
+        .with_warning(),
+    );
+    false
+}
+
+/// Leave the camera in a clean state before the application exits
+pub fn shutdown(state: &mut AppState) {
+    #[cfg(feature = "video")]
+    if let Some(viewer_state) = &mut state.video_viewer {
+        let _ = video_viewer::olympus_udp::stop_udp_receiver(viewer_state);
+        let _ = video_viewer::olympus_udp::stop_live_view(&state.camera);
+    }
+
+    if let Some(timelapse) = &state.timelapse {
+        timelapse.stop();
+    }
+    if let Some(queue) = &state.capture_queue {
+        queue.stop();
+    }
+    for plugin in &mut state.plugins {
+        plugin.stop();
+    }
+    if let Some(job) = &state.transcode_job {
+        job.cancel();
+    }
+    if let Some(job) = &state.dng_job {
+        job.cancel();
+    }
+    if let Some(fetch) = &state.movie_fetch {
+        fetch.cancel();
+    }
+    if let Some(recording) = state.movie_recording.take()
+        && let Err(e) = state.camera.stop_movie(&recording)
+    {
+        warn!("Failed to stop video recording: {}", e);
+    }
+    end_session(state);
+}
+
+/// Start a shoot session, routing new files into its folder
+fn start_session(state: &mut AppState, label: &str) {
+    match ShootSession::start(&state.config.session, label) {
+        Ok(session) => {
+            state.set_status(&trf(
+                "Session {} started, new files go to {}",
+                &[&session.name, &session.directory.display()],
+            ));
+            state.session = Some(session);
+            #[cfg(feature = "video")]
+            state.refresh_recordings();
+        }
+        Err(e) => state.set_status(&trf("Failed to start session: {}", &[&e])),
     }
 }
 
+/// End the running shoot session, if any, and write its summary
+fn end_session(state: &mut AppState) {
+    let Some(session) = state.session.take() else {
+        return;
+    };
+    match session.finish() {
+        Ok(summary) => state.set_status(&trf(
+            "Session {} ended with {} files",
+            &[&summary.name, &summary.total_files()],
+        )),
+        Err(e) => state.set_status(&trf("Failed to write session summary: {}", &[&e])),
+    }
+    #[cfg(feature = "video")]
+    state.refresh_recordings();
+}
+
 /// Handle input in the main menu
 fn handle_main_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
     match key {
-        KeyCode::Char('q') => return Ok(true), // Signal to quit
+        KeyCode::Char('q') => return Ok(request_quit(state)),
         KeyCode::Up => state.selection_up(),
         KeyCode::Down => state.selection_down(),
         KeyCode::Char('d') => {
             // Debug: Explore camera API endpoints
-            state.set_status("Exploring camera API endpoints...");
-            match state.explore_camera_api() {
-                Ok(_) => state.set_status("API exploration complete. Check logs for details."),
-                Err(e) => state.set_status(&format!("API exploration failed: {}", e)),
+            state.set_status(tr("Exploring camera API endpoints..."));
+            let capabilities = state.explore_camera_api();
+            state.set_status(&trf(
+                "API exploration complete: {} of {} endpoints supported. Check logs for details.",
+                &[
+                    &capabilities.supported_endpoints(),
+                    &capabilities.endpoints.len(),
+                ],
+            ));
+        }
+        KeyCode::Char('f') => {
+            // Capture a focus bracket for stacking
+            state.set_status(tr("Capturing focus bracket..."));
+            match run_focus_bracket(state) {
+                Ok(frames) => {
+                    state.set_status(&trf("Focus bracket complete: {} frames", &[&frames]))
+                }
+                Err(e) => state.set_status(&trf("Focus bracket failed: {}", &[&e])),
             }
         }
+        KeyCode::Char('t') => toggle_timelapse(state),
+        KeyCode::Char('c') => toggle_capture_queue(state),
+        KeyCode::Char('e') => open_transcode_screen(state),
+        KeyCode::Char('m') => open_dashboard(state),
+        KeyCode::Char('w') => open_pairing(state),
+        KeyCode::Char('g') => open_settings(state),
+        KeyCode::Char('x') => {
+            state.set_mode(AppMode::Console);
+            ask_console_command(state);
+        }
+        KeyCode::Char('u') => {
+            state.set_mode(AppMode::Plugins);
+            if state.plugins.is_empty() {
+                state.set_status(tr("No plugins in this build"));
+            }
+        }
+        KeyCode::Char('i') => show_request_stats(state),
+        KeyCode::Char('l') => {
+            state.refresh_audit_log();
+            state.set_mode(AppMode::AuditLog);
+            state.set_status(&trf(
+                "{} operations in the audit log",
+                &[&state.audit_entries.len()],
+            ));
+        }
+        KeyCode::Char('n') => {
+            if state.session.is_some() {
+                end_session(state);
+            } else {
+                let field = InputField::new("session_name", Validation::Text { max_len: 40 })
+                    .with_history(&state.input_history);
+                state.open_dialog(Dialog::input(
+                    tr("Start Session"),
+                    tr("Session name (may be left empty):"),
+                    field,
+                    DialogAction::StartSession,
+                ));
+            }
+        }
+        KeyCode::Char('p') => {
+            state.next_profile();
+            match state.selected_profile() {
+                Some(name) => state.set_status(&trf("Selected profile: {}", &[&name])),
+                None => state.set_status(tr("No settings profiles configured")),
+            }
+        }
+        KeyCode::Char('s') => match save_profile(state) {
+            Ok(name) => state.set_status(&trf("Saved camera settings as profile {}", &[&name])),
+            Err(e) => state.set_status(&trf("Failed to save profile: {}", &[&e])),
+        },
+        KeyCode::Char('r') => match apply_profile(state) {
+            Ok((name, applied)) => {
+                state.set_status(&trf("Applied profile {} ({} settings)", &[&name, &applied]))
+            }
+            Err(e) => state.set_status(&trf("Failed to apply profile: {}", &[&e])),
+        },
         KeyCode::Enter => {
-            match state.selected_index {
-                0 => {
-                    state.set_status("Taking photo with warm-up...");
+            match menu::action_at(state.selected_index) {
+                Some(MenuAction::TakePhoto) if state.camera.is_read_only() => {
+                    state.set_status(tr("Capture is disabled in read-only mode"));
+                }
+                Some(MenuAction::TakePhoto) if state.config.tether.enabled => {
+                    state.set_status(tr("Taking tethered photo..."));
+                    take_tethered_photo(state)?;
+                }
+                Some(MenuAction::TakePhoto) => {
+                    state.set_status(tr("Taking photo with warm-up..."));
                     take_photo_with_warmup(state)?;
                 }
-                1 => {
+                Some(MenuAction::ViewImages) => {
                     // Just show the list of images - DON'T take a photo
-                    state.set_status("Loading image list...");
+                    state.set_status(tr("Loading image list..."));
                     state.refresh_images()?;
                     state.set_mode(AppMode::ImageList);
                 }
-                2 => {
-                    // Start live view streaming
-                    state.set_status("Starting live view stream...");
-                    match start_live_view(state) {
-                        Ok(_) => {
-                            state.set_mode(AppMode::ViewingVideo);
-                            state.set_status("Live view stream started");
-                        }
-                        Err(e) => {
-                            state.set_status(&format!("Failed to start live view: {}", e));
-                            info!("Failed to start live view: {}", e);
-                        }
-                    }
+                Some(MenuAction::LiveView) => open_live_view(state),
+                Some(MenuAction::RecordVideo) if state.camera.is_read_only() => {
+                    state.set_status(tr("Recording video is disabled in read-only mode"));
                 }
-                3 => {
-                    state.set_status("Refreshing image count...");
+                Some(MenuAction::RecordVideo) => toggle_movie_recording(state),
+                Some(MenuAction::RefreshImages) => {
+                    state.set_status(tr("Refreshing image count..."));
                     state.refresh_images()?;
                 }
-                4 => {
-                    return Ok(true); // Signal to quit
-                }
-                _ => {}
+                Some(MenuAction::Quit) => return Ok(request_quit(state)),
+                None => {}
             }
         }
         _ => {}
@@ -96,78 +383,188 @@ fn handle_main_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
 /// Handle input in the image list
 fn handle_image_list_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
     match key {
-        KeyCode::Char('q') => return Ok(true), // Signal to quit
         KeyCode::Up => state.selection_up(),
         KeyCode::Down => state.selection_down(),
         KeyCode::PageUp => state.prev_page(),
         KeyCode::PageDown => state.next_page(),
         KeyCode::Home => state.first_image(),
         KeyCode::End => state.last_image(),
-        KeyCode::Char('d') => {
-            if state.selected_image().is_some() {
-                info!(
-                    "Moving to download screen for image at index: {}",
-                    state.selected_index
-                );
-                state.set_mode(AppMode::Downloading);
-            } else {
-                state.set_status("No image selected for download");
+        KeyCode::Char('[') => state.prev_folder(),
+        KeyCode::Char(']') => state.next_folder(),
+        KeyCode::Char('d') => match state.selected_image().map(|image| image.trim().to_string()) {
+            Some(image) => state.open_dialog(Dialog::confirm(
+                tr("Download Image"),
+                &trf("Download {} to the downloads directory?", &[&image]),
+                DialogAction::Download(image),
+            )),
+            None => state.set_status(tr("No image selected for download")),
+        },
+        KeyCode::Char('o') => match state.selected_image().map(|image| image.to_string()) {
+            Some(image) => open_in_system_viewer(state, &image),
+            None => state.set_status(tr("No image selected to open")),
+        },
+        KeyCode::Char('c') => match state.selected_image().map(|image| image.to_string()) {
+            Some(image) => copy_image_location(state, &image),
+            None => state.set_status(tr("No image selected to copy")),
+        },
+        KeyCode::Char('w') => match state.selected_image().map(|image| image.to_string()) {
+            Some(image) => save_web_size(state, &image),
+            None => state.set_status(tr("No image selected for a web size copy")),
+        },
+        KeyCode::Char(digit @ '0'..='5') => {
+            match state.selected_image().map(|image| image.trim().to_string()) {
+                Some(image) => rate_image(state, &image, digit as u8 - b'0'),
+                None => state.set_status(tr("No image selected to rate")),
             }
         }
-        KeyCode::Delete => {
-            if state.selected_image().is_some() {
-                info!(
-                    "Moving to delete screen for image at index: {}",
-                    state.selected_index
-                );
-                state.set_mode(AppMode::Deleting);
+        KeyCode::Char('t') => match state.selected_image().map(|image| image.trim().to_string()) {
+            Some(image) => {
+                let mut field = InputField::new("image_tags", Validation::Text { max_len: 200 })
+                    .with_history(&state.input_history);
+                if let Some(notes) = state.notes.get(&image) {
+                    field.set_value(&notes.tags.join(", "));
+                }
+                state.open_dialog(Dialog::input(
+                    tr("Tag Image"),
+                    &trf("Tags for {}, separated by commas:", &[&image]),
+                    field,
+                    DialogAction::Tag(image),
+                ));
+            }
+            None => state.set_status(tr("No image selected to tag")),
+        },
+        KeyCode::Delete if state.camera.is_read_only() => {
+            state.set_status(tr("Deleting images is disabled in read-only mode"));
+        }
+        KeyCode::Delete
+            if !state
+                .camera
+                .known_capabilities()
+                .allows_command(DELETE_COMMAND) =>
+        {
+            state.set_status(tr("This camera can't delete images over WiFi"));
+        }
+        KeyCode::Delete => match state.selected_image().map(|image| image.trim().to_string()) {
+            Some(image) => state.open_dialog(
+                Dialog::confirm(
+                    tr("Delete Image"),
+                    &trf(
+                        "Delete {} from the camera?\n\nThis cannot be undone.",
+                        &[&image],
+                    ),
+                    DialogAction::Delete(image),
+                )
+                .with_warning(),
+            ),
+            None => state.set_status(tr("No image selected for deletion")),
+        },
+        KeyCode::Char('g') => {
+            if state.images.is_empty() {
+                state.set_status(tr("No images to go to"));
             } else {
-                state.set_status("No image selected for deletion");
+                let field = InputField::new(
+                    "go_to_image",
+                    Validation::Integer {
+                        min: 1,
+                        max: state.images.len() as u64,
+                    },
+                )
+                .with_history(&state.input_history);
+                state.open_dialog(Dialog::input(
+                    tr("Go To Image"),
+                    &trf("Image number (1-{}):", &[&state.images.len()]),
+                    field,
+                    DialogAction::GoTo,
+                ));
             }
         }
         KeyCode::Enter => {
-            // New: View the selected image
-            if state.selected_image().is_some() {
-                info!("Viewing image at index: {}", state.selected_index);
+            // Movies go to the external player rather than the image viewer
+            if let Some(movie) = state
+                .selected_image()
+                .filter(|name| list::is_movie(name))
+                .map(str::to_string)
+            {
+                open_movie(state, &movie);
+            } else if state.selected_image().is_some() {
+                // New: View the selected image
+                info!(
+                    "Viewing image at index: {}",
+                    state.image_selection.selected()
+                );
                 match state.view_selected_image() {
                     Ok(_) => {
                         info!("Image viewer opened successfully");
                     }
                     Err(e) => {
                         info!("Failed to open image viewer: {}", e);
-                        state.set_status(&format!("Failed to view image: {}", e));
+                        state.set_status(&trf("Failed to view image: {}", &[&e]));
 
                         // Debug mode - show error dialog with more details
-                        state.set_error_message(
-                            "Image Loading Failed",
-                            &format!(
+                        state.open_dialog(Dialog::error(
+                            tr("Image Loading Failed"),
+                            &trf(
                                 "Failed to load image: {}\n\nCheck the logs for more details.",
-                                e
+                                &[&e],
                             ),
-                        );
-                        state.set_show_error_dialog(true);
+                        ));
                     }
                 }
             } else {
-                state.set_status("No image selected to view");
+                state.set_status(tr("No image selected to view"));
+            }
+        }
+        KeyCode::Char('s') => {
+            if state.images.is_empty() {
+                state.set_status(tr("No images to sync"));
+            } else {
+                state.set_status(&trf("Syncing {} images...", &[&state.images.len()]));
+                match sync_images(state) {
+                    Ok(summary) => state.set_status(&trf(
+                        "Sync complete: {} downloaded, {} already present, {} duplicates skipped, {} failed",
+                        &[
+                            &summary.downloaded,
+                            &summary.present,
+                            &summary.duplicates,
+                            &summary.failed,
+                        ],
+                    )),
+                    Err(e) => state.set_status(&trf("Sync stopped: {}", &[&e])),
+                }
+                start_dng_conversion(state);
             }
         }
         KeyCode::Char('r') => {
-            state.refresh_images()?;
-            state.set_status(&format!(
-                "Image list refreshed - {} images found",
-                state.images.len()
-            ));
+            let changes = state.refresh_images()?;
+            if changes.is_empty() {
+                state.set_status(&trf(
+                    "Image list unchanged - {} images",
+                    &[&state.images.len()],
+                ));
+            } else {
+                state.set_status(&trf(
+                    "Image list refreshed - {} images found, {} new, {} removed",
+                    &[
+                        &state.images.len(),
+                        &changes.added.len(),
+                        &changes.removed.len(),
+                    ],
+                ));
+            }
         }
         KeyCode::Char('a') => {
             // Debug command - explore API
-            state.set_status("Exploring camera API endpoints...");
-            match state.explore_camera_api() {
-                Ok(_) => state.set_status("API exploration complete. Check logs for details."),
-                Err(e) => state.set_status(&format!("API exploration failed: {}", e)),
-            }
+            state.set_status(tr("Exploring camera API endpoints..."));
+            let capabilities = state.explore_camera_api();
+            state.set_status(&trf(
+                "API exploration complete: {} of {} endpoints supported. Check logs for details.",
+                &[
+                    &capabilities.supported_endpoints(),
+                    &capabilities.endpoints.len(),
+                ],
+            ));
         }
-        KeyCode::Esc => {
+        KeyCode::Esc | KeyCode::Char('q') => {
             state.set_mode(AppMode::Main);
         }
         _ => {}
@@ -175,181 +572,1089 @@ fn handle_image_list_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
     Ok(false)
 }
 
-/// Handle input in the download screen
-fn handle_download_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
-    match key {
-        KeyCode::Char('q') => return Ok(true), // Signal to quit
-        KeyCode::Enter => {
-            // IMPORTANT: Get the currently selected image by index
-            // Store the index for debugging
-            let selected_idx = state.selected_index;
+/// Show how the camera's endpoints have been answering
+fn show_request_stats(state: &mut AppState) {
+    let summary = {
+        let stats = state.camera.requests.lock().unwrap();
+        let mut lines: Vec<String> = stats
+            .endpoints()
+            .map(|(endpoint, totals)| {
+                trf(
+                    "{}: {} requests, {} failed, avg {} ms, max {} ms",
+                    &[
+                        &endpoint,
+                        &totals.count,
+                        &totals.failures,
+                        &totals.average().as_millis(),
+                        &totals.max.as_millis(),
+                    ],
+                )
+            })
+            .collect();
+        let failures: Vec<String> = stats
+            .recent()
+            .filter(|record| record.status.is_none_or(|status| status >= 400))
+            .map(|record| {
+                let status = record
+                    .status
+                    .map_or_else(|| tr("no response").to_string(), |s| s.to_string());
+                format!("  {} {} -> {}", record.method, record.endpoint, status)
+            })
+            .collect();
+        if !failures.is_empty() {
+            lines.push(tr("Recent failures:").to_string());
+            lines.extend(failures);
+        }
+        lines
+    };
 
-            // Get the image name by direct array access to ensure correct selection
-            let image_to_download = if !state.images.is_empty() && selected_idx < state.images.len()
-            {
-                let image = &state.images[selected_idx];
-                info!(
-                    "Selected for download by direct access: index={}, image={}",
-                    selected_idx, image
-                );
-                image.trim().to_string() // Ensure no whitespace
+    if summary.is_empty() {
+        state.open_dialog(Dialog::message(
+            tr("Camera requests"),
+            tr("No requests have been made yet"),
+        ));
+    } else {
+        state.open_dialog(Dialog::message(tr("Camera requests"), &summary.join("\n")));
+    }
+}
+
+/// Run the action of a confirmed dialog, returning true if the application should quit
+fn run_dialog_action(state: &mut AppState, action: DialogAction, input: &str) -> bool {
+    match action {
+        DialogAction::Quit => return true,
+        DialogAction::TakeOver => state.take_over(),
+        #[cfg(feature = "video")]
+        DialogAction::SnapshotFallback => video_viewer::handlers::start_snapshot_fallback(state),
+        DialogAction::Download(image) => confirm_download(state, &image),
+        DialogAction::Delete(image) => confirm_delete(state, &image),
+        DialogAction::Tag(image) => tag_image(state, &image, input),
+        DialogAction::StartSession => start_session(state, input),
+        DialogAction::SwitchConnectMode(mode) => switch_connect_mode(state, &mode),
+        DialogAction::SetAccessPointName => ask_access_point_password(state, input),
+        DialogAction::SetAccessPointPassword(ssid) => set_access_point(state, &ssid, input),
+        DialogAction::RunCommand => state.run_console_command(input),
+        DialogAction::SaveMacro(keys) => save_macro(state, &keys, input),
+        DialogAction::PlayMacro => play_macro(state, input.trim()),
+        DialogAction::ExportCapabilities => export_capabilities(state, input),
+        DialogAction::ImportCapabilities => import_capabilities(state, input),
+        DialogAction::GoTo => match input.parse::<usize>() {
+            Ok(number) if (1..=state.images.len()).contains(&number) => {
+                state.image_selection.select(number - 1);
+                state.set_status(&trf("Selected image {}", &[&number]));
+            }
+            _ => state.set_status(&trf(
+                "Not an image number: {} (1-{})",
+                &[&input, &state.images.len()],
+            )),
+        },
+    }
+    false
+}
+
+/// Open the local copy of an image with the system viewer, downloading it first if needed
+pub fn open_in_system_viewer(state: &mut AppState, image: &str) {
+    let image = image.trim();
+    let download = match session::find_download(&state.config.session, image) {
+        Some(path) => Ok(path),
+        None => download_image(state, image),
+    };
+    let path = match download {
+        Ok(path) => path,
+        Err(e) => {
+            state.set_status(&trf("Download failed: {}", &[&e]));
+            return;
+        }
+    };
+
+    match open::open_with_default_app(&path) {
+        Ok(()) => state.set_status(&trf("Opened {} in the system viewer", &[&path.display()])),
+        Err(e) => state.set_status(&trf("Failed to open {}: {}", &[&image, &e])),
+    }
+}
+
+/// Play a movie from the card, streaming it or downloading it first as configured
+pub fn open_movie(state: &mut AppState, movie: &str) {
+    let movie = movie.trim();
+    if state.movie_fetch.is_some() {
+        state.set_status(tr("A movie is already downloading"));
+        return;
+    }
+
+    if state.config.movies.stream {
+        let url = UrlFormatGenerator::movie_url_formats(
+            state.camera.base_url(),
+            &state.camera.image_folder(),
+            movie,
+        )
+        .remove(0);
+        state.play_movie(movie, OsStr::new(&url));
+        return;
+    }
+
+    if let Some(path) = session::find_download(&state.config.session, movie) {
+        state.play_movie(movie, path.as_os_str());
+        return;
+    }
+
+    let path = paths::output_dir(DataDir::Downloads).join(movie);
+    state.movie_fetch = Some(MovieFetch::start(&state.camera, movie, path));
+    state.open_dialog(Dialog::progress(
+        tr("Movie download"),
+        &trf("Downloading {} to play it", &[&movie]),
+        ProgressSource::MovieDownload,
+    ));
+}
+
+/// Copy the local path of an image to the clipboard, or its camera URL if it isn't downloaded
+pub fn copy_image_location(state: &mut AppState, image: &str) {
+    let image = image.trim();
+    let location = if let Some(path) = session::find_download(&state.config.session, image) {
+        path.canonicalize().unwrap_or(path).display().to_string()
+    } else {
+        let image_path = format!("{}/{}", state.camera.image_folder(), image);
+        format!(
+            "{}{}",
+            state.camera.base_url(),
+            UrlFormatGenerator::resolution_url(&image_path, "full")
+        )
+    };
+
+    match state.clipboard.copy(&location) {
+        Ok(()) => state.set_status(&trf("Copied to clipboard: {}", &[&location])),
+        Err(e) => state.set_status(&trf("Failed to copy to clipboard: {}", &[&e])),
+    }
+}
+
+/// Save a small copy of an image for sharing to the web size directory
+pub fn save_web_size(state: &mut AppState, image: &str) {
+    let image = image.trim();
+    let config = state.config.web_size.clone();
+    let directory = paths::resolve_output(&config.directory);
+    // Raw files come back from the camera as JPEGs too
+    let destination = directory.join(Path::new(image).with_extension("JPG"));
+    state.set_status(&trf("Saving web size copy of {}...", &[&image]));
+
+    let result = std::fs::create_dir_all(&directory)
+        .map_err(anyhow::Error::from)
+        .and_then(|_| fetch_web_size(state, image, &config))
+        .and_then(|data| atomic::write_atomic(&destination, &data));
+    match result {
+        Ok(_) => state.set_status(&trf(
+            "Saved web size copy of {} to {}",
+            &[&image, &destination.display()],
+        )),
+        Err(e) => state.set_status(&trf("Failed to save web size copy: {}", &[&e])),
+    }
+}
+
+/// Web size version of an image, resized by the camera where possible
+fn fetch_web_size(state: &AppState, image: &str, config: &WebSizeConfig) -> Result<Vec<u8>> {
+    if config.on_camera {
+        match state
+            .camera
+            .fetch_resized_image(image, config.max_dimension)
+        {
+            // The camera's smallest size can still be over the limit
+            Ok(data) if config.max_dimension >= resize::CAMERA_RESIZE_SIZES[0] => return Ok(data),
+            Ok(data) => return resize::resize_jpeg(&data, config.max_dimension, config.quality),
+            Err(e) => warn!("Camera resize failed, downloading the full image: {}", e),
+        }
+    }
+
+    let data = state.camera.fetch_image(image)?;
+    resize::resize_jpeg(&data, config.max_dimension, config.quality)
+}
+
+/// Give an image a star rating, or clear it with 0
+fn rate_image(state: &mut AppState, image: &str, rating: u8) {
+    if let Err(e) = state.notes.set_rating(image, rating) {
+        state.set_status(&trf("Failed to save rating: {}", &[&e]));
+        return;
+    }
+    update_sidecar(state, image);
+    if rating == 0 {
+        state.set_status(&trf("Cleared the rating of {}", &[&image]));
+    } else {
+        state.set_status(&trf("Rated {} {} stars", &[&image, &rating]));
+    }
+}
+
+/// Replace the tags of an image with the comma separated ones entered
+fn tag_image(state: &mut AppState, image: &str, input: &str) {
+    let tags = notes::parse_tags(input);
+    let count = tags.len();
+    if let Err(e) = state.notes.set_tags(image, tags) {
+        state.set_status(&trf("Failed to save tags: {}", &[&e]));
+        return;
+    }
+    update_sidecar(state, image);
+    state.set_status(&trf("Tagged {} with {} tags", &[&image, &count]));
+}
+
+/// Rewrite the XMP sidecar of an image that has been downloaded
+fn update_sidecar(state: &AppState, image: &str) {
+    let Some(path) = session::find_download(&state.config.session, image) else {
+        return;
+    };
+    if let Err(e) = xmp::write_sidecar(&path, state.notes.get(image), &state.config.xmp) {
+        warn!("Failed to write the XMP sidecar of {}: {}", image, e);
+    }
+}
+
+/// Download an image once the user has confirmed it
+fn confirm_download(state: &mut AppState, image: &str) {
+    info!("Downloading image: {}", image);
+    state.set_status(&trf("Downloading image: {}...", &[&image]));
+
+    match download_image(state, image) {
+        Ok(_) => info!("Download success: {}", image),
+        Err(e) => {
+            state.set_status(&trf("Download failed: {}", &[&e]));
+            info!("Download error: {}", e);
+        }
+    }
+}
+
+/// Delete an image once the user has confirmed it
+fn confirm_delete(state: &mut AppState, image: &str) {
+    state.set_status(&trf("Attempting to delete: {}...", &[&image]));
+
+    // Try to delete the image with enhanced error handling
+    match delete_image(state, image) {
+        Ok(_) => {
+            // Successful deletion
+            state.set_status(&trf("Successfully deleted: {}", &[&image]));
+            info!("Deletion successful for: {}", image);
+        }
+        Err(e) => {
+            // Enhanced error reporting
+            let error_msg = format!("{}", e);
+            info!("Deletion error: {}", error_msg);
+
+            if error_msg.contains("WiFi") {
+                // WiFi-specific error with guidance
+                state.set_status(tr(
+                    "Camera doesn't support WiFi deletion. Try using camera's menu.",
+                ));
             } else {
-                state.set_status("Error: No image selected");
-                state.set_mode(AppMode::ImageList);
-                return Ok(false);
+                state.set_status(&trf("Deletion failed: {}", &[&e]));
+            }
+
+            // Show longer explanation in a dialog
+            show_delete_error_dialog(state);
+        }
+    }
+
+    // Refresh to confirm the image is gone, or that our list is current
+    let _ = state.refresh_images();
+}
+
+/// Show a detailed error dialog for delete operations
+fn show_delete_error_dialog(state: &mut AppState) {
+    state.open_dialog(Dialog::error(
+        tr("Olympus Camera Delete Limitation"),
+        tr("Most Olympus cameras do not support deleting images over WiFi. This is a limitation of the camera's firmware.\n\nAlternatives:\n1. Use the camera's menu to delete images\n2. Connect the SD card to your computer\n3. Format the SD card (will delete ALL images)"),
+    ));
+}
+
+// Camera operation functions
+
+/// Take a photo with warm-up
+fn take_photo_with_warmup(state: &mut AppState) -> Result<()> {
+    state.camera.take_photo()?;
+    alert::notify(&state.config.alerts, AlertEvent::Capture);
+    state.refresh_images()?;
+    state.set_status(tr("Photo captured successfully"));
+    Ok(())
+}
+
+/// Start recording a movie on the camera, or stop the one being recorded
+fn toggle_movie_recording(state: &mut AppState) {
+    match state.movie_recording.take() {
+        Some(recording) => match state.camera.stop_movie(&recording) {
+            Ok(()) => {
+                // The movie is on the card now; refresh first so its status stays up
+                let _ = state.refresh_images();
+                state.set_status(&trf(
+                    "Video recording stopped after {}",
+                    &[&recording.length()],
+                ));
+            }
+            Err(e) => {
+                state.set_status(&trf("Failed to stop video recording: {}", &[&e]));
+                state.movie_recording = Some(recording);
+            }
+        },
+        None => match state.camera.start_movie() {
+            Ok(recording) => {
+                state.movie_recording = Some(recording);
+                state.set_status(tr("Recording video, choose Record Video again to stop"));
+            }
+            Err(e) => state.set_status(&trf("Failed to start video recording: {}", &[&e])),
+        },
+    }
+}
+
+/// Capture a focus bracket with the configured step size and direction
+fn run_focus_bracket(state: &mut AppState) -> Result<u32> {
+    let frames = state
+        .camera
+        .focus_bracket(&state.config.focus_bracket, |frame, total| {
+            info!("Focus bracket frame {}/{} captured", frame, total)
+        })?;
+    alert::notify(&state.config.alerts, AlertEvent::Capture);
+    state.refresh_images()?;
+    Ok(frames)
+}
+
+/// Start a timelapse with the configured settings, or stop the running one
+fn toggle_timelapse(state: &mut AppState) {
+    if let Some(timelapse) = &state.timelapse {
+        timelapse.stop();
+        state.set_status(tr("Stopping timelapse after the current frame..."));
+        return;
+    }
+
+    match Intervalometer::start(state.camera.clone(), &state.config.timelapse) {
+        Ok(timelapse) => {
+            let status = timelapse.status();
+            state.timelapse = Some(timelapse);
+            let interval = state.config.timelapse.interval_secs;
+            let started = match status.starts_at {
+                Some(starts_at) => trf(
+                    "Timelapse scheduled: {} frames every {}s from {} UTC",
+                    &[
+                        &status.frames_total,
+                        &interval,
+                        &session::datetime(starts_at),
+                    ],
+                ),
+                None => trf(
+                    "Timelapse started: {} frames every {}s",
+                    &[&status.frames_total, &interval],
+                ),
             };
+            state.open_dialog(Dialog::progress(
+                tr("Timelapse"),
+                &started,
+                ProgressSource::Timelapse,
+            ));
+            state.set_status(&started);
+        }
+        Err(e) => state.set_status(&trf("Failed to start timelapse: {}", &[&e])),
+    }
+}
+
+/// Start the configured capture queue, or stop the running one
+fn toggle_capture_queue(state: &mut AppState) {
+    if let Some(queue) = &state.capture_queue {
+        queue.stop();
+        state.set_status(tr("Stopping capture queue after the current shot..."));
+        return;
+    }
 
-            // Log which image we're trying to download
-            info!(
-                "Downloading image at index: {}, filename: {}",
-                selected_idx, image_to_download
+    match CaptureQueue::start(state.camera.clone(), &state.config.capture_queue) {
+        Ok(queue) => {
+            let started = trf(
+                "Capture queue started: {} shots",
+                &[&queue.status().shots_total],
             );
-            state.set_status(&format!("Downloading image: {}...", image_to_download));
+            state.capture_queue = Some(queue);
+            state.open_dialog(Dialog::progress(
+                tr("Capture Queue"),
+                &started,
+                ProgressSource::CaptureQueue,
+            ));
+            state.set_status(&started);
+        }
+        Err(e) => state.set_status(&trf("Failed to start capture queue: {}", &[&e])),
+    }
+}
 
-            // Try to download the image
-            match download_image(state, &image_to_download) {
-                Ok(_) => {
-                    state.set_status(&format!("Successfully downloaded: {}", image_to_download));
-                    info!("Download success: {}", image_to_download);
-                }
-                Err(e) => {
-                    state.set_status(&format!("Download failed: {}", e));
-                    info!("Download error: {}", e);
+/// Show the live view recordings available for transcoding
+fn open_transcode_screen(state: &mut AppState) {
+    #[cfg(feature = "video")]
+    {
+        state.set_mode(AppMode::Transcoding);
+        state.refresh_recordings();
+        state.set_status(&trf("Found {} recordings", &[&state.recordings.len()]));
+    }
+
+    #[cfg(not(feature = "video"))]
+    state.set_status(tr("Transcoding is not available in this build"));
+}
+
+/// Handle input on the transcode screen
+#[cfg(feature = "video")]
+fn handle_transcode_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::PageUp => state.prev_page(),
+        KeyCode::PageDown => state.next_page(),
+        KeyCode::Char('r') => {
+            state.refresh_recordings();
+            state.set_status(&trf("Found {} recordings", &[&state.recordings.len()]));
+        }
+        KeyCode::Char('c') => match &state.transcode_job {
+            Some(job) => {
+                job.cancel();
+                state.set_status(tr("Cancelling transcode..."));
+            }
+            None => state.set_status(tr("No transcode running")),
+        },
+        KeyCode::Enter => {
+            if state.transcode_job.is_some() {
+                state.set_status(tr("A transcode is already running"));
+                return Ok(false);
+            }
+            let Some(input) = state.selected_recording().cloned() else {
+                state.set_status(tr("No recording selected"));
+                return Ok(false);
+            };
+
+            match crate::utils::ffmpeg::TranscodeJob::start(&input, &state.config.transcode) {
+                Ok(job) => {
+                    state.set_status(&trf("Transcoding to {}...", &[&job.output.display()]));
+                    state.transcode_job = Some(job);
                 }
+                Err(e) => state.set_status(&trf("Failed to start transcode: {}", &[&e])),
             }
+        }
+        KeyCode::Esc | KeyCode::Char('q') => state.set_mode(AppMode::Main),
+        _ => {}
+    }
+    Ok(false)
+}
 
-            // Return to image list
-            state.set_mode(AppMode::ImageList);
+/// Start monitoring the configured cameras and show them side by side
+pub fn open_dashboard(state: &mut AppState) {
+    #[cfg(feature = "video")]
+    {
+        let config = &state.config.dashboard;
+        if config.cameras.is_empty() {
+            state.set_status(tr("No dashboard cameras configured"));
+            return;
         }
-        KeyCode::Esc => {
-            state.set_mode(AppMode::ImageList);
+
+        let dashboard = video_viewer::dashboard::Dashboard::start(config, &state.config.timeouts);
+        state.dashboard_selection.set_len(dashboard.len());
+        state.set_status(&trf("Monitoring {} cameras", &[&dashboard.len()]));
+        state.dashboard = Some(dashboard);
+        state.set_mode(AppMode::Dashboard);
+    }
+
+    #[cfg(not(feature = "video"))]
+    state.set_status(tr("The dashboard is not available in this build"));
+}
+
+/// Handle input on the dashboard
+#[cfg(feature = "video")]
+fn handle_dashboard_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::PageUp => state.prev_page(),
+        KeyCode::PageDown => state.next_page(),
+        KeyCode::Enter => view_dashboard_camera(state),
+        KeyCode::Esc | KeyCode::Char('q') => {
+            // Dropping the monitors stops their live views
+            state.dashboard = None;
+            state.set_mode(AppMode::Main);
+            state.set_status(tr("Returned to main menu"));
         }
         _ => {}
     }
     Ok(false)
 }
 
-/// Handle input in the delete screen
-fn handle_delete_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+/// Open the full live viewer of the selected dashboard camera
+#[cfg(feature = "video")]
+fn view_dashboard_camera(state: &mut AppState) {
+    let selected = state.dashboard_selection.selected();
+    let Some(camera) = state
+        .dashboard
+        .as_ref()
+        .and_then(|dashboard| dashboard.camera(selected))
+        .cloned()
+    else {
+        return;
+    };
+
+    // The monitors have to let go of the cameras and their streams first
+    state.dashboard = None;
+    let result = state
+        .switch_to_dashboard_camera(&camera)
+        .and_then(|_| video_viewer::handlers::create_live_view(state));
+
+    match result {
+        Ok(_) => {
+            if let Some(viewer_state) = &mut state.video_viewer {
+                viewer_state.stream_name = camera.name.clone();
+            }
+        }
+        Err(e) => {
+            state.return_home_camera();
+            open_dashboard(state);
+            state.set_status(&trf("Failed to view {}: {}", &[&camera.name, &e]));
+        }
+    }
+}
+
+/// Handle input on the audit log screen
+fn handle_audit_log_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
     match key {
-        KeyCode::Char('q') => return Ok(true), // Signal to quit
-        KeyCode::Enter => {
-            // IMPORTANT: Get the currently selected image by index
-            // Store the index for debugging
-            let selected_idx = state.selected_index;
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::PageUp => state.prev_page(),
+        KeyCode::PageDown => state.next_page(),
+        KeyCode::Char('r') => {
+            state.refresh_audit_log();
+            state.set_status(&trf(
+                "{} operations in the audit log",
+                &[&state.audit_entries.len()],
+            ));
+        }
+        KeyCode::Esc | KeyCode::Char('q') => state.set_mode(AppMode::Main),
+        _ => {}
+    }
+    Ok(false)
+}
 
-            // Get the image name by direct array access to ensure correct selection
-            let image_to_delete = if !state.images.is_empty() && selected_idx < state.images.len() {
-                let image = &state.images[selected_idx];
-                info!(
-                    "Selected for deletion by direct access: index={}, image={}",
-                    selected_idx, image
-                );
-                image.trim().to_string() // Ensure no whitespace
-            } else {
-                state.set_status("Error: No image selected");
-                state.set_mode(AppMode::ImageList);
-                return Ok(false);
-            };
+/// Open the pairing screen with the camera's connect mode and access point
+fn open_pairing(state: &mut AppState) {
+    match state.refresh_pairing() {
+        Ok(()) => {
+            state.selected_index = 0;
+            state.set_mode(AppMode::Pairing);
+        }
+        Err(e) => state.set_status(&trf("Failed to read the connect settings: {}", &[&e])),
+    }
+}
 
-            // Log which image we're trying to delete
-            info!(
-                "Deleting image at index: {}, filename: {}",
-                selected_idx, image_to_delete
-            );
-            state.set_status(&format!("Attempting to delete: {}...", image_to_delete));
+/// Handle input on the pairing screen
+fn handle_pairing_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::Char('r') => match state.refresh_pairing() {
+            Ok(()) => state.set_status(tr("Connect settings reloaded")),
+            Err(e) => state.set_status(&trf("Failed to read the connect settings: {}", &[&e])),
+        },
+        KeyCode::Enter if state.selected_index == 0 => ask_connect_mode(state),
+        KeyCode::Enter => ask_access_point_name(state),
+        KeyCode::Esc | KeyCode::Char('q') => state.set_mode(AppMode::Main),
+        _ => {}
+    }
+    Ok(false)
+}
 
-            // Try to delete the image with enhanced error handling
-            match delete_image(state, &image_to_delete) {
-                Ok(_) => {
-                    // Successful deletion
-                    state.set_status(&format!("Successfully deleted: {}", image_to_delete));
-                    info!("Deletion successful for: {}", image_to_delete);
+/// Open the settings screen with the camera's exposure settings
+fn open_settings(state: &mut AppState) {
+    match state.refresh_camera_settings() {
+        Ok(()) => {
+            state.selected_index = 0;
+            state.set_mode(AppMode::Settings);
+        }
+        Err(e) => state.set_status(&trf("Failed to read the camera settings: {}", &[&e])),
+    }
+}
 
-                    // Refresh immediately to confirm the image is gone
-                    let _ = state.refresh_images();
-                }
-                Err(e) => {
-                    // Enhanced error reporting
-                    let error_msg = format!("{}", e);
-                    info!("Deletion error: {}", error_msg);
-
-                    if error_msg.contains("WiFi") {
-                        // WiFi-specific error with guidance
-                        state.set_status(
-                            "Camera doesn't support WiFi deletion. Try using camera's menu.",
-                        );
-                    } else {
-                        state.set_status(&format!("Deletion failed: {}", e));
-                    }
+/// Handle input on the settings screen
+fn handle_settings_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::Left | KeyCode::Char('-') => step_camera_setting(state, -1),
+        KeyCode::Right | KeyCode::Char('+') | KeyCode::Enter => step_camera_setting(state, 1),
+        KeyCode::Char('r') => match state.refresh_camera_settings() {
+            Ok(()) => state.set_status(tr("Camera settings reloaded")),
+            Err(e) => state.set_status(&trf("Failed to read the camera settings: {}", &[&e])),
+        },
+        KeyCode::Esc | KeyCode::Char('q') => state.set_mode(AppMode::Main),
+        _ => {}
+    }
+    Ok(false)
+}
 
-                    // Show longer explanation in a dialog
-                    show_delete_error_dialog(state);
+/// Move the selected setting `steps` values along and send it to the camera
+fn step_camera_setting(state: &mut AppState, steps: isize) {
+    let Some(entry) = state.camera_settings.get(state.selected_index) else {
+        return;
+    };
+    let setting = entry.setting;
+    if !entry.writable {
+        state.set_status(&trf(
+            "{} is fixed by the current exposure mode",
+            &[&tr(setting.label())],
+        ));
+        return;
+    }
+    let Some(value) = entry.step(steps).map(str::to_string) else {
+        state.set_status(&trf("{} is already at its limit", &[&tr(setting.label())]));
+        return;
+    };
 
-                    // Refresh anyway to ensure our list is current
-                    let _ = state.refresh_images();
-                }
+    match state.camera.change_setting(setting, &value) {
+        Ok(()) => {
+            state.camera_settings[state.selected_index].value = Some(value.clone());
+            state.set_status(&trf(
+                "{} set to {}",
+                &[&tr(setting.label()), &setting.display_value(&value)],
+            ));
+        }
+        Err(e) => state.set_status(&trf("Failed to change {}: {}", &[&tr(setting.label()), &e])),
+    }
+}
+
+/// Ask before switching to the next configured connect mode
+fn ask_connect_mode(state: &mut AppState) {
+    let current = state
+        .pairing
+        .as_ref()
+        .and_then(|settings| settings.mode.as_deref());
+    let Some(mode) = pairing::next_mode(&state.config.pairing, current).map(str::to_string) else {
+        state.set_status(tr("No connect modes configured"));
+        return;
+    };
+    state.open_dialog(
+        Dialog::confirm(
+            tr("Connect Mode"),
+            &trf(
+                "Switch the camera to {} mode? It may restart its Wi-Fi and drop this connection.",
+                &[&mode],
+            ),
+            DialogAction::SwitchConnectMode(mode),
+        )
+        .with_warning(),
+    );
+}
+
+/// Switch the connect mode and show it on the pairing screen
+fn switch_connect_mode(state: &mut AppState, mode: &str) {
+    match state.camera.set_connect_mode(&state.config.pairing, mode) {
+        Ok(()) => {
+            if let Some(settings) = &mut state.pairing {
+                settings.mode = Some(mode.to_string());
             }
+            state.set_status(&trf("Camera switched to {} mode", &[&mode]));
+        }
+        Err(e) => state.set_status(&trf("Failed to switch the connect mode: {}", &[&e])),
+    }
+}
+
+/// Ask for a new access point name, starting from the current one
+fn ask_access_point_name(state: &mut AppState) {
+    let Some(network) = state
+        .pairing
+        .as_ref()
+        .and_then(|settings| settings.network.as_ref())
+    else {
+        state.set_status(tr(
+            "The camera doesn't report its access point, so it can't be changed here",
+        ));
+        return;
+    };
 
-            // Return to image list
-            state.set_mode(AppMode::ImageList);
+    let mut field = InputField::new("access_point_ssid", Validation::Text { max_len: 32 });
+    if let Some(ssid) = &network.ssid {
+        field.set_value(ssid);
+    }
+    state.open_dialog(Dialog::input(
+        tr("Access Point"),
+        tr("Network name:"),
+        field,
+        DialogAction::SetAccessPointName,
+    ));
+}
+
+/// Ask for the password of the access point named `ssid`
+fn ask_access_point_password(state: &mut AppState, ssid: &str) {
+    let field = InputField::new("access_point_password", Validation::Text { max_len: 63 });
+    state.open_dialog(Dialog::input(
+        tr("Access Point"),
+        &trf("Password for {} (8 to 63 characters):", &[&ssid]),
+        field,
+        DialogAction::SetAccessPointPassword(ssid.to_string()),
+    ));
+}
+
+/// Change the access point the camera opens
+fn set_access_point(state: &mut AppState, ssid: &str, password: &str) {
+    match state
+        .camera
+        .set_access_point(&state.config.pairing, ssid, password)
+    {
+        Ok(()) => {
+            if let Some(settings) = &mut state.pairing {
+                settings.network = Some(AccessPoint {
+                    ssid: Some(ssid.to_string()),
+                    password: Some(password.to_string()),
+                });
+            }
+            state.set_status(&trf(
+                "Access point changed to {}, join that network to reconnect",
+                &[&ssid],
+            ));
         }
-        KeyCode::Esc => {
-            state.set_mode(AppMode::ImageList);
+        Err(e) => state.set_status(&trf("Failed to change the access point: {}", &[&e])),
+    }
+}
+
+/// Handle input on the command console
+fn handle_console_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::PageUp => state.prev_page(),
+        KeyCode::PageDown => state.next_page(),
+        KeyCode::Enter => ask_console_command(state),
+        KeyCode::Char('d') => toggle_console_diff(state),
+        KeyCode::Char('e') => ask_capability_file(state, DialogAction::ExportCapabilities),
+        KeyCode::Char('i') => ask_capability_file(state, DialogAction::ImportCapabilities),
+        KeyCode::Esc | KeyCode::Char('q') => state.set_mode(AppMode::Main),
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Handle input on the plugins screen
+fn handle_plugins_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Up => state.selection_up(),
+        KeyCode::Down => state.selection_down(),
+        KeyCode::PageUp => state.prev_page(),
+        KeyCode::PageDown => state.next_page(),
+        KeyCode::Esc | KeyCode::Char('q') => state.set_mode(AppMode::Main),
+        KeyCode::Enter => {
+            let selected = state.plugin_selection.selected();
+            if let Some((index, entry)) = state.plugin_entries().get(selected).copied() {
+                state.run_plugin(index, entry.key);
+            }
+        }
+        KeyCode::Char(key) => {
+            // Keys go to the first plugin offering them
+            let entry = state
+                .plugin_entries()
+                .into_iter()
+                .find(|(_, entry)| entry.key == key);
+            if let Some((index, entry)) = entry {
+                state.run_plugin(index, entry.key);
+            }
         }
         _ => {}
     }
     Ok(false)
 }
 
-/// Show a detailed error dialog for delete operations
-fn show_delete_error_dialog(state: &mut AppState) {
-    state.set_error_message(
-        "Olympus Camera Delete Limitation",
-        "Most Olympus cameras do not support deleting images over WiFi. This is a limitation of the camera's firmware.\n\nAlternatives:\n1. Use the camera's menu to delete images\n2. Connect the SD card to your computer\n3. Format the SD card (will delete ALL images)"
-    );
-    state.set_show_error_dialog(true);
+/// Start recording a macro, or stop and ask for a name to save it under
+fn toggle_macro_recording(state: &mut AppState) {
+    if state.macro_playback.is_some() {
+        state.set_status(tr(
+            "A macro is playing, wait for it to finish before recording",
+        ));
+        return;
+    }
+
+    match state.macro_recording.take() {
+        None => {
+            state.macro_recording = Some(Vec::new());
+            state.set_status(tr("Recording a macro, Ctrl-r again to stop"));
+        }
+        Some(keys) if keys.is_empty() => {
+            state.set_status(tr("Macro recording stopped, no keys were recorded"));
+        }
+        Some(keys) => {
+            let mut field = InputField::new("macro_name", Validation::Text { max_len: 40 })
+                .with_history(&state.input_history);
+            if let Some(name) = &state.last_macro {
+                field.set_value(name);
+            }
+            let prompt = trf("Name for the {} keys recorded:", &[&keys.len()]);
+            state.open_dialog(Dialog::input(
+                tr("Save Macro"),
+                &prompt,
+                field,
+                DialogAction::SaveMacro(keys),
+            ));
+        }
+    }
 }
 
-// Camera operation functions
+/// Save recorded keys as a macro
+fn save_macro(state: &mut AppState, keys: &[KeyCode], name: &str) {
+    let name = name.trim();
+    if name.is_empty() {
+        state.set_status(tr("Macro not saved: no name given"));
+        return;
+    }
 
-/// Take a photo with warm-up
-fn take_photo_with_warmup(state: &mut AppState) -> Result<()> {
-    state.camera.take_photo()?;
-    state.refresh_images()?;
-    state.set_status("Photo captured successfully");
+    state.macros.insert(name, keys);
+    match state
+        .macros
+        .save(&paths::resolve(&state.config.macros.file))
+    {
+        Ok(()) => {
+            state.last_macro = Some(name.to_string());
+            state.set_status(&trf(
+                "Macro {} saved with {} keys, Ctrl-p plays it",
+                &[&name, &keys.len()],
+            ));
+        }
+        Err(e) => state.set_status(&trf("Failed to save the macro: {}", &[&e])),
+    }
+}
+
+/// Ask which recorded macro to play
+fn ask_macro_to_play(state: &mut AppState) {
+    if state.macros.macros.is_empty() {
+        state.set_status(tr("No macros recorded yet, Ctrl-r starts recording one"));
+        return;
+    }
+
+    let names: Vec<&str> = state.macros.macros.keys().map(String::as_str).collect();
+    let prompt = trf("Macro to play ({}):", &[&names.join(", ")]);
+    let mut field = InputField::new("macro_play", Validation::Text { max_len: 40 })
+        .with_history(&state.input_history);
+    field.set_value(state.last_macro.as_deref().unwrap_or(names[0]));
+    state.open_dialog(Dialog::input(
+        tr("Play Macro"),
+        &prompt,
+        field,
+        DialogAction::PlayMacro,
+    ));
+}
+
+/// Start playing a macro, one key per step
+fn play_macro(state: &mut AppState, name: &str) {
+    if state.macro_recording.is_some() {
+        state.set_status(tr("Stop recording with Ctrl-r before playing a macro"));
+        return;
+    }
+
+    match state.macros.keys(name) {
+        Ok(keys) => {
+            info!("Playing macro {} ({} keys)", name, keys.len());
+            state.macro_playback = Some(MacroPlayback::new(name, keys));
+            state.last_macro = Some(name.to_string());
+            state.set_status(&trf("Playing macro {}, any key stops it", &[&name]));
+        }
+        Err(e) => state.set_status(&e.to_string()),
+    }
+}
+
+/// Switch between the selected response and its changes from the one before it
+fn toggle_console_diff(state: &mut AppState) {
+    if state.console_diff {
+        state.console_diff = false;
+        state.set_status(tr("Showing the full response"));
+        return;
+    }
+
+    let Some(entry) = state.selected_console_entry() else {
+        state.set_status(tr("No requests sent yet"));
+        return;
+    };
+    let Some(baseline) = state.console_baseline() else {
+        let message = trf("Send {} again to compare its responses", &[&entry.request]);
+        state.set_status(&message);
+        return;
+    };
+
+    let changed = console::compare(baseline, entry).map(|lines| {
+        lines
+            .iter()
+            .filter(|(change, _)| *change != Change::Same)
+            .count()
+    });
+    let message = match changed {
+        Some(0) => tr("The responses are the same").to_string(),
+        Some(changed) => trf("{} lines changed", &[&changed]),
+        None => tr("The responses are too long to compare").to_string(),
+    };
+    state.console_diff = true;
+    state.set_status(&message);
+}
+
+/// Ask for a request to send, starting from the selected one so it can be varied
+fn ask_console_command(state: &mut AppState) {
+    let mut field = InputField::new("console_command", Validation::Text { max_len: 500 })
+        .with_history(&state.input_history);
+    if let Some(entry) = state.selected_console_entry() {
+        field.set_value(&entry.request);
+    }
+    state.open_dialog(Dialog::input(
+        tr("Command Console"),
+        tr("Request, e.g. get_caminfo.cgi:"),
+        field,
+        DialogAction::RunCommand,
+    ));
+}
+
+/// Ask for the file to export the capability cache to or import one from
+fn ask_capability_file(state: &mut AppState, action: DialogAction) {
+    let model = state
+        .camera
+        .capabilities()
+        .lock()
+        .unwrap()
+        .capabilities()
+        .model
+        .clone();
+    let mut field = InputField::new("capability_file", Validation::Text { max_len: 500 })
+        .with_history(&state.input_history);
+    field.set_value(&capabilities::export_file_name(model.as_deref()));
+    let prompt = if matches!(action, DialogAction::ExportCapabilities) {
+        tr("Export capabilities to:")
+    } else {
+        tr("Import capabilities from:")
+    };
+    state.open_dialog(Dialog::input(
+        tr("Camera capabilities"),
+        prompt,
+        field,
+        action,
+    ));
+}
+
+/// Write what has been learned about the camera to a file others can import
+fn export_capabilities(state: &mut AppState, file: &str) {
+    let path = paths::resolve(file);
+    let result = state.camera.capabilities().lock().unwrap().export(&path);
+    match result {
+        Ok(()) => state.set_status(&trf("Capabilities exported to {}", &[&path.display()])),
+        Err(e) => state.set_status(&trf("Failed to export capabilities: {}", &[&e])),
+    }
+}
+
+/// Merge capabilities another user exported for the same model
+fn import_capabilities(state: &mut AppState, file: &str) {
+    let path = paths::resolve(file);
+    let result = state.camera.capabilities().lock().unwrap().import(&path);
+    match result {
+        Ok(0) => state.set_status(tr("Nothing new in that capability file")),
+        Ok(added) => state.set_status(&trf("Imported {} capability entries", &[&added])),
+        Err(e) => state.set_status(&trf("Failed to import capabilities: {}", &[&e])),
+    }
+}
+
+/// Save the current camera settings under the selected profile name
+fn save_profile(state: &mut AppState) -> Result<String> {
+    let name = state
+        .selected_profile()
+        .ok_or_else(|| anyhow!("No settings profiles configured"))?;
+
+    let settings = state.camera.read_profile()?;
+    state.profiles.insert(&name, settings);
+    state
+        .profiles
+        .save(&paths::resolve(&state.config.profiles.file))?;
+    Ok(name)
+}
+
+/// Re-apply the settings saved under the selected profile name
+fn apply_profile(state: &mut AppState) -> Result<(String, usize)> {
+    state.camera.ensure_writable("Changing camera settings")?;
+    let name = state
+        .selected_profile()
+        .ok_or_else(|| anyhow!("No settings profiles configured"))?;
+    let settings = state
+        .profiles
+        .get(&name)
+        .ok_or_else(|| anyhow!("Profile {} has not been saved yet", name))?;
+
+    let applied = state.camera.apply_profile(settings)?;
+    Ok((name, applied))
+}
+
+/// Take a photo and pull it straight to the host
+fn take_tethered_photo(state: &mut AppState) -> Result<()> {
+    let directory = paths::resolve_output(&state.config.tether.directory);
+    let path = state.camera.capture_to_host(&directory)?;
+    alert::notify(&state.config.alerts, AlertEvent::Capture);
+
+    if state.config.tether.refresh_list {
+        state.refresh_images()?;
+    }
+    state.set_status(&trf("Photo saved to {}", &[&path.display()]));
     Ok(())
 }
 
+/// Switch to live view and start streaming, reporting failures in the status bar
+pub fn open_live_view(state: &mut AppState) {
+    #[cfg(feature = "video")]
+    {
+        state.set_status(tr("Starting live view stream..."));
+        match start_live_view(state) {
+            Ok(_) => {
+                state.set_mode(AppMode::ViewingVideo);
+                state.set_status(tr("Live view stream started"));
+            }
+            Err(e) => {
+                state.set_status(&trf("Failed to start live view: {}", &[&e]));
+                info!("Failed to start live view: {}", e);
+                alert::notify(&state.config.alerts, AlertEvent::StreamError);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "video"))]
+    state.set_status(tr("Live view is not available in this build"));
+}
+
+/// Open live view right away when configured to run as a monitor
+pub fn auto_start_live_view(state: &mut AppState) {
+    if state.config.auto_liveview {
+        info!("Starting live view automatically");
+        open_live_view(state);
+    }
+}
+
 /// Start the live view video stream
+#[cfg(feature = "video")]
 fn start_live_view(state: &mut AppState) -> Result<()> {
     // Create the video viewer and start the live stream
     video_viewer::handlers::create_live_view(state)?;
     Ok(())
 }
 
-/// Download an image
-fn download_image(state: &mut AppState, image: &str) -> Result<()> {
+/// Download an image, returning where it was saved
+fn download_image(state: &mut AppState, image: &str) -> Result<PathBuf> {
     // Log which image is being downloaded
     info!("Downloading image: {}", image);
 
-    // Create a downloads directory if it doesn't exist
-    let download_dir = Path::new("downloads");
+    // Create the downloads directory, or the session's, if it doesn't exist
+    let download_dir = paths::output_dir(DataDir::Downloads);
     if !download_dir.exists() {
-        std::fs::create_dir_all(download_dir)?;
+        std::fs::create_dir_all(&download_dir)?;
     }
 
+    // Stop before the transfer rather than fail while writing the file
+    let space = disk::check_free_space(&download_dir, 0, &state.config.disk)?;
+
     // Set status to indicate which image is being downloaded
-    state.set_status(&format!("Downloading: {} to downloads directory...", image));
+    state.set_status(&trf("Downloading: {} to downloads directory...", &[&image]));
 
     // Create the destination path
     let destination = download_dir.join(image);
 
     // Download the image
-    match state.camera.download_image(image, &destination) {
+    let result = state.camera.download_image(image, &destination);
+    audit::record(AuditAction::Download, image, &result);
+    match result {
         Ok(_) => {
             info!("Successfully downloaded: {}", image);
-            state.set_status(&format!("Downloaded: {} to downloads/{}", image, image));
+            update_sidecar(state, image);
+            match space {
+                SpaceCheck::Low(free) => state.set_status(&trf(
+                    "Downloaded: {} to {} - only {} MB of disk space left",
+                    &[&image, &destination.display(), &free],
+                )),
+                SpaceCheck::Ok => state.set_status(&trf(
+                    "Downloaded: {} to {}",
+                    &[&image, &destination.display()],
+                )),
+            }
         }
         Err(e) => {
             info!("Download error: {}", e);
@@ -357,7 +1662,124 @@ fn download_image(state: &mut AppState, image: &str) -> Result<()> {
         }
     }
 
-    Ok(())
+    Ok(destination)
+}
+
+/// Counts reported at the end of a sync
+#[derive(Default)]
+struct SyncSummary {
+    downloaded: usize,
+    present: usize,
+    duplicates: usize,
+    failed: usize,
+}
+
+/// Download every image on the camera that isn't already in the downloads directory
+///
+/// Images are compared by content as well as by name, so a file the camera
+/// renamed or stored twice is not saved a second time.
+fn sync_images(state: &mut AppState) -> Result<SyncSummary> {
+    let download_dir = paths::output_dir(DataDir::Downloads);
+    std::fs::create_dir_all(&download_dir)?;
+    // Earlier downloads count wherever they went, in or out of a session
+    let mut catalog = Catalog::load(
+        &session::download_dirs(&state.config.session),
+        &paths::dir(DataDir::Cache).join(catalog::CATALOG_FILE),
+    );
+    let mut summary = SyncSummary::default();
+
+    let images = state.images.clone();
+    for image in images.iter().map(|image| image.trim()) {
+        // Movies are fetched when played, not with the photos
+        if list::is_movie(image) {
+            info!("Leaving movie {} out of the sync", image);
+            continue;
+        }
+        if catalog.contains_name(image) {
+            summary.present += 1;
+            continue;
+        }
+
+        let data = match state.camera.fetch_image(image) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!("Failed to sync {}: {}", image, e);
+                audit::record::<()>(AuditAction::Download, image, &Err(e));
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        let hash = catalog::content_hash(&data);
+        if let Some(existing) = catalog.find(hash) {
+            info!("Skipping {}, same content as {}", image, existing);
+            summary.duplicates += 1;
+            continue;
+        }
+
+        // Stop the whole sync before the disk fills up
+        if let Err(e) = disk::check_free_space(&download_dir, data.len() as u64, &state.config.disk)
+        {
+            let _ = catalog.save();
+            return Err(e);
+        }
+
+        let destination = download_dir.join(image);
+        let written = atomic::write_atomic(&destination, &data);
+        audit::record(AuditAction::Download, image, &written);
+        match written {
+            Ok(_) => {
+                info!("Synced {}", image);
+                update_sidecar(state, image);
+                catalog.insert(&destination, hash);
+                summary.downloaded += 1;
+            }
+            Err(e) => {
+                warn!("Failed to save {}: {}", image, e);
+                summary.failed += 1;
+            }
+        }
+    }
+
+    if let Err(e) = catalog.save() {
+        warn!("{}", e);
+    }
+    Ok(summary)
+}
+
+/// Convert synced raw files to DNG in the background, if the selected profile asks for it
+fn start_dng_conversion(state: &mut AppState) {
+    let profile = state.selected_profile();
+    if !state.config.dng.enabled_for(profile.as_deref()) || state.dng_job.is_some() {
+        return;
+    }
+
+    let mut files = Vec::new();
+    for directory in session::download_dirs(&state.config.session) {
+        match dng::unconverted_raw_files(&directory) {
+            Ok(found) => files.extend(found),
+            Err(e) => {
+                warn!("Failed to list raw files to convert: {}", e);
+                return;
+            }
+        }
+    }
+    if files.is_empty() {
+        return;
+    }
+
+    match DngConverter::find(&state.config.dng) {
+        Ok(converter) => {
+            let count = files.len();
+            state.dng_job = Some(DngJob::start(converter, files, state.config.dng.keep_raw));
+            state.open_dialog(Dialog::progress(
+                tr("DNG conversion"),
+                &trf("Converting {} raw files to DNG", &[&count]),
+                ProgressSource::DngConversion,
+            ));
+        }
+        Err(e) => state.set_status(&trf("DNG conversion skipped: {}", &[&e])),
+    }
 }
 
 /// Delete an image
@@ -366,13 +1788,15 @@ fn delete_image(state: &mut AppState, image: &str) -> Result<()> {
     info!("Attempting to delete image: {}", image);
 
     // Set status to indicate which image is being deleted
-    state.set_status(&format!("Deleting: {}...", image));
+    state.set_status(&trf("Deleting: {}...", &[&image]));
 
     // Try to delete the image
-    match state.camera.delete_image(image) {
+    let result = state.camera.delete_image(image);
+    audit::record(AuditAction::Delete, image, &result);
+    match result {
         Ok(_) => {
             info!("Delete operation completed for: {}", image);
-            state.set_status(&format!("Deletion attempt for {} completed", image));
+            state.set_status(&trf("Deletion attempt for {} completed", &[&image]));
         }
         Err(e) => {
             info!("Delete error: {}", e);
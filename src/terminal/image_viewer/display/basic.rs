@@ -4,10 +4,12 @@ use log::{info, warn};
 use std::path::Path;
 use std::process::Command;
 
+use crate::utils::i18n::{tr, trf};
+
 /// Display image using a more basic approach when sophisticated methods fail
 pub fn try_display(image_path: &Path) -> Result<bool> {
     // Try to use a more basic display method
-    println!("Attempting basic image rendering methods...");
+    println!("{}", tr("Attempting basic image rendering methods..."));
 
     #[cfg(unix)]
     {
@@ -23,6 +25,11 @@ pub fn try_display(image_path: &Path) -> Result<bool> {
         ];
 
         for (tool, args) in tools.iter() {
+            // Skip tools that aren't installed
+            if !crate::utils::exec::has_program(tool) {
+                continue;
+            }
+
             info!("Trying {} for image display", tool);
 
             let result = Command::new(tool).args(args).status();
@@ -68,8 +75,14 @@ pub fn try_display(image_path: &Path) -> Result<bool> {
 
     // If all else fails, just inform the user
     warn!("Could not display image with any available method");
-    println!("Could not display image. Your terminal may not support image display.");
-    println!("The image is located at: {}", image_path.display());
+    println!(
+        "{}",
+        tr("Could not display image. Your terminal may not support image display.")
+    );
+    println!(
+        "{}",
+        trf("The image is located at: {}", &[&image_path.display()])
+    );
 
     Ok(false)
 }
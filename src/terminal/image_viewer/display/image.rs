@@ -3,12 +3,26 @@ use anyhow::Result;
 use log::{error, info, warn};
 use std::io::Write;
 use std::path::Path;
-
-use super::{basic, iterm, kitty, sixel, viuer};
+use std::time::Duration;
+
+#[cfg(feature = "graphics")]
+use super::viuer;
+#[cfg(feature = "graphics")]
+use super::zebra;
+use super::{basic, iterm, kitty, sixel};
+use crate::terminal::image_viewer::renderer::terminal::{KeyWait, wait_for_key};
+use crate::terminal::image_viewer::renderer::utils::get_terminal_dimensions;
 use crate::terminal::image_viewer::state::{DisplayMethod, ImageViewerState};
+use crate::utils::i18n::{tr, trf};
 
 /// Display the actual image using the best available method
-pub fn display_image(viewer_state: &ImageViewerState) -> Result<()> {
+///
+/// The image stays up until a key is pressed, or until `timeout` runs out so
+/// a slideshow can move on by itself. Returns how the wait ended.
+pub fn display_image(
+    viewer_state: &ImageViewerState,
+    timeout: Option<Duration>,
+) -> Result<KeyWait> {
     info!("Displaying image: {:?}", viewer_state.image_path);
 
     // Prepare terminal for image display
@@ -44,17 +58,23 @@ pub fn display_image(viewer_state: &ImageViewerState) -> Result<()> {
 
     // Add extra newlines for clarity
     println!(
-        "\n\nViewing image: {} (Resolution: {})",
-        viewer_state.image_name,
-        viewer_state.get_resolution_name()
+        "\n\n{}",
+        trf(
+            "Viewing image: {} (Resolution: {})",
+            &[
+                &viewer_state.image_name,
+                &tr(viewer_state.get_resolution_name())
+            ],
+        )
+    );
+    println!(
+        "{}\n",
+        tr("Press any key to return to the application, or Esc to go back to the image list...")
     );
-    println!("Press any key to return to the application...\n");
     std::io::stdout().flush()?;
 
     // Calculate optimal dimensions based on terminal size
-    let term_dims = termsize::get()
-        .map(|size| (size.cols as u32, size.rows as u32))
-        .unwrap_or((80, 24));
+    let term_dims = get_terminal_dimensions();
 
     let (width, height) = viewer_state.calculate_dimensions(term_dims.0, term_dims.1);
 
@@ -66,8 +86,9 @@ pub fn display_image(viewer_state: &ImageViewerState) -> Result<()> {
         info!("Using higher resolution image data for display");
 
         // Create a temporary file for high-res image data
+        use crate::utils::paths::{self, DataDir};
         use tempfile::NamedTempFile;
-        let mut temp_file = NamedTempFile::new()?;
+        let mut temp_file = NamedTempFile::new_in(paths::dir(DataDir::Cache))?;
         temp_file.write_all(high_res_data)?;
         temp_file.flush()?;
 
@@ -94,16 +115,20 @@ pub fn display_image(viewer_state: &ImageViewerState) -> Result<()> {
     }
 
     if !display_success {
-        println!("\nFailed to display image with all available methods.");
+        println!(
+            "\n{}",
+            tr("Failed to display image with all available methods.")
+        );
     }
 
-    println!("\nPress any key to return to the application...");
+    println!(
+        "\n{}",
+        tr("Press any key to return to the application, or Esc to go back to the image list...")
+    );
     std::io::stdout().flush()?;
 
     // Wait for user input
-    let mut buffer = [0; 1];
-    let mut stdin = std::io::stdin();
-    std::io::Read::read_exact(&mut stdin, &mut buffer)?;
+    let key_wait = wait_for_key(timeout)?;
 
     // Restore terminal
     // Reset any potential escape sequences
@@ -132,7 +157,7 @@ pub fn display_image(viewer_state: &ImageViewerState) -> Result<()> {
     // Small delay to ensure terminal has processed everything
     std::thread::sleep(std::time::Duration::from_millis(50));
 
-    Ok(())
+    Ok(key_wait)
 }
 
 /// Try to display image using the best available method
@@ -144,6 +169,18 @@ pub fn try_display_image(
 ) -> Result<bool> {
     let mut display_success = false;
 
+    // Stripe clipped highlights on a copy so the original stays untouched
+    #[cfg(feature = "graphics")]
+    let zebra_file = if viewer_state.zebra {
+        Some(zebra::write_overlay(image_path, zebra::ZEBRA_THRESHOLD)?)
+    } else {
+        None
+    };
+    #[cfg(feature = "graphics")]
+    let image_path = zebra_file
+        .as_ref()
+        .map_or(image_path, |temp_file| temp_file.path());
+
     // Get terminal capabilities
     let term = std::env::var("TERM").unwrap_or_default();
     let term_program = std::env::var("TERM_PROGRAM").unwrap_or_default();
@@ -191,6 +228,7 @@ pub fn try_display_image(
             }
 
             // Try viuer as fallback
+            #[cfg(feature = "graphics")]
             if !display_success {
                 display_success = viuer::try_display(image_path, width, height, &capabilities)?;
             }
@@ -14,12 +14,14 @@ pub fn try_display(image_path: &Path) -> Result<bool> {
     info!("Attempting iTerm2 native display via imgcat");
 
     // Use imgcat if available (comes with iTerm2)
-    let imgcat_result = Command::new("imgcat").arg(image_path).status();
-
-    if let Ok(status) = imgcat_result {
-        if status.success() {
-            info!("Successfully displayed image using imgcat");
-            return Ok(true);
+    if crate::utils::exec::has_program("imgcat") {
+        let imgcat_result = Command::new("imgcat").arg(image_path).status();
+
+        if let Ok(status) = imgcat_result {
+            if status.success() {
+                info!("Successfully displayed image using imgcat");
+                return Ok(true);
+            }
         }
     }
 
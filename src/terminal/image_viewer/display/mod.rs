@@ -4,4 +4,7 @@ pub mod image;
 pub mod iterm;
 pub mod kitty;
 pub mod sixel;
+#[cfg(feature = "graphics")]
 pub mod viuer;
+#[cfg(feature = "graphics")]
+pub mod zebra;
@@ -13,9 +13,13 @@ pub fn try_display(image_path: &Path) -> Result<bool> {
         return Ok(false);
     }
 
+    // Only img2sixel can render SIXEL output here
+    if !crate::utils::exec::has_program("img2sixel") {
+        return Ok(false);
+    }
+
     info!("Attempting SIXEL display");
 
-    // Try img2sixel if available
     let img2sixel_result = Command::new("img2sixel")
         .arg("-w")
         .arg("80%")
@@ -1,4 +1,5 @@
 // src/terminal/image_viewer/display/viuer.rs
+use crate::utils::i18n::{tr, trf};
 use anyhow::Result;
 use log::{error, info, warn};
 use std::path::Path;
@@ -33,13 +34,13 @@ pub fn try_display(
     match viuer::print_from_file(image_path, &conf) {
         Ok(_) => {
             // Success with first attempt
-            println!("\nImage displayed successfully");
+            println!("\n{}", tr("Image displayed successfully"));
             Ok(true)
         }
         Err(e) => {
             // First attempt failed, try with fallback settings
             warn!("Standard display method failed: {}", e);
-            println!("Trying alternative display method...");
+            println!("{}", tr("Trying alternative display method..."));
 
             try_fallback_display(image_path, width, height)
         }
@@ -64,12 +65,12 @@ fn try_fallback_display(image_path: &Path, width: u32, height: u32) -> Result<bo
 
     match viuer::print_from_file(image_path, &fallback_conf) {
         Ok(_) => {
-            println!("\nAlternative display method succeeded");
+            println!("\n{}", tr("Alternative display method succeeded"));
             Ok(true)
         }
         Err(e) => {
             error!("Alternative display method failed: {}", e);
-            println!("Alternative display method failed: {}", e);
+            println!("{}", trf("Alternative display method failed: {}", &[&e]));
             Ok(false)
         }
     }
@@ -0,0 +1,58 @@
+// src/terminal/image_viewer/display/zebra.rs
+use crate::utils::paths::{self, DataDir};
+use anyhow::Result;
+use image::{Rgb, RgbImage};
+use log::info;
+use std::path::Path;
+use tempfile::NamedTempFile;
+
+/// Luma above which a pixel counts as clipped
+pub const ZEBRA_THRESHOLD: u8 = 250;
+
+/// Stripes per image width, kept coarse enough to survive terminal downscaling
+const STRIPES_ACROSS: u32 = 60;
+
+/// Write a copy of the image with clipped highlights striped, for display
+pub fn write_overlay(image_path: &Path, threshold: u8) -> Result<NamedTempFile> {
+    let mut rgb = image::open(image_path)?.to_rgb8();
+    let clipped = apply_zebra(&mut rgb, threshold);
+    info!(
+        "Zebra overlay marked {} of {} pixels above {}",
+        clipped,
+        rgb.width() * rgb.height(),
+        threshold
+    );
+
+    let temp_file = tempfile::Builder::new()
+        .suffix(".png")
+        .tempfile_in(paths::dir(DataDir::Cache))?;
+    rgb.save_with_format(temp_file.path(), image::ImageFormat::Png)?;
+    Ok(temp_file)
+}
+
+/// Paint diagonal black and white stripes over pixels brighter than `threshold`,
+/// returning how many pixels were marked
+fn apply_zebra(rgb: &mut RgbImage, threshold: u8) -> u32 {
+    let stripe = (rgb.width() / STRIPES_ACROSS).max(2);
+    let mut clipped = 0;
+
+    for (x, y, pixel) in rgb.enumerate_pixels_mut() {
+        if luma(pixel) <= threshold as f32 {
+            continue;
+        }
+        clipped += 1;
+        *pixel = if ((x + y) / stripe).is_multiple_of(2) {
+            Rgb([0, 0, 0])
+        } else {
+            Rgb([255, 255, 255])
+        };
+    }
+
+    clipped
+}
+
+/// Rec. 601 luma of an RGB pixel
+fn luma(pixel: &Rgb<u8>) -> f32 {
+    let [r, g, b] = pixel.0;
+    0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32
+}
@@ -0,0 +1,59 @@
+// src/terminal/image_viewer/fetch.rs
+// Higher resolution versions of the viewed image are fetched on a worker
+// thread, so the viewer stays responsive while a large image comes in over
+// Wi-Fi. The event loop picks up the result once it has arrived.
+use crate::camera::image::download::ImageDownloader;
+use crate::camera::olympus::OlympusCamera;
+use crate::terminal::image_viewer::state::ResolutionLevel;
+use anyhow::{Result, anyhow};
+use log::info;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A higher resolution version of an image being fetched in the background
+pub struct HighResFetch {
+    /// Resolution the fetched image will have
+    pub level: ResolutionLevel,
+    started: Instant,
+    receiver: Receiver<Result<Vec<u8>>>,
+}
+
+impl HighResFetch {
+    /// Start fetching the image at `image_path` on the camera at `level` on a worker thread
+    pub fn start(camera: OlympusCamera, image_path: String, level: ResolutionLevel) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        info!(
+            "Fetching {:?} resolution image in the background: {}",
+            level, image_path
+        );
+        thread::spawn(move || {
+            let result = camera.get_image_with_resolution(&image_path, level.name());
+            // The viewer may have been closed in the meantime, which drops the receiver
+            let _ = sender.send(result);
+        });
+
+        Self {
+            level,
+            started: Instant::now(),
+            receiver,
+        }
+    }
+
+    /// How long the fetch has been running
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// The fetched image once it has arrived
+    pub fn try_result(&self) -> Option<Result<Vec<u8>>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                Some(Err(anyhow!("Higher resolution fetch stopped unexpectedly")))
+            }
+        }
+    }
+}
@@ -1,8 +1,13 @@
 // src/terminal/image_viewer/handlers.rs
 use crate::camera::client::basic::ClientOperations;
+use crate::terminal::handlers;
 use crate::terminal::image_viewer::display::image;
+use crate::terminal::image_viewer::fetch::HighResFetch;
+use crate::terminal::image_viewer::renderer::terminal::KeyWait;
 use crate::terminal::image_viewer::state::ImageViewerState;
 use crate::terminal::state::{AppMode, AppState};
+use crate::utils::i18n::{tr, trf};
+use crate::utils::paths::{self, DataDir};
 use anyhow::Result;
 use crossterm::event::KeyCode;
 use log::{error, info};
@@ -18,7 +23,7 @@ pub fn create_image_viewer(
     info!("Creating image viewer for image: {}", image_name);
 
     // Create a temporary file to store the image data
-    let mut temp_file = NamedTempFile::new()?;
+    let mut temp_file = NamedTempFile::new_in(paths::dir(DataDir::Cache))?;
 
     // Write the image data to the file
     temp_file.write_all(&image_data)?;
@@ -28,7 +33,8 @@ pub fn create_image_viewer(
     let image_path = temp_file.path().to_path_buf();
 
     // Create the image viewer state
-    let viewer_state = ImageViewerState::new(image_path, image_name);
+    let mut viewer_state = ImageViewerState::new(image_path, image_name);
+    viewer_state.display_method = app_state.config.display_method;
 
     // Store the image viewer state in the app state
     app_state.image_viewer = Some(viewer_state);
@@ -40,7 +46,7 @@ pub fn create_image_viewer(
     app_state.set_mode(AppMode::ViewingImage);
 
     // Set status
-    app_state.set_status(&format!("Viewing image: {}", image_name));
+    app_state.set_status(&trf("Viewing image: {}", &[&image_name]));
 
     info!("Image viewer created successfully");
 
@@ -60,7 +66,7 @@ pub fn create_image_viewer_with_url(
     );
 
     // Create a temporary file to store the image data
-    let mut temp_file = NamedTempFile::new()?;
+    let mut temp_file = NamedTempFile::new_in(paths::dir(DataDir::Cache))?;
 
     // Write the image data to the file
     temp_file.write_all(&image_data)?;
@@ -70,10 +76,12 @@ pub fn create_image_viewer_with_url(
     let image_path = temp_file.path().to_path_buf();
 
     // Create the image viewer state with original URL for higher resolution
-    let viewer_state = ImageViewerState::with_original_url(image_path, image_name, original_url);
+    let mut viewer_state =
+        ImageViewerState::with_original_url(image_path, image_name, original_url);
+    viewer_state.display_method = app_state.config.display_method;
 
     // Get resolution info before moving
-    let resolution_name = viewer_state.get_resolution_name().to_string();
+    let resolution_name = tr(viewer_state.get_resolution_name());
 
     // Store the image viewer state in the app state
     app_state.image_viewer = Some(viewer_state);
@@ -85,33 +93,82 @@ pub fn create_image_viewer_with_url(
     app_state.set_mode(AppMode::ViewingImage);
 
     // Set status with resolution info
-    app_state.set_status(&format!(
+    app_state.set_status(&trf(
         "Viewing image: {} (Resolution: {})",
-        image_name, resolution_name
+        &[&image_name, &resolution_name],
     ));
 
+    // Bring in a sharper version while the thumbnail is already on screen
+    if app_state.config.progressive_images {
+        start_high_res_fetch(app_state);
+    }
+
     info!("Image viewer created successfully with URL for higher resolution");
 
     Ok(())
 }
 
-/// Handle input for the image viewer
-pub fn handle_image_viewer_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
-    match key {
-        KeyCode::Char('q') => return Ok(true), // Signal to quit
-        KeyCode::Esc => {
-            info!("Returning to image list");
+/// Start fetching the next resolution up in the background, returning whether it started
+pub fn start_high_res_fetch(state: &mut AppState) -> bool {
+    let camera = state.camera.clone();
+    let Some(viewer) = &mut state.image_viewer else {
+        return false;
+    };
+    if viewer.is_high_res_loading() || !viewer.can_increase_resolution() {
+        return false;
+    }
+    let Some(level) = viewer.next_resolution() else {
+        return false;
+    };
 
-            // Return to image list
-            state.set_mode(AppMode::ImageList);
+    let image_path = format!("{}/{}", camera.image_folder(), viewer.image_name.trim());
+    viewer.high_res_fetch = Some(HighResFetch::start(camera, image_path, level));
+    true
+}
 
-            // Clear image viewer state and temp file
-            state.image_viewer = None;
-            state.temp_file = None;
+/// Start loading the next resolution up without blocking the interface
+fn request_higher_resolution(state: &mut AppState) {
+    let Some(viewer) = &state.image_viewer else {
+        return;
+    };
+    let current_res_name = tr(viewer.get_resolution_name());
+
+    if viewer.is_high_res_loading() {
+        state.set_status(tr("Higher resolution image is already loading..."));
+    } else if viewer.original_url.is_none() {
+        state.set_status(tr("No URL available for higher resolution"));
+    } else if !viewer.can_increase_resolution() {
+        state.set_status(&trf(
+            "Already at {} resolution (maximum available)",
+            &[&current_res_name],
+        ));
+    } else if start_high_res_fetch(state) {
+        state.set_status(&trf(
+            "Loading higher resolution image... (Current: {})",
+            &[&current_res_name],
+        ));
+    }
+}
 
-            // Set status
-            state.set_status("Returned to image list");
-        }
+/// Close the viewer and go back to the image list
+fn return_to_image_list(state: &mut AppState) {
+    info!("Returning to image list");
+
+    // Return to image list
+    state.set_mode(AppMode::ImageList);
+
+    // Clear image viewer state and temp file
+    state.image_viewer = None;
+    state.temp_file = None;
+
+    // Set status
+    state.set_status(tr("Returned to image list"));
+}
+
+/// Handle input for the image viewer
+pub fn handle_image_viewer_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') => return_to_image_list(state),
         KeyCode::Enter => {
             // Display the full image using viuer if image viewer state exists
             if let Some(viewer_state) = &state.image_viewer {
@@ -124,12 +181,14 @@ pub fn handle_image_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
                 );
 
                 // Temporarily suspend TUI and show image
-                match image::display_image(viewer_state) {
+                match image::display_image(viewer_state, None) {
+                    // Esc while the image is up skips the viewer screen too
+                    Ok(KeyWait::Escape) => return_to_image_list(state),
                     Ok(_) => {
-                        state.set_status("Image displayed successfully");
+                        state.set_status(tr("Image displayed successfully"));
                     }
                     Err(e) => {
-                        state.set_status(&format!("Failed to display image: {}", e));
+                        state.set_status(&trf("Failed to display image: {}", &[&e]));
                         error!("Failed to display image: {}", e);
                     }
                 }
@@ -139,7 +198,7 @@ pub fn handle_image_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
             if let Some(viewer_state) = &mut state.image_viewer {
                 viewer_state.zoom_in();
                 let zoom = viewer_state.zoom_factor;
-                state.set_status(&format!("Zoom: {:.1}x", zoom));
+                state.set_status(&trf("Zoom: {}x", &[&format!("{:.1}", zoom)]));
                 info!("Zoomed in to {:.1}x", zoom);
             }
         }
@@ -147,14 +206,14 @@ pub fn handle_image_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
             if let Some(viewer_state) = &mut state.image_viewer {
                 viewer_state.zoom_out();
                 let zoom = viewer_state.zoom_factor;
-                state.set_status(&format!("Zoom: {:.1}x", zoom));
+                state.set_status(&trf("Zoom: {}x", &[&format!("{:.1}", zoom)]));
                 info!("Zoomed out to {:.1}x", zoom);
             }
         }
         KeyCode::Char('0') => {
             if let Some(viewer_state) = &mut state.image_viewer {
                 viewer_state.reset_zoom();
-                state.set_status("Zoom reset to 1.0x");
+                state.set_status(tr("Zoom reset to 1.0x"));
                 info!("Zoom reset to 1.0x");
             }
         }
@@ -164,111 +223,55 @@ pub fn handle_image_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
                 let preserve = viewer_state.preserve_aspect;
 
                 let status = if preserve {
-                    "Aspect ratio preservation enabled"
+                    tr("Aspect ratio preservation enabled")
+                } else {
+                    tr("Aspect ratio preservation disabled")
+                };
+                state.set_status(status);
+                info!("{}", status);
+            }
+        }
+        KeyCode::Char('z') => {
+            #[cfg(feature = "graphics")]
+            if let Some(viewer_state) = &mut state.image_viewer {
+                viewer_state.toggle_zebra();
+                let status = if viewer_state.zebra {
+                    tr("Zebra clipping overlay enabled")
                 } else {
-                    "Aspect ratio preservation disabled"
+                    tr("Zebra clipping overlay disabled")
                 };
                 state.set_status(status);
                 info!("{}", status);
             }
+
+            #[cfg(not(feature = "graphics"))]
+            state.set_status(tr("Zebra overlay is not available in this build"));
         }
         KeyCode::Char('d') => {
             if let Some(viewer_state) = &mut state.image_viewer {
                 viewer_state.cycle_display_method();
                 let method = viewer_state.display_method_name();
-                state.set_status(&format!("Display method: {}", method));
+                state.set_status(&trf("Display method: {}", &[&tr(method)]));
                 info!("Changed display method to: {}", method);
             }
         }
-        KeyCode::Char('r') => {
-            // Fix for borrowing issues: First check if we can improve resolution
-            // and collect the necessary information
-            let can_process_resolution = if let Some(viewer) = &state.image_viewer {
-                let can_increase = viewer.can_increase_resolution() && !viewer.is_high_res_loading;
-                let current_res_name = viewer.get_resolution_name().to_string();
-                let original_url = viewer.original_url.clone();
-
-                if can_increase {
-                    // Set loading status while we still have the immutable borrow
-                    let status_msg = format!(
-                        "Loading higher resolution image... (Current: {})",
-                        current_res_name
-                    );
-
-                    // Store resolution processing info
-                    Some((status_msg, original_url))
-                } else if viewer.is_high_res_loading {
-                    state.set_status("Higher resolution image is already loading...");
-                    None
-                } else if !viewer.can_increase_resolution() {
-                    state.set_status(&format!(
-                        "Already at {} resolution (maximum available)",
-                        current_res_name
-                    ));
-                    None
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-
-            // Now process resolution upgrade if needed
-            if let Some((status_msg, url_opt)) = can_process_resolution {
-                // Update status
-                state.set_status(&status_msg);
-
-                // Mark as loading
-                if let Some(viewer) = &mut state.image_viewer {
-                    viewer.is_high_res_loading = true;
-                }
-
-                // Process the URL if we have one
-                if let Some(url) = url_opt {
-                    // Get a clone of camera
-                    let camera_clone = state.camera.clone();
-
-                    // Attempt to get a higher resolution
-                    let higher_res_result = if url.contains("&size=") {
-                        // Just increase the size parameter
-                        let higher_res_url = url.replace("&size=1024", "&size=2048");
-                        camera_clone.get_binary(&higher_res_url)
-                    } else {
-                        // No size parameter to change, use original URL
-                        camera_clone.get_binary(&url)
-                    };
-
-                    // Update viewer state with result
-                    match higher_res_result {
-                        Ok(image_data) => {
-                            // Store data and update resolution level
-                            if let Some(viewer) = &mut state.image_viewer {
-                                viewer.high_res_data = Some(image_data);
-                                viewer.increase_resolution();
-                                let new_res = viewer.get_resolution_name();
-                                viewer.is_high_res_loading = false;
-                                state.set_status(&format!(
-                                    "Image resolution increased to {}",
-                                    new_res
-                                ));
-                            }
-                        }
-                        Err(e) => {
-                            // Reset loading flag and update status
-                            if let Some(viewer) = &mut state.image_viewer {
-                                viewer.is_high_res_loading = false;
-                            }
-                            state.set_status(&format!("Failed to load higher resolution: {}", e));
-                            error!("Failed to load higher resolution: {}", e);
-                        }
-                    }
-                } else {
-                    // No URL available
-                    if let Some(viewer) = &mut state.image_viewer {
-                        viewer.is_high_res_loading = false;
-                    }
-                    state.set_status("No URL available for higher resolution");
-                }
+        KeyCode::Char('r') => request_higher_resolution(state),
+        KeyCode::Char('o') => {
+            if let Some(viewer_state) = &state.image_viewer {
+                let image = viewer_state.image_name.clone();
+                handlers::open_in_system_viewer(state, &image);
+            }
+        }
+        KeyCode::Char('c') => {
+            if let Some(viewer_state) = &state.image_viewer {
+                let image = viewer_state.image_name.clone();
+                handlers::copy_image_location(state, &image);
+            }
+        }
+        KeyCode::Char('w') => {
+            if let Some(viewer_state) = &state.image_viewer {
+                let image = viewer_state.image_name.clone();
+                handlers::save_web_size(state, &image);
             }
         }
         _ => {}
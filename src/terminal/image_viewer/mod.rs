@@ -1,5 +1,6 @@
 // src/terminal/image_viewer/mod.rs
 pub mod display;
+pub mod fetch;
 pub mod handlers;
 pub mod renderer;
 pub mod state;
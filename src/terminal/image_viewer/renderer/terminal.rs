@@ -2,20 +2,24 @@
 use anyhow::Result;
 use crossterm::{
     cursor::{Hide, Show},
+    event::{self, Event, KeyCode, KeyEventKind},
     execute,
     style::ResetColor,
     terminal::{
         Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
-        enable_raw_mode,
+        enable_raw_mode, is_raw_mode_enabled,
     },
 };
 use log::info;
 use std::{
     io::{Write, stdout},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
+/// How often a key wait checks whether its timeout has run out
+const KEY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Clean the terminal completely - more robust version
 pub fn clean_terminal() -> Result<()> {
     // Reset any potential escape sequences
@@ -75,12 +79,62 @@ pub fn prepare_for_image_display() -> Result<()> {
     Ok(())
 }
 
-/// Wait for a key press
-pub fn wait_for_keypress() -> Result<()> {
-    // Wait for user input using a more robust approach
-    let mut buffer = [0; 1];
-    let mut stdin = std::io::stdin();
-    std::io::Read::read_exact(&mut stdin, &mut buffer)?;
+/// How a wait for a key press ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyWait {
+    /// A key other than Esc was pressed
+    Key,
+    /// Esc was pressed
+    Escape,
+    /// The timeout ran out first
+    TimedOut,
+}
 
-    Ok(())
+/// Wait for a key press, giving up after `timeout` if one is given
+///
+/// Keys are read through crossterm rather than straight from stdin, so the
+/// key isn't left half-read for the TUI's own event reader afterwards.
+pub fn wait_for_key(timeout: Option<Duration>) -> Result<KeyWait> {
+    // Single key presses only arrive without Enter in raw mode
+    let was_raw = is_raw_mode_enabled()?;
+    if !was_raw {
+        enable_raw_mode()?;
+    }
+
+    let result = read_key(timeout);
+
+    if !was_raw {
+        disable_raw_mode()?;
+    }
+    result
+}
+
+/// Read events until a key is pressed or the timeout runs out
+fn read_key(timeout: Option<Duration>) -> Result<KeyWait> {
+    // Drop keys typed before the wait started, such as a repeat of the one that opened it
+    while event::poll(Duration::ZERO)? {
+        event::read()?;
+    }
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    loop {
+        let wait = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) => remaining,
+                None => return Ok(KeyWait::TimedOut),
+            },
+            None => KEY_POLL_INTERVAL,
+        };
+
+        if event::poll(wait.min(KEY_POLL_INTERVAL))? {
+            // Resizes, mouse events and key releases don't count
+            if let Event::Key(key) = event::read()? {
+                match (key.kind, key.code) {
+                    (KeyEventKind::Release, _) => {}
+                    (_, KeyCode::Esc) => return Ok(KeyWait::Escape),
+                    _ => return Ok(KeyWait::Key),
+                }
+            }
+        }
+    }
 }
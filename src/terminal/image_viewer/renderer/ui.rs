@@ -1,5 +1,6 @@
 // src/terminal/image_viewer/renderer/ui.rs
 use crate::terminal::image_viewer::state::ImageViewerState;
+use crate::utils::i18n::{tr, trf};
 use tui::{
     Frame,
     backend::Backend,
@@ -29,35 +30,53 @@ pub fn render<B: Backend>(viewer_state: &ImageViewerState, frame: &mut Frame<B>,
 /// Render the title section with resolution information
 fn render_title<B: Backend>(viewer_state: &ImageViewerState, frame: &mut Frame<B>, area: Rect) {
     // Render title with resolution information
-    let resolution_status = if viewer_state.is_high_res_loading {
-        format!(
-            "(Resolution: {} - Loading higher...)",
-            viewer_state.get_resolution_name()
+    let resolution_status = if let Some(fetch) = &viewer_state.high_res_fetch {
+        trf(
+            "(Resolution: {} - Loading higher... {}s)",
+            &[
+                &tr(viewer_state.get_resolution_name()),
+                &fetch.elapsed().as_secs(),
+            ],
         )
     } else if viewer_state.can_increase_resolution() {
-        format!(
+        trf(
             "(Resolution: {} - Press 'r' for higher)",
-            viewer_state.get_resolution_name()
+            &[&tr(viewer_state.get_resolution_name())],
         )
     } else {
-        format!("(Resolution: {})", viewer_state.get_resolution_name())
+        trf(
+            "(Resolution: {})",
+            &[&tr(viewer_state.get_resolution_name())],
+        )
     };
 
     let title = Paragraph::new(vec![
         Spans::from(vec![Span::styled(
-            format!("Image Viewer - {}", viewer_state.image_name),
+            trf("Image Viewer - {}", &[&viewer_state.image_name]),
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
         Spans::from(vec![
             Span::styled(
-                format!("Zoom: {:.1}x ", viewer_state.zoom_factor),
+                trf("Zoom: {}x ", &[&format!("{:.1}", viewer_state.zoom_factor)]),
                 Style::default().fg(Color::Green),
             ),
+            Span::styled(
+                if viewer_state.zebra {
+                    tr("Zebra: On ")
+                } else {
+                    tr("Zebra: Off ")
+                },
+                Style::default().fg(if viewer_state.zebra {
+                    Color::Yellow
+                } else {
+                    Color::Green
+                }),
+            ),
             Span::styled(
                 resolution_status,
-                Style::default().fg(if viewer_state.is_high_res_loading {
+                Style::default().fg(if viewer_state.is_high_res_loading() {
                     Color::Yellow
                 } else {
                     Color::Green
@@ -78,9 +97,9 @@ fn render_image_area<B: Backend>(
 ) {
     // Render image placeholder
     let image_info = if viewer_state.high_res_data.is_some() {
-        "Higher resolution version loaded. Press Enter to view it."
+        tr("Higher resolution version loaded. Press Enter to view it.")
     } else {
-        "To view the image, press Enter. The image will be displayed using viuer."
+        tr("To view the image, press Enter. The image will be displayed using viuer.")
     };
 
     let image_area = Paragraph::new(vec![
@@ -88,16 +107,16 @@ fn render_image_area<B: Backend>(
             image_info,
             Style::default().fg(Color::Yellow),
         )]),
-        Spans::from(vec![Span::raw(
+        Spans::from(vec![Span::raw(tr(
             "The terminal UI will be temporarily suspended while viewing the image.",
-        )]),
-        Spans::from(vec![Span::raw(
-            "Press any key to return to the application after viewing.",
-        )]),
+        ))]),
+        Spans::from(vec![Span::raw(tr(
+            "Press any key to return to the application after viewing, or Esc to go back to the image list.",
+        ))]),
     ])
     .block(
         Block::default()
-            .title("Image Preview")
+            .title(tr("Image Preview"))
             .borders(Borders::ALL),
     )
     .wrap(Wrap { trim: true });
@@ -109,14 +128,21 @@ fn render_image_area<B: Backend>(
 fn render_controls<B: Backend>(frame: &mut Frame<B>, area: Rect) {
     // Render controls with added resolution control
     let controls = Paragraph::new(vec![Spans::from(vec![
-        Span::styled("Controls: ", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw("+/- - Zoom in/out   "),
-        Span::raw("0 - Reset zoom   "),
-        Span::raw("d - Cycle display modes   "),
-        Span::raw("r - Higher resolution   "),
-        Span::raw("a - Toggle aspect ratio   "),
-        Span::raw("Esc - Return to image list   "),
-        Span::raw("q - Quit"),
+        Span::styled(
+            tr("Controls: "),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(tr("+/- - Zoom in/out   ")),
+        Span::raw(tr("0 - Reset zoom   ")),
+        Span::raw(tr("d - Cycle display modes   ")),
+        Span::raw(tr("r - Higher resolution   ")),
+        Span::raw(tr("o - Open in system viewer   ")),
+        Span::raw(tr("c - Copy path   ")),
+        Span::raw(tr("w - Web size copy   ")),
+        Span::raw(tr("a - Toggle aspect ratio   ")),
+        Span::raw(tr("z - Zebra clipping overlay   ")),
+        Span::raw(tr("Esc/q - Return to image list   ")),
+        Span::raw(tr("Ctrl-q - Quit")),
     ])])
     .block(Block::default().borders(Borders::ALL));
 
@@ -1,4 +1,5 @@
 // src/terminal/image_viewer/renderer/utils.rs
+use crate::utils::paths::{self, DataDir};
 use anyhow::Result;
 use log::warn;
 use std::{io::Write, path::PathBuf};
@@ -6,7 +7,7 @@ use tempfile::NamedTempFile;
 
 /// Create a temporary file for high-res image data
 pub fn write_temp_image_file(image_data: &[u8]) -> Result<PathBuf> {
-    let mut temp_file = NamedTempFile::new()?;
+    let mut temp_file = NamedTempFile::new_in(paths::dir(DataDir::Cache))?;
     temp_file.write_all(image_data)?;
     temp_file.flush()?;
 
@@ -26,8 +27,17 @@ pub fn cleanup_temp_file(temp_path: &PathBuf) {
 }
 
 /// Get terminal dimensions
+#[cfg(feature = "graphics")]
 pub fn get_terminal_dimensions() -> (u32, u32) {
     termsize::get()
         .map(|size| (size.cols as u32, size.rows as u32))
         .unwrap_or((80, 24))
 }
+
+/// Get terminal dimensions
+#[cfg(not(feature = "graphics"))]
+pub fn get_terminal_dimensions() -> (u32, u32) {
+    crossterm::terminal::size()
+        .map(|(cols, rows)| (cols as u32, rows as u32))
+        .unwrap_or((80, 24))
+}
@@ -1,8 +1,11 @@
 // src/terminal/image_viewer/state.rs
+use crate::terminal::image_viewer::fetch::HighResFetch;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Available display methods for images
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum DisplayMethod {
     /// Automatically select the best available method
     Auto,
@@ -33,6 +36,17 @@ pub enum ResolutionLevel {
     High,
 }
 
+impl ResolutionLevel {
+    /// Name of the resolution level
+    pub fn name(self) -> &'static str {
+        match self {
+            ResolutionLevel::Low => "Low",
+            ResolutionLevel::Medium => "Medium",
+            ResolutionLevel::High => "High",
+        }
+    }
+}
+
 impl Default for ResolutionLevel {
     fn default() -> Self {
         ResolutionLevel::Low
@@ -59,14 +73,17 @@ pub struct ImageViewerState {
     /// Current resolution level
     pub resolution_level: ResolutionLevel,
 
-    /// Original image URL for fetching higher resolution
+    /// URL the preview was loaded from; only images from the camera have higher resolutions
     pub original_url: Option<String>,
 
-    /// Flag to indicate if higher resolution is being loaded
-    pub is_high_res_loading: bool,
-
     /// Higher resolution image data
     pub high_res_data: Option<Vec<u8>>,
+
+    /// Higher resolution version being fetched in the background
+    pub high_res_fetch: Option<HighResFetch>,
+
+    /// Whether clipped highlights are striped when the image is displayed
+    pub zebra: bool,
 }
 
 impl ImageViewerState {
@@ -80,8 +97,9 @@ impl ImageViewerState {
             display_method: DisplayMethod::default(),
             resolution_level: ResolutionLevel::default(),
             original_url: None,
-            is_high_res_loading: false,
             high_res_data: None,
+            high_res_fetch: None,
+            zebra: false,
         }
     }
 
@@ -99,8 +117,9 @@ impl ImageViewerState {
             display_method: DisplayMethod::default(),
             resolution_level: ResolutionLevel::default(),
             original_url,
-            is_high_res_loading: false,
             high_res_data: None,
+            high_res_fetch: None,
+            zebra: false,
         }
     }
 
@@ -118,8 +137,9 @@ impl ImageViewerState {
             display_method: method,
             resolution_level: ResolutionLevel::default(),
             original_url: None,
-            is_high_res_loading: false,
             high_res_data: None,
+            high_res_fetch: None,
+            zebra: false,
         }
     }
 
@@ -144,6 +164,12 @@ impl ImageViewerState {
         self.zoom_factor = 1.0;
     }
 
+    /// Toggle the zebra clipping overlay
+    #[cfg_attr(not(feature = "graphics"), allow(dead_code))]
+    pub fn toggle_zebra(&mut self) {
+        self.zebra = !self.zebra;
+    }
+
     /// Toggle aspect ratio preservation
     pub fn toggle_aspect_ratio(&mut self) {
         self.preserve_aspect = !self.preserve_aspect;
@@ -173,28 +199,23 @@ impl ImageViewerState {
 
     /// Get resolution level name as string
     pub fn get_resolution_name(&self) -> &'static str {
-        match self.resolution_level {
-            ResolutionLevel::Low => "Low",
-            ResolutionLevel::Medium => "Medium",
-            ResolutionLevel::High => "High",
-        }
+        self.resolution_level.name()
     }
 
-    /// Increase the resolution level
-    pub fn increase_resolution(&mut self) -> bool {
+    /// Resolution one step above the current one
+    pub fn next_resolution(&self) -> Option<ResolutionLevel> {
         match self.resolution_level {
-            ResolutionLevel::Low => {
-                self.resolution_level = ResolutionLevel::Medium;
-                true
-            }
-            ResolutionLevel::Medium => {
-                self.resolution_level = ResolutionLevel::High;
-                true
-            }
-            ResolutionLevel::High => false,
+            ResolutionLevel::Low => Some(ResolutionLevel::Medium),
+            ResolutionLevel::Medium => Some(ResolutionLevel::High),
+            ResolutionLevel::High => None,
         }
     }
 
+    /// Whether a higher resolution version is being fetched
+    pub fn is_high_res_loading(&self) -> bool {
+        self.high_res_fetch.is_some()
+    }
+
     /// Check if resolution can be increased
     pub fn can_increase_resolution(&self) -> bool {
         self.resolution_level != ResolutionLevel::High && self.original_url.is_some()
@@ -0,0 +1,218 @@
+// src/terminal/input.rs
+// A single-line text field for forms and dialogs. The field only edits text;
+// what the submitted value is used for is up to whoever opened it.
+use anyhow::{Result, anyhow};
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+
+/// Most values remembered for each kind of field
+const HISTORY_LIMIT: usize = 20;
+
+/// Check a value must pass before it can be submitted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validation {
+    /// A whole number between `min` and `max`, inclusive
+    Integer { min: u64, max: u64 },
+    /// Any text of at most `max_len` characters, including none
+    Text { max_len: usize },
+}
+
+impl Validation {
+    /// Check a value, explaining what is wrong with it
+    pub fn check(&self, value: &str) -> Result<()> {
+        let value = value.trim();
+        match *self {
+            Validation::Integer { min, max } => match value.parse::<u64>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(()),
+                _ => Err(anyhow!("Enter a number from {} to {}", min, max)),
+            },
+            Validation::Text { max_len } if value.chars().count() > max_len => {
+                Err(anyhow!("Enter at most {} characters", max_len))
+            }
+            Validation::Text { .. } => Ok(()),
+        }
+    }
+}
+
+/// Result of passing a key to an input field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputEvent {
+    /// The field is still being edited
+    Editing,
+    /// Enter was pressed on a valid value
+    Submitted(String),
+    /// Esc was pressed
+    Cancelled,
+}
+
+/// Text field with a cursor, validation and a history of earlier values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputField {
+    /// Key the field's history is kept under
+    name: &'static str,
+    value: String,
+    /// Byte offset of the cursor in `value`, always on a character boundary
+    cursor: usize,
+    validation: Validation,
+    /// Why the last submitted value was rejected
+    error: Option<String>,
+    /// Earlier values, oldest first
+    history: Vec<String>,
+    /// Entry of `history` being shown, if the user has stepped into it
+    history_index: Option<usize>,
+    /// Text being typed before stepping into the history
+    draft: String,
+}
+
+impl InputField {
+    /// Create an empty field
+    pub fn new(name: &'static str, validation: Validation) -> Self {
+        Self {
+            name,
+            value: String::new(),
+            cursor: 0,
+            validation,
+            error: None,
+            history: Vec::new(),
+            history_index: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Offer earlier values with Up and Down
+    pub fn with_history(mut self, history: &InputHistory) -> Self {
+        self.history = history.entries(self.name).to_vec();
+        self
+    }
+
+    /// Key the field's history is kept under
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Text before and after the cursor, for drawing the cursor between them
+    pub fn split_at_cursor(&self) -> (&str, &str) {
+        self.value.split_at(self.cursor)
+    }
+
+    /// Why the last submitted value was rejected
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Replace the text, with the cursor at the end
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.to_string();
+        self.cursor = self.value.len();
+        self.error = None;
+    }
+
+    /// Handle a key pressed while the field has focus
+    pub fn handle_key(&mut self, key: KeyCode) -> InputEvent {
+        match key {
+            KeyCode::Enter => match self.validation.check(&self.value) {
+                Ok(_) => return InputEvent::Submitted(self.value.trim().to_string()),
+                Err(e) => {
+                    self.error = Some(e.to_string());
+                    return InputEvent::Editing;
+                }
+            },
+            KeyCode::Esc => return InputEvent::Cancelled,
+            KeyCode::Char(c) => {
+                self.value.insert(self.cursor, c);
+                self.cursor += c.len_utf8();
+            }
+            KeyCode::Backspace => {
+                if let Some(c) = self.value[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                    self.value.remove(self.cursor);
+                }
+            }
+            KeyCode::Delete => {
+                if self.cursor < self.value.len() {
+                    self.value.remove(self.cursor);
+                }
+            }
+            KeyCode::Left => {
+                if let Some(c) = self.value[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                }
+            }
+            KeyCode::Right => {
+                if let Some(c) = self.value[self.cursor..].chars().next() {
+                    self.cursor += c.len_utf8();
+                }
+            }
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.value.len(),
+            KeyCode::Up => self.history_back(),
+            KeyCode::Down => self.history_forward(),
+            _ => return InputEvent::Editing,
+        }
+
+        // Any edit may have fixed the rejected value
+        self.error = None;
+        InputEvent::Editing
+    }
+
+    /// Show the previous history entry
+    fn history_back(&mut self) {
+        let index = match self.history_index {
+            Some(0) => return,
+            Some(index) => index - 1,
+            None if self.history.is_empty() => return,
+            None => {
+                self.draft = self.value.clone();
+                self.history.len() - 1
+            }
+        };
+        self.history_index = Some(index);
+        let value = self.history[index].clone();
+        self.set_value(&value);
+    }
+
+    /// Show the next history entry, or the draft after the newest one
+    fn history_forward(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+        if index + 1 < self.history.len() {
+            self.history_index = Some(index + 1);
+            let value = self.history[index + 1].clone();
+            self.set_value(&value);
+        } else {
+            self.history_index = None;
+            let draft = std::mem::take(&mut self.draft);
+            self.set_value(&draft);
+        }
+    }
+}
+
+/// Values submitted to each kind of field during this session
+#[derive(Debug, Default)]
+pub struct InputHistory {
+    entries: HashMap<&'static str, Vec<String>>,
+}
+
+impl InputHistory {
+    /// Earlier values for a field, oldest first
+    pub fn entries(&self, name: &str) -> &[String] {
+        self.entries
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Remember a submitted value, moving a repeated one to the end
+    pub fn record(&mut self, name: &'static str, value: &str) {
+        if value.is_empty() {
+            return;
+        }
+        let entries = self.entries.entry(name).or_default();
+        entries.retain(|entry| entry != value);
+        entries.push(value.to_string());
+        if entries.len() > HISTORY_LIMIT {
+            entries.remove(0);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+// src/terminal/macros.rs
+// Recorded key sequences replayed through the regular handlers. Every action
+// in the app is reached by keys, so a macro of keys can navigate, capture and
+// download like the user would, without a scripting language. Keys are stored
+// by name so the macros file can be written or touched up by hand.
+use anyhow::{Context, Result, anyhow};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::utils::atomic;
+
+/// Macro storage and playback speed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MacroConfig {
+    /// File the recorded macros are kept in
+    pub file: String,
+    /// Pause between keys on playback, so the screen and background work keep up
+    pub step_millis: u64,
+}
+
+impl Default for MacroConfig {
+    fn default() -> Self {
+        Self {
+            file: "olympus_macros.json".to_string(),
+            step_millis: 200,
+        }
+    }
+}
+
+/// Whether a key starts or stops recording a macro
+pub fn is_record(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('r')
+}
+
+/// Whether a key plays the last macro again
+pub fn is_replay(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('p')
+}
+
+/// Whether a key asks which macro to play
+pub fn is_choose(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('o')
+}
+
+/// Name a key is stored under, e.g. `Enter`, `PageDown`, `F5` or `d`
+pub fn key_name(key: KeyCode) -> Option<String> {
+    let name = match key {
+        KeyCode::Char(' ') => "Space",
+        KeyCode::Char(c) => return Some(c.to_string()),
+        KeyCode::F(number) => return Some(format!("F{}", number)),
+        KeyCode::Enter => "Enter",
+        KeyCode::Esc => "Esc",
+        KeyCode::Backspace => "Backspace",
+        KeyCode::Tab => "Tab",
+        KeyCode::BackTab => "BackTab",
+        KeyCode::Delete => "Delete",
+        KeyCode::Insert => "Insert",
+        KeyCode::Home => "Home",
+        KeyCode::End => "End",
+        KeyCode::PageUp => "PageUp",
+        KeyCode::PageDown => "PageDown",
+        KeyCode::Up => "Up",
+        KeyCode::Down => "Down",
+        KeyCode::Left => "Left",
+        KeyCode::Right => "Right",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Key stored under a name, the reverse of `key_name`
+pub fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let key = match name {
+        "Space" => KeyCode::Char(' '),
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Backspace" => KeyCode::Backspace,
+        "Tab" => KeyCode::Tab,
+        "BackTab" => KeyCode::BackTab,
+        "Delete" => KeyCode::Delete,
+        "Insert" => KeyCode::Insert,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        _ => return name.strip_prefix('F')?.parse().ok().map(KeyCode::F),
+    };
+    Some(key)
+}
+
+/// Recorded macros by name, each a list of key names
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MacroStore {
+    pub macros: BTreeMap<String, Vec<String>>,
+}
+
+impl MacroStore {
+    /// Load saved macros, starting empty if the file is missing or invalid
+    pub fn load(path: &Path) -> Self {
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let loaded = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))
+            .and_then(|text| {
+                serde_json::from_str(&text)
+                    .with_context(|| format!("Failed to parse {}", path.display()))
+            });
+
+        match loaded {
+            Ok(store) => {
+                info!("Loaded macros from {}", path.display());
+                store
+            }
+            Err(e) => {
+                warn!("Failed to load macros: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write all macros to the given file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+        atomic::write_atomic(path, text.as_bytes())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        info!("Saved macros to {}", path.display());
+        Ok(())
+    }
+
+    /// Store recorded keys under a name, replacing any macro of that name
+    pub fn insert(&mut self, name: &str, keys: &[KeyCode]) {
+        let names = keys.iter().filter_map(|key| key_name(*key)).collect();
+        self.macros.insert(name.to_string(), names);
+    }
+
+    /// Keys of the named macro
+    pub fn keys(&self, name: &str) -> Result<Vec<KeyCode>> {
+        let names = self
+            .macros
+            .get(name)
+            .ok_or_else(|| anyhow!("No macro named {}", name))?;
+        names
+            .iter()
+            .map(|key| {
+                parse_key(key).ok_or_else(|| anyhow!("Macro {} has an unknown key {}", name, key))
+            })
+            .collect()
+    }
+}
+
+/// Macro being played back, one key at a time
+#[derive(Debug, Clone)]
+pub struct MacroPlayback {
+    /// Name of the macro
+    pub name: String,
+    /// Keys still to send
+    pub keys: VecDeque<KeyCode>,
+    /// Keys in the whole macro
+    pub total: usize,
+    /// When the next key is due
+    next_step: Instant,
+}
+
+impl MacroPlayback {
+    pub fn new(name: &str, keys: Vec<KeyCode>) -> Self {
+        Self {
+            name: name.to_string(),
+            total: keys.len(),
+            keys: keys.into(),
+            next_step: Instant::now(),
+        }
+    }
+
+    /// Keys sent so far
+    pub fn played(&self) -> usize {
+        self.total - self.keys.len()
+    }
+
+    /// Next key if it is due, scheduling the one after `step` later
+    pub fn next_key(&mut self, step: Duration) -> Option<KeyCode> {
+        let now = Instant::now();
+        if now < self.next_step {
+            return None;
+        }
+        self.next_step = now + step;
+        self.keys.pop_front()
+    }
+}
@@ -0,0 +1,56 @@
+// src/terminal/menu.rs
+// The main menu is defined once here; the renderers list these entries and
+// the handlers look up the selected one, so they can't disagree on the order
+// or the number of entries.
+
+/// What choosing a main menu entry does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuAction {
+    TakePhoto,
+    ViewImages,
+    LiveView,
+    RecordVideo,
+    RefreshImages,
+    Quit,
+}
+
+/// One main menu entry
+#[derive(Debug, Clone, Copy)]
+pub struct MenuEntry {
+    /// Untranslated label shown in the menu
+    pub label: &'static str,
+    pub action: MenuAction,
+}
+
+/// Main menu entries, in display order
+pub const MAIN_MENU: [MenuEntry; 6] = [
+    MenuEntry {
+        label: "Take Photo",
+        action: MenuAction::TakePhoto,
+    },
+    MenuEntry {
+        label: "View Images",
+        action: MenuAction::ViewImages,
+    },
+    MenuEntry {
+        label: "Live View",
+        action: MenuAction::LiveView,
+    },
+    MenuEntry {
+        label: "Record Video",
+        action: MenuAction::RecordVideo,
+    },
+    MenuEntry {
+        label: "Refresh Image List",
+        action: MenuAction::RefreshImages,
+    },
+    MenuEntry {
+        label: "Quit",
+        action: MenuAction::Quit,
+    },
+];
+
+/// Action of the entry at `index`, if there is one
+pub fn action_at(index: usize) -> Option<MenuAction> {
+    MAIN_MENU.get(index).map(|entry| entry.action)
+}
@@ -1,7 +1,22 @@
 // src/terminal/mod.rs
 pub mod app;
+pub mod dialog;
+pub mod events;
 pub mod handlers;
 pub mod image_viewer;
+pub mod input;
+pub mod macros;
+pub mod menu;
+pub mod movie;
+pub mod plain;
+pub mod player_window;
+pub mod refresh;
 pub mod renderer;
+pub mod selection;
+pub mod shell;
 pub mod state;
+#[cfg(all(test, feature = "demo"))]
+mod ui_tests;
+#[cfg(feature = "video")]
 pub mod video_viewer;
+pub mod wizard;
@@ -0,0 +1,146 @@
+// src/terminal/movie.rs
+// Movies on the card can't be shown in the terminal, so they are handed to an
+// external player: either streamed straight from the camera or downloaded in
+// the background first, with a progress dialog, and played from disk.
+use crate::camera::image::download::ImageDownloader;
+use crate::camera::olympus::OlympusCamera;
+use crate::terminal::player_window::PlayerWindowConfig;
+use crate::utils::exec;
+use anyhow::{Context, Result, anyhow};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Players tried when none is configured, in order
+const DEFAULT_PLAYERS: [&str; 2] = ["mpv", "ffplay"];
+
+/// How movies are played
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoviePlayerConfig {
+    /// Player program, by name or path; mpv or ffplay if not set
+    pub player: Option<String>,
+    /// Let the player stream the movie from the camera instead of downloading it first
+    pub stream: bool,
+}
+
+/// Find the configured player, or the first default one installed
+pub fn find_player(config: &MoviePlayerConfig) -> Result<PathBuf> {
+    if let Some(player) = &config.player {
+        let path = Path::new(player);
+        if path.is_file() {
+            return Ok(path.to_path_buf());
+        }
+        return exec::find_program(player)
+            .ok_or_else(|| anyhow!("Movie player {} not found", player));
+    }
+
+    DEFAULT_PLAYERS
+        .iter()
+        .find_map(|name| exec::find_program(name))
+        .ok_or_else(|| anyhow!("No movie player found, install mpv or ffplay"))
+}
+
+/// Start the player on a file or URL without waiting for it
+pub fn launch(player: &Path, target: &OsStr, window: &PlayerWindowConfig) -> Result<()> {
+    info!("Playing {:?} with {:?}", target, player);
+    Command::new(player)
+        .args(window.args(player))
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to start {}", player.display()))?;
+    Ok(())
+}
+
+/// Snapshot of a movie download
+#[derive(Debug, Clone, Default)]
+pub struct MovieProgress {
+    /// Bytes received so far
+    pub received: u64,
+    /// Size of the movie, if the camera sent it
+    pub total: Option<u64>,
+    /// Whether the download has ended, successfully or not
+    pub finished: bool,
+    /// Why the download failed, if it did
+    pub error: Option<String>,
+}
+
+impl MovieProgress {
+    /// Share of the movie received, from 0 to 100
+    pub fn percent(&self) -> u16 {
+        self.total
+            .and_then(|total| (self.received * 100).checked_div(total))
+            .unwrap_or(0)
+            .min(100) as u16
+    }
+}
+
+/// A movie being downloaded in the background to be played once complete
+pub struct MovieFetch {
+    /// Name of the movie on the card
+    pub name: String,
+    /// Where the movie is saved
+    pub destination: PathBuf,
+    progress: Arc<Mutex<MovieProgress>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl MovieFetch {
+    /// Start downloading `name` to `destination` on a worker thread
+    pub fn start(camera: &OlympusCamera, name: &str, destination: PathBuf) -> Self {
+        let progress = Arc::new(Mutex::new(MovieProgress::default()));
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let camera = camera.clone();
+        let movie = name.to_string();
+        let path = destination.clone();
+        let thread_progress = Arc::clone(&progress);
+        let thread_cancelled = Arc::clone(&cancelled);
+        thread::spawn(move || {
+            let result = camera.download_movie(&movie, &path, &mut |received, total| {
+                if let Ok(mut progress) = thread_progress.lock() {
+                    progress.received = received;
+                    progress.total = total;
+                }
+                !thread_cancelled.load(Ordering::Relaxed)
+            });
+            if let Err(e) = &result {
+                warn!("Failed to download movie {}: {}", movie, e);
+            }
+
+            if let Ok(mut progress) = thread_progress.lock() {
+                progress.error = result.err().map(|e| e.to_string());
+                progress.finished = true;
+            }
+        });
+
+        Self {
+            name: name.to_string(),
+            destination,
+            progress,
+            cancelled,
+        }
+    }
+
+    /// Snapshot of the download progress
+    pub fn progress(&self) -> MovieProgress {
+        self.progress
+            .lock()
+            .map(|progress| progress.clone())
+            .unwrap_or_default()
+    }
+
+    /// Stop the download; the movie keeps its `.part` name until cleaned up
+    pub fn cancel(&self) {
+        info!("Cancelling download of movie {}", self.name);
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
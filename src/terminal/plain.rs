@@ -0,0 +1,727 @@
+// src/terminal/plain.rs
+// Line-oriented interface for screen readers. Commands typed at a prompt are
+// translated into the same key codes the TUI uses, so every operation goes
+// through the regular handlers and only the presentation differs.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::connection::ConnectionManager;
+use crate::camera::console;
+use crate::net::wifi::{self, WifiConfig};
+use crate::terminal::dialog::{Dialog, DialogKind};
+use crate::terminal::events::{self, AppMessage};
+use crate::terminal::handlers;
+use crate::terminal::image_viewer::display::image;
+use crate::terminal::image_viewer::renderer::utils::get_terminal_dimensions;
+use crate::terminal::menu::MAIN_MENU;
+use crate::terminal::renderer;
+use crate::terminal::state::{AppMode, AppState, PAIRING_ENTRIES};
+use crate::utils::config::Config;
+use crate::utils::i18n::{tr, trf};
+use crate::utils::paths::{self, DataDir};
+use anyhow::Result;
+use crossterm::event::KeyCode;
+use log::info;
+use std::io::{self, BufRead, Write};
+use tempfile::NamedTempFile;
+
+/// Run the application with the plain, line-oriented interface
+pub fn run(config: Config) -> Result<()> {
+    info!("Starting plain interface");
+    println!("{}", tr("Connecting to Olympus camera..."));
+
+    let mut state = match connect(&config) {
+        Some(state) => state,
+        None => return Ok(()),
+    };
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut last_status = String::new();
+
+    handlers::auto_start_live_view(&mut state);
+    describe_screen(&state);
+
+    loop {
+        print!("{}", prompt(&state));
+        io::stdout().flush()?;
+
+        // End of input behaves like quitting
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+
+        let mut quit = handle_command(&mut state, line.trim())?;
+        quit |= events::dispatch(&mut state, AppMessage::Tick)?;
+
+        // Messages and progress are read out once and dismissed, questions stay open
+        if let Some(dialog) = state
+            .dialog
+            .as_ref()
+            .filter(|dialog| matches!(dialog.kind, DialogKind::Message | DialogKind::Progress(_)))
+        {
+            println!("{}: {}", dialog.title, dialog.message);
+            state.close_dialog();
+        }
+
+        if state.status != last_status {
+            println!("{}", state.status);
+            last_status = state.status.clone();
+        }
+
+        if quit {
+            break;
+        }
+
+        describe_screen(&state);
+    }
+
+    // Leave the camera in a clean state if a stream is still running
+    handlers::shutdown(&mut state);
+
+    println!("{}", tr("Olympus Camera Control terminated successfully."));
+    Ok(())
+}
+
+/// Connect to the camera, offering to retry while offline
+fn connect(config: &Config) -> Option<AppState> {
+    let stdin = io::stdin();
+
+    loop {
+        match AppState::new(config) {
+            Ok(state) => {
+                println!(
+                    "{}",
+                    trf("Found {} images on camera", &[&state.images.len()])
+                );
+                return Some(state);
+            }
+            Err(e) => {
+                println!("{}", trf("Error connecting to camera: {}", &[&e]));
+                println!("{}", tr("Please check:"));
+                println!("{}", tr("1. Camera is powered on"));
+                println!("{}", tr("2. WiFi connection is active"));
+                println!("{}", tr("3. Camera IP address is correct"));
+                print!(
+                    "{}",
+                    tr("Type r to retry, w to join the camera's Wi-Fi or q to quit: ")
+                );
+                let _ = io::stdout().flush();
+
+                let mut line = String::new();
+                match stdin.lock().read_line(&mut line) {
+                    Ok(0) | Err(_) => return None,
+                    Ok(_) if line.trim().eq_ignore_ascii_case("r") => continue,
+                    Ok(_) if line.trim().eq_ignore_ascii_case("w") => {
+                        join_camera_wifi(&config.wifi)
+                    }
+                    Ok(_) => return None,
+                }
+            }
+        }
+    }
+}
+
+/// List the camera's Wi-Fi networks and join the one whose number is typed
+pub fn join_camera_wifi(config: &WifiConfig) {
+    println!("{}", tr("Looking for the camera's Wi-Fi network..."));
+    let networks = match wifi::scan(config) {
+        Ok(networks) if networks.is_empty() => {
+            println!("{}", tr("No camera Wi-Fi network found"));
+            return;
+        }
+        Ok(networks) => networks,
+        Err(e) => {
+            println!("{}", trf("Wi-Fi scan failed: {}", &[&e]));
+            return;
+        }
+    };
+
+    for (i, network) in networks.iter().enumerate() {
+        println!("{}. {}", i + 1, network);
+    }
+    print!("{}", tr("Type the number of the network to join: "));
+    let _ = io::stdout().flush();
+
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line).unwrap_or(0) == 0 {
+        return;
+    }
+    let Some(network) = line
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| networks.get(number.checked_sub(1)?))
+    else {
+        println!("{}", tr("No network joined"));
+        return;
+    };
+
+    // Without a configured password, ask for one unless the system remembers it
+    let mut config = config.clone();
+    if config.password.is_none() {
+        print!(
+            "{}",
+            tr("Wi-Fi password, or Enter if this computer already knows it: ")
+        );
+        let _ = io::stdout().flush();
+        let mut password = String::new();
+        let _ = io::stdin().lock().read_line(&mut password);
+        let password = password.trim();
+        config.password = (!password.is_empty()).then(|| password.to_string());
+    }
+
+    match wifi::connect(network, &config) {
+        Ok(()) => println!("{}", trf("Joined {}", &[&network.ssid])),
+        Err(e) => println!("{}", trf("Failed to join {}: {}", &[&network.ssid, &e])),
+    }
+}
+
+/// Prompt shown before reading a command
+fn prompt(state: &AppState) -> String {
+    if let Some(dialog) = &state.dialog {
+        return format!("{}> ", dialog.title);
+    }
+    let screen = match state.mode {
+        AppMode::Main => tr("Main Menu"),
+        AppMode::ImageList => tr("Image List"),
+        AppMode::ViewingImage => tr("Image Viewer"),
+        AppMode::ViewingVideo => tr("Live View"),
+        AppMode::Transcoding => tr("Transcode"),
+        AppMode::AuditLog => tr("Audit Log"),
+        AppMode::Dashboard => tr("Dashboard"),
+        AppMode::Pairing => tr("Pairing"),
+        AppMode::Settings => tr("Camera Settings"),
+        AppMode::Console => tr("Command Console"),
+        AppMode::Plugins => tr("Plugins"),
+    };
+    format!("{}> ", screen)
+}
+
+/// Print the current screen as plain lines
+fn describe_screen(state: &AppState) {
+    if let Some(dialog) = &state.dialog {
+        describe_dialog(dialog);
+        return;
+    }
+
+    if let Some(banner) = renderer::breaker_banner(state) {
+        println!("{}", banner);
+    }
+    if state.camera.is_read_only() {
+        println!(
+            "{}",
+            tr("Read-only mode: capture, deleting images and settings changes are disabled")
+        );
+    }
+
+    match state.mode {
+        AppMode::Main => {
+            println!("{}", tr("Main Menu"));
+            println!(
+                "{}",
+                renderer::connection_label(&state.camera.connection_state())
+            );
+            for (i, entry) in MAIN_MENU.iter().enumerate() {
+                match renderer::menu_badge(state, entry.action) {
+                    Some(badge) => println!("  {}. {} ({})", i + 1, tr(entry.label), badge),
+                    None => println!("  {}. {}", i + 1, tr(entry.label)),
+                }
+            }
+            println!(
+                "{}",
+                tr(
+                    "Type a number to choose, f for a focus bracket, t to start or stop a timelapse, c to start or stop the capture queue, p/s/r to select, save or restore a settings profile, n to start or end a shoot session, e to transcode recordings, m for the camera dashboard, w for pairing and the camera's access point, g for ISO, shutter, aperture, white balance and exposure compensation, x for the command console, u for plugins, i for camera request statistics, l for the audit log, a to explore the camera API, play NAME to play a recorded macro, or q to quit."
+                )
+            );
+        }
+        AppMode::ImageList => {
+            println!("{}", state.image_list_title());
+            for i in state.image_selection.page_range() {
+                let marker = if i == state.image_selection.selected() {
+                    "*"
+                } else {
+                    " "
+                };
+                let notes = state
+                    .notes
+                    .get(state.images[i].trim())
+                    .map(|notes| format!("  {}", notes.summary()))
+                    .unwrap_or_default();
+                let new = if state.is_new_image(&state.images[i]) {
+                    format!(" ({})", tr("new"))
+                } else {
+                    String::new()
+                };
+                println!("{} {}. {}{}{}", marker, i + 1, state.images[i], new, notes);
+            }
+            println!(
+                "{}",
+                tr(
+                    "Type a number to select, v to view, d to download, o to open in the system viewer, c to copy its path, w to save a web size copy, *1-*5 to rate (*0 clears), t to tag, s to sync all new images, x to delete, n/p for next/previous page, [/] for previous/next folder, r to refresh, b to go back."
+                )
+            );
+        }
+        AppMode::ViewingImage => {
+            if let Some(viewer_state) = &state.image_viewer {
+                println!(
+                    "{}",
+                    trf(
+                        "Viewing image: {} (Resolution: {})",
+                        &[
+                            &viewer_state.image_name,
+                            &tr(viewer_state.get_resolution_name())
+                        ],
+                    )
+                );
+                println!(
+                    "{}",
+                    trf("Preview file: {}", &[&viewer_state.image_path.display()])
+                );
+            }
+            println!(
+                "{}",
+                tr(
+                    "Type v to display the image, r for higher resolution, o to open in the system viewer, c to copy its path, w to save a web size copy, +/- to zoom, 0 to reset zoom, a to toggle aspect ratio, z to toggle the zebra clipping overlay, m to cycle display modes, b to go back."
+                )
+            );
+        }
+        AppMode::ViewingVideo => {
+            #[cfg(feature = "video")]
+            if let Some(viewer_state) = &state.video_viewer {
+                if !viewer_state.is_playing {
+                    println!("{}", tr("Stream stopped"));
+                } else if viewer_state.is_paused() {
+                    let clock = viewer_state
+                        .last_frame_clock()
+                        .unwrap_or_else(|| "--:--:--".to_string());
+                    println!("{}", trf("PAUSED - last frame at {} UTC", &[&clock]));
+                }
+
+                let (packets, frames, frame_size) = viewer_state.get_statistics();
+                let fps = viewer_state.get_fps_summary();
+                println!(
+                    "{}",
+                    trf(
+                        "Statistics: {} packets, {} frames, {} FPS",
+                        &[&packets, &frames, &fps.current],
+                    )
+                );
+                println!(
+                    "{}",
+                    trf(
+                        "Last frame: {} KB, received {}s ago",
+                        &[
+                            &(frame_size / 1024),
+                            &format!(
+                                "{:.1}",
+                                viewer_state.get_time_since_last_frame().as_secs_f64()
+                            ),
+                        ],
+                    )
+                );
+                println!(
+                    "{}",
+                    trf("Stream Health: {}", &[&tr(viewer_state.health().label())])
+                );
+                println!(
+                    "{}",
+                    crate::terminal::video_viewer::renderer::latency_estimate_label(
+                        viewer_state.latency_estimate().as_ref()
+                    )
+                );
+                println!(
+                    "{}",
+                    trf(
+                        "Exposure: {} EV | White balance: {}",
+                        &[
+                            &viewer_state.adjustments.ev(),
+                            &tr(viewer_state.adjustments.wb_label()),
+                        ],
+                    )
+                );
+            }
+            println!(
+                "{}",
+                tr(
+                    "Press Enter to refresh statistics. Type s to restart, p to pause/resume, x to stop/start the stream, l to relaunch a closed player, r to toggle recording, g to grab a snapshot, i to export an instant replay, t to start or stop interval stills, h to toggle the MJPEG server, o to toggle RTMP streaming, c to toggle stats CSV, + or - to adjust exposure, w to change white balance, d for diagnostics, b to go back."
+                )
+            );
+        }
+        AppMode::Transcoding => {
+            println!(
+                "{}",
+                trf("Recordings ({} total)", &[&state.recordings.len()])
+            );
+            for (i, path) in state.recordings.iter().enumerate() {
+                let marker = if path.with_extension("mp4").exists() {
+                    "*"
+                } else {
+                    " "
+                };
+                println!("{} {}. {}", marker, i + 1, path.display());
+            }
+            if let Some(job) = &state.transcode_job {
+                let progress = job.progress();
+                let done = match progress.percent() {
+                    Some(percent) => format!("{}%", percent),
+                    None => trf("{} frames", &[&progress.frames_done]),
+                };
+                println!(
+                    "{}",
+                    trf("Transcoding {}: {}", &[&job.input.display(), &done])
+                );
+            }
+            println!(
+                "{}",
+                tr(
+                    "Type a number to transcode that recording to MP4 (* means an MP4 exists), c to cancel, r to refresh, b to go back. Press Enter to refresh progress."
+                )
+            );
+        }
+        AppMode::AuditLog => {
+            println!(
+                "{}",
+                trf(
+                    "Audit log ({} operations, newest first) - Page {}/{}",
+                    &[
+                        &state.audit_entries.len(),
+                        &(state.audit_selection.page() + 1),
+                        &state.audit_selection.total_pages(),
+                    ],
+                )
+            );
+            for entry in &state.audit_entries[state.audit_selection.page_range()] {
+                println!("  {}", renderer::audit_entry_line(entry));
+            }
+            println!(
+                "{}",
+                tr("Type n/p for next/previous page, r to reload, b to go back.")
+            );
+        }
+        AppMode::Dashboard => describe_dashboard(state),
+        AppMode::Plugins => {
+            println!("{}", trf("Plugins ({} loaded)", &[&state.plugins.len()]));
+            for (i, (index, entry)) in state.plugin_entries().iter().enumerate() {
+                println!(
+                    "  {}. {}",
+                    i + 1,
+                    renderer::plugin_entry_line(state, *index, entry)
+                );
+            }
+            println!("{}", tr("Type a number to run that action, b to go back."));
+        }
+        AppMode::Pairing => {
+            if let Some(settings) = &state.pairing {
+                for (i, entry) in renderer::pairing_entries(settings).iter().enumerate() {
+                    println!("  {}. {}", i + 1, entry);
+                }
+            }
+            println!(
+                "{}",
+                tr("Type a number to change that setting, r to reload, b to go back.")
+            );
+        }
+        AppMode::Settings => {
+            for (i, entry) in state.camera_settings.iter().enumerate() {
+                let marker = if i == state.selected_index { ">" } else { " " };
+                println!("{} {}. {}", marker, i + 1, renderer::setting_entry(entry));
+            }
+            println!(
+                "{}",
+                tr(
+                    "Type a number to select a setting, + or - to change it, r to reload, b to go back."
+                )
+            );
+        }
+        AppMode::Console => {
+            if let Some(entry) = state.selected_console_entry() {
+                println!("  {}", renderer::console_entry_line(entry));
+                let baseline = state
+                    .console_diff
+                    .then(|| state.console_baseline())
+                    .flatten();
+                match baseline.map(|baseline| console::compare(baseline, entry)) {
+                    Some(Some(lines)) => {
+                        for (change, line) in lines {
+                            println!("{}", renderer::console_diff_line(change, &line));
+                        }
+                    }
+                    Some(None) => println!("{}", tr("The responses are too long to compare")),
+                    None => println!("{}", renderer::console_response(entry)),
+                }
+            }
+            println!(
+                "{}",
+                tr(
+                    "Type c to send a request, d to compare it with the last response to the same request, e/i to export/import capabilities, n/p for the next/previous one sent, b to go back."
+                )
+            );
+        }
+    }
+}
+
+/// Print every dashboard camera with its number
+#[cfg_attr(not(feature = "video"), allow(unused_variables))]
+fn describe_dashboard(state: &AppState) {
+    #[cfg(feature = "video")]
+    if let Some(dashboard) = &state.dashboard {
+        println!("{}", trf("Cameras ({} total)", &[&dashboard.len()]));
+        for (i, (camera, status)) in dashboard.statuses().iter().enumerate() {
+            println!("  {}. {}", i + 1, renderer::dashboard_line(camera, status));
+        }
+    }
+    println!(
+        "{}",
+        tr("Type a number to open that camera's live view, b to go back. Press Enter to refresh.")
+    );
+}
+
+/// Print an open question as plain lines
+fn describe_dialog(dialog: &Dialog) {
+    println!("{}", dialog.title);
+    println!("{}", dialog.message);
+    match dialog.input_field() {
+        Some(field) => {
+            if let Some(error) = field.error() {
+                println!("{}", error);
+            }
+            println!("{}", tr("Type a value, or an empty line to cancel."))
+        }
+        None => println!("{}", tr("Type y to confirm or n to cancel.")),
+    }
+}
+
+/// Answer the open dialog with a typed line
+fn answer_dialog(state: &mut AppState, line: &str) -> Result<bool> {
+    // The typed line replaces the field's text, an empty one cancels
+    let key = match state.dialog.as_mut().and_then(Dialog::input_field_mut) {
+        Some(_) if line.is_empty() => KeyCode::Esc,
+        Some(field) => {
+            field.set_value(line);
+            KeyCode::Enter
+        }
+        None => match line.to_lowercase().as_str() {
+            "y" | "yes" => KeyCode::Enter,
+            "n" | "no" | "b" => KeyCode::Esc,
+            _ => {
+                println!("{}", tr("Type y to confirm or n to cancel."));
+                return Ok(false);
+            }
+        },
+    };
+    handlers::handle_input(state, key)
+}
+
+/// Handle one command line, returning true if the application should quit
+fn handle_command(state: &mut AppState, command: &str) -> Result<bool> {
+    // An open dialog gets the whole line as its answer
+    if state.dialog.is_some() {
+        return answer_dialog(state, command);
+    }
+
+    // Macro names keep their case
+    if command == "play" || command.starts_with("play ") {
+        let name = command["play".len()..].trim();
+        let name = match (name, &state.last_macro) {
+            ("", Some(last)) => last.clone(),
+            _ => name.to_string(),
+        };
+        return handlers::run_macro(state, &name).or_else(|e| {
+            println!("{}", e);
+            Ok(false)
+        });
+    }
+
+    let command = command.to_lowercase();
+
+    if command == "q" || command == "quit" {
+        return Ok(handlers::request_quit(state));
+    }
+
+    // Numbers pick menu entries or images directly
+    if let Ok(number) = command.parse::<usize>() {
+        return select_number(state, number);
+    }
+
+    // Displaying an image needs the terminal as-is, without the TUI screen switching
+    if state.mode == AppMode::ViewingImage && command == "v" {
+        show_image(state);
+        return Ok(false);
+    }
+
+    match key_for_command(state.mode, &command) {
+        Some(key) => handlers::handle_input(state, key),
+        None => {
+            // An empty line only refreshes the screen description
+            if !command.is_empty() {
+                println!("{}", trf("Unknown command: {}", &[&command]));
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// Translate a typed command into the key the TUI handlers expect
+fn key_for_command(mode: AppMode, command: &str) -> Option<KeyCode> {
+    let key = match (mode, command) {
+        (AppMode::Main, "a") => KeyCode::Char('d'),
+        (AppMode::Main, "f") => KeyCode::Char('f'),
+        (AppMode::Main, "t") => KeyCode::Char('t'),
+        (AppMode::Main, "c") => KeyCode::Char('c'),
+        (AppMode::Main, "p") => KeyCode::Char('p'),
+        (AppMode::Main, "s") => KeyCode::Char('s'),
+        (AppMode::Main, "r") => KeyCode::Char('r'),
+        (AppMode::Main, "e") => KeyCode::Char('e'),
+        (AppMode::Main, "m") => KeyCode::Char('m'),
+        (AppMode::Main, "w") => KeyCode::Char('w'),
+        (AppMode::Main, "g") => KeyCode::Char('g'),
+        (AppMode::Main, "x") => KeyCode::Char('x'),
+        (AppMode::Main, "i") => KeyCode::Char('i'),
+        (AppMode::Main, "l") => KeyCode::Char('l'),
+        (AppMode::Main, "n") => KeyCode::Char('n'),
+        (AppMode::Main, "u") => KeyCode::Char('u'),
+
+        (AppMode::ImageList, "v") => KeyCode::Enter,
+        (AppMode::ImageList, "d") => KeyCode::Char('d'),
+        (AppMode::ImageList, "o") => KeyCode::Char('o'),
+        (AppMode::ImageList, "c") => KeyCode::Char('c'),
+        (AppMode::ImageList, "w") => KeyCode::Char('w'),
+        (AppMode::ImageList, "t") => KeyCode::Char('t'),
+        (AppMode::ImageList, "*0") => KeyCode::Char('0'),
+        (AppMode::ImageList, "*1") => KeyCode::Char('1'),
+        (AppMode::ImageList, "*2") => KeyCode::Char('2'),
+        (AppMode::ImageList, "*3") => KeyCode::Char('3'),
+        (AppMode::ImageList, "*4") => KeyCode::Char('4'),
+        (AppMode::ImageList, "*5") => KeyCode::Char('5'),
+        (AppMode::ImageList, "s") => KeyCode::Char('s'),
+        (AppMode::ImageList, "x") => KeyCode::Delete,
+        (AppMode::ImageList, "n") => KeyCode::PageDown,
+        (AppMode::ImageList, "p") => KeyCode::PageUp,
+        (AppMode::ImageList, "[") => KeyCode::Char('['),
+        (AppMode::ImageList, "]") => KeyCode::Char(']'),
+        (AppMode::ImageList, "r") => KeyCode::Char('r'),
+        (AppMode::ImageList, "a") => KeyCode::Char('a'),
+
+        (AppMode::ViewingImage, "r") => KeyCode::Char('r'),
+        (AppMode::ViewingImage, "o") => KeyCode::Char('o'),
+        (AppMode::ViewingImage, "c") => KeyCode::Char('c'),
+        (AppMode::ViewingImage, "w") => KeyCode::Char('w'),
+        (AppMode::ViewingImage, "+") => KeyCode::Char('+'),
+        (AppMode::ViewingImage, "-") => KeyCode::Char('-'),
+        (AppMode::ViewingImage, "0") => KeyCode::Char('0'),
+        (AppMode::ViewingImage, "a") => KeyCode::Char('a'),
+        (AppMode::ViewingImage, "z") => KeyCode::Char('z'),
+        (AppMode::ViewingImage, "m") => KeyCode::Char('d'),
+
+        (AppMode::ViewingVideo, "s") => KeyCode::Enter,
+        (AppMode::ViewingVideo, "p") => KeyCode::Char(' '),
+        (AppMode::ViewingVideo, "x") => KeyCode::Char('x'),
+        (AppMode::ViewingVideo, "l") => KeyCode::Char('p'),
+        (AppMode::ViewingVideo, "r") => KeyCode::Char('r'),
+        (AppMode::ViewingVideo, "g") => KeyCode::Char('s'),
+        (AppMode::ViewingVideo, "h") => KeyCode::Char('h'),
+        (AppMode::ViewingVideo, "c") => KeyCode::Char('c'),
+        (AppMode::ViewingVideo, "d") => KeyCode::Char('d'),
+        (AppMode::ViewingVideo, "+") => KeyCode::Char('+'),
+        (AppMode::ViewingVideo, "-") => KeyCode::Char('-'),
+        (AppMode::ViewingVideo, "w") => KeyCode::Char('w'),
+        (AppMode::ViewingVideo, "i") => KeyCode::Char('i'),
+        (AppMode::ViewingVideo, "o") => KeyCode::Char('o'),
+        (AppMode::ViewingVideo, "t") => KeyCode::Char('t'),
+
+        (AppMode::Transcoding, "c") => KeyCode::Char('c'),
+        (AppMode::Transcoding, "r") => KeyCode::Char('r'),
+        (AppMode::AuditLog, "r") => KeyCode::Char('r'),
+        (AppMode::AuditLog, "n") => KeyCode::PageDown,
+        (AppMode::AuditLog, "p") => KeyCode::PageUp,
+        (AppMode::Pairing, "r") => KeyCode::Char('r'),
+        (AppMode::Settings, "+") => KeyCode::Right,
+        (AppMode::Settings, "-") => KeyCode::Left,
+        (AppMode::Settings, "r") => KeyCode::Char('r'),
+        (AppMode::Console, "c") => KeyCode::Enter,
+        (AppMode::Console, "d") => KeyCode::Char('d'),
+        (AppMode::Console, "e") => KeyCode::Char('e'),
+        (AppMode::Console, "i") => KeyCode::Char('i'),
+        (AppMode::Console, "n") => KeyCode::Down,
+        (AppMode::Console, "p") => KeyCode::Up,
+
+        (_, "b") | (_, "back") => KeyCode::Esc,
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Select a main menu entry or an image by its 1-based number, returning true to quit
+fn select_number(state: &mut AppState, number: usize) -> Result<bool> {
+    match state.mode {
+        AppMode::Main if (1..=MAIN_MENU.len()).contains(&number) => {
+            state.selected_index = number - 1;
+            return handlers::handle_input(state, KeyCode::Enter);
+        }
+        AppMode::ImageList if (1..=state.images.len()).contains(&number) => {
+            state.image_selection.select(number - 1);
+            println!("{}", trf("Selected: {}", &[&state.images[number - 1]]));
+        }
+        AppMode::Transcoding if (1..=state.recordings.len()).contains(&number) => {
+            state.recording_selection.select(number - 1);
+            return handlers::handle_input(state, KeyCode::Enter);
+        }
+        AppMode::Dashboard if (1..=state.dashboard_selection.len()).contains(&number) => {
+            state.dashboard_selection.select(number - 1);
+            return handlers::handle_input(state, KeyCode::Enter);
+        }
+        AppMode::Plugins if (1..=state.plugin_selection.len()).contains(&number) => {
+            state.plugin_selection.select(number - 1);
+            return handlers::handle_input(state, KeyCode::Enter);
+        }
+        AppMode::Pairing if (1..=PAIRING_ENTRIES).contains(&number) => {
+            state.selected_index = number - 1;
+            return handlers::handle_input(state, KeyCode::Enter);
+        }
+        AppMode::Settings if (1..=state.camera_settings.len()).contains(&number) => {
+            state.selected_index = number - 1;
+            println!(
+                "{}",
+                trf(
+                    "Selected: {}",
+                    &[&renderer::setting_entry(&state.camera_settings[number - 1])]
+                )
+            );
+        }
+        _ => println!("{}", trf("No entry numbered {}", &[&number])),
+    }
+
+    Ok(false)
+}
+
+/// Display the current image directly on the terminal
+fn show_image(state: &mut AppState) {
+    let Some(viewer_state) = &state.image_viewer else {
+        return;
+    };
+
+    let term_dims = get_terminal_dimensions();
+    let (width, height) = viewer_state.calculate_dimensions(term_dims.0, term_dims.1);
+
+    let result = match &viewer_state.high_res_data {
+        // Higher resolution data only lives in memory, so write it out first
+        Some(high_res_data) => NamedTempFile::new_in(paths::dir(DataDir::Cache))
+            .and_then(|mut temp_file| {
+                temp_file.write_all(high_res_data)?;
+                temp_file.flush()?;
+                Ok(temp_file)
+            })
+            .map_err(anyhow::Error::from)
+            .and_then(|temp_file| {
+                image::try_display_image(viewer_state, temp_file.path(), width, height)
+            }),
+        None => image::try_display_image(viewer_state, &viewer_state.image_path, width, height),
+    };
+
+    match result {
+        Ok(true) => state.set_status(tr("Image displayed successfully")),
+        Ok(false) => state.set_status(tr("Failed to display image with all available methods.")),
+        Err(e) => state.set_status(&trf("Failed to display image: {}", &[&e])),
+    }
+}
@@ -0,0 +1,136 @@
+// src/terminal/player_window.rs
+// Where external players open their window. Each player spells size,
+// position and fullscreen differently, so the configured window is turned
+// into flags for MPlayer, FFplay or mpv, the players the app launches.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Size and position of the external player window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerWindowConfig {
+    /// Window width in pixels
+    pub width: u32,
+    /// Window height in pixels
+    pub height: u32,
+    /// Left edge of the window on the desktop, or wherever the window manager puts it
+    pub x: Option<i32>,
+    /// Top edge of the window on the desktop
+    pub y: Option<i32>,
+    /// Open the window fullscreen
+    pub fullscreen: bool,
+    /// Monitor to open on, counted from 0; MPlayer and mpv only
+    pub screen: Option<u32>,
+}
+
+impl Default for PlayerWindowConfig {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 600,
+            x: None,
+            y: None,
+            fullscreen: false,
+            screen: None,
+        }
+    }
+}
+
+impl PlayerWindowConfig {
+    /// The same window stood on its side, for video turned a quarter
+    #[cfg(feature = "player")]
+    pub fn turned(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            ..self.clone()
+        }
+    }
+
+    /// Flags placing the window for `player`, by program name or path
+    ///
+    /// Players other than MPlayer, FFplay and mpv get no flags.
+    pub fn args(&self, player: &Path) -> Vec<String> {
+        let name = player
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match name.as_str() {
+            "mplayer" => self.mplayer_args(),
+            "ffplay" => self.ffplay_args(),
+            "mpv" => self.mpv_args(),
+            _ => {
+                info!("No window options known for {}", player.display());
+                Vec::new()
+            }
+        }
+    }
+
+    /// Position as `+x+y`, with a missing side at 0
+    fn offset(&self) -> Option<String> {
+        if self.x.is_none() && self.y.is_none() {
+            return None;
+        }
+        Some(format!(
+            "{:+}{:+}",
+            self.x.unwrap_or(0),
+            self.y.unwrap_or(0)
+        ))
+    }
+
+    fn mplayer_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "-x".to_string(),
+            self.width.to_string(),
+            "-y".to_string(),
+            self.height.to_string(),
+        ];
+        if let Some(offset) = self.offset() {
+            args.extend(["-geometry".to_string(), offset]);
+        }
+        if let Some(screen) = self.screen {
+            args.extend(["-xineramascreen".to_string(), screen.to_string()]);
+        }
+        if self.fullscreen {
+            args.push("-fs".to_string());
+        }
+        args
+    }
+
+    fn ffplay_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "-x".to_string(),
+            self.width.to_string(),
+            "-y".to_string(),
+            self.height.to_string(),
+        ];
+        if let Some(x) = self.x {
+            args.extend(["-left".to_string(), x.to_string()]);
+        }
+        if let Some(y) = self.y {
+            args.extend(["-top".to_string(), y.to_string()]);
+        }
+        if self.fullscreen {
+            args.push("-fs".to_string());
+        }
+        args
+    }
+
+    fn mpv_args(&self) -> Vec<String> {
+        let mut args = vec![format!(
+            "--geometry={}x{}{}",
+            self.width,
+            self.height,
+            self.offset().unwrap_or_default()
+        )];
+        if let Some(screen) = self.screen {
+            args.push(format!("--screen={}", screen));
+            args.push(format!("--fs-screen={}", screen));
+        }
+        if self.fullscreen {
+            args.push("--fs".to_string());
+        }
+        args
+    }
+}
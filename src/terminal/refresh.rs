@@ -0,0 +1,78 @@
+// src/terminal/refresh.rs
+// Periodic refresh of the image list while it is on screen, so photos taken
+// with the camera's own shutter button show up without pressing `r`. The
+// listing is fetched on a worker thread and applied by the event loop.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::image::list::ImageLister;
+use crate::camera::olympus::OlympusCamera;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Folder listed, hash of the listing response and the image names in it
+pub type Listing = (String, u64, Vec<String>);
+
+/// Automatic image list refresh settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListRefreshConfig {
+    /// Refresh the image list on a timer while it is shown
+    pub enabled: bool,
+    /// Seconds between refreshes
+    pub interval_secs: u64,
+    /// Seconds newly found images stay highlighted
+    pub highlight_secs: u64,
+}
+
+impl Default for ListRefreshConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: 15,
+            highlight_secs: 120,
+        }
+    }
+}
+
+/// Timer and in-flight request of the automatic list refresh
+#[derive(Default)]
+pub struct ListRefresh {
+    last: Option<Instant>,
+    receiver: Option<Receiver<Result<Listing>>>,
+}
+
+impl ListRefresh {
+    /// Start listing the open folder in the background if a refresh is due
+    pub fn start_if_due(&mut self, camera: &OlympusCamera, interval: Duration) {
+        let due = self.last.is_none_or(|last| last.elapsed() >= interval);
+        if !due || self.receiver.is_some() || camera.camera_unresponsive() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let camera = camera.clone();
+        thread::spawn(move || {
+            let folder = camera.image_folder();
+            let listing = camera
+                .list_folder(&folder)
+                .map(|(hash, images)| (folder, hash, images));
+            // The list may have been left in the meantime, which drops the receiver
+            let _ = sender.send(listing);
+        });
+        self.receiver = Some(receiver);
+        self.last = Some(Instant::now());
+    }
+
+    /// The listing once it has arrived
+    pub fn try_result(&mut self) -> Option<Result<Listing>> {
+        let result = match self.receiver.as_ref()?.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err(anyhow!("Image list refresh stopped")),
+        };
+        self.receiver = None;
+        Some(result)
+    }
+}
@@ -1,26 +1,112 @@
 // src/terminal/renderer.rs
-use crate::terminal::state::{AppMode, AppState};
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::client::breaker::BreakerState;
+use crate::camera::connection::{ConnectionManager, ConnectionState};
+use crate::camera::console::{self, ConsoleEntry};
+use crate::camera::pairing::ConnectSettings;
+use crate::camera::settings::SettingState;
+use crate::net::latency::Latency;
+use crate::plugins::PluginEntry;
+use crate::terminal::dialog::{Dialog, DialogKind, ProgressSource};
+use crate::terminal::image_viewer;
+use crate::terminal::input::InputField;
+use crate::terminal::menu::{MAIN_MENU, MenuAction};
+use crate::terminal::state::{AppMode, AppState, PAIRING_ENTRIES};
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::dashboard::{CameraStatus, DashboardCamera};
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::stats::StreamHealth;
+use crate::utils::audit::AuditEntry;
+use crate::utils::diff::Change;
+use crate::utils::i18n::{tr, trf};
+use crate::utils::session;
 use tui::{
     Frame,
     backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Span, Spans},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    text::{Span, Spans, Text},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Wrap},
 };
 
+/// Narrowest terminal the screens can be laid out in
+pub const MIN_WIDTH: u16 = 60;
+
+/// Shortest terminal the screens can be laid out in
+pub const MIN_HEIGHT: u16 = 20;
+
+/// Whether `area` is below the minimum size
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+/// Render a placeholder asking for a larger terminal
+pub fn render_too_small<B: Backend>(frame: &mut Frame<B>, area: Rect) {
+    let text = vec![
+        Spans::from(Span::styled(
+            tr("Terminal too small"),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Spans::from(Span::raw(trf(
+            "{}x{}, need at least {}x{}",
+            &[&area.width, &area.height, &MIN_WIDTH, &MIN_HEIGHT],
+        ))),
+    ];
+
+    // Vertically centered, in whatever space there is
+    let top = area.height.saturating_sub(text.len() as u16) / 2;
+    let text_area = Rect::new(
+        area.x,
+        area.y + top,
+        area.width,
+        area.height.saturating_sub(top),
+    );
+    let placeholder = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, text_area);
+}
+
+/// Render the screen of the current mode, with any open dialog on top
+pub fn render_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>) {
+    let size = frame.size();
+    match state.mode {
+        AppMode::ViewingImage => {
+            if let Some(viewer_state) = &state.image_viewer {
+                image_viewer::renderer::ui::render(viewer_state, frame, size);
+            }
+        }
+        #[cfg(feature = "video")]
+        AppMode::ViewingVideo => {
+            if let Some(viewer_state) = &state.video_viewer {
+                video_viewer::renderer::render(viewer_state, frame, size);
+            }
+        }
+        _ => render_app(state, frame),
+    }
+
+    // Dialogs are drawn over every screen, viewers included
+    if let Some(dialog) = &state.dialog {
+        render_dialog(state, dialog, frame, size);
+    }
+}
+
 /// Render the application interface
 pub fn render_app<B: Backend>(state: &AppState, frame: &mut Frame<B>) {
     let size = frame.size();
+    let banner = breaker_banner(state);
 
     // Split the layout into sections
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
-                Constraint::Length(3), // Title
-                Constraint::Min(5),    // Main content
-                Constraint::Length(3), // Status
+                Constraint::Length(3),                                    // Title
+                Constraint::Length(if banner.is_some() { 3 } else { 0 }), // Banner
+                Constraint::Min(5),                                       // Main content
+                Constraint::Length(3),                                    // Status
             ]
             .as_ref(),
         )
@@ -28,21 +114,230 @@ pub fn render_app<B: Backend>(state: &AppState, frame: &mut Frame<B>) {
 
     // Render different content based on mode
     render_title(state, frame, chunks[0]);
-    render_content(state, frame, chunks[1]);
-    render_status(state, frame, chunks[2]);
+    if let Some(banner) = banner {
+        render_banner(&banner, frame, chunks[1]);
+    }
+    render_content(state, frame, chunks[2]);
+    render_status(state, frame, chunks[3]);
+}
+
+/// Warning shown while the circuit breaker holds off automatic requests
+pub fn breaker_banner(state: &AppState) -> Option<String> {
+    match state.camera.breaker_state() {
+        BreakerState::Closed => None,
+        BreakerState::Open { retry_in } => Some(trf(
+            "Camera unresponsive - automatic requests paused, checking again in {}s",
+            &[&retry_in.as_secs().max(1)],
+        )),
+        BreakerState::HalfOpen => {
+            Some(tr("Camera unresponsive - checking whether it answers again").to_string())
+        }
+    }
+}
+
+/// Render the camera unresponsive banner
+fn render_banner<B: Backend>(banner: &str, frame: &mut Frame<B>, area: Rect) {
+    let paragraph = Paragraph::new(Spans::from(Span::styled(
+        banner.to_string(),
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD),
+    )))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Centered area of the given size, clamped to `area`
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Render a dialog over whatever is already drawn in `area`
+pub fn render_dialog<B: Backend>(
+    state: &AppState,
+    dialog: &Dialog,
+    frame: &mut Frame<B>,
+    area: Rect,
+) {
+    let color = if dialog.warning {
+        Color::Red
+    } else {
+        Color::Cyan
+    };
+
+    let mut text: Vec<Spans> = dialog
+        .message
+        .lines()
+        .map(|line| Spans::from(Span::raw(line.to_string())))
+        .collect();
+    text.push(Spans::from(Span::raw("")));
+    if let Some(field) = dialog.input_field() {
+        text.push(input_field_line(field));
+        text.push(match field.error() {
+            Some(error) => Spans::from(Span::styled(
+                error.to_string(),
+                Style::default().fg(Color::Red),
+            )),
+            None => Spans::from(Span::raw("")),
+        });
+    }
+    text.push(Spans::from(Span::styled(
+        tr(dialog.hint()),
+        Style::default().fg(Color::Yellow),
+    )));
+
+    // Room for the text, the borders and a progress bar
+    let gauge_height = match dialog.kind {
+        DialogKind::Progress(_) => 3,
+        _ => 0,
+    };
+    let dialog_area = centered_rect(60, text.len() as u16 + 2 + gauge_height, area);
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            dialog.title.clone(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color));
+    let inner = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(gauge_height)].as_ref())
+        .split(inner);
+    let paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, chunks[0]);
+
+    if let DialogKind::Progress(source) = dialog.kind {
+        let (label, percent) = dialog_progress(state, source);
+        let gauge = Gauge::default()
+            .block(Block::default().borders(Borders::ALL))
+            .gauge_style(Style::default().fg(Color::Green))
+            .label(label)
+            .percent(percent);
+        frame.render_widget(gauge, chunks[1]);
+    }
+}
+
+/// Text of an input field with the character under the cursor highlighted
+fn input_field_line(field: &InputField) -> Spans<'static> {
+    let (before, after) = field.split_at_cursor();
+    let mut rest = after.chars();
+    let under_cursor = rest
+        .next()
+        .map(String::from)
+        .unwrap_or_else(|| " ".to_string());
+    let text_style = Style::default().add_modifier(Modifier::BOLD);
+
+    Spans::from(vec![
+        Span::styled(format!("> {}", before), text_style),
+        Span::styled(under_cursor, text_style.add_modifier(Modifier::REVERSED)),
+        Span::styled(rest.as_str().to_string(), text_style),
+    ])
+}
+
+/// Label and percentage for a progress dialog
+fn dialog_progress(state: &AppState, source: ProgressSource) -> (String, u16) {
+    match source {
+        ProgressSource::Timelapse => match &state.timelapse {
+            Some(timelapse) => {
+                let status = timelapse.status();
+                let percent = (status.frames_taken * 100)
+                    .checked_div(status.frames_total)
+                    .unwrap_or(0)
+                    .min(100) as u16;
+                let label = match status.starts_at {
+                    Some(starts_at) if session::unix_now() < starts_at => {
+                        trf("Starts at {} UTC", &[&session::datetime(starts_at)])
+                    }
+                    _ => trf(
+                        "{}/{} frames",
+                        &[&status.frames_taken, &status.frames_total],
+                    ),
+                };
+                (label, percent)
+            }
+            None => (tr("Finished").to_string(), 100),
+        },
+        ProgressSource::CaptureQueue => match &state.capture_queue {
+            Some(queue) => {
+                let status = queue.status();
+                let percent = (status.shots_taken * 100)
+                    .checked_div(status.shots_total)
+                    .unwrap_or(0)
+                    .min(100) as u16;
+                let mut label = trf("{}/{} shots", &[&status.shots_taken, &status.shots_total]);
+                if let Some(current) = &status.current {
+                    label.push_str(&format!(" ({})", current));
+                }
+                (label, percent)
+            }
+            None => (tr("Finished").to_string(), 100),
+        },
+        ProgressSource::DngConversion => match &state.dng_job {
+            Some(job) => {
+                let progress = job.progress();
+                let percent = (progress.done() * 100)
+                    .checked_div(progress.total)
+                    .unwrap_or(0)
+                    .min(100) as u16;
+                (
+                    trf("{}/{} files", &[&progress.done(), &progress.total]),
+                    percent,
+                )
+            }
+            None => (tr("Finished").to_string(), 100),
+        },
+        ProgressSource::MovieDownload => match &state.movie_fetch {
+            Some(fetch) => {
+                let progress = fetch.progress();
+                let received = progress.received / 1_000_000;
+                let label = match progress.total {
+                    Some(total) => trf("{}/{} MB", &[&received, &(total / 1_000_000)]),
+                    None => trf("{} MB", &[&received]),
+                };
+                (label, progress.percent())
+            }
+            None => (tr("Finished").to_string(), 100),
+        },
+    }
 }
 
 /// Render the title bar
 fn render_title<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
     // Create title text
-    let title_text = match state.mode {
-        AppMode::Main => "Olympus Camera Control - Main Menu",
-        AppMode::ImageList => "Olympus Camera Control - Image List",
-        AppMode::Downloading => "Olympus Camera Control - Download Image",
-        AppMode::Deleting => "Olympus Camera Control - Delete Image",
-        AppMode::ViewingImage => "Olympus Camera Control - Image Viewer",
-        AppMode::ViewingVideo => "Olympus Camera Control - Video Viewer",
-    };
+    let mut title_text = match state.mode {
+        AppMode::Main => tr("Olympus Camera Control - Main Menu"),
+        AppMode::ImageList => tr("Olympus Camera Control - Image List"),
+        AppMode::ViewingImage => tr("Olympus Camera Control - Image Viewer"),
+        AppMode::ViewingVideo => tr("Olympus Camera Control - Video Viewer"),
+        AppMode::Transcoding => tr("Olympus Camera Control - Transcode Recordings"),
+        AppMode::AuditLog => tr("Olympus Camera Control - Audit Log"),
+        AppMode::Dashboard => tr("Olympus Camera Control - Camera Dashboard"),
+        AppMode::Pairing => tr("Olympus Camera Control - Pairing"),
+        AppMode::Settings => tr("Olympus Camera Control - Camera Settings"),
+        AppMode::Console => tr("Olympus Camera Control - Command Console"),
+        AppMode::Plugins => tr("Olympus Camera Control - Plugins"),
+    }
+    .to_string();
+    if state.camera.is_read_only() {
+        title_text.push_str(&format!(" [{}]", tr("read-only")));
+    }
 
     // Create the title paragraph
     let title = Paragraph::new(Spans::from(vec![Span::styled(
@@ -61,8 +356,16 @@ fn render_content<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect
     match state.mode {
         AppMode::Main => render_main_menu(state, frame, area),
         AppMode::ImageList => render_image_list(state, frame, area),
-        AppMode::Downloading => render_download_screen(state, frame, area),
-        AppMode::Deleting => render_delete_screen(state, frame, area),
+        AppMode::Transcoding => render_transcode_screen(state, frame, area),
+        AppMode::AuditLog => render_audit_log_screen(state, frame, area),
+        #[cfg(feature = "video")]
+        AppMode::Dashboard => render_dashboard_screen(state, frame, area),
+        #[cfg(not(feature = "video"))]
+        AppMode::Dashboard => {}
+        AppMode::Pairing => render_pairing_screen(state, frame, area),
+        AppMode::Settings => render_settings_screen(state, frame, area),
+        AppMode::Console => render_console_screen(state, frame, area),
+        AppMode::Plugins => render_plugins_screen(state, frame, area),
         // Don't render anything in viewing mode - this is handled by image_viewer
         AppMode::ViewingImage => {}
         AppMode::ViewingVideo => {}
@@ -72,17 +375,32 @@ fn render_content<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect
 /// Render the main menu
 fn render_main_menu<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
     // Create menu items
-    let menu_items = vec![
-        ListItem::new(Spans::from(Span::raw("Take Photo"))),
-        ListItem::new(Spans::from(Span::raw("View Images"))),
-        ListItem::new(Spans::from(Span::raw("Live View"))),
-        ListItem::new(Spans::from(Span::raw("Refresh Image List"))),
-        ListItem::new(Spans::from(Span::raw("Quit"))),
-    ];
+    let menu_items: Vec<ListItem> = MAIN_MENU
+        .iter()
+        .map(|entry| {
+            let mut spans = vec![Span::raw(tr(entry.label))];
+            if let Some(badge) = menu_badge(state, entry.action) {
+                spans.push(Span::styled(
+                    format!(" {}", badge),
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            ListItem::new(Spans::from(spans))
+        })
+        .collect();
 
     // Create the menu list
     let menu = List::new(menu_items)
-        .block(Block::default().title("Main Menu").borders(Borders::ALL))
+        .block(
+            Block::default()
+                .title(tr(
+                    "Main Menu (f: focus bracket, t: timelapse, c: capture queue, p/s/r: profiles, n: session, e: transcode, m: dashboard, w: pairing, g: settings, x: console, u: plugins, i: requests, l: audit log, d: explore API)",
+                ))
+                .borders(Borders::ALL),
+        )
         .highlight_style(
             Style::default()
                 .fg(Color::Yellow)
@@ -98,33 +416,56 @@ fn render_main_menu<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Re
     frame.render_stateful_widget(menu, area, &mut list_state);
 }
 
+/// Count shown next to a main menu entry, such as new photos next to View Images
+pub fn menu_badge(state: &AppState, action: MenuAction) -> Option<String> {
+    match action {
+        MenuAction::ViewImages if state.unseen_images > 0 => {
+            Some(trf("{} new", &[&state.unseen_images]))
+        }
+        MenuAction::RecordVideo => state
+            .movie_recording
+            .as_ref()
+            .map(|recording| trf("recording {}", &[&recording.length()])),
+        _ => None,
+    }
+}
+
 /// Render the image list
 fn render_image_list<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
-    // Get pagination info
-    let start_idx = state.page_start_index();
-    let end_idx = state.page_end_index();
-    let total_pages = state.total_pages();
+    let selection = &state.image_selection;
 
     // Create list items for current page
-    let items: Vec<ListItem> = state
-        .images
+    let items: Vec<ListItem> = state.images[selection.page_range()]
         .iter()
-        .skip(start_idx)
-        .take(end_idx - start_idx)
         .enumerate()
         .map(|(i, image_name)| {
-            let content = Spans::from(vec![Span::raw(format!("{}", image_name))]);
+            // Images that just appeared on the card stand out until the highlight expires
+            let new = state.is_new_image(image_name);
+            let name_style = if new {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![Span::styled(image_name.clone(), name_style)];
+            if new {
+                spans.push(Span::styled(
+                    format!(" {}", tr("NEW")),
+                    name_style.add_modifier(Modifier::BOLD),
+                ));
+            }
+            if let Some(notes) = state.notes.get(image_name.trim()) {
+                spans.push(Span::styled(
+                    format!("  {}", notes.summary()),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+            let content = Spans::from(spans);
             ListItem::new(content)
         })
         .collect();
 
     // Create image list with pagination info
-    let list_title = format!(
-        "Images ({} total) - Page {}/{}",
-        state.images.len(),
-        state.current_page_index + 1,
-        total_pages
-    );
+    let list_title = state.image_list_title();
 
     let images_list = List::new(items)
         .block(Block::default().title(list_title).borders(Borders::ALL))
@@ -135,124 +476,711 @@ fn render_image_list<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: R
         )
         .highlight_symbol(">> ");
 
-    // Calculate the adjusted index for rendering
-    let adjusted_index = state.selected_index.saturating_sub(start_idx);
-
-    // Create a ListState for the adjusted index
+    // Create a ListState for the selected item's position on the page
     let mut list_state = ListState::default();
     // Only select if there are items in the list
-    if end_idx > start_idx {
-        list_state.select(Some(adjusted_index));
+    if !selection.is_empty() {
+        list_state.select(Some(selection.offset_in_page()));
     }
 
     // Create help text
     let help_text = vec![
-        Spans::from(Span::raw("Enter - View selected image")),
-        Spans::from(Span::raw("d - Download selected image")),
-        Spans::from(Span::raw("Delete - Delete selected image")),
-        Spans::from(Span::raw("r - Refresh image list")),
-        Spans::from(Span::raw("Esc - Return to main menu")),
+        Spans::from(Span::raw(tr("Enter - View selected image"))),
+        Spans::from(Span::raw(tr(
+            "d - Download selected image, w - Web size copy",
+        ))),
+        Spans::from(Span::raw(tr("o - Open in system viewer, c - Copy path"))),
+        Spans::from(Span::raw(tr("1-5 - Rate, 0 - Clear rating, t - Tags"))),
+        Spans::from(Span::raw(tr("s - Sync all new images to downloads"))),
+        Spans::from(Span::raw(tr("Delete - Delete selected image"))),
+        Spans::from(Span::raw(tr("g - Go to image number"))),
+        Spans::from(Span::raw(tr("[ / ] - Previous/next folder"))),
+        Spans::from(Span::raw(tr("r - Refresh image list"))),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
     ];
 
     // Split area for list and help
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(5), Constraint::Length(5)].as_ref())
+        .constraints([Constraint::Min(5), Constraint::Length(12)].as_ref())
         .split(area);
 
     // Render the image list
     frame.render_stateful_widget(images_list, chunks[0], &mut list_state);
 
     // Render help
-    let help =
-        Paragraph::new(help_text).block(Block::default().title("Controls").borders(Borders::ALL));
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
     frame.render_widget(help, chunks[1]);
 }
 
-/// Render the download confirmation screen
-fn render_download_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
-    // Get the selected image
-    let image = match state.selected_image() {
-        Some(img) => img,
-        None => "No image selected",
+/// Render the list of recordings and the progress of a running transcode
+fn render_transcode_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let selection = &state.recording_selection;
+    let items: Vec<ListItem> = state.recordings[selection.page_range()]
+        .iter()
+        .map(|path| {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let size = std::fs::metadata(path)
+                .map(|metadata| metadata.len() as f64 / (1024.0 * 1024.0))
+                .unwrap_or_default();
+            let mut label = format!("{} ({:.1} MB)", name, size);
+            if path.with_extension("mp4").exists() {
+                label.push_str(&format!(" - {}", tr("MP4 ready")));
+            }
+            ListItem::new(Spans::from(Span::raw(label)))
+        })
+        .collect();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(5),
+                Constraint::Length(3),
+                Constraint::Length(6),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    let list_title = trf(
+        "Recordings ({} total) - Page {}/{}",
+        &[
+            &state.recordings.len(),
+            &(selection.page() + 1),
+            &selection.total_pages(),
+        ],
+    );
+    let recordings = List::new(items)
+        .block(Block::default().title(list_title).borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    if !selection.is_empty() {
+        list_state.select(Some(selection.offset_in_page()));
+    }
+    frame.render_stateful_widget(recordings, chunks[0], &mut list_state);
+
+    // Progress of the running job, or an idle bar
+    let (label, percent) = match &state.transcode_job {
+        Some(job) => {
+            let progress = job.progress();
+            let name = job
+                .input
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            match progress.percent() {
+                Some(percent) => (format!("{} - {}%", name, percent), percent),
+                None => (trf("{} - {} frames", &[&name, &progress.frames_done]), 0),
+            }
+        }
+        None => (tr("No transcode running").to_string(), 0),
     };
+    let gauge = Gauge::default()
+        .block(Block::default().title(tr("Progress")).borders(Borders::ALL))
+        .gauge_style(Style::default().fg(Color::Green))
+        .label(label)
+        .percent(percent);
+    frame.render_widget(gauge, chunks[1]);
 
-    // Create confirmation text
-    let confirmation_text = vec![
-        Spans::from(Span::styled(
-            "Download Confirmation",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Spans::from(Span::raw("")),
-        Spans::from(Span::raw(format!("Download the image: {}", image))),
-        Spans::from(Span::raw(
-            "The image will be saved to the 'downloads' directory.",
-        )),
-        Spans::from(Span::raw("")),
-        Spans::from(Span::styled(
-            "Press Enter to confirm or Esc to cancel",
-            Style::default().fg(Color::Yellow),
-        )),
+    let help_text = vec![
+        Spans::from(Span::raw(tr("Enter - Transcode selected recording to MP4"))),
+        Spans::from(Span::raw(tr("c - Cancel running transcode"))),
+        Spans::from(Span::raw(tr("r - Refresh recording list"))),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
     ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[2]);
+}
 
-    // Create confirmation dialog
-    let confirmation = Paragraph::new(confirmation_text)
-        .block(Block::default().title("Download").borders(Borders::ALL))
-        .wrap(Wrap { trim: true });
+/// One audit log entry as shown on its screen and in plain mode
+pub fn audit_entry_line(entry: &AuditEntry) -> String {
+    let outcome = match &entry.error {
+        Some(e) => trf("failed: {}", &[e]),
+        None => tr("ok").to_string(),
+    };
+    format!(
+        "{}  {:<8}  {}  {}",
+        session::datetime(entry.time),
+        tr(entry.action.label()),
+        entry.target,
+        outcome
+    )
+}
 
-    frame.render_widget(confirmation, area);
+/// Render the audit log screen
+fn render_audit_log_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let selection = &state.audit_selection;
+    let items: Vec<ListItem> = state.audit_entries[selection.page_range()]
+        .iter()
+        .map(|entry| {
+            let style = if entry.error.is_some() {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default()
+            };
+            ListItem::new(Spans::from(Span::styled(audit_entry_line(entry), style)))
+        })
+        .collect();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(4)].as_ref())
+        .split(area);
+
+    let list_title = trf(
+        "Audit log ({} operations, newest first) - Page {}/{}",
+        &[
+            &state.audit_entries.len(),
+            &(selection.page() + 1),
+            &selection.total_pages(),
+        ],
+    );
+    let entries = List::new(items)
+        .block(Block::default().title(list_title).borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    if !selection.is_empty() {
+        list_state.select(Some(selection.offset_in_page()));
+    }
+    frame.render_stateful_widget(entries, chunks[0], &mut list_state);
+
+    let help_text = vec![
+        Spans::from(Span::raw(tr("Up/Down, PgUp/PgDn - Browse, r - Reload"))),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
+    ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[1]);
 }
 
-/// Render the delete confirmation screen
-fn render_delete_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
-    // Get the selected image
-    let image = match state.selected_image() {
-        Some(img) => img,
-        None => "No image selected",
+/// One line of the plugins screen, e.g. `s  Astro stacking: Start or stop a stack`
+pub fn plugin_entry_line(state: &AppState, index: usize, entry: &PluginEntry) -> String {
+    format!(
+        "{}  {}: {}",
+        entry.key,
+        tr(state.plugins[index].name()),
+        tr(entry.label)
+    )
+}
+
+/// Render the plugins screen: every plugin's actions with the keys that run them
+fn render_plugins_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let selection = &state.plugin_selection;
+    let entries = state.plugin_entries();
+    let items: Vec<ListItem> = if entries.is_empty() {
+        vec![ListItem::new(tr(
+            "No plugins in this build; they are compiled in with cargo features such as astro",
+        ))]
+    } else {
+        entries[selection.page_range()]
+            .iter()
+            .map(|(index, entry)| ListItem::new(plugin_entry_line(state, *index, entry)))
+            .collect()
     };
 
-    // Create warning text
-    let warning_text = vec![
-        Spans::from(Span::styled(
-            "Delete Confirmation",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-        )),
-        Spans::from(Span::raw("")),
-        Spans::from(Span::raw(format!(
-            "Are you sure you want to delete: {}",
-            image
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(4)].as_ref())
+        .split(area);
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(trf("Plugins ({} loaded)", &[&state.plugins.len()]))
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    if !selection.is_empty() {
+        list_state.select(Some(selection.offset_in_page()));
+    }
+    frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let help_text = vec![
+        Spans::from(Span::raw(tr(
+            "Enter or the key shown - Run, Up/Down, PgUp/PgDn - Browse",
         ))),
-        Spans::from(Span::styled(
-            "This action cannot be undone!",
-            Style::default().fg(Color::Red),
-        )),
-        Spans::from(Span::raw("")),
-        Spans::from(Span::styled(
-            "Press Enter to confirm or Esc to cancel",
-            Style::default().fg(Color::Yellow),
-        )),
-        Spans::from(Span::raw("")),
-        Spans::from(Span::raw(
-            "Note: Some Olympus cameras do not support deleting images via WiFi.",
-        )),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
     ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[1]);
+}
 
-    // Create delete dialog
-    let warning = Paragraph::new(warning_text)
-        .block(Block::default().title("Delete").borders(Borders::ALL))
-        .wrap(Wrap { trim: true });
+/// One line of the console history, e.g. `2024-05-01 10:00:00  200  get_caminfo.cgi  42 ms`
+pub fn console_entry_line(entry: &ConsoleEntry) -> String {
+    let status = entry
+        .status
+        .map_or_else(|| tr("failed").to_string(), |status| status.to_string());
+    format!(
+        "{}  {}  {}  {} ms",
+        session::datetime(entry.time),
+        status,
+        entry.request,
+        entry.duration_ms
+    )
+}
+
+/// Response of a console command as shown, with where a binary response was saved
+pub fn console_response(entry: &ConsoleEntry) -> String {
+    match &entry.saved {
+        Some(path) => trf("{}, saved to {}", &[&entry.response, &path.display()]),
+        None if entry.response.trim().is_empty() => tr("(empty response)").to_string(),
+        None => entry.response.clone(),
+    }
+}
+
+/// One line of a console diff, marked `-` or `+` as in a unified diff
+pub fn console_diff_line(change: Change, line: &str) -> String {
+    let marker = match change {
+        Change::Same => ' ',
+        Change::Removed => '-',
+        Change::Added => '+',
+    };
+    format!("{} {}", marker, line)
+}
+
+/// Console diff with removed lines in red and added ones in green
+fn console_diff_text(earlier: &ConsoleEntry, later: &ConsoleEntry) -> Text<'static> {
+    let Some(lines) = console::compare(earlier, later) else {
+        return Text::from(tr("The responses are too long to compare"));
+    };
+    let lines: Vec<Spans> = lines
+        .into_iter()
+        .map(|(change, line)| {
+            let style = match change {
+                Change::Same => Style::default().fg(Color::DarkGray),
+                Change::Removed => Style::default().fg(Color::Red),
+                Change::Added => Style::default().fg(Color::Green),
+            };
+            Spans::from(Span::styled(console_diff_line(change, &line), style))
+        })
+        .collect();
+    Text::from(lines)
+}
+
+/// Render the command console: sent requests above the selected one's response
+fn render_console_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let selection = &state.console_selection;
+    let items: Vec<ListItem> = state.console[selection.page_range()]
+        .iter()
+        .map(|entry| {
+            let style = if entry.succeeded() {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            ListItem::new(Spans::from(Span::styled(console_entry_line(entry), style)))
+        })
+        .collect();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(selection.per_page() as u16 + 2),
+                Constraint::Min(5),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    let history = List::new(items)
+        .block(
+            Block::default()
+                .title(trf(
+                    "Requests sent ({}, newest first)",
+                    &[&state.console.len()],
+                ))
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    if !selection.is_empty() {
+        list_state.select(Some(selection.offset_in_page()));
+    }
+    frame.render_stateful_widget(history, chunks[0], &mut list_state);
+
+    let baseline = state
+        .console_diff
+        .then(|| state.console_baseline())
+        .flatten();
+    let (title, text) = match (state.selected_console_entry(), baseline) {
+        (Some(entry), Some(baseline)) => (
+            trf("Changes since {}", &[&session::datetime(baseline.time)]),
+            console_diff_text(baseline, entry),
+        ),
+        (entry, _) => (
+            tr("Response").to_string(),
+            Text::from(entry.map(console_response).unwrap_or_default()),
+        ),
+    };
+    let response = Paragraph::new(text)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(response, chunks[1]);
+
+    let help_text = vec![
+        Spans::from(Span::raw(tr(
+            "Enter - Send a request, d - Compare with the last response to it",
+        ))),
+        Spans::from(Span::raw(tr(
+            "e/i - Export/import capabilities, Up/Down, PgUp/PgDn - Browse, Esc - Back",
+        ))),
+    ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[2]);
+}
+
+/// Entries of the pairing screen, as shown on it and in plain mode
+pub fn pairing_entries(settings: &ConnectSettings) -> [String; PAIRING_ENTRIES] {
+    let network = match &settings.network {
+        Some(network) => trf(
+            "Access point: {} (password {})",
+            &[
+                &network.ssid.as_deref().unwrap_or("-"),
+                // Shown masked, so it isn't read off the screen or a plain mode log
+                &network.password.as_ref().map_or("-", |_| "********"),
+            ],
+        ),
+        None => tr("Access point: not reported by this camera").to_string(),
+    };
+    [
+        trf(
+            "Connect mode: {}",
+            &[&settings.mode.as_deref().unwrap_or("-")],
+        ),
+        network,
+    ]
+}
+
+/// Render the connect mode and access point settings
+fn render_pairing_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let entries = state
+        .pairing
+        .as_ref()
+        .map(pairing_entries)
+        .unwrap_or_default();
+    let items: Vec<ListItem> = entries
+        .into_iter()
+        .map(|entry| ListItem::new(Spans::from(Span::raw(entry))))
+        .collect();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(4)].as_ref())
+        .split(area);
+
+    let settings = List::new(items)
+        .block(
+            Block::default()
+                .title(tr("Camera connection"))
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.selected_index));
+    frame.render_stateful_widget(settings, chunks[0], &mut list_state);
+
+    let help_text = vec![
+        Spans::from(Span::raw(tr(
+            "Up/Down - Select, Enter - Change the selected setting, r - Reload",
+        ))),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
+    ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[1]);
+}
+
+/// One exposure setting as shown on its screen and in plain mode
+pub fn setting_entry(entry: &SettingState) -> String {
+    let value = entry
+        .value
+        .as_deref()
+        .map_or("-".to_string(), |value| entry.setting.display_value(value));
+    if entry.writable {
+        format!("{}: {}", tr(entry.setting.label()), value)
+    } else {
+        format!("{}: {} ({})", tr(entry.setting.label()), value, tr("fixed"))
+    }
+}
+
+/// Render the exposure settings, changed in place with Left and Right
+fn render_settings_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let items: Vec<ListItem> = state
+        .camera_settings
+        .iter()
+        .map(|entry| ListItem::new(Spans::from(Span::raw(setting_entry(entry)))))
+        .collect();
 
-    frame.render_widget(warning, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(7), Constraint::Length(4)].as_ref())
+        .split(area);
+
+    let settings = List::new(items)
+        .block(
+            Block::default()
+                .title(tr("Exposure settings"))
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(state.selected_index));
+    frame.render_stateful_widget(settings, chunks[0], &mut list_state);
+
+    let help_text = vec![
+        Spans::from(Span::raw(tr(
+            "Up/Down - Select, Left/Right - Change the selected setting, r - Reload",
+        ))),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
+    ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[1]);
+}
+
+/// One dashboard camera as shown on its screen and in plain mode
+#[cfg(feature = "video")]
+pub fn dashboard_line(camera: &DashboardCamera, status: &CameraStatus) -> String {
+    let health = match (&status.error, status.health()) {
+        (Some(error), _) => trf("Unreachable: {}", &[error]),
+        (None, Some(health)) => tr(health.label()).to_string(),
+        (None, None) => tr("Waiting for frames").to_string(),
+    };
+    let age = status.last_frame.map_or("-".to_string(), |time| {
+        format!("{:.1}s", time.elapsed().as_secs_f64())
+    });
+    trf(
+        "{} | {} fps, {}% loss | last frame {} | battery {} | {}",
+        &[
+            &format!("{:<16}", camera.name),
+            &format!("{:>2}", status.fps),
+            &format!("{:.1}", status.loss_percent),
+            &age,
+            &status.battery.as_deref().unwrap_or("-"),
+            &health,
+        ],
+    )
+}
+
+/// Color of a dashboard camera, from its reachability and stream health
+#[cfg(feature = "video")]
+fn dashboard_color(status: &CameraStatus) -> Color {
+    match (&status.error, status.health()) {
+        (Some(_), _) | (None, Some(StreamHealth::Stalled(_))) => Color::Red,
+        (None, Some(StreamHealth::Degraded(_))) | (None, Some(StreamHealth::Congested)) => {
+            Color::Yellow
+        }
+        (None, Some(StreamHealth::Good)) => Color::Green,
+        (None, None) => Color::Gray,
+    }
+}
+
+/// Render the health of every dashboard camera
+#[cfg(feature = "video")]
+fn render_dashboard_screen<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
+    let statuses = state
+        .dashboard
+        .as_ref()
+        .map(|dashboard| dashboard.statuses())
+        .unwrap_or_default();
+    let selection = &state.dashboard_selection;
+    let items: Vec<ListItem> = statuses[selection.page_range()]
+        .iter()
+        .map(|(camera, status)| {
+            ListItem::new(Spans::from(Span::styled(
+                dashboard_line(camera, status),
+                Style::default().fg(dashboard_color(status)),
+            )))
+        })
+        .collect();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(4)].as_ref())
+        .split(area);
+
+    let list_title = trf(
+        "Cameras ({} total) - Page {}/{}",
+        &[
+            &statuses.len(),
+            &(selection.page() + 1),
+            &selection.total_pages(),
+        ],
+    );
+    let cameras = List::new(items)
+        .block(Block::default().title(list_title).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    if !selection.is_empty() {
+        list_state.select(Some(selection.offset_in_page()));
+    }
+    frame.render_stateful_widget(cameras, chunks[0], &mut list_state);
+
+    let help_text = vec![
+        Spans::from(Span::raw(tr(
+            "Up/Down - Select, Enter - Open live view of the selected camera",
+        ))),
+        Spans::from(Span::raw(tr("Esc - Return to main menu"))),
+    ];
+    let help = Paragraph::new(help_text)
+        .block(Block::default().title(tr("Controls")).borders(Borders::ALL));
+    frame.render_widget(help, chunks[1]);
 }
 
 /// Render status bar
 fn render_status<B: Backend>(state: &AppState, frame: &mut Frame<B>, area: Rect) {
-    // Create status bar
+    let connection = state.camera.connection_state();
+    let connection_color = match connection {
+        ConnectionState::Connected { .. } => Color::Green,
+        ConnectionState::Connecting => Color::Yellow,
+        ConnectionState::Disconnected | ConnectionState::Error(_) => Color::Red,
+    };
+
+    // Create status bar, with the camera connection in the border title
     let status = Paragraph::new(Spans::from(vec![Span::styled(
         &state.status,
         Style::default().add_modifier(Modifier::BOLD),
     )]))
-    .block(Block::default().borders(Borders::ALL));
+    .block(
+        Block::default()
+            .title(Span::styled(
+                status_title(state, &connection),
+                Style::default().fg(connection_color),
+            ))
+            .borders(Borders::ALL),
+    );
 
     frame.render_widget(status, area);
 }
+
+/// Status bar title: connection, battery, Wi-Fi, latency, selected profile, session, timelapse and queue progress
+fn status_title(state: &AppState, connection: &ConnectionState) -> String {
+    let mut parts = vec![connection_label(connection)];
+
+    if let Some(level) = &state.battery_level {
+        parts.push(trf("Battery: {}", &[level]));
+    }
+
+    if let Some(link) = &state.link_quality {
+        let mut wifi = trf("Wi-Fi: {}", &[&link.indicator()]);
+        if link.weak {
+            wifi.push_str(&format!(" ({})", tr("weak")));
+        }
+        parts.push(wifi);
+    }
+
+    if let Some(latency) = &state.latency {
+        parts.push(latency_label(latency));
+    }
+
+    if let Some(profile) = state.selected_profile() {
+        parts.push(trf("Profile: {}", &[&profile]));
+    }
+
+    if let Some(session) = &state.session {
+        parts.push(trf("Session: {}", &[&session.name]));
+    }
+
+    if let Some(timelapse) = &state.timelapse {
+        let status = timelapse.status();
+        let mut progress = trf(
+            "Timelapse {}/{}",
+            &[&status.frames_taken, &status.frames_total],
+        );
+        if let Some(exposure) = &status.exposure {
+            progress.push_str(&format!(" ({})", exposure));
+        }
+        parts.push(progress);
+    }
+
+    if let Some(queue) = &state.capture_queue {
+        let status = queue.status();
+        parts.push(trf(
+            "Queue {}/{}",
+            &[&status.shots_taken, &status.shots_total],
+        ));
+    }
+
+    parts.extend(state.plugins.iter().filter_map(|plugin| plugin.activity()));
+
+    if let Some(keys) = &state.macro_recording {
+        parts.push(trf("Recording macro: {} keys", &[&keys.len()]));
+    }
+    if let Some(playback) = &state.macro_playback {
+        parts.push(trf(
+            "Macro {} {}/{}",
+            &[&playback.name, &playback.played(), &playback.total],
+        ));
+    }
+
+    parts.join(" | ")
+}
+
+/// Describe the last round trip to the camera, e.g. `RTT 42 ms`
+pub fn latency_label(latency: &Latency) -> String {
+    match latency.last {
+        Some(rtt) => trf("RTT {} ms", &[&rtt.as_millis()]),
+        None => tr("RTT timeout").to_string(),
+    }
+}
+
+/// Describe the camera connection for display
+pub fn connection_label(connection: &ConnectionState) -> String {
+    match connection {
+        ConnectionState::Disconnected => tr("Camera: disconnected").to_string(),
+        ConnectionState::Connecting => tr("Camera: connecting...").to_string(),
+        ConnectionState::Connected { mode } => {
+            trf("Camera: connected ({} mode)", &[&mode.as_param()])
+        }
+        ConnectionState::Error(message) => trf("Camera: error - {}", &[message]),
+    }
+}
@@ -0,0 +1,374 @@
+// src/terminal/selection.rs
+use std::ops::Range;
+
+/// Selected item of a list that is shown one page at a time
+///
+/// The page always follows the selection, so the selected item is on the
+/// current page whatever moves it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PagedSelection {
+    selected: usize,
+    len: usize,
+    per_page: usize,
+}
+
+impl PagedSelection {
+    /// Create a selection over an empty list
+    pub fn new(per_page: usize) -> Self {
+        Self {
+            selected: 0,
+            len: 0,
+            per_page: per_page.max(1),
+        }
+    }
+
+    /// Index of the selected item
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Number of items in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the list has no items
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of items on a full page
+    pub fn per_page(&self) -> usize {
+        self.per_page
+    }
+
+    /// Index of the current page, starting at 0
+    pub fn page(&self) -> usize {
+        self.selected / self.per_page
+    }
+
+    /// Number of pages, at least 1 so an empty list still has a page to show
+    pub fn total_pages(&self) -> usize {
+        self.len.div_ceil(self.per_page).max(1)
+    }
+
+    /// Indices of the items on the current page
+    pub fn page_range(&self) -> Range<usize> {
+        let start = (self.page() * self.per_page).min(self.len);
+        start..(start + self.per_page).min(self.len)
+    }
+
+    /// Position of the selected item within the current page
+    pub fn offset_in_page(&self) -> usize {
+        self.selected - self.page() * self.per_page
+    }
+
+    /// Update the list length, keeping the selection in range
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+
+    /// Select an item, clamped to the list
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.len.saturating_sub(1));
+    }
+
+    /// Select the previous item
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Select the next item
+    pub fn down(&mut self) {
+        self.select(self.selected + 1);
+    }
+
+    /// Select the first item of the next page
+    pub fn next_page(&mut self) {
+        if self.page() + 1 < self.total_pages() {
+            self.selected = (self.page() + 1) * self.per_page;
+        }
+    }
+
+    /// Select the first item of the previous page
+    pub fn prev_page(&mut self) {
+        if self.page() > 0 {
+            self.selected = (self.page() - 1) * self.per_page;
+        }
+    }
+
+    /// Select the first item
+    pub fn first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Select the last item
+    pub fn last(&mut self) {
+        self.selected = self.len.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection(len: usize, per_page: usize) -> PagedSelection {
+        let mut selection = PagedSelection::new(per_page);
+        selection.set_len(len);
+        selection
+    }
+
+    #[test]
+    fn empty_list_has_one_empty_page() {
+        let selection = selection(0, 15);
+        assert_eq!(selection.total_pages(), 1);
+        assert_eq!(selection.page_range(), 0..0);
+        assert_eq!(selection.selected(), 0);
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn moving_down_crosses_into_the_next_page() {
+        let mut selection = selection(20, 15);
+        selection.select(14);
+        assert_eq!(selection.page(), 0);
+
+        selection.down();
+        assert_eq!(selection.selected(), 15);
+        assert_eq!(selection.page(), 1);
+        assert_eq!(selection.page_range(), 15..20);
+        assert_eq!(selection.offset_in_page(), 0);
+    }
+
+    #[test]
+    fn moving_up_crosses_into_the_previous_page() {
+        let mut selection = selection(20, 15);
+        selection.select(15);
+        selection.up();
+        assert_eq!(selection.selected(), 14);
+        assert_eq!(selection.page(), 0);
+        assert_eq!(selection.offset_in_page(), 14);
+    }
+
+    #[test]
+    fn movement_stops_at_the_ends() {
+        let mut selection = selection(3, 15);
+        selection.up();
+        assert_eq!(selection.selected(), 0);
+
+        selection.last();
+        selection.down();
+        assert_eq!(selection.selected(), 2);
+    }
+
+    #[test]
+    fn paging_selects_the_first_item_of_the_page() {
+        let mut selection = selection(40, 15);
+        selection.select(3);
+        selection.next_page();
+        assert_eq!(selection.selected(), 15);
+
+        selection.next_page();
+        assert_eq!(selection.selected(), 30);
+        assert_eq!(selection.page_range(), 30..40);
+
+        // There is no fourth page
+        selection.next_page();
+        assert_eq!(selection.selected(), 30);
+
+        selection.prev_page();
+        assert_eq!(selection.selected(), 15);
+    }
+
+    #[test]
+    fn shrinking_the_list_keeps_the_selection_in_range() {
+        let mut selection = selection(40, 15);
+        selection.last();
+        selection.set_len(10);
+        assert_eq!(selection.selected(), 9);
+        assert_eq!(selection.page(), 0);
+
+        selection.set_len(0);
+        assert_eq!(selection.selected(), 0);
+    }
+
+    #[test]
+    fn select_is_clamped_to_the_list() {
+        let mut selection = selection(5, 2);
+        selection.select(100);
+        assert_eq!(selection.selected(), 4);
+        assert_eq!(selection.page(), 2);
+        assert_eq!(selection.total_pages(), 3);
+        assert_eq!(selection.page_range(), 4..5);
+    }
+
+    #[test]
+    fn zero_items_per_page_is_treated_as_one() {
+        let selection = selection(3, 0);
+        assert_eq!(selection.total_pages(), 3);
+    }
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Anything the list screens do to a selection
+        #[derive(Debug, Clone)]
+        enum Op {
+            Up,
+            Down,
+            NextPage,
+            PrevPage,
+            First,
+            Last,
+            Select(usize),
+            SetLen(usize),
+        }
+
+        fn op() -> impl Strategy<Value = Op> {
+            prop_oneof![
+                Just(Op::Up),
+                Just(Op::Down),
+                Just(Op::NextPage),
+                Just(Op::PrevPage),
+                Just(Op::First),
+                Just(Op::Last),
+                (0..500usize).prop_map(Op::Select),
+                (0..500usize).prop_map(Op::SetLen),
+            ]
+        }
+
+        fn apply(selection: &mut PagedSelection, op: &Op) {
+            match op {
+                Op::Up => selection.up(),
+                Op::Down => selection.down(),
+                Op::NextPage => selection.next_page(),
+                Op::PrevPage => selection.prev_page(),
+                Op::First => selection.first(),
+                Op::Last => selection.last(),
+                Op::Select(index) => selection.select(*index),
+                Op::SetLen(len) => selection.set_len(*len),
+            }
+        }
+
+        /// What must hold after every operation
+        fn check_invariants(selection: &PagedSelection, len: usize, per_page: usize) {
+            let per_page = per_page.max(1);
+            let range = selection.page_range();
+
+            assert!(selection.selected() < len.max(1));
+            assert!(selection.page() < selection.total_pages());
+            assert_eq!(range.start, (selection.page() * per_page).min(len));
+            assert!(range.len() <= per_page);
+            assert!(range.end <= len);
+            assert_eq!(
+                selection.offset_in_page(),
+                selection.selected() - range.start
+            );
+            if len > 0 {
+                assert!(range.contains(&selection.selected()));
+            }
+            // Only the last page may be short
+            if selection.page() + 1 < selection.total_pages() {
+                assert_eq!(range.len(), per_page);
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn total_pages_covers_the_list_exactly(len in 0..10_000usize, per_page in 0..200usize) {
+                let selection = selection(len, per_page);
+                let per_page = per_page.max(1);
+                let pages = selection.total_pages();
+
+                prop_assert!(pages >= 1);
+                prop_assert!(pages * per_page >= len);
+                if len > 0 {
+                    prop_assert!((pages - 1) * per_page < len);
+                } else {
+                    prop_assert_eq!(pages, 1);
+                }
+            }
+
+            #[test]
+            fn page_ranges_tile_the_list(len in 0..2_000usize, per_page in 1..100usize) {
+                let mut selection = selection(len, per_page);
+                let mut covered = 0;
+                for page in 0..selection.total_pages() {
+                    selection.select(page * per_page);
+                    let range = selection.page_range();
+                    prop_assert_eq!(range.start, covered);
+                    covered = range.end;
+                }
+                prop_assert_eq!(covered, len);
+            }
+
+            #[test]
+            fn invariants_hold_after_any_operations(
+                len in 0..500usize,
+                per_page in 0..40usize,
+                ops in prop::collection::vec(op(), 0..64),
+            ) {
+                let mut selection = selection(len, per_page);
+                let mut len = len;
+                check_invariants(&selection, len, per_page);
+                for op in &ops {
+                    apply(&mut selection, op);
+                    if let Op::SetLen(new_len) = op {
+                        len = *new_len;
+                    }
+                    check_invariants(&selection, len, per_page);
+                }
+            }
+
+            #[test]
+            fn walking_down_visits_every_item_in_order(len in 1..300usize, per_page in 1..40usize) {
+                let mut selection = selection(len, per_page);
+                for index in 0..len {
+                    prop_assert_eq!(selection.selected(), index);
+                    prop_assert_eq!(selection.page(), index / per_page);
+                    prop_assert_eq!(selection.offset_in_page(), index % per_page);
+                    selection.down();
+                }
+                prop_assert_eq!(selection.selected(), len - 1);
+            }
+
+            #[test]
+            fn up_undoes_down_away_from_the_end(
+                len in 2..300usize,
+                per_page in 1..40usize,
+                start in 0..300usize,
+            ) {
+                let mut selection = selection(len, per_page);
+                selection.select(start.min(len - 2));
+                let before = selection.clone();
+                selection.down();
+                selection.up();
+                prop_assert_eq!(selection, before);
+            }
+
+            #[test]
+            fn paging_lands_on_the_first_item_of_a_page(
+                len in 1..500usize,
+                per_page in 1..40usize,
+                start in 0..500usize,
+                forward in any::<bool>(),
+            ) {
+                let mut selection = selection(len, per_page);
+                selection.select(start);
+                let page = selection.page();
+                if forward {
+                    selection.next_page();
+                } else {
+                    selection.prev_page();
+                }
+
+                if selection.page() != page {
+                    prop_assert_eq!(selection.offset_in_page(), 0);
+                    prop_assert_eq!(selection.page().abs_diff(page), 1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// src/terminal/shell.rs
+// Ctrl-z drops out of the interface into a shell in the downloads directory
+// for quick file work. The app keeps running behind it, connection and
+// background jobs included, and the interface comes back when the shell exits.
+use crate::utils::i18n::{tr, trf};
+use crate::utils::paths::{self, DataDir};
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use log::info;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Whether a key suspends the interface to a shell
+pub fn is_suspend(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('z')
+}
+
+/// The user's shell, or the platform's default one
+fn shell_program() -> PathBuf {
+    let variable = if cfg!(windows) { "COMSPEC" } else { "SHELL" };
+    let fallback = if cfg!(windows) { "cmd.exe" } else { "/bin/sh" };
+    std::env::var_os(variable)
+        .filter(|shell| !shell.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(fallback))
+}
+
+/// Run an interactive shell in the downloads directory until the user exits it
+///
+/// The terminal must already be out of raw mode and the alternate screen.
+pub fn run_in_downloads() -> Result<()> {
+    let shell = shell_program();
+    let directory = paths::output_dir(DataDir::Downloads);
+    info!("Suspending to {:?} in {:?}", shell, directory);
+
+    println!(
+        "{}",
+        trf(
+            "Olympus Camera Control is still running. Type 'exit' to return. Shell in {}",
+            &[&directory.display()]
+        )
+    );
+    let status = Command::new(&shell)
+        .current_dir(&directory)
+        .status()
+        .with_context(|| format!("Failed to start shell {}", shell.display()))?;
+
+    info!("Shell exited with {}, resuming", status);
+    println!("{}", tr("Returning to Olympus Camera Control..."));
+    Ok(())
+}
@@ -1,15 +1,67 @@
 // src/terminal/state.rs
+use crate::camera::capabilities::{
+    CAPABILITIES_FILE, Capabilities, CapabilityProbe, ImageOperation,
+};
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::client::error::FailureKind;
+use crate::camera::connection::ModeManager;
 use crate::camera::connection::init::ConnectionManager;
+use crate::camera::console::{CommandConsole, ConsoleEntry};
+use crate::camera::events::{CameraEvent, EventPoller};
+use crate::camera::image::cache::{IMAGE_LIST_CACHE_FILE, ImageListCache, ListChanges};
 use crate::camera::image::download::ImageDownloader;
-use crate::camera::image::list::ImageLister;
+use crate::camera::image::formats::UrlFormatGenerator;
+use crate::camera::image::list::{DEFAULT_FOLDER, ImageLister};
+use crate::camera::image::prefetch::ThumbnailPrefetcher;
 use crate::camera::olympus::OlympusCamera;
+use crate::camera::pairing::{ConnectSettings, PairingManager};
+use crate::camera::photo::movie::MovieRecording;
+use crate::camera::photo::queue::{CaptureQueue, QueueStatus};
+use crate::camera::photo::timelapse::{Intervalometer, TimelapseStatus};
+use crate::camera::profiles::ProfileStore;
+use crate::camera::settings::{CameraSettings, SettingState};
+use crate::net::latency::{Latency, LatencyMonitor};
+use crate::net::link::{LinkMonitor, LinkQuality};
+use crate::plugins::{self, Plugin, PluginEntry};
+use crate::terminal::dialog::{Dialog, DialogAction, DialogKind, ProgressSource};
+use crate::terminal::events::{AppMessage, JobUpdate};
 use crate::terminal::image_viewer::state::ImageViewerState;
+use crate::terminal::input::InputHistory;
+use crate::terminal::macros::{MacroPlayback, MacroStore};
+use crate::terminal::menu::MAIN_MENU;
+use crate::terminal::movie::{self, MovieFetch, MovieProgress};
+use crate::terminal::refresh::ListRefresh;
+use crate::terminal::selection::PagedSelection;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::dashboard::{Dashboard, DashboardCamera};
+#[cfg(feature = "player")]
+use crate::terminal::video_viewer::olympus_udp;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::quality::{self, LinkProblem, QualityStep};
+#[cfg(feature = "video")]
 use crate::terminal::video_viewer::state::VideoViewerState;
+#[cfg(feature = "video")]
+use crate::utils::alert::TitleFlash;
+use crate::utils::alert::{self, AlertEvent};
+use crate::utils::audit::{self, AuditAction, AuditEntry};
+use crate::utils::clipboard::SystemClipboard;
+use crate::utils::config::Config;
+use crate::utils::dng::{DngJob, DngProgress};
+use crate::utils::ffmpeg::{TranscodeJob, TranscodeProgress};
+use crate::utils::i18n::{tr, trf};
+use crate::utils::instance::{self, InstanceLock};
+use crate::utils::notes::NotesStore;
+use crate::utils::paths::{self, DataDir};
+use crate::utils::session::ShootSession;
 use anyhow::{Result, anyhow};
+use crossterm::event::KeyCode;
 use log::{error, info, warn};
+use std::collections::{HashMap, VecDeque};
+use std::ffi::OsStr;
+use std::ops::Range;
+use std::path::PathBuf;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tempfile::NamedTempFile;
 
 /// Different application states
@@ -17,10 +69,35 @@ use tempfile::NamedTempFile;
 pub enum AppMode {
     Main,
     ImageList,
-    Downloading,
-    Deleting,
     ViewingImage,
+    #[cfg_attr(not(feature = "video"), allow(dead_code))]
     ViewingVideo,
+    #[cfg_attr(not(feature = "video"), allow(dead_code))]
+    Transcoding,
+    AuditLog,
+    #[cfg_attr(not(feature = "video"), allow(dead_code))]
+    Dashboard,
+    Pairing,
+    Settings,
+    Console,
+    Plugins,
+}
+
+/// Items shown on each page of a list
+const ITEMS_PER_PAGE: usize = 15;
+
+/// Commands shown on each page of the console history, leaving room for the response
+const CONSOLE_ENTRIES_PER_PAGE: usize = 6;
+
+/// Entries of the pairing screen: the connect mode and the access point
+pub const PAIRING_ENTRIES: usize = 2;
+
+/// Folder in the cache directory holding prefetched thumbnails
+const THUMBNAIL_DIR: &str = "thumbnails";
+
+/// Where the image listing is cached between sessions
+fn image_cache_path() -> PathBuf {
+    paths::dir(DataDir::Cache).join(IMAGE_LIST_CACHE_FILE)
 }
 
 /// Application state
@@ -28,86 +105,1050 @@ pub struct AppState {
     /// Camera connection
     pub camera: OlympusCamera,
 
+    /// Application configuration
+    pub config: Config,
+
     /// Current application mode
     pub mode: AppMode,
 
-    /// Index of the currently selected item
+    /// Index of the selected main menu entry
     pub selected_index: usize,
 
-    /// List of images on the camera
+    /// Selected image and page of the image list
+    pub image_selection: PagedSelection,
+
+    /// List of images in the open folder
     pub images: Vec<String>,
 
-    /// Status message
-    pub status: String,
+    /// Image folders on the card, listed one at a time when opened
+    pub folders: Vec<String>,
 
-    /// Images per page (for pagination)
-    pub items_per_page: usize,
+    /// Last listing fetched from the camera, used for incremental refreshes
+    pub image_cache: ImageListCache,
 
-    /// Current page in image list
-    pub current_page_index: usize,
+    /// Status message
+    pub status: String,
 
-    /// Whether to show error dialog
-    pub show_error_dialog: bool,
+    /// Messages posted and not yet applied, oldest first
+    messages: VecDeque<AppMessage>,
 
-    /// Error dialog title
-    pub error_title: String,
+    /// Dialog shown over the current screen, if any
+    pub dialog: Option<Dialog>,
 
-    /// Error dialog message
-    pub error_message: String,
+    /// Values typed into input fields, recalled with Up and Down
+    pub input_history: InputHistory,
 
     /// Image viewer state (when in viewing mode)
     pub image_viewer: Option<ImageViewerState>,
 
     /// Video viewer state (when in video viewing mode)
+    #[cfg(feature = "video")]
     pub video_viewer: Option<VideoViewerState>,
 
+    /// Monitors of the dashboard cameras, while the dashboard is open
+    #[cfg(feature = "video")]
+    pub dashboard: Option<Dashboard>,
+
+    /// Selected camera on the dashboard
+    pub dashboard_selection: PagedSelection,
+
+    /// The app's own camera and its address, while a dashboard camera is being viewed
+    #[cfg(feature = "video")]
+    pub home_camera: Option<(OlympusCamera, String)>,
+
+    /// Background poller for changes made on the camera itself
+    pub event_poller: Option<EventPoller>,
+
+    /// Battery level last reported by the camera
+    pub battery_level: Option<String>,
+
+    /// Background reader of the wireless link quality
+    pub link_monitor: Option<LinkMonitor>,
+
+    /// Latest wireless link quality, if the system reports one
+    pub link_quality: Option<LinkQuality>,
+
+    /// Background round-trip measurement to the camera
+    pub latency_monitor: Option<LatencyMonitor>,
+
+    /// Latest round trip to the camera
+    pub latency: Option<Latency>,
+
+    /// Running timelapse sequence, if any
+    pub timelapse: Option<Intervalometer>,
+
+    /// Running capture queue, if any
+    pub capture_queue: Option<CaptureQueue>,
+
+    /// Saved camera settings profiles
+    pub profiles: ProfileStore,
+
+    /// Index of the selected settings profile
+    pub profile_index: usize,
+
+    /// Recorded key macros
+    pub macros: MacroStore,
+
+    /// Keys typed since macro recording started, while recording
+    pub macro_recording: Option<Vec<KeyCode>>,
+
+    /// Macro being played back, if any
+    pub macro_playback: Option<MacroPlayback>,
+
+    /// Macro last recorded or played, which Ctrl-p plays again
+    pub last_macro: Option<String>,
+
+    /// Live view recordings on disk, newest first
+    pub recordings: Vec<PathBuf>,
+
+    /// Selected recording on the transcode screen
+    pub recording_selection: PagedSelection,
+
+    /// Audit log entries shown on the audit log screen, newest first
+    pub audit_entries: Vec<AuditEntry>,
+
+    /// Selected entry on the audit log screen
+    pub audit_selection: PagedSelection,
+
+    /// Connect mode and access point shown on the pairing screen
+    pub pairing: Option<ConnectSettings>,
+
+    /// Exposure settings shown on the settings screen
+    pub camera_settings: Vec<SettingState>,
+
+    /// Commands sent from the console this session, newest first
+    pub console: Vec<ConsoleEntry>,
+
+    /// Selected command on the console screen
+    pub console_selection: PagedSelection,
+
+    /// Show the selected response as changes from the one before it to the same request
+    pub console_diff: bool,
+
+    /// Plugins compiled into this build
+    pub plugins: Vec<Box<dyn Plugin>>,
+
+    /// Selected entry on the plugins screen
+    pub plugin_selection: PagedSelection,
+
+    /// Running transcode of a recording, if any
+    pub transcode_job: Option<TranscodeJob>,
+
+    /// Running conversion of synced raw files to DNG, if any
+    pub dng_job: Option<DngJob>,
+
+    /// Movie being downloaded to be played, if any
+    pub movie_fetch: Option<MovieFetch>,
+
+    /// Movie being recorded on the camera, if any
+    pub movie_recording: Option<MovieRecording>,
+
+    /// Ratings and tags given to images
+    pub notes: NotesStore,
+
+    /// Running shoot session new files are routed into, if any
+    pub session: Option<ShootSession>,
+
+    /// Timer of the automatic image list refresh
+    pub list_refresh: ListRefresh,
+
+    /// Background fetching of thumbnails around the shown page, if enabled
+    pub prefetcher: Option<ThumbnailPrefetcher>,
+
+    /// Images last handed to the prefetcher
+    prefetch_range: Option<Range<usize>>,
+
+    /// When each recently found image appeared, for highlighting it
+    pub new_images: HashMap<String, Instant>,
+
+    /// Control of the camera held by this instance, if it has it
+    pub instance_lock: Option<InstanceLock>,
+
+    /// Photos the event poller found since the image list was last opened
+    pub unseen_images: usize,
+
+    /// System clipboard for copying image paths
+    pub clipboard: SystemClipboard,
+
     /// Temporary file for image viewing (needed to prevent early deletion)
     pub temp_file: Option<NamedTempFile>,
 }
 
 impl AppState {
     /// Create a new application state
-    pub fn new(camera_url: &str) -> Result<Self> {
+    pub fn new(config: &Config) -> Result<Self> {
         // Create the camera
-        let camera = OlympusCamera::new(camera_url);
+        let camera = OlympusCamera::new(&config.camera_url, config.timeouts.clone())
+            .with_protocol_log(&config.protocol_log)
+            .with_breaker(&config.breaker)
+            .with_read_only(config.read_only)
+            .with_capability_cache(&paths::dir(DataDir::Cache).join(CAPABILITIES_FILE));
 
         // Connect to the camera
         camera.connect()?;
 
-        // Get the image list
-        let images = camera.get_image_list()?;
+        // Only the folder names are read up front, each folder is listed when it is opened
+        let folders = camera.get_image_folders().unwrap_or_else(|e| {
+            warn!("Failed to list the image folders: {}", e);
+            Vec::new()
+        });
+
+        let folder = folders
+            .iter()
+            .find(|folder| *folder == DEFAULT_FOLDER)
+            .or(folders.first())
+            .map_or(DEFAULT_FOLDER, String::as_str);
+
+        // Get the image list, starting from the listing cached by the last session
+        let mut image_cache = ImageListCache::load(&image_cache_path());
+        let changed = if image_cache.folder() == folder {
+            camera.open_folder(folder);
+            !camera.refresh_image_list(&mut image_cache)?.is_empty()
+        } else {
+            camera.open_image_folder(folder, &mut image_cache)?;
+            true
+        };
+        if changed {
+            image_cache
+                .save(&image_cache_path())
+                .unwrap_or_else(|e| warn!("{}", e));
+        }
+        let images = image_cache.images().to_vec();
+        let mut image_selection = PagedSelection::new(ITEMS_PER_PAGE);
+        image_selection.set_len(images.len());
+
+        // Find out once which endpoints and URL formats this camera supports
+        let probed = camera.capabilities.lock().unwrap().capabilities().probed();
+        if !probed {
+            camera.probe_capabilities(images.first().map(String::as_str));
+        }
 
-        Ok(Self {
+        // Watch for photos taken with the shutter button and other camera-side changes
+        let event_poller = config
+            .events
+            .enabled
+            .then(|| EventPoller::start(camera.clone(), &config.events, &images));
+
+        // Show how good the Wi-Fi link to the camera is
+        let link_monitor = config
+            .link
+            .enabled
+            .then(|| LinkMonitor::start(&config.camera_url, &config.link));
+
+        // Time round trips to the camera to tell camera stalls from network trouble
+        let latency_monitor = config
+            .latency
+            .enabled
+            .then(|| LatencyMonitor::start(camera.clone(), &config.latency));
+
+        // Fetch thumbnails ahead of time, slowly enough not to upset the camera
+        let prefetcher = config.prefetch.enabled.then(|| {
+            ThumbnailPrefetcher::start(
+                &camera,
+                &config.prefetch,
+                &paths::dir(DataDir::Cache).join(THUMBNAIL_DIR),
+            )
+        });
+
+        // Another instance driving the camera leaves this one read-only until the user takes over
+        let other_instance = instance::other_owner();
+        let instance_lock = match other_instance {
+            Some(_) => None,
+            None => InstanceLock::claim()
+                .map_err(|e| warn!("Failed to claim the instance lock: {}", e))
+                .ok(),
+        };
+
+        // Point at the protocol log so it can be attached to bug reports
+        let status = match &camera.protocol {
+            Some(log) => trf("Logging camera protocol to {}", &[&log.path().display()]),
+            None => "Ready".to_string(),
+        };
+
+        let plugins = plugins::registered(&config.plugins);
+        let mut plugin_selection = PagedSelection::new(ITEMS_PER_PAGE);
+        plugin_selection.set_len(plugins.iter().map(|plugin| plugin.entries().len()).sum());
+
+        let mut state = Self {
             camera,
+            config: config.clone(),
             mode: AppMode::Main,
             selected_index: 0,
+            image_selection,
             images,
-            status: "Ready".to_string(),
-            items_per_page: 15, // Show 15 items per page
-            current_page_index: 0,
-            show_error_dialog: false,
-            error_title: String::new(),
-            error_message: String::new(),
+            folders,
+            image_cache,
+            status,
+            messages: VecDeque::new(),
+            dialog: None,
+            input_history: InputHistory::default(),
             image_viewer: None,
+            #[cfg(feature = "video")]
             video_viewer: None,
+            #[cfg(feature = "video")]
+            dashboard: None,
+            dashboard_selection: PagedSelection::new(ITEMS_PER_PAGE),
+            #[cfg(feature = "video")]
+            home_camera: None,
+            event_poller,
+            battery_level: None,
+            link_monitor,
+            link_quality: None,
+            latency_monitor,
+            latency: None,
+            timelapse: None,
+            capture_queue: None,
+            profiles: ProfileStore::load(&paths::resolve(&config.profiles.file)),
+            profile_index: 0,
+            macros: MacroStore::load(&paths::resolve(&config.macros.file)),
+            macro_recording: None,
+            macro_playback: None,
+            last_macro: None,
+            recordings: Vec::new(),
+            recording_selection: PagedSelection::new(ITEMS_PER_PAGE),
+            audit_entries: Vec::new(),
+            audit_selection: PagedSelection::new(ITEMS_PER_PAGE),
+            pairing: None,
+            camera_settings: Vec::new(),
+            console: Vec::new(),
+            console_selection: PagedSelection::new(CONSOLE_ENTRIES_PER_PAGE),
+            console_diff: false,
+            plugins,
+            plugin_selection,
+            transcode_job: None,
+            dng_job: None,
+            movie_fetch: None,
+            movie_recording: None,
+            notes: NotesStore::load(&paths::resolve(&config.xmp.notes_file)),
+            session: None,
+            list_refresh: ListRefresh::default(),
+            prefetcher,
+            prefetch_range: None,
+            new_images: HashMap::new(),
+            instance_lock,
+            unseen_images: 0,
+            clipboard: SystemClipboard::default(),
             temp_file: None,
-        })
+        };
+
+        if let Some(owner) = other_instance {
+            state.offer_take_over(&trf(
+                "Another instance of the app (process {}) is controlling this camera. This window is read-only.\n\nTake control?",
+                &[&owner.pid],
+            ));
+        }
+        Ok(state)
+    }
+
+    /// Go read-only and ask whether to take control of the camera
+    fn offer_take_over(&mut self, message: &str) {
+        self.camera.set_read_only(true);
+        self.set_status(tr("Read-only: the camera is being controlled elsewhere"));
+        self.open_dialog(
+            Dialog::confirm(tr("Camera In Use"), message, DialogAction::TakeOver).with_warning(),
+        );
+    }
+
+    /// Take control of the camera from another instance or app
+    pub fn take_over(&mut self) {
+        match InstanceLock::claim() {
+            Ok(lock) => self.instance_lock = Some(lock),
+            Err(e) => warn!("Failed to claim the instance lock: {}", e),
+        }
+        self.camera.set_read_only(self.config.read_only);
+        self.set_status(tr("Took control of the camera"));
+    }
+
+    /// Refresh the instance lock, going read-only if another instance took over
+    pub fn process_instance_lock(&mut self) {
+        let Some(lock) = &mut self.instance_lock else {
+            return;
+        };
+        let Some(owner) = lock.heartbeat() else {
+            return;
+        };
+
+        self.instance_lock = None;
+        self.offer_take_over(&trf(
+            "Another instance of the app (process {}) took control of the camera. This window is now read-only.\n\nTake control back?",
+            &[&owner.pid],
+        ));
+    }
+
+    /// Events the camera poller has queued since the last call
+    pub fn poll_camera_events(&self) -> Vec<CameraEvent> {
+        let Some(poller) = &self.event_poller else {
+            return Vec::new();
+        };
+
+        // Live view initialization and running jobs are sensitive to concurrent requests
+        poller.set_paused(self.mode == AppMode::ViewingVideo || !self.active_jobs().is_empty());
+        poller.drain()
+    }
+
+    /// Apply a change the camera reported to the image list and status
+    pub fn apply_camera_event(&mut self, event: CameraEvent) {
+        match event {
+            CameraEvent::NewImages(new_images) => {
+                let latest = new_images.last().cloned().unwrap_or_default();
+                self.mark_new_images(&new_images);
+                if self.mode != AppMode::ImageList {
+                    self.unseen_images += new_images.len();
+                }
+                let mut images = self.images.clone();
+                images.extend(new_images);
+                images.sort();
+                images.dedup();
+                self.set_images(images);
+                self.set_status(&trf("New photo on camera: {}", &[&latest]));
+            }
+            CameraEvent::StateChanged { key, value } => {
+                self.set_status(&trf("Camera {} changed to {}", &[&key, &value]));
+            }
+            CameraEvent::ExternalControl(mode) => {
+                // Follow the camera so our next mode switch isn't skipped
+                self.camera.set_mode(mode);
+                if !self.camera.is_read_only() {
+                    self.offer_take_over(&trf(
+                        "The camera switched to {} mode without this app asking; another app such as OI.Share may be controlling it. This window is now read-only.\n\nTake control?",
+                        &[&mode.as_param()],
+                    ));
+                }
+            }
+            CameraEvent::Battery(level) => {
+                let lowered = level.to_lowercase();
+                if ["low", "empty", "warning"]
+                    .iter()
+                    .any(|word| lowered.contains(word))
+                {
+                    self.set_status(&trf("Camera battery low: {}", &[&level]));
+                }
+                self.battery_level = Some(level);
+            }
+        }
+    }
+
+    /// Pick up the latest link quality, warning when it becomes too weak for live view
+    pub fn process_link_quality(&mut self) {
+        let Some(monitor) = &self.link_monitor else {
+            return;
+        };
+
+        let quality = monitor.latest();
+        let was_weak = self.link_quality.as_ref().is_some_and(|link| link.weak);
+        if let Some(link) = quality.as_ref().filter(|link| link.weak && !was_weak) {
+            warn!("Wi-Fi link weak: {}% on {}", link.percent, link.interface);
+            self.set_status(&trf(
+                "Wi-Fi signal weak ({}%), live view may stutter",
+                &[&link.percent],
+            ));
+        }
+
+        // The link to the app's own camera says nothing about a dashboard camera being viewed
+        #[cfg(feature = "video")]
+        if let Some(viewer) = self
+            .video_viewer
+            .as_mut()
+            .filter(|_| self.home_camera.is_none())
+        {
+            viewer.link_quality = quality.clone();
+        }
+        self.link_quality = quality;
+    }
+
+    /// Pick up the latest round trip to the camera
+    pub fn process_latency(&mut self) {
+        let Some(monitor) = &self.latency_monitor else {
+            return;
+        };
+
+        let latency = monitor.latest();
+        #[cfg(feature = "video")]
+        if let Some(viewer) = self
+            .video_viewer
+            .as_mut()
+            .filter(|_| self.home_camera.is_none())
+        {
+            viewer.latency = latency;
+        }
+        self.latency = latency;
+    }
+
+    /// Offer the snapshot loop when live view has not delivered a single packet
+    #[cfg(feature = "video")]
+    pub fn process_stream_fallback(&mut self) {
+        let config = &self.config.snapshot_fallback;
+        if !config.enabled || self.mode != AppMode::ViewingVideo || self.dialog.is_some() {
+            return;
+        }
+        let Some(viewer) = &mut self.video_viewer else {
+            return;
+        };
+        if !viewer.is_playing || viewer.snapshot_loop.is_some() || viewer.fallback_offered {
+            return;
+        }
+
+        let (packets, _, _) = viewer.get_statistics();
+        let timeout = Duration::from_secs(config.udp_timeout_secs);
+        if packets > 0 || viewer.get_time_since_last_frame() < timeout {
+            return;
+        }
+
+        viewer.fallback_offered = true;
+        warn!("No live view packets after {:?}", timeout);
+        let fps = format!("{:.1}", config.fps);
+        let message = trf(
+            "No live view packets arrived in {} seconds. A VPN or firewall may be blocking UDP.\n\nSwitch to a snapshot loop at {} fps?",
+            &[&config.udp_timeout_secs, &fps],
+        );
+        self.open_dialog(Dialog::confirm(
+            tr("No Live View Packets"),
+            &message,
+            DialogAction::SnapshotFallback,
+        ));
+    }
+
+    /// Tell the user when the player window was closed while the stream runs on
+    #[cfg(feature = "player")]
+    pub fn process_player_exit(&mut self) {
+        let Some(viewer) = &mut self.video_viewer else {
+            return;
+        };
+        if olympus_udp::check_player(viewer).is_none() {
+            return;
+        }
+        warn!("Player window closed while the stream was running");
+        self.set_status(tr(
+            "Player closed. Press p to relaunch it or v to watch in the terminal.",
+        ));
+    }
+
+    /// Step the live view size down on a poor link and back up once it recovers
+    #[cfg(feature = "video")]
+    pub fn process_stream_quality(&mut self) {
+        let Some(viewer) = &mut self.video_viewer else {
+            return;
+        };
+        // A stream that never got a packet is blocked, not poor, and gets the snapshot fallback
+        let (packets, _, _) = viewer.get_statistics();
+        let Some(controller) = &mut viewer.quality else {
+            return;
+        };
+
+        if let Some(Err(e)) = controller.poll_change() {
+            warn!("{}", e);
+            self.set_status(&trf("Could not change the live view size: {}", &[&e]));
+            return;
+        }
+        if !viewer.is_playing || viewer.snapshot_loop.is_some() || packets == 0 {
+            return;
+        }
+
+        let recent = viewer
+            .stream_stats
+            .lock()
+            .map(|stats| stats.recent(controller.window()))
+            .unwrap_or_default();
+        let Some(step) = controller.evaluate(&recent) else {
+            return;
+        };
+        controller.apply(&self.camera, viewer.udp_port, step);
+
+        let status = match step {
+            QualityStep::Down {
+                size,
+                problem: LinkProblem::PacketLoss,
+            } => trf(
+                "Packet loss: live view reduced to {}",
+                &[&quality::display_size(size)],
+            ),
+            QualityStep::Down {
+                size,
+                problem: LinkProblem::Stalls,
+            } => trf(
+                "Stream stalling: live view reduced to {}",
+                &[&quality::display_size(size)],
+            ),
+            QualityStep::Up { size } => trf(
+                "Link recovered: live view back up to {}",
+                &[&quality::display_size(size)],
+            ),
+        };
+        info!("{}", status);
+        self.set_status(&status);
+    }
+
+    /// Ring and flash the title when live view stops delivering frames, for unattended monitoring
+    #[cfg(feature = "video")]
+    pub fn process_freeze_alert(&mut self) {
+        let alerts = &self.config.alerts;
+        let Some(viewer) = &mut self.video_viewer else {
+            return;
+        };
+
+        // Only a stream that has delivered frames can freeze
+        let (_, frames, _) = viewer.get_statistics();
+        let since_last_frame = viewer.get_time_since_last_frame();
+        let frozen = alerts.wants(AlertEvent::FrameFreeze)
+            && viewer.is_playing
+            && frames > 0
+            && since_last_frame >= Duration::from_secs(alerts.freeze_secs);
+
+        if let Some(flash) = &mut viewer.title_flash {
+            flash.tick();
+        }
+        if frozen == viewer.frozen {
+            return;
+        }
+        viewer.frozen = frozen;
+
+        if frozen {
+            warn!("No live view frames for {:?}", since_last_frame);
+            if alerts.flash_title {
+                viewer.title_flash = Some(TitleFlash::start(tr("Live view frozen")));
+            }
+            alert::notify(alerts, AlertEvent::FrameFreeze);
+            self.set_status(&trf(
+                "Live view frozen: no frames for {} seconds",
+                &[&since_last_frame.as_secs()],
+            ));
+        } else {
+            viewer.title_flash = None;
+            info!("Live view frames arriving again");
+            self.set_status(tr("Live view frames arriving again"));
+        }
+    }
+
+    /// Point the app at a dashboard camera until `return_home_camera`
+    #[cfg(feature = "video")]
+    pub fn switch_to_dashboard_camera(&mut self, camera: &DashboardCamera) -> Result<()> {
+        let client = OlympusCamera::new(&camera.url, self.config.timeouts.clone())
+            .with_breaker(&self.config.breaker)
+            .with_read_only(self.config.read_only);
+        client.connect()?;
+
+        let home = std::mem::replace(&mut self.camera, client);
+        let home_url = std::mem::replace(&mut self.config.camera_url, camera.url.clone());
+        self.home_camera = Some((home, home_url));
+        info!(
+            "Switched to dashboard camera {} at {}",
+            camera.name, camera.url
+        );
+        Ok(())
+    }
+
+    /// Point the app back at its own camera, returning whether it had been switched away
+    #[cfg(feature = "video")]
+    pub fn return_home_camera(&mut self) -> bool {
+        let Some((camera, url)) = self.home_camera.take() else {
+            return false;
+        };
+        self.camera = camera;
+        self.config.camera_url = url;
+        info!("Switched back to {}", self.config.camera_url);
+        true
+    }
+
+    /// Swap in a higher resolution image once its background fetch has finished
+    pub fn process_image_fetch(&mut self) {
+        let Some(viewer) = &mut self.image_viewer else {
+            return;
+        };
+        let Some(result) = viewer.high_res_fetch.as_ref().and_then(|f| f.try_result()) else {
+            return;
+        };
+        let level = viewer.high_res_fetch.take().map(|f| f.level);
+
+        match (result, level) {
+            (Ok(image_data), Some(level)) => {
+                viewer.high_res_data = Some(image_data);
+                viewer.resolution_level = level;
+                let new_res = tr(viewer.get_resolution_name());
+                self.set_status(&trf("Image resolution increased to {}", &[&new_res]));
+            }
+            (Err(e), _) => {
+                error!("Failed to load higher resolution: {}", e);
+                self.set_status(&trf("Failed to load higher resolution: {}", &[&e]));
+            }
+            (Ok(_), None) => {}
+        }
+    }
+
+    /// Jobs that have ended since the last call, with how they ended
+    pub fn finished_jobs(&mut self) -> Vec<JobUpdate> {
+        let mut jobs = Vec::new();
+        if let Some(status) = self
+            .timelapse
+            .as_ref()
+            .map(Intervalometer::status)
+            .filter(|status| status.finished)
+        {
+            jobs.push(JobUpdate::Timelapse(status));
+        }
+        if let Some(status) = self
+            .capture_queue
+            .as_ref()
+            .map(CaptureQueue::status)
+            .filter(|status| status.finished)
+        {
+            jobs.push(JobUpdate::CaptureQueue(status));
+        }
+        for plugin in &mut self.plugins {
+            if let Some(message) = plugin.poll() {
+                jobs.push(JobUpdate::Plugin(message));
+            }
+        }
+        if let Some(progress) = self
+            .transcode_job
+            .as_ref()
+            .map(TranscodeJob::progress)
+            .filter(|progress| progress.finished)
+        {
+            jobs.push(JobUpdate::Transcode(progress));
+        }
+        if let Some(progress) = self
+            .dng_job
+            .as_ref()
+            .map(DngJob::progress)
+            .filter(|progress| progress.finished)
+        {
+            jobs.push(JobUpdate::DngConversion(progress));
+        }
+        if let Some(progress) = self
+            .movie_fetch
+            .as_ref()
+            .map(MovieFetch::progress)
+            .filter(|progress| progress.finished)
+        {
+            jobs.push(JobUpdate::MovieDownload(progress));
+        }
+        jobs
+    }
+
+    /// Report how a background job ended
+    pub fn apply_job_update(&mut self, update: JobUpdate) {
+        match update {
+            JobUpdate::Timelapse(status) => self.finish_timelapse(status),
+            JobUpdate::CaptureQueue(status) => self.finish_capture_queue(status),
+            JobUpdate::Plugin(message) => self.finish_plugin(&message),
+            JobUpdate::Transcode(progress) => self.finish_transcode(progress),
+            JobUpdate::DngConversion(progress) => self.finish_dng_conversion(progress),
+            JobUpdate::MovieDownload(progress) => self.finish_movie_fetch(progress),
+        }
+    }
+
+    /// Report the end of a timelapse sequence
+    fn finish_timelapse(&mut self, status: TimelapseStatus) {
+        self.timelapse = None;
+        if self
+            .dialog
+            .as_ref()
+            .is_some_and(|dialog| dialog.kind == DialogKind::Progress(ProgressSource::Timelapse))
+        {
+            self.close_dialog();
+        }
+
+        let taken = status.frames_taken.to_string();
+        let total = status.frames_total.to_string();
+        match status.error {
+            Some(e) => self.set_status(&trf(
+                "Timelapse stopped after {}/{} frames: {}",
+                &[&taken, &total, &e],
+            )),
+            None if status.frames_taken < status.frames_total => self.set_status(&trf(
+                "Timelapse cancelled after {}/{} frames",
+                &[&taken, &total],
+            )),
+            None => self.set_status(tr("Timelapse complete")),
+        }
+        alert::notify(&self.config.alerts, AlertEvent::Timelapse);
+    }
+
+    /// Report the end of a capture queue
+    fn finish_capture_queue(&mut self, status: QueueStatus) {
+        self.capture_queue = None;
+        if self
+            .dialog
+            .as_ref()
+            .is_some_and(|dialog| dialog.kind == DialogKind::Progress(ProgressSource::CaptureQueue))
+        {
+            self.close_dialog();
+        }
+
+        // Refreshed first so the outcome isn't replaced by the image count
+        if let Err(e) = self.refresh_images() {
+            warn!("Failed to refresh images after the capture queue: {}", e);
+        }
+
+        let taken = status.shots_taken.to_string();
+        let total = status.shots_total.to_string();
+        match status.error {
+            Some(e) => self.set_status(&trf(
+                "Capture queue stopped after {}/{} shots: {}",
+                &[&taken, &total, &e],
+            )),
+            None if status.shots_taken < status.shots_total => self.set_status(&trf(
+                "Capture queue cancelled after {}/{} shots",
+                &[&taken, &total],
+            )),
+            None => self.set_status(&trf("Capture queue complete: {} shots", &[&total])),
+        }
+    }
+
+    /// Report the end of a plugin's background work
+    fn finish_plugin(&mut self, message: &str) {
+        if let Err(e) = self.refresh_images() {
+            warn!("Failed to refresh images after a plugin finished: {}", e);
+        }
+        self.set_status(message);
     }
 
-    /// Set error dialog message
-    pub fn set_error_message(&mut self, title: &str, message: &str) {
-        self.error_title = title.to_string();
-        self.error_message = message.to_string();
+    /// Every plugin entry in order, with the index of the plugin offering it
+    pub fn plugin_entries(&self) -> Vec<(usize, PluginEntry)> {
+        self.plugins
+            .iter()
+            .enumerate()
+            .flat_map(|(index, plugin)| {
+                plugin
+                    .entries()
+                    .into_iter()
+                    .map(move |entry| (index, entry))
+            })
+            .collect()
     }
 
-    /// Set whether to show the error dialog
-    pub fn set_show_error_dialog(&mut self, show: bool) {
-        self.show_error_dialog = show;
+    /// Run a plugin action, showing its outcome in the status bar
+    pub fn run_plugin(&mut self, index: usize, key: char) {
+        let Some(plugin) = self.plugins.get_mut(index) else {
+            return;
+        };
+        let name = plugin.name();
+        match plugin.run(key, &self.camera) {
+            Ok(message) => self.set_status(&message),
+            Err(e) => self.set_status(&trf("{} failed: {}", &[&name, &e])),
+        }
     }
 
-    /// Dismiss the error dialog
-    pub fn dismiss_error_dialog(&mut self) {
-        self.show_error_dialog = false;
+    /// Report the end of a transcode once ffmpeg has exited
+    fn finish_transcode(&mut self, progress: TranscodeProgress) {
+        let Some(job) = self.transcode_job.take() else {
+            return;
+        };
+        let output = job.output.display().to_string();
+
+        match progress.error {
+            Some(e) => self.set_status(&trf("Transcode failed: {}", &[&e])),
+            None => self.set_status(&trf("Transcoded to {}", &[&output])),
+        }
+        #[cfg(feature = "video")]
+        self.refresh_recordings();
+    }
+
+    /// Report the end of a DNG conversion once every file has been handled
+    fn finish_dng_conversion(&mut self, progress: DngProgress) {
+        self.dng_job = None;
+        if self.dialog.as_ref().is_some_and(|dialog| {
+            dialog.kind == DialogKind::Progress(ProgressSource::DngConversion)
+        }) {
+            self.close_dialog();
+        }
+
+        if progress.failed > 0 {
+            self.set_status(&trf(
+                "Converted {} raw files to DNG, {} failed",
+                &[&progress.converted, &progress.failed],
+            ));
+        } else {
+            self.set_status(&trf(
+                "Converted {} raw files to DNG",
+                &[&progress.converted],
+            ));
+        }
+    }
+
+    /// Play a downloaded movie once its download has ended
+    fn finish_movie_fetch(&mut self, progress: MovieProgress) {
+        let Some(fetch) = self.movie_fetch.take() else {
+            return;
+        };
+        let name = fetch.name.clone();
+        let destination = fetch.destination.clone();
+        if self.dialog.as_ref().is_some_and(|dialog| {
+            dialog.kind == DialogKind::Progress(ProgressSource::MovieDownload)
+        }) {
+            self.close_dialog();
+        }
+
+        let result = match progress.error {
+            Some(e) => Err(anyhow!(e)),
+            None => Ok(()),
+        };
+        audit::record(AuditAction::Download, &name, &result);
+        if let Err(e) = result {
+            self.set_status(&trf("Failed to download {}: {}", &[&name, &e]));
+            return;
+        }
+        self.play_movie(&name, destination.as_os_str());
+    }
+
+    /// Hand a movie file or URL to the external player
+    pub fn play_movie(&mut self, name: &str, target: &OsStr) {
+        let played = movie::find_player(&self.config.movies).and_then(|player| {
+            movie::launch(&player, target, &self.config.player_window).map(|_| player)
+        });
+        match played {
+            Ok(player) => self.set_status(&trf("Playing {} in {}", &[&name, &player.display()])),
+            Err(e) => self.set_status(&trf("Failed to play {}: {}", &[&name, &e])),
+        }
+    }
+
+    /// Reload the list of live view recordings from the recording directory
+    #[cfg(feature = "video")]
+    pub fn refresh_recordings(&mut self) {
+        let directory = paths::resolve_output(&self.config.recording.directory);
+        match crate::utils::ffmpeg::list_recordings(&directory) {
+            Ok(recordings) => self.recordings = recordings,
+            Err(e) => {
+                warn!("Failed to list recordings in {:?}: {}", directory, e);
+                self.recordings.clear();
+            }
+        }
+        self.recording_selection.set_len(self.recordings.len());
+    }
+
+    /// Reload the audit log for its screen
+    pub fn refresh_audit_log(&mut self) {
+        self.audit_entries = audit::load();
+        self.audit_selection.set_len(self.audit_entries.len());
+    }
+
+    /// Read the connect mode and access point for the pairing screen
+    pub fn refresh_pairing(&mut self) -> Result<()> {
+        self.pairing = Some(self.camera.read_connect_settings(&self.config.pairing)?);
+        Ok(())
+    }
+
+    /// Read the exposure settings shown on the settings screen
+    pub fn refresh_camera_settings(&mut self) -> Result<()> {
+        self.camera_settings = self.camera.read_settings()?;
+        self.selected_index = self
+            .selected_index
+            .min(self.camera_settings.len().saturating_sub(1));
+        Ok(())
+    }
+
+    /// Send a command typed on the console and show its response
+    pub fn run_console_command(&mut self, request: &str) {
+        let entry = self.camera.run_command(request);
+        self.set_status(&match entry.status {
+            Some(status) => trf(
+                "{} answered {} in {} ms",
+                &[&entry.request, &status, &entry.duration_ms],
+            ),
+            None => trf("{} failed: {}", &[&entry.request, &entry.response]),
+        });
+        self.console.insert(0, entry);
+        self.console_selection.set_len(self.console.len());
+        self.console_selection.select(0);
+    }
+
+    /// Command selected on the console screen
+    pub fn selected_console_entry(&self) -> Option<&ConsoleEntry> {
+        self.console.get(self.console_selection.selected())
+    }
+
+    /// Earlier response to the selected command's request, which it is compared with
+    pub fn console_baseline(&self) -> Option<&ConsoleEntry> {
+        let selected = self.console_selection.selected();
+        let request = &self.console.get(selected)?.request;
+        self.console[selected + 1..]
+            .iter()
+            .find(|entry| entry.request == *request)
+    }
+
+    /// Recording selected on the transcode screen
+    #[cfg(feature = "video")]
+    pub fn selected_recording(&self) -> Option<&PathBuf> {
+        self.recordings.get(self.recording_selection.selected())
+    }
+
+    /// Name of the selected settings profile
+    pub fn selected_profile(&self) -> Option<String> {
+        self.profiles
+            .names(&self.config.profiles.names)
+            .get(self.profile_index)
+            .cloned()
+    }
+
+    /// Select the next settings profile, wrapping around
+    pub fn next_profile(&mut self) {
+        let count = self.profiles.names(&self.config.profiles.names).len();
+        if count > 0 {
+            self.profile_index = (self.profile_index + 1) % count;
+        }
+    }
+
+    /// Queue a message for the update loop
+    pub fn post(&mut self, message: AppMessage) {
+        self.messages.push_back(message);
+    }
+
+    /// Oldest message not yet applied
+    pub fn next_message(&mut self) -> Option<AppMessage> {
+        self.messages.pop_front()
+    }
+
+    /// Show a dialog over the current screen, replacing any open one
+    pub fn open_dialog(&mut self, dialog: Dialog) {
+        info!("Opening dialog: {}", dialog.title);
+        self.dialog = Some(dialog);
+    }
+
+    /// Close the open dialog
+    pub fn close_dialog(&mut self) {
+        self.dialog = None;
+    }
+
+    /// Running work that quitting would cut short, as untranslated names
+    pub fn active_jobs(&self) -> Vec<&'static str> {
+        let mut jobs = Vec::new();
+        if self.timelapse.is_some() {
+            jobs.push("timelapse");
+        }
+        if self.capture_queue.is_some() {
+            jobs.push("capture queue");
+        }
+        for plugin in &self.plugins {
+            if plugin.activity().is_some() {
+                jobs.push(plugin.name());
+            }
+        }
+        if self.transcode_job.is_some() {
+            jobs.push("transcode");
+        }
+        if self.dng_job.is_some() {
+            jobs.push("DNG conversion");
+        }
+        if self.movie_fetch.is_some() {
+            jobs.push("movie download");
+        }
+        if self.movie_recording.is_some() {
+            jobs.push("video recording");
+        }
+
+        #[cfg(feature = "video")]
+        if let Some(viewer_state) = &self.video_viewer {
+            if viewer_state.is_recording() {
+                jobs.push("recording");
+            }
+            if viewer_state.is_streaming_rtmp() {
+                jobs.push("RTMP stream");
+            }
+            if viewer_state.stills_saved().is_some() {
+                jobs.push("interval stills");
+            }
+        }
+        jobs
     }
 
     /// Update the status message
@@ -116,44 +1157,51 @@ impl AppState {
     }
 
     /// Function to retry a request with backoff
-    fn retry_with_backoff<F, T, E>(&self, mut operation: F, max_retries: usize) -> Result<T>
+    ///
+    /// Only failures that can go away by themselves, such as a busy camera, are
+    /// retried; anything else is returned straight away.
+    fn retry_with_backoff<F, T>(&self, mut operation: F, max_retries: usize) -> Result<T>
     where
-        F: FnMut() -> std::result::Result<T, E>,
-        E: std::fmt::Display,
+        F: FnMut() -> Result<T>,
     {
         let mut retries = 0;
-        let mut last_error = None;
 
-        while retries < max_retries {
-            match operation() {
+        loop {
+            let e = match operation() {
                 Ok(result) => return Ok(result),
-                Err(e) => {
-                    last_error = Some(format!("{}", e));
-                    retries += 1;
-                    let delay = Duration::from_millis(500 * 2u64.pow(retries as u32));
-                    info!(
-                        "Request failed, retrying in {:?}... (attempt {}/{})",
-                        delay, retries, max_retries
-                    );
-                    thread::sleep(delay);
-                }
+                Err(e) => e,
+            };
+
+            let kind = FailureKind::classify(&e);
+            if !kind.is_transient() {
+                info!("Not retrying, {}: {}", kind.describe(), e);
+                return Err(e.context(kind.describe()));
+            }
+            if self.camera.camera_unresponsive() {
+                info!("Not retrying, the camera is unresponsive: {}", e);
+                return Err(e.context("the camera is unresponsive"));
             }
-        }
 
-        Err(anyhow!(
-            "Operation failed after {} retries. Last error: {}",
-            max_retries,
-            last_error.unwrap_or_default()
-        ))
+            retries += 1;
+            if retries >= max_retries {
+                return Err(anyhow!(
+                    "Operation failed after {} retries. Last error: {}",
+                    max_retries,
+                    e
+                ));
+            }
+            let delay = Duration::from_millis(500 * 2u64.pow(retries as u32));
+            info!(
+                "Request failed, retrying in {:?}... (attempt {}/{})",
+                delay, retries, max_retries
+            );
+            thread::sleep(delay);
+        }
     }
 
     /// Verify camera connection and reconnect if needed
     fn ensure_camera_connected(&mut self) -> Result<()> {
-        if !self
-            .camera
-            .connected
-            .load(std::sync::atomic::Ordering::Relaxed)
-        {
+        if !self.camera.is_connected() {
             info!("Camera connection inactive, attempting to reconnect");
             match self.camera.connect() {
                 Ok(_) => {
@@ -170,124 +1218,58 @@ impl AppState {
         }
     }
 
-    /// Explore camera API for debugging
-    pub fn explore_camera_api(&self) -> Result<()> {
-        info!("🔍 Beginning camera API exploration");
-
-        // Basic endpoints that most cameras support
-        let basic_endpoints = [
-            "",
-            "get_state.cgi",
-            "get_imglist.cgi?DIR=/DCIM/100OLYMP",
-            "get_capability.cgi",
-            "get_connectmode.cgi",
-            "exec_takemisc.cgi?com=getdevicestatus",
-        ];
-
-        // Additional endpoints to try for image access
-        let image_endpoints = [
-            "DCIM",
-            "DCIM/100OLYMP",
-            "DCIM/",
-            "/DCIM/100OLYMP",
-            "get_imglist.cgi",
-            "get_imglist.cgi?DIR=/DCIM",
-        ];
-
-        // Try all basic endpoints first
-        info!("Testing basic camera endpoints...");
-        for endpoint in basic_endpoints.iter() {
-            info!("Trying endpoint: {}", endpoint);
-            match self.camera.get_page(endpoint) {
-                Ok(_) => info!("✅ Endpoint {} succeeded", endpoint),
-                Err(e) => info!("❌ Endpoint {} failed: {}", endpoint, e),
-            }
-            // Add delay between requests
-            thread::sleep(Duration::from_millis(500));
-        }
-
-        // Try image-specific endpoints
-        info!("Testing image-related endpoints...");
-        for endpoint in image_endpoints.iter() {
-            info!("Trying endpoint: {}", endpoint);
-            match self.camera.get_page(endpoint) {
-                Ok(_) => info!("✅ Endpoint {} succeeded", endpoint),
-                Err(e) => info!("❌ Endpoint {} failed: {}", endpoint, e),
-            }
-            // Add delay between requests
-            thread::sleep(Duration::from_millis(500));
-        }
-
-        // If we have images, try to get the first one with different URL formats
-        if !self.images.is_empty() {
-            let test_image = &self.images[0];
-            info!("Testing image access for: {}", test_image);
-
-            // Test different image access URLs
-            let image_urls = [
-                format!(
-                    "get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
-                    test_image
-                ),
-                format!(
-                    "get_thumbnail.cgi?DIR=DCIM/100OLYMP&FILE={}&size=1024",
-                    test_image
-                ),
-                format!("get_img.cgi?DIR=/DCIM/100OLYMP&FILE={}", test_image),
-                format!("DCIM/100OLYMP/{}", test_image),
-            ];
-
-            for (i, url) in image_urls.iter().enumerate() {
-                info!("Trying image URL format #{}: {}", i + 1, url);
-                match self.camera.get_binary(url) {
-                    Ok(data) => {
-                        if data.len() >= 2 && data[0] == 0xFF && data[1] == 0xD8 {
-                            info!(
-                                "✅ Successfully loaded image with format #{} ({} bytes - valid JPEG)",
-                                i + 1,
-                                data.len()
-                            );
-                        } else {
-                            info!(
-                                "⚠️ Got response with format #{} but not valid JPEG ({} bytes)",
-                                i + 1,
-                                data.len()
-                            );
-                        }
-                    }
-                    Err(e) => info!("❌ Failed with URL format #{}: {}", i + 1, e),
-                }
-                // Add delay between requests
-                thread::sleep(Duration::from_millis(500));
-            }
-        }
-
-        info!("🔍 Camera API exploration complete");
-        Ok(())
+    /// Probe the camera's endpoints and URL formats again and cache the results
+    pub fn explore_camera_api(&self) -> Capabilities {
+        self.camera
+            .probe_capabilities(self.images.first().map(String::as_str))
     }
 
     /// View the currently selected image with enhanced debugging
     pub fn view_selected_image(&mut self) -> Result<()> {
-        // Check if we have images and a valid selection
-        if self.images.is_empty() || self.selected_index >= self.images.len() {
-            return Err(anyhow!("No image selected or invalid selection"));
-        }
-
         // Clone the image name to avoid borrow issues
-        let image_name = self.images[self.selected_index].clone();
+        let image_name = self
+            .selected_image()
+            .ok_or_else(|| anyhow!("No image selected or invalid selection"))?
+            .to_string();
         info!("Attempting to load image: {}", image_name);
 
         // Ensure camera is connected
         self.ensure_camera_connected()?;
 
         // Update status with the cloned name
-        self.set_status(&format!(
+        self.set_status(&trf(
             "Loading image: {} (Trying multiple formats...)",
-            image_name
+            &[&image_name],
         ));
 
-        // Try different URL formats
-        let url_formats = self.generate_url_formats(&image_name);
+        // Try different URL formats, starting with the one known to work on this camera
+        let url_formats = self.camera.known_first(
+            ImageOperation::Preview,
+            &image_name,
+            UrlFormatGenerator::generate_url_formats(
+                self.camera.base_url(),
+                &self.camera.image_folder(),
+                &image_name,
+            ),
+        );
+
+        // A prefetched thumbnail shows at once, the full image still comes from the camera
+        if let Some(image_data) = self
+            .prefetcher
+            .as_ref()
+            .and_then(|prefetcher| prefetcher.cached(&image_name))
+            .filter(|data| self.check_image_valid(data))
+        {
+            info!("Using prefetched thumbnail of {}", image_name);
+            crate::terminal::image_viewer::handlers::create_image_viewer_with_url(
+                self,
+                image_data,
+                &image_name,
+                Some(url_formats[0].clone()),
+            )?;
+            self.set_status(tr("Image loaded from prefetched thumbnail"));
+            return Ok(());
+        }
 
         // Log all formats we'll try
         for (i, url) in url_formats.iter().enumerate() {
@@ -297,11 +1279,9 @@ impl AppState {
         // Try each URL format with retries
         for (i, url) in url_formats.iter().enumerate() {
             info!("🔍 Trying URL format #{}: {}", i + 1, url);
-            self.set_status(&format!(
+            self.set_status(&trf(
                 "Loading image: {} (Trying format #{}/{})",
-                image_name,
-                i + 1,
-                url_formats.len()
+                &[&image_name, &(i + 1), &url_formats.len()],
             ));
 
             // Use retry logic
@@ -325,6 +1305,9 @@ impl AppState {
                         continue;
                     }
 
+                    self.camera
+                        .remember_format(ImageOperation::Preview, &image_name, url);
+
                     // Create image viewer with original URL for high-res loading
                     info!("Creating image viewer with URL: {}", url);
                     crate::terminal::image_viewer::handlers::create_image_viewer_with_url(
@@ -334,14 +1317,18 @@ impl AppState {
                         Some(url.clone()),
                     )?;
 
-                    self.set_status(&format!(
+                    self.set_status(&trf(
                         "Image loaded successfully using format #{}",
-                        i + 1
+                        &[&(i + 1)],
                     ));
                     return Ok(());
                 }
                 Err(e) => {
                     warn!("❌ Failed with URL format #{}: {}", i + 1, e);
+                    // Other formats won't get through to a camera that can't be reached
+                    if FailureKind::classify(&e) == FailureKind::Unreachable {
+                        return Err(e);
+                    }
                     // Continue to next format
                 }
             }
@@ -349,14 +1336,14 @@ impl AppState {
 
         // If all formats failed, try to download the full image directly
         info!("All thumbnail formats failed, trying direct image download");
-        self.set_status(&format!(
-            "All thumbnail formats failed, trying direct image download"
+        self.set_status(tr(
+            "All thumbnail formats failed, trying direct image download",
         ));
 
         match self.try_load_direct_image(&image_name) {
             Ok(_) => {
                 info!("✅ Successfully loaded image directly");
-                self.set_status(&format!("Image loaded successfully (direct method)"));
+                self.set_status(tr("Image loaded successfully (direct method)"));
                 return Ok(());
             }
             Err(e) => {
@@ -366,12 +1353,14 @@ impl AppState {
         }
 
         // If all approaches failed, show error and suggest exploration
-        self.set_status(&format!("Failed to load image: All URL formats failed"));
-        self.set_error_message(
-            "Image Loading Failed", 
-            &format!("Failed to load image {} after trying multiple formats.\n\nTry exploring the camera API or refreshing the image list.", image_name)
-        );
-        self.set_show_error_dialog(true);
+        self.set_status(tr("Failed to load image: All URL formats failed"));
+        self.open_dialog(Dialog::error(
+            tr("Image Loading Failed"),
+            &trf(
+                "Failed to load image {} after trying multiple formats.\n\nTry exploring the camera API or refreshing the image list.",
+                &[&image_name],
+            ),
+        ));
 
         // Suggest API exploration
         info!("Suggesting API exploration. Try calling explore_camera_api() for more info.");
@@ -379,47 +1368,6 @@ impl AppState {
         Err(anyhow!("Failed to load image: All URL formats failed"))
     }
 
-    /// Generate various URL formats to try
-    fn generate_url_formats(&self, image_name: &str) -> Vec<String> {
-        vec![
-            // Format 1: Standard thumbnail format
-            format!(
-                "get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
-                image_name
-            ),
-            // Format 2: Without leading slash in DIR
-            format!(
-                "get_thumbnail.cgi?DIR=DCIM/100OLYMP&FILE={}&size=1024",
-                image_name
-            ),
-            // Format 3: Without DIR parameter
-            format!("get_thumbnail.cgi?FILE={}&size=1024", image_name),
-            // Format 4: Direct path
-            format!("DCIM/100OLYMP/{}", image_name),
-            // Format 5: Using get_img.cgi instead
-            format!("get_img.cgi?DIR=/DCIM/100OLYMP&FILE={}", image_name),
-            // Format 6: Using get_img.cgi without leading slash
-            format!("get_img.cgi?DIR=DCIM/100OLYMP&FILE={}", image_name),
-            // Format 7: Using get_resized_img.cgi
-            format!(
-                "get_resized_img.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
-                image_name
-            ),
-            // Format 8: Alternative path structure
-            format!("get_img.cgi?PATH=/DCIM/100OLYMP/{}", image_name),
-            // Format 9: With uppercase filename
-            format!(
-                "get_thumbnail.cgi?DIR=/DCIM/100OLYMP&FILE={}&size=1024",
-                image_name.to_uppercase()
-            ),
-            // Format 10: With lowercase path
-            format!(
-                "get_thumbnail.cgi?DIR=/dcim/100olymp&FILE={}&size=1024",
-                image_name
-            ),
-        ]
-    }
-
     /// Try to load image directly
     fn try_load_direct_image(&mut self, image_name: &str) -> Result<()> {
         // Get the full image list first to confirm existence
@@ -433,10 +1381,11 @@ impl AppState {
         }
 
         // Try direct access with multiple formats
+        let folder = self.camera.image_folder();
         let direct_formats = [
-            format!("DCIM/100OLYMP/{}", image_name),
-            format!("/DCIM/100OLYMP/{}", image_name),
-            format!("get_img.cgi?DIR=/DCIM/100OLYMP&FILE={}", image_name),
+            format!("{}/{}", folder.trim_start_matches('/'), image_name),
+            format!("{}/{}", folder, image_name),
+            format!("get_img.cgi?DIR={}&FILE={}", folder, image_name),
         ];
 
         for (i, url) in direct_formats.iter().enumerate() {
@@ -481,198 +1430,352 @@ impl AppState {
     }
 
     /// Refresh the image list with better error handling
-    pub fn refresh_images(&mut self) -> Result<()> {
-        self.set_status("Refreshing image count...");
+    ///
+    /// Only the images added or removed since the last listing are applied,
+    /// and the selected image stays selected.
+    pub fn refresh_images(&mut self) -> Result<ListChanges> {
+        self.set_status(tr("Refreshing image count..."));
 
         // Ensure camera connection
         self.ensure_camera_connected()?;
 
-        match self.camera.get_image_list() {
-            Ok(images) => {
-                self.images = images;
-                self.set_status(&format!("Found {} images", self.images.len()));
+        match self.camera.refresh_image_list(&mut self.image_cache) {
+            Ok(changes) => {
+                if changes.is_empty() {
+                    self.set_status(&trf("Found {} images", &[&self.images.len()]));
+                    return Ok(changes);
+                }
 
-                // Reset to first page when refreshing
-                self.current_page_index = 0;
+                self.set_images(self.image_cache.images().to_vec());
+                self.mark_new_images(&changes.added);
+                self.set_status(&trf("Found {} images", &[&self.images.len()]));
 
-                // Update selected index if it's now out of bounds
-                if !self.images.is_empty() && self.selected_index >= self.images.len() {
-                    self.selected_index = self.images.len() - 1;
+                if let Err(e) = self.image_cache.save(&image_cache_path()) {
+                    warn!("{}", e);
                 }
+                Ok(changes)
             }
             Err(e) => {
                 // Handle the error but don't crash
-                self.set_status(&format!("Error refreshing images: {}", e));
+                self.set_status(&trf("Error refreshing images: {}", &[&e]));
 
                 // Don't clear existing images list, but let the user know there was an error
-                return Err(e);
+                Err(e)
             }
         }
+    }
 
-        Ok(())
+    /// Refresh the image list on a timer while it is shown, applying listings as they arrive
+    pub fn process_list_refresh(&mut self) {
+        let config = &self.config.list_refresh;
+        if config.enabled && self.mode == AppMode::ImageList {
+            self.list_refresh
+                .start_if_due(&self.camera, Duration::from_secs(config.interval_secs));
+        }
+
+        let highlight = Duration::from_secs(config.highlight_secs);
+        self.new_images
+            .retain(|_, found| found.elapsed() < highlight);
+
+        let (folder, hash, images) = match self.list_refresh.try_result() {
+            Some(Ok(listing)) => listing,
+            Some(Err(e)) => {
+                warn!("Automatic image list refresh failed: {}", e);
+                return;
+            }
+            None => return,
+        };
+
+        // Another folder may have been opened while this one was listed
+        if folder != self.image_cache.folder() {
+            return;
+        }
+        let changes = self.image_cache.update(hash, images);
+        if changes.is_empty() {
+            return;
+        }
+        self.set_images(self.image_cache.images().to_vec());
+        self.mark_new_images(&changes.added);
+        if let Err(e) = self.image_cache.save(&image_cache_path()) {
+            warn!("{}", e);
+        }
+        self.set_status(&trf(
+            "Image list updated - {} new, {} removed",
+            &[&changes.added.len(), &changes.removed.len()],
+        ));
+    }
+
+    /// Queue thumbnails of the shown page and the next one, pausing during live view
+    pub fn process_prefetch(&mut self) {
+        let Some(prefetcher) = &self.prefetcher else {
+            return;
+        };
+
+        // Live view initialization is sensitive to concurrent requests
+        prefetcher.set_paused(self.mode == AppMode::ViewingVideo);
+        if self.mode != AppMode::ImageList {
+            return;
+        }
+
+        let page = self.image_selection.page_range();
+        let wanted = page.start..(page.end + ITEMS_PER_PAGE).min(self.images.len());
+        if self.prefetch_range.as_ref() == Some(&wanted) {
+            return;
+        }
+        prefetcher.request(&self.images[wanted.clone()]);
+        self.prefetch_range = Some(wanted);
+    }
+
+    /// Highlight images that just appeared on the card
+    fn mark_new_images(&mut self, images: &[String]) {
+        let now = Instant::now();
+        for image in images {
+            self.new_images.insert(image.clone(), now);
+        }
+    }
+
+    /// Whether an image appeared recently enough to be highlighted
+    pub fn is_new_image(&self, image: &str) -> bool {
+        self.new_images.contains_key(image)
+    }
+
+    /// Replace the image list, keeping the selected image selected
+    fn set_images(&mut self, images: Vec<String>) {
+        let selected = self.images.get(self.image_selection.selected()).cloned();
+        self.images = images;
+        self.image_selection.set_len(self.images.len());
+        self.prefetch_range = None;
+
+        // Follow the selected image to its new position
+        if let Some(index) =
+            selected.and_then(|name| self.images.iter().position(|image| *image == name))
+        {
+            self.image_selection.select(index);
+        }
     }
 
     /// Set the application mode
     pub fn set_mode(&mut self, mode: AppMode) {
-        // When switching to Download, Delete, or View mode, preserve the selection index
-        let preserve_selection = mode == AppMode::Downloading
-            || mode == AppMode::Deleting
-            || mode == AppMode::ViewingImage;
-
         self.mode = mode;
 
-        // Only reset selection if we're not going to operation screens
-        if !preserve_selection {
+        // Lists keep their own selection, only the menu starts from the top
+        if mode == AppMode::Main {
             self.selected_index = 0;
         }
+        // Opening the list shows the new photos, so they no longer count as unseen
+        if mode == AppMode::ImageList {
+            self.unseen_images = 0;
+        }
 
         // Always log the mode change for debugging
         info!(
-            "Mode changed to {:?}, selected_index={}",
-            mode, self.selected_index
+            "Mode changed to {:?}, selected_index={}, selected image={}",
+            mode,
+            self.selected_index,
+            self.image_selection.selected()
         );
     }
 
     /// Get the maximum index for the current mode
     pub fn get_max_index(&self) -> usize {
         match self.mode {
-            AppMode::Main => 3, // Updated for new menu items
-            AppMode::ImageList => self.images.len().saturating_sub(1),
-            AppMode::Downloading
-            | AppMode::Deleting
+            AppMode::Main => MAIN_MENU.len() - 1,
+            AppMode::ImageList
             | AppMode::ViewingImage
-            | AppMode::ViewingVideo => 0,
+            | AppMode::ViewingVideo
+            | AppMode::Transcoding
+            | AppMode::AuditLog
+            | AppMode::Dashboard
+            | AppMode::Console
+            | AppMode::Plugins => 0,
+            AppMode::Pairing => PAIRING_ENTRIES - 1,
+            AppMode::Settings => self.camera_settings.len().saturating_sub(1),
+        }
+    }
+
+    /// Paged list shown in the current mode, if any
+    fn active_selection(&mut self) -> Option<&mut PagedSelection> {
+        match self.mode {
+            AppMode::ImageList => Some(&mut self.image_selection),
+            AppMode::Transcoding => Some(&mut self.recording_selection),
+            AppMode::AuditLog => Some(&mut self.audit_selection),
+            AppMode::Console => Some(&mut self.console_selection),
+            AppMode::Plugins => Some(&mut self.plugin_selection),
+            AppMode::Dashboard => Some(&mut self.dashboard_selection),
+            _ => None,
         }
     }
 
     /// Move the selection up
     pub fn selection_up(&mut self) {
-        if self.selected_index > 0 {
+        if let Some(selection) = self.active_selection() {
+            selection.up();
+            info!("Selection moved up to index: {}", selection.selected());
+        } else if self.selected_index > 0 {
             self.selected_index -= 1;
             info!("Selection moved up to index: {}", self.selected_index);
-
-            // Update page if selection moves outside current page
-            if self.mode == AppMode::ImageList {
-                let start_idx = self.page_start_index();
-                if self.selected_index < start_idx {
-                    self.current_page_index = self.current_page_index.saturating_sub(1);
-                    info!("Page moved up to: {}", self.current_page_index);
-                }
-            }
         }
     }
 
     /// Move the selection down
     pub fn selection_down(&mut self) {
         let max = self.get_max_index();
-        if self.selected_index < max {
+        if let Some(selection) = self.active_selection() {
+            selection.down();
+            info!("Selection moved down to index: {}", selection.selected());
+        } else if self.selected_index < max {
             self.selected_index += 1;
             info!("Selection moved down to index: {}", self.selected_index);
-
-            // Update page if selection moves outside current page
-            if self.mode == AppMode::ImageList {
-                let end_idx = self.page_end_index();
-                if self.selected_index >= end_idx {
-                    if self.current_page_index < self.total_pages().saturating_sub(1) {
-                        self.current_page_index += 1;
-                        info!("Page moved down to: {}", self.current_page_index);
-                    }
-                }
-            }
         }
     }
 
-    /// Move to the next page
+    /// Move to the next page, paging into the next folder from the last page of the image list
     pub fn next_page(&mut self) {
-        if self.current_page_index < self.total_pages().saturating_sub(1) {
-            self.current_page_index += 1;
-            info!("Page moved to: {}", self.current_page_index);
-
-            // Update selected index to first item on new page
-            self.selected_index = self.page_start_index();
-            info!("Selection set to start of page: {}", self.selected_index);
+        if self.mode == AppMode::ImageList
+            && self.image_selection.page() + 1 == self.image_selection.total_pages()
+            && let Some(index) = self.folder_index().filter(|i| i + 1 < self.folders.len())
+        {
+            self.open_folder(index + 1);
+            return;
+        }
+        if let Some(selection) = self.active_selection() {
+            selection.next_page();
+            info!("Page moved to: {}", selection.page());
         }
     }
 
-    /// Move to the previous page
+    /// Move to the previous page, paging into the last page of the previous folder from the first
     pub fn prev_page(&mut self) {
-        if self.current_page_index > 0 {
-            self.current_page_index -= 1;
-            info!("Page moved to: {}", self.current_page_index);
+        if self.mode == AppMode::ImageList
+            && self.image_selection.page() == 0
+            && let Some(index) = self.folder_index().filter(|&i| i > 0)
+        {
+            if self.open_folder(index - 1) {
+                self.image_selection.last();
+                let start = self.image_selection.page_range().start;
+                self.image_selection.select(start);
+            }
+            return;
+        }
+        if let Some(selection) = self.active_selection() {
+            selection.prev_page();
+            info!("Page moved to: {}", selection.page());
+        }
+    }
 
-            // Update selected index to first item on new page
-            self.selected_index = self.page_start_index();
-            info!("Selection set to start of page: {}", self.selected_index);
+    /// Title of the image list, naming the open folder when the card has several
+    pub fn image_list_title(&self) -> String {
+        let title = trf(
+            "Images ({} total) - Page {}/{}",
+            &[
+                &self.images.len(),
+                &(self.image_selection.page() + 1),
+                &self.image_selection.total_pages(),
+            ],
+        );
+        match self.folder_index() {
+            Some(index) if self.folders.len() > 1 => trf(
+                "{} (folder {} of {}) - {}",
+                &[
+                    &self.folders[index],
+                    &(index + 1),
+                    &self.folders.len(),
+                    &title,
+                ],
+            ),
+            _ => title,
         }
     }
 
-    /// Jump to first image
-    pub fn first_image(&mut self) {
-        self.selected_index = 0;
-        self.current_page_index = 0;
-        info!("Selection set to first image: index=0, page=0");
+    /// Position of the open folder among the card's folders
+    pub fn folder_index(&self) -> Option<usize> {
+        let open = self.image_cache.folder();
+        self.folders.iter().position(|folder| folder == open)
     }
 
-    /// Jump to last image
-    pub fn last_image(&mut self) {
-        if !self.images.is_empty() {
-            self.selected_index = self.images.len() - 1;
-            self.current_page_index = self.total_pages().saturating_sub(1);
-            info!(
-                "Selection set to last image: index={}, page={}",
-                self.selected_index, self.current_page_index
-            );
+    /// Open the folder after the open one
+    pub fn next_folder(&mut self) {
+        match self.folder_index() {
+            Some(index) if index + 1 < self.folders.len() => {
+                self.open_folder(index + 1);
+            }
+            _ => self.set_status(tr("No next folder")),
         }
     }
 
-    /// Get the currently selected image, if any
-    pub fn selected_image(&self) -> Option<&str> {
-        // Make sure index is valid
-        if self.images.is_empty() || self.selected_index >= self.images.len() {
-            warn!(
-                "Invalid selection index: {}, images count: {}",
-                self.selected_index,
-                self.images.len()
-            );
-            None
-        } else {
-            // Important: Get image directly from the array using the index
-            let selected = &self.images[self.selected_index];
-            info!(
-                "Getting selected image: index={}, image={}",
-                self.selected_index, selected
-            );
-            Some(selected)
+    /// Open the folder before the open one
+    pub fn prev_folder(&mut self) {
+        match self.folder_index() {
+            Some(index) if index > 0 => {
+                self.open_folder(index - 1);
+            }
+            _ => self.set_status(tr("No previous folder")),
+        }
+    }
+
+    /// List the folder at `index` and show its images from the first one
+    ///
+    /// The previous folder's listing is dropped, so only one folder is held at a time.
+    /// Returns whether the folder was opened.
+    fn open_folder(&mut self, index: usize) -> bool {
+        let folder = self.folders[index].clone();
+        self.set_status(&trf("Opening folder {}...", &[&folder]));
+        if let Err(e) = self
+            .camera
+            .open_image_folder(&folder, &mut self.image_cache)
+        {
+            self.set_status(&trf("Failed to open folder {}: {}", &[&folder, &e]));
+            return false;
+        }
+        if let Err(e) = self.image_cache.save(&image_cache_path()) {
+            warn!("{}", e);
         }
+
+        self.images = self.image_cache.images().to_vec();
+        self.image_selection.set_len(self.images.len());
+        self.image_selection.first();
+        self.set_status(&trf(
+            "Folder {} - {} images",
+            &[&folder, &self.images.len()],
+        ));
+        true
     }
 
-    /// Get the starting index for the current page
-    pub fn page_start_index(&self) -> usize {
-        let start = self.current_page_index * self.items_per_page;
-        info!("Page start index: {}", start);
-        start
+    /// Jump to first image
+    pub fn first_image(&mut self) {
+        self.image_selection.first();
+        info!("Selection set to first image");
     }
 
-    /// Get the ending index (exclusive) for the current page
-    pub fn page_end_index(&self) -> usize {
-        let start = self.page_start_index();
-        let end = start + self.items_per_page;
-        let actual_end = end.min(self.images.len());
+    /// Jump to last image
+    pub fn last_image(&mut self) {
+        self.image_selection.last();
         info!(
-            "Page end index: {} (min of {} and {})",
-            actual_end,
-            end,
-            self.images.len()
+            "Selection set to last image: index={}, page={}",
+            self.image_selection.selected(),
+            self.image_selection.page()
         );
-        actual_end
     }
 
-    /// Get the total number of pages
-    pub fn total_pages(&self) -> usize {
-        if self.images.is_empty() {
-            1
-        } else {
-            (self.images.len() + self.items_per_page - 1) / self.items_per_page
+    /// Get the currently selected image, if any
+    pub fn selected_image(&self) -> Option<&str> {
+        let index = self.image_selection.selected();
+        match self.images.get(index) {
+            Some(selected) => {
+                info!(
+                    "Getting selected image: index={}, image={}",
+                    index, selected
+                );
+                Some(selected)
+            }
+            None => {
+                warn!(
+                    "Invalid selection index: {}, images count: {}",
+                    index,
+                    self.images.len()
+                );
+                None
+            }
         }
     }
 }
@@ -0,0 +1,366 @@
+// src/terminal/ui_tests.rs
+// Drives the interface with scripted keys and messages against the demo
+// camera and checks the rendered screens, so regressions in menus, list
+// paging and dialogs show up without a camera or a terminal.
+use crate::camera::demo::DemoCamera;
+use crate::camera::events::CameraEvent;
+use crate::terminal::events::{self, AppMessage};
+use crate::terminal::handlers;
+use crate::terminal::menu::MAIN_MENU;
+use crate::terminal::renderer;
+use crate::terminal::state::{AppMode, AppState};
+use crate::utils::config::Config;
+use crate::utils::paths;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::OnceLock;
+use tempfile::TempDir;
+use tui::Terminal;
+use tui::backend::TestBackend;
+
+/// Size of the test terminal
+const WIDTH: u16 = 80;
+const HEIGHT: u16 = 30;
+
+/// Data directory shared by every test, since paths are set once per process
+static DATA_DIR: OnceLock<TempDir> = OnceLock::new();
+
+/// A connected state on a fresh demo camera, on the main menu
+fn demo_state() -> AppState {
+    let data_dir = DATA_DIR.get_or_init(|| TempDir::new().unwrap());
+    let camera = DemoCamera::start().unwrap();
+
+    let mut config = Config {
+        camera_url: camera.url(),
+        ..Config::default()
+    };
+    config.paths.data_dir = data_dir.path().display().to_string();
+    config.paths.camera = "demo".to_string();
+    // Background pollers would change the screen while it is checked
+    config.events.enabled = false;
+    config.link.enabled = false;
+    config.latency.enabled = false;
+    config.prefetch.enabled = false;
+    paths::init(&config.paths);
+
+    AppState::new(&config).unwrap()
+}
+
+/// Send keys one at a time, as the event loop does
+fn press(state: &mut AppState, keys: &[KeyCode]) {
+    for key in keys {
+        handlers::handle_input(state, *key).unwrap();
+    }
+}
+
+/// Type keys one at a time, with macro recording and the Ctrl keys as in the event loop
+fn type_keys(state: &mut AppState, keys: &[KeyEvent]) {
+    for key in keys {
+        handlers::handle_key_event(state, key).unwrap();
+    }
+}
+
+/// Rendered screen, one string per row
+fn screen(state: &AppState) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+    terminal
+        .draw(|frame| renderer::render_screen(state, frame))
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| buffer.get(x, y).symbol.as_str())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Whether any row of the screen contains `text`
+fn shows(screen: &[String], text: &str) -> bool {
+    screen.iter().any(|row| row.contains(text))
+}
+
+/// Row holding the selection marker
+fn selected_row(screen: &[String]) -> &str {
+    screen
+        .iter()
+        .find(|row| row.contains(">> "))
+        .map(String::as_str)
+        .unwrap_or_default()
+}
+
+#[test]
+fn main_menu_lists_every_entry_with_the_first_selected() {
+    let state = demo_state();
+    let screen = screen(&state);
+
+    for entry in MAIN_MENU {
+        assert!(
+            shows(&screen, entry.label),
+            "{} missing:\n{}",
+            entry.label,
+            screen.join("\n")
+        );
+    }
+    assert!(selected_row(&screen).contains("Take Photo"));
+}
+
+#[test]
+fn main_menu_selection_follows_arrow_keys() {
+    let mut state = demo_state();
+
+    press(&mut state, &[KeyCode::Down, KeyCode::Down]);
+    assert!(selected_row(&screen(&state)).contains("Live View"));
+
+    press(&mut state, &[KeyCode::Up]);
+    assert!(selected_row(&screen(&state)).contains("View Images"));
+}
+
+#[test]
+fn image_list_pages_through_the_card() {
+    let mut state = demo_state();
+    press(&mut state, &[KeyCode::Down, KeyCode::Enter]);
+    assert_eq!(state.mode, AppMode::ImageList);
+
+    // 44 files at 15 per page
+    let first = screen(&state);
+    assert!(shows(&first, "Images (44 total) - Page 1/3"));
+    assert!(selected_row(&first).contains("P1010001.JPG"));
+
+    press(&mut state, &[KeyCode::PageDown]);
+    let second = screen(&state);
+    assert!(shows(&second, "Page 2/3"));
+    assert!(selected_row(&second).contains("P1010015.JPG"));
+    assert!(!shows(&second, "P1010014.JPG"));
+
+    press(&mut state, &[KeyCode::End]);
+    let last = screen(&state);
+    assert!(shows(&last, "Page 3/3"));
+    assert!(selected_row(&last).contains("P1010900.MOV"));
+
+    press(&mut state, &[KeyCode::Home]);
+    assert!(selected_row(&screen(&state)).contains("P1010001.JPG"));
+}
+
+#[test]
+fn paging_past_the_last_page_opens_the_next_folder() {
+    let mut state = demo_state();
+    press(&mut state, &[KeyCode::Down, KeyCode::Enter]);
+    assert!(shows(&screen(&state), "/DCIM/100OLYMP (folder 1 of 2)"));
+
+    press(&mut state, &[KeyCode::PageDown; 3]);
+    let next = screen(&state);
+    assert!(shows(
+        &next,
+        "/DCIM/101OLYMP (folder 2 of 2) - Images (5 total)"
+    ));
+    assert!(selected_row(&next).contains("P1020001.JPG"));
+    assert_eq!(state.image_cache.images().len(), 5);
+
+    // Paging back lands on the last page of the previous folder
+    press(&mut state, &[KeyCode::PageUp]);
+    let back = screen(&state);
+    assert!(shows(&back, "/DCIM/100OLYMP (folder 1 of 2)"));
+    assert!(shows(&back, "Page 3/3"));
+    assert!(selected_row(&back).contains("P1010030.JPG"));
+
+    press(&mut state, &[KeyCode::Char(']')]);
+    assert!(shows(&screen(&state), "/DCIM/101OLYMP"));
+    press(&mut state, &[KeyCode::Char(']')]);
+    assert_eq!(state.status, "No next folder");
+}
+
+#[test]
+fn moving_past_the_end_of_a_page_turns_it() {
+    let mut state = demo_state();
+    press(&mut state, &[KeyCode::Down, KeyCode::Enter]);
+
+    press(&mut state, &[KeyCode::Down; 15]);
+    let turned = screen(&state);
+    assert!(shows(&turned, "Page 2/3"));
+    assert_eq!(state.selected_image(), Some("P1010015.JPG"));
+    assert!(selected_row(&turned).contains("P1010015.JPG"));
+
+    press(&mut state, &[KeyCode::Up]);
+    let back = screen(&state);
+    assert!(shows(&back, "Page 1/3"));
+    assert!(selected_row(&back).contains("P1010014.JPG"));
+}
+
+#[test]
+fn download_dialog_opens_over_the_list_and_closes() {
+    let mut state = demo_state();
+    press(&mut state, &[KeyCode::Down, KeyCode::Enter, KeyCode::Down]);
+
+    press(&mut state, &[KeyCode::Char('d')]);
+    let open = screen(&state);
+    assert!(shows(&open, "Download Image"));
+    assert!(shows(&open, "Download P1010001.ORF"));
+
+    // Keys go to the dialog while it is open
+    press(&mut state, &[KeyCode::Down, KeyCode::Esc]);
+    let closed = screen(&state);
+    assert!(state.dialog.is_none());
+    assert!(!shows(&closed, "Download Image"));
+    assert_eq!(state.selected_image(), Some("P1010001.ORF"));
+}
+
+#[test]
+fn quit_from_the_menu_needs_no_confirmation_when_idle() {
+    let mut state = demo_state();
+    assert!(handlers::handle_input(&mut state, KeyCode::Char('q')).unwrap());
+    assert!(state.dialog.is_none());
+}
+
+#[test]
+fn record_video_starts_and_stops_a_movie() {
+    let mut state = demo_state();
+    let movies = |state: &AppState| {
+        state
+            .images
+            .iter()
+            .filter(|image| image.ends_with(".MOV"))
+            .count()
+    };
+    let before = movies(&state);
+
+    press(&mut state, &[KeyCode::Down, KeyCode::Down, KeyCode::Down]);
+    press(&mut state, &[KeyCode::Enter]);
+    assert!(state.movie_recording.is_some(), "{}", state.status);
+    assert!(selected_row(&screen(&state)).contains("recording 0:00"));
+
+    press(&mut state, &[KeyCode::Enter]);
+    assert!(state.status.starts_with("Video recording stopped after"));
+    assert_eq!(movies(&state), before + 1);
+}
+
+#[test]
+fn recorded_macro_replays_the_same_keys() {
+    let mut state = demo_state();
+    state.config.macros.step_millis = 0;
+    let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+    type_keys(&mut state, &[ctrl('r')]);
+    type_keys(
+        &mut state,
+        &[
+            key(KeyCode::Down),
+            key(KeyCode::Enter),
+            key(KeyCode::PageDown),
+        ],
+    );
+    type_keys(&mut state, &[ctrl('r')]);
+    assert!(shows(&screen(&state), "Name for the 3 keys recorded:"));
+
+    let name: Vec<KeyEvent> = "pages".chars().map(|c| key(KeyCode::Char(c))).collect();
+    type_keys(&mut state, &name);
+    type_keys(&mut state, &[key(KeyCode::Enter), key(KeyCode::Esc)]);
+    assert_eq!(state.mode, AppMode::Main);
+
+    type_keys(&mut state, &[ctrl('p')]);
+    while state.macro_playback.is_some() {
+        assert!(!handlers::process_macro(&mut state).unwrap());
+    }
+    // The list keeps its page, so the replayed PageDown turns one page further
+    assert_eq!(state.mode, AppMode::ImageList);
+    assert!(shows(&screen(&state), "Page 3/3"));
+    assert_eq!(state.status, "Macro pages finished");
+}
+
+#[test]
+fn camera_event_messages_reach_the_image_list() {
+    let mut state = demo_state();
+    let new_images = vec!["P1019999.JPG".to_string()];
+    let quit = events::dispatch(
+        &mut state,
+        AppMessage::Camera(CameraEvent::NewImages(new_images)),
+    );
+    assert!(!quit.unwrap());
+    assert_eq!(state.status, "New photo on camera: P1019999.JPG");
+    assert!(shows(&screen(&state), "View Images 1 new"));
+
+    events::dispatch(
+        &mut state,
+        AppMessage::UserInput(KeyEvent::from(KeyCode::Down)),
+    )
+    .unwrap();
+    events::dispatch(
+        &mut state,
+        AppMessage::UserInput(KeyEvent::from(KeyCode::Enter)),
+    )
+    .unwrap();
+    press(&mut state, &[KeyCode::End]);
+    assert!(selected_row(&screen(&state)).contains("P1019999.JPG"));
+}
+
+#[test]
+fn shutter_speed_changes_on_the_camera() {
+    let mut state = demo_state();
+    press(&mut state, &[KeyCode::Char('g')]);
+    assert_eq!(state.mode, AppMode::Settings);
+    let rows = screen(&state);
+    assert!(selected_row(&rows).contains("ISO: Auto"));
+    assert!(shows(&rows, "White balance: Auto"));
+
+    press(&mut state, &[KeyCode::Down, KeyCode::Right]);
+    assert_eq!(state.status, "Shutter speed set to 1/500");
+
+    // Reading the settings back shows what the camera stored
+    press(&mut state, &[KeyCode::Char('r')]);
+    assert!(selected_row(&screen(&state)).contains("Shutter speed: 1/500"));
+}
+
+#[test]
+fn settings_listed_in_the_caplist_are_all_read() {
+    use crate::camera::client::basic::ClientOperations;
+    use crate::camera::settings::{CAMERA_SETTINGS, CameraSettings};
+
+    let state = demo_state();
+    // The demo camera lists its properties under their Olympus names
+    assert!(state.camera.known_capabilities().caplist.is_some());
+
+    let settings = state.camera.read_settings().unwrap();
+    let read: Vec<_> = settings.iter().map(|state| state.setting).collect();
+    assert_eq!(read, CAMERA_SETTINGS);
+    assert!(settings.iter().all(|state| state.value.is_some()));
+}
+
+#[cfg(all(unix, feature = "player", feature = "graphics"))]
+#[test]
+fn closed_player_offers_the_terminal_view() {
+    use crate::terminal::video_viewer::handlers::create_video_viewer;
+    use bytes::Bytes;
+    use std::io::Cursor;
+    use std::process::Command;
+
+    let mut state = demo_state();
+    create_video_viewer(&mut state, "127.0.0.1", "Test Stream").unwrap();
+    let viewer = state.video_viewer.as_mut().unwrap();
+    viewer.is_playing = true;
+    let mut player = Command::new("true").spawn().unwrap();
+    player.wait().unwrap();
+    viewer.player = Some(player);
+
+    events::dispatch(&mut state, AppMessage::Tick).unwrap();
+    assert!(
+        state
+            .status
+            .starts_with("Player closed. Press p to relaunch it")
+    );
+    let viewer = state.video_viewer.as_ref().unwrap();
+    assert!(viewer.player.is_none());
+    assert!(!viewer.sink_names().contains(&"player".to_string()));
+
+    // A red frame is drawn with half blocks in the terminal view
+    let mut jpeg = Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgb8(image::RgbImage::from_pixel(32, 24, image::Rgb([255, 0, 0])))
+        .write_to(&mut jpeg, image::ImageOutputFormat::Jpeg(90))
+        .unwrap();
+    *viewer.latest_frame.lock().unwrap() = Some(Bytes::from(jpeg.into_inner()));
+    press(&mut state, &[KeyCode::Char('v')]);
+    let rows = screen(&state);
+    assert!(shows(&rows, "Terminal View"));
+    assert!(shows(&rows, "▀"));
+}
@@ -0,0 +1,337 @@
+// src/terminal/video_viewer/access.rs
+// Who may watch the MJPEG server. It only listens on this machine unless `lan`
+// is set, and then only with a token or a user name and password, so the
+// stream is never open to the whole network.
+// Browsers ask for the password themselves; players that can't, such as VLC or
+// an <img> tag, pass the token in the URL. With the tls feature and a
+// certificate configured the stream is served over HTTPS, so neither is sent
+// in the clear.
+#[cfg(feature = "tls")]
+use crate::utils::paths;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+/// Longest request head read from a client
+const MAX_REQUEST: usize = 8192;
+
+/// Time a client has to connect and send its request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time a frame may take to reach a client before it is disconnected
+const WRITE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Realm browsers show when asking for the password
+const REALM: &str = "Olympus live view";
+
+/// Protection for the MJPEG server
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerAccessConfig {
+    /// Listen on every network interface instead of only this machine
+    pub lan: bool,
+    /// Token accepted as `Authorization: Bearer <token>` or `?token=<token>` in the URL
+    pub token: Option<String>,
+    /// User name for HTTP basic authentication
+    pub username: Option<String>,
+    /// Password for HTTP basic authentication
+    pub password: Option<String>,
+    /// PEM certificate chain to serve HTTPS with, which needs the tls feature
+    pub tls_cert: Option<String>,
+    /// PEM private key of the certificate
+    pub tls_key: Option<String>,
+}
+
+impl ServerAccessConfig {
+    /// Whether clients have to send a token or password
+    pub fn requires_auth(&self) -> bool {
+        self.token.is_some() || self.basic_credentials().is_some()
+    }
+
+    /// Whether the stream is served over HTTPS
+    pub fn uses_tls(&self) -> bool {
+        self.tls_cert.is_some() || self.tls_key.is_some()
+    }
+
+    /// URL scheme clients connect with
+    pub fn scheme(&self) -> &'static str {
+        if self.uses_tls() { "https" } else { "http" }
+    }
+
+    /// Refuse settings that would leave the server less protected than they suggest
+    pub fn check(&self) -> Result<()> {
+        if self.username.is_some() != self.password.is_some() {
+            return Err(anyhow!(
+                "MJPEG server needs both a username and a password for basic authentication"
+            ));
+        }
+        if self.token.as_deref() == Some("") || self.password.as_deref() == Some("") {
+            return Err(anyhow!("MJPEG server token and password can't be empty"));
+        }
+        if self.lan && !self.requires_auth() {
+            return Err(anyhow!(
+                "MJPEG server needs a token or password to listen on the network"
+            ));
+        }
+        if self.tls_cert.is_some() != self.tls_key.is_some() {
+            return Err(anyhow!(
+                "MJPEG server needs both tls_cert and tls_key for HTTPS"
+            ));
+        }
+        Ok(())
+    }
+
+    /// `user:password` encoded the way basic authentication sends it
+    fn basic_credentials(&self) -> Option<String> {
+        let (user, password) = (self.username.as_ref()?, self.password.as_ref()?);
+        Some(base64(format!("{}:{}", user, password).as_bytes()))
+    }
+
+    /// Whether the request head `request` carries an accepted token or password
+    pub fn authorizes(&self, request: &str) -> bool {
+        if !self.requires_auth() {
+            return true;
+        }
+
+        let mut lines = request.lines();
+        let target = lines
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or("");
+        let url_token = target.split_once('?').and_then(|(_, query)| {
+            query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("token="))
+        });
+        let authorization = lines.find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim()
+                .eq_ignore_ascii_case("authorization")
+                .then(|| value.trim())
+        });
+
+        if let Some(token) = &self.token {
+            let bearer = authorization.and_then(|value| value.strip_prefix("Bearer "));
+            if [url_token, bearer]
+                .into_iter()
+                .flatten()
+                .any(|given| same(given.trim(), token))
+            {
+                return true;
+            }
+        }
+        if let Some(expected) = self.basic_credentials()
+            && let Some(given) = authorization.and_then(|value| value.strip_prefix("Basic "))
+        {
+            return same(given.trim(), &expected);
+        }
+        false
+    }
+
+    /// Response sent to a client without an accepted token or password
+    fn unauthorized(&self) -> String {
+        let challenge = if self.basic_credentials().is_some() {
+            format!("Basic realm=\"{}\"", REALM)
+        } else {
+            format!("Bearer realm=\"{}\"", REALM)
+        };
+        format!(
+            "HTTP/1.0 401 Unauthorized\r\n\
+             WWW-Authenticate: {}\r\n\
+             Content-Length: 0\r\n\
+             Connection: close\r\n\r\n",
+            challenge
+        )
+    }
+}
+
+/// Compare secrets without stopping at the first difference
+fn same(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Standard base64 with padding
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, byte)| {
+            bits | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Connection to a client watching the stream, plain or over TLS
+pub trait ClientConnection: Read + Write + Send {
+    /// Socket underneath the connection
+    fn socket(&self) -> &TcpStream;
+}
+
+impl ClientConnection for TcpStream {
+    fn socket(&self) -> &TcpStream {
+        self
+    }
+}
+
+#[cfg(feature = "tls")]
+impl ClientConnection for rustls::StreamOwned<rustls::ServerConnection, TcpStream> {
+    fn socket(&self) -> &TcpStream {
+        &self.sock
+    }
+}
+
+/// Access rules of a running server, with its certificate loaded
+pub struct ServerAccess {
+    config: ServerAccessConfig,
+    #[cfg(feature = "tls")]
+    tls: Option<std::sync::Arc<rustls::ServerConfig>>,
+}
+
+impl ServerAccess {
+    /// Check the settings and load the certificate, if HTTPS is configured
+    pub fn new(config: &ServerAccessConfig) -> Result<Self> {
+        config.check()?;
+
+        #[cfg(feature = "tls")]
+        let tls = match (&config.tls_cert, &config.tls_key) {
+            (Some(cert), Some(key)) => Some(tls_config(cert, key)?),
+            _ => None,
+        };
+        #[cfg(not(feature = "tls"))]
+        if config.uses_tls() {
+            return Err(anyhow!("HTTPS for the MJPEG server needs the tls feature"));
+        }
+
+        Ok(Self {
+            config: config.clone(),
+            #[cfg(feature = "tls")]
+            tls,
+        })
+    }
+
+    /// Read a client's request and answer it
+    ///
+    /// Returns the connection to send frames to, or `None` if the client was
+    /// refused for lack of a token or password.
+    pub fn admit(&self, stream: TcpStream) -> Result<Option<Box<dyn ClientConnection>>> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+        stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+
+        #[cfg(feature = "tls")]
+        let mut client: Box<dyn ClientConnection> = match &self.tls {
+            Some(tls) => Box::new(rustls::StreamOwned::new(
+                rustls::ServerConnection::new(std::sync::Arc::clone(tls))?,
+                stream,
+            )),
+            None => Box::new(stream),
+        };
+        #[cfg(not(feature = "tls"))]
+        let mut client: Box<dyn ClientConnection> = Box::new(stream);
+
+        let request = read_request(client.as_mut())?;
+        if !self.config.authorizes(&request) {
+            client.write_all(self.config.unauthorized().as_bytes())?;
+            client.flush()?;
+            return Ok(None);
+        }
+
+        let response = "HTTP/1.0 200 OK\r\n\
+            Content-Type: multipart/x-mixed-replace; boundary=frame\r\n\
+            Cache-Control: no-cache\r\n\
+            Connection: close\r\n\r\n";
+        client.write_all(response.as_bytes())?;
+        client.flush()?;
+        client.socket().set_write_timeout(Some(WRITE_TIMEOUT))?;
+        Ok(Some(client))
+    }
+}
+
+/// Request line and headers sent by a client
+fn read_request(client: &mut dyn ClientConnection) -> Result<String> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST {
+            return Err(anyhow!("Request is longer than {} bytes", MAX_REQUEST));
+        }
+        let read = client.read(&mut buffer)?;
+        if read == 0 {
+            return Err(anyhow!("Client closed the connection before its request"));
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+/// Server TLS settings from PEM files, relative to the camera directory
+#[cfg(feature = "tls")]
+fn tls_config(cert: &str, key: &str) -> Result<std::sync::Arc<rustls::ServerConfig>> {
+    use anyhow::Context;
+    use rustls::pki_types::pem::PemObject;
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+    let (cert, key) = (paths::resolve(cert), paths::resolve(key));
+    let certs = CertificateDer::pem_file_iter(&cert)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| anyhow!("Failed to read certificate {:?}: {}", cert, e))?;
+    let key = PrivateKeyDer::from_pem_file(&key)
+        .map_err(|e| anyhow!("Failed to read private key {:?}: {}", key, e))?;
+
+    let provider = std::sync::Arc::new(rustls::crypto::ring::default_provider());
+    let config = rustls::ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .context("Certificate and private key don't make a usable TLS setup")?;
+    Ok(std::sync::Arc::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_need_the_token_or_password() {
+        let config = ServerAccessConfig {
+            token: Some("s3cret".to_string()),
+            username: Some("viewer".to_string()),
+            password: Some("pass word".to_string()),
+            ..ServerAccessConfig::default()
+        };
+        let basic = format!("Basic {}", base64(b"viewer:pass word"));
+        assert_eq!(basic, "Basic dmlld2VyOnBhc3Mgd29yZA==");
+
+        assert!(!config.authorizes("GET / HTTP/1.1\r\n\r\n"));
+        assert!(config.authorizes("GET /?token=s3cret HTTP/1.1\r\n\r\n"));
+        assert!(!config.authorizes("GET /?token=s3cre HTTP/1.1\r\n\r\n"));
+        assert!(config.authorizes("GET / HTTP/1.1\r\nauthorization: Bearer s3cret\r\n\r\n"));
+        assert!(config.authorizes(&format!(
+            "GET / HTTP/1.1\r\nAuthorization: {}\r\n\r\n",
+            basic
+        )));
+        assert!(ServerAccessConfig::default().authorizes("GET / HTTP/1.1\r\n\r\n"));
+        assert!(config.check().is_ok());
+
+        let open_lan = ServerAccessConfig {
+            lan: true,
+            ..ServerAccessConfig::default()
+        };
+        assert!(open_lan.check().is_err());
+    }
+}
@@ -0,0 +1,89 @@
+// src/terminal/video_viewer/adjust.rs
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::settings::{CameraSetting, EV_VALUES, WB_PRESETS};
+use anyhow::Result;
+use log::{info, warn};
+
+/// Index of 0.0 EV in `EV_VALUES`
+const EV_NEUTRAL: usize = 9;
+
+/// Exposure compensation and white balance applied while streaming
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreviewAdjustments {
+    ev_index: usize,
+    wb_index: usize,
+}
+
+impl Default for PreviewAdjustments {
+    fn default() -> Self {
+        Self {
+            ev_index: EV_NEUTRAL,
+            wb_index: 0,
+        }
+    }
+}
+
+impl PreviewAdjustments {
+    /// Start from the camera's current values, falling back to 0.0 EV and auto WB
+    pub fn read(camera: &impl ClientOperations) -> Self {
+        let mut adjustments = Self::default();
+
+        match camera.get_camprop(CameraSetting::ExposureCompensation.property()) {
+            Ok(value) => match EV_VALUES.iter().position(|ev| *ev == value) {
+                Some(index) => adjustments.ev_index = index,
+                None => warn!("Unexpected exposure compensation value: {}", value),
+            },
+            Err(e) => warn!("Could not read exposure compensation: {}", e),
+        }
+
+        match camera.get_camprop(CameraSetting::WhiteBalance.property()) {
+            Ok(value) => match WB_PRESETS.iter().position(|(wb, _)| *wb == value) {
+                Some(index) => adjustments.wb_index = index,
+                None => warn!("Unexpected white balance value: {}", value),
+            },
+            Err(e) => warn!("Could not read white balance: {}", e),
+        }
+
+        adjustments
+    }
+
+    /// Current exposure compensation, e.g. `+0.7`
+    pub fn ev(&self) -> &'static str {
+        EV_VALUES[self.ev_index]
+    }
+
+    /// Name of the current white balance preset
+    pub fn wb_label(&self) -> &'static str {
+        WB_PRESETS[self.wb_index].1
+    }
+
+    /// Move exposure compensation by `steps` third stops and send it to the camera
+    pub fn step_ev(
+        &mut self,
+        camera: &impl ClientOperations,
+        steps: isize,
+    ) -> Result<&'static str> {
+        let index = self
+            .ev_index
+            .saturating_add_signed(steps)
+            .min(EV_VALUES.len() - 1);
+        if index != self.ev_index {
+            camera.set_camprop(
+                CameraSetting::ExposureCompensation.property(),
+                EV_VALUES[index],
+            )?;
+            self.ev_index = index;
+            info!("Exposure compensation set to {} EV", self.ev());
+        }
+        Ok(self.ev())
+    }
+
+    /// Switch to the next white balance preset and send it to the camera
+    pub fn next_wb(&mut self, camera: &impl ClientOperations) -> Result<&'static str> {
+        let index = (self.wb_index + 1) % WB_PRESETS.len();
+        camera.set_camprop(CameraSetting::WhiteBalance.property(), WB_PRESETS[index].0)?;
+        self.wb_index = index;
+        info!("White balance set to {}", self.wb_label());
+        Ok(self.wb_label())
+    }
+}
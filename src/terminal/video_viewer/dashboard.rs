@@ -0,0 +1,286 @@
+// src/terminal/video_viewer/dashboard.rs
+// Health of several cameras at once. Each configured camera gets a monitor
+// thread running a bare live view receiver on its own port, which counts frames
+// and lost packets without assembling or forwarding anything, and polling the
+// camera state for the battery level.
+use crate::camera::client::timeouts::TimeoutConfig;
+use crate::camera::connection::init::ConnectionManager;
+use crate::camera::olympus::OlympusCamera;
+use crate::net::address;
+use crate::terminal::video_viewer::olympus_udp;
+use crate::terminal::video_viewer::pool::ReceiveArena;
+use crate::terminal::video_viewer::rtp::RtpPacket;
+use crate::terminal::video_viewer::stats::{StreamHealth, StreamStats};
+use anyhow::Result;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Seconds of statistics the loss figure covers
+const LOSS_WINDOW_SECONDS: usize = 5;
+
+/// Sequence jumps this large are a restarted stream rather than loss
+const MAX_SEQUENCE_GAP: u16 = 1000;
+
+/// One camera shown on the dashboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardCamera {
+    /// Name shown in the dashboard
+    pub name: String,
+    /// Address the camera is reached at
+    pub url: String,
+}
+
+/// Multi-camera dashboard settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashboardConfig {
+    /// Cameras to monitor
+    pub cameras: Vec<DashboardCamera>,
+    /// Live view port of the first camera, the others following on consecutive ports
+    pub first_udp_port: u16,
+    /// Seconds between battery polls, and between retries for cameras that can't be reached
+    pub poll_secs: u64,
+}
+
+impl Default for DashboardConfig {
+    fn default() -> Self {
+        Self {
+            cameras: Vec::new(),
+            first_udp_port: 65010,
+            poll_secs: 10,
+        }
+    }
+}
+
+/// Latest figures from one camera's monitor
+#[derive(Debug, Clone, Default)]
+pub struct CameraStatus {
+    /// Why the camera or its stream can't be monitored
+    pub error: Option<String>,
+    /// Battery level from the last state poll
+    pub battery: Option<String>,
+    /// When the last complete frame arrived
+    pub last_frame: Option<Instant>,
+    /// Frames during the last completed second
+    pub fps: u64,
+    /// Share of packets lost over the last few seconds
+    pub loss_percent: f64,
+}
+
+impl CameraStatus {
+    /// Stream health, once a frame has arrived
+    pub fn health(&self) -> Option<StreamHealth> {
+        self.last_frame
+            .map(|time| StreamHealth::assess(time.elapsed(), None))
+    }
+}
+
+/// Monitors every configured camera until dropped
+pub struct Dashboard {
+    monitors: Vec<CameraMonitor>,
+}
+
+impl Dashboard {
+    /// Start a monitor for each configured camera
+    pub fn start(config: &DashboardConfig, timeouts: &TimeoutConfig) -> Self {
+        let interval = Duration::from_secs(config.poll_secs.max(1));
+        let monitors = config
+            .cameras
+            .iter()
+            .zip(config.first_udp_port..)
+            .map(|(camera, port)| CameraMonitor::start(camera, port, interval, timeouts))
+            .collect();
+        Self { monitors }
+    }
+
+    /// Number of cameras shown
+    pub fn len(&self) -> usize {
+        self.monitors.len()
+    }
+
+    /// Camera at `index`
+    pub fn camera(&self, index: usize) -> Option<&DashboardCamera> {
+        self.monitors.get(index).map(|monitor| &monitor.camera)
+    }
+
+    /// Every camera with its latest figures, in configured order
+    pub fn statuses(&self) -> Vec<(&DashboardCamera, CameraStatus)> {
+        self.monitors
+            .iter()
+            .map(|monitor| {
+                let status = monitor
+                    .status
+                    .lock()
+                    .map(|status| status.clone())
+                    .unwrap_or_default();
+                (&monitor.camera, status)
+            })
+            .collect()
+    }
+}
+
+impl Drop for Dashboard {
+    fn drop(&mut self) {
+        // Let every monitor wind down at once before each is joined
+        for monitor in &self.monitors {
+            monitor.running.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Background monitor of one camera
+struct CameraMonitor {
+    camera: DashboardCamera,
+    status: Arc<Mutex<CameraStatus>>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl CameraMonitor {
+    fn start(
+        camera: &DashboardCamera,
+        udp_port: u16,
+        interval: Duration,
+        timeouts: &TimeoutConfig,
+    ) -> Self {
+        let status = Arc::new(Mutex::new(CameraStatus::default()));
+        let running = Arc::new(AtomicBool::new(true));
+        let watcher = Watcher {
+            camera: OlympusCamera::new(&camera.url, timeouts.clone()),
+            url: camera.url.clone(),
+            udp_port,
+            interval,
+            status: Arc::clone(&status),
+            running: Arc::clone(&running),
+        };
+
+        info!("Monitoring {} on port {}", camera.name, udp_port);
+        Self {
+            camera: camera.clone(),
+            status,
+            running,
+            handle: Some(thread::spawn(move || watcher.run())),
+        }
+    }
+}
+
+impl Drop for CameraMonitor {
+    fn drop(&mut self) {
+        // Joining makes sure live view is stopped before the camera is used elsewhere
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// State of a monitor thread
+struct Watcher {
+    camera: OlympusCamera,
+    url: String,
+    udp_port: u16,
+    interval: Duration,
+    status: Arc<Mutex<CameraStatus>>,
+    running: Arc<AtomicBool>,
+}
+
+impl Watcher {
+    fn run(self) {
+        let mut socket: Option<UdpSocket> = None;
+        let mut last_poll: Option<Instant> = None;
+        let mut stats = StreamStats::new();
+        let mut last_sequence: Option<u16> = None;
+        let mut arena = ReceiveArena::default();
+
+        while self.running.load(Ordering::Relaxed) {
+            if last_poll.is_none_or(|time| time.elapsed() >= self.interval) {
+                last_poll = Some(Instant::now());
+                self.poll_state();
+                if socket.is_none() {
+                    socket = self
+                        .start_stream()
+                        .map_err(|e| self.update(|status| status.error = Some(e.to_string())))
+                        .ok();
+                }
+            }
+
+            let Some(receiver) = &socket else {
+                thread::sleep(Duration::from_millis(500));
+                continue;
+            };
+
+            // Times out regularly so the running flag and polls are checked
+            if let Ok(datagram) = arena.recv(receiver) {
+                stats.record_packet(datagram.len());
+                if let Some(packet) = RtpPacket::parse(datagram) {
+                    if let Some(previous) = last_sequence {
+                        let gap = packet.sequence.wrapping_sub(previous).wrapping_sub(1);
+                        if gap > 0 && gap < MAX_SEQUENCE_GAP {
+                            stats.record_loss(gap as u64);
+                        }
+                    }
+                    last_sequence = Some(packet.sequence);
+
+                    if packet.marker && packet.continues_frame() {
+                        stats.record_frame();
+                        self.update(|status| status.last_frame = Some(Instant::now()));
+                    }
+                }
+            }
+
+            stats.roll();
+            let recent = stats.recent(LOSS_WINDOW_SECONDS);
+            let lost: u64 = recent.iter().map(|sample| sample.lost).sum();
+            let expected = lost + recent.iter().map(|sample| sample.packets).sum::<u64>();
+            self.update(|status| {
+                status.fps = recent.last().map_or(0, |sample| sample.frames);
+                status.loss_percent = match expected {
+                    0 => 0.0,
+                    expected => lost as f64 * 100.0 / expected as f64,
+                };
+            });
+        }
+
+        if socket.is_some() {
+            let _ = olympus_udp::stop_live_view(&self.camera);
+        }
+        info!("Stopped monitoring {}", self.url);
+    }
+
+    /// Read the battery level, noting whether the camera answers at all
+    fn poll_state(&self) {
+        match self.camera.get_state() {
+            Ok(state) => {
+                self.update(|status| {
+                    status.battery = state.battery;
+                    status.error = None;
+                });
+            }
+            Err(e) => {
+                warn!("Dashboard camera {} not answering: {}", self.url, e);
+                self.update(|status| status.error = Some(e.to_string()));
+            }
+        }
+    }
+
+    /// Start live view towards a socket of our own
+    fn start_stream(&self) -> Result<UdpSocket> {
+        self.camera.connect()?;
+        olympus_udp::initialize_camera(&self.camera, self.udp_port)?;
+
+        let socket = UdpSocket::bind(address::receiver_address(&self.url, self.udp_port))?;
+        socket.set_read_timeout(Some(Duration::from_millis(500)))?;
+        Ok(socket)
+    }
+
+    fn update(&self, change: impl FnOnce(&mut CameraStatus)) {
+        if let Ok(mut status) = self.status.lock() {
+            change(&mut status);
+        }
+    }
+}
@@ -1,14 +1,26 @@
 // src/terminal/video_viewer/handlers.rs
+use crate::camera::client::basic::ClientOperations;
 use crate::camera::connection::init::ConnectionManager;
+use crate::net::address;
+use crate::terminal::handlers;
 use crate::terminal::state::{AppMode, AppState};
+use crate::terminal::video_viewer::adjust::PreviewAdjustments;
 use crate::terminal::video_viewer::olympus_udp;
+use crate::terminal::video_viewer::quality::QualityController;
+use crate::terminal::video_viewer::sinks::DEFAULT_HTTP_PORT;
+use crate::terminal::video_viewer::snapshot::SnapshotLoop;
 use crate::terminal::video_viewer::state::VideoViewerState;
+use crate::terminal::video_viewer::stills;
+use crate::utils::alert::{self, AlertEvent};
+use crate::utils::disk::{self, SpaceCheck};
+#[cfg(feature = "player")]
+use crate::utils::exec;
+use crate::utils::i18n::{tr, trf};
+use crate::utils::paths::{self, DataDir};
 use anyhow::{Result, anyhow};
 use crossterm::event::KeyCode;
 use log::{error, info, warn};
-use std::path::Path;
-use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Create a video viewer for the given stream
 pub fn create_video_viewer(
@@ -18,33 +30,50 @@ pub fn create_video_viewer(
 ) -> Result<()> {
     info!("Creating Olympus video viewer for stream: {}", stream_name);
 
-    // Check if MPlayer is available
-    match Command::new("which").arg("mplayer").output() {
-        Ok(output) if output.status.success() => {
-            info!("MPlayer is available for Olympus streaming");
-        }
-        _ => {
-            // Check if FFplay is available as fallback
-            match Command::new("which").arg("ffplay").output() {
-                Ok(output) if output.status.success() => {
-                    info!("FFplay is available as fallback player");
-                }
-                _ => {
-                    warn!(
-                        "Neither MPlayer nor FFplay found. Please install one of them for streaming"
-                    );
-                    app_state
-                        .set_status("Video player not found. Please install MPlayer or FFplay");
-                }
-            }
-        }
+    // Check if MPlayer is available, with FFplay as fallback
+    #[cfg(feature = "player")]
+    if exec::has_program("mplayer") {
+        info!("MPlayer is available for Olympus streaming");
+    } else if exec::has_program("ffplay") {
+        info!("FFplay is available as fallback player");
+    } else {
+        warn!("Neither MPlayer nor FFplay found. Please install one of them for streaming");
+        app_state.set_status(tr(
+            "Video player not found. Please install MPlayer or FFplay",
+        ));
     }
 
     // Create the viewer state
-    let viewer_state = VideoViewerState::new(stream_url, stream_name);
+    let mut viewer_state = VideoViewerState::new(stream_url, stream_name);
+    viewer_state.player_window = app_state.config.player_window.clone();
+    viewer_state.orientation = app_state
+        .config
+        .orientation
+        .for_profile(app_state.selected_profile().as_deref());
+    viewer_state.privacy = app_state.config.privacy.clone();
+    viewer_state.http_access = app_state.config.http_access.clone();
+
+    // Keep recent frames around so the last few seconds can be exported
+    let replay = &app_state.config.replay;
+    if replay.enabled {
+        viewer_state
+            .start_replay_buffer(Duration::from_secs(replay.seconds))
+            .unwrap_or_else(|e| warn!("Failed to start replay buffer: {}", e));
+    }
+
+    // Step the live view size down when the link gets poor
+    let quality = &app_state.config.adaptive_quality;
+    if quality.enabled {
+        let capabilities = app_state.camera.known_capabilities();
+        match QualityController::new(quality, capabilities.liveview_sizes()) {
+            Ok(controller) => viewer_state.quality = Some(controller),
+            Err(e) => warn!("Adaptive live view size disabled: {}", e),
+        }
+    }
+
     app_state.video_viewer = Some(viewer_state);
     app_state.set_mode(AppMode::ViewingVideo);
-    app_state.set_status(&format!("Viewing video stream: {}", stream_name));
+    app_state.set_status(&trf("Viewing video stream: {}", &[&stream_name]));
 
     Ok(())
 }
@@ -52,14 +81,14 @@ pub fn create_video_viewer(
 /// Create a live view stream to the Olympus camera
 pub fn create_live_view(app_state: &mut AppState) -> Result<()> {
     info!("Creating live view stream to camera");
-    app_state.set_status("Initializing camera for live view...");
+    app_state.set_status(tr("Initializing camera for live view..."));
 
     // Make sure the camera is connected
     match app_state.camera.connect() {
         Ok(_) => info!("Camera connection successful"),
         Err(e) => {
             error!("Failed to connect to camera: {}", e);
-            app_state.set_status(&format!("Failed to connect to camera: {}", e));
+            app_state.set_status(&trf("Failed to connect to camera: {}", &[&e]));
             return Err(anyhow!("Failed to connect to camera: {}", e));
         }
     }
@@ -71,27 +100,32 @@ pub fn create_live_view(app_state: &mut AppState) -> Result<()> {
     match olympus_udp::initialize_camera(&app_state.camera, udp_port) {
         Ok(_) => {
             info!("Camera initialized for live view on port {}", udp_port);
-            app_state.set_status(&format!("Live view started on port {}", udp_port));
+            app_state.set_status(&trf("Live view started on port {}", &[&udp_port]));
         }
         Err(e) => {
             error!("Failed to start live view: {}", e);
-            app_state.set_status(&format!("Failed to start live view: {}", e));
+            app_state.set_status(&trf("Failed to start live view: {}", &[&e]));
             return Err(anyhow!("Failed to start live view: {}", e));
         }
     }
 
     // Create the video viewer
-    match create_video_viewer(app_state, "192.168.0.10", "Camera Live View") {
+    let camera_url = app_state.config.camera_url.clone();
+    let camera_host = address::camera_host(&camera_url).unwrap_or_else(|_| camera_url.clone());
+    match create_video_viewer(app_state, &camera_host, "Camera Live View") {
         Ok(_) => {
             if let Some(viewer_state) = &mut app_state.video_viewer {
                 viewer_state.udp_port = udp_port;
+                viewer_state.listen_address = address::receiver_address(&camera_url, udp_port);
+                viewer_state.adjustments = PreviewAdjustments::read(&app_state.camera);
 
                 // Start the stream
                 if let Err(e) = olympus_udp::start_udp_receiver(viewer_state) {
                     error!("Failed to start UDP receiver: {}", e);
-                    app_state.set_status(&format!("Failed to start video stream: {}", e));
+                    app_state.set_status(&trf("Failed to start video stream: {}", &[&e]));
+                    alert::notify(&app_state.config.alerts, AlertEvent::StreamError);
                 } else {
-                    app_state.set_status("Video stream started successfully");
+                    app_state.set_status(tr("Video stream started successfully"));
                 }
             }
         }
@@ -104,18 +138,49 @@ pub fn create_live_view(app_state: &mut AppState) -> Result<()> {
     Ok(())
 }
 
-/// Handle input for the video viewer
-pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
-    match key {
-        KeyCode::Char('q') => {
-            // Quit application
+/// Start the camera stream and receiver again after a hard stop
+fn start_stream(state: &mut AppState) {
+    let udp_port = state
+        .video_viewer
+        .as_ref()
+        .map_or(65001, |viewer_state| viewer_state.udp_port);
+
+    match olympus_udp::initialize_camera(&state.camera, udp_port) {
+        Ok(_) => {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+
             if let Some(viewer_state) = &mut state.video_viewer {
-                let _ = olympus_udp::stop_udp_receiver(viewer_state);
-                let _ = olympus_udp::stop_live_view(&state.camera);
+                if let Err(e) = olympus_udp::start_udp_receiver(viewer_state) {
+                    state.set_status(&trf("Failed to start video stream: {}", &[&e]));
+                    alert::notify(&state.config.alerts, AlertEvent::StreamError);
+                } else {
+                    state.set_status(tr("Stream started"));
+                }
             }
-            return Ok(true);
         }
-        KeyCode::Esc => {
+        Err(e) => state.set_status(&trf("Failed to restart live view: {}", &[&e])),
+    }
+}
+
+/// Replace the UDP receiver with the snapshot loop, keeping the player and sinks
+pub fn start_snapshot_fallback(state: &mut AppState) {
+    let Some(viewer_state) = &mut state.video_viewer else {
+        return;
+    };
+    olympus_udp::stop_udp_thread(viewer_state);
+    let _ = olympus_udp::stop_live_view(&state.camera);
+
+    let config = &state.config.snapshot_fallback;
+    let snapshot_loop = SnapshotLoop::start(&state.camera, config, viewer_state);
+    let fps = format!("{:.1}", snapshot_loop.fps());
+    viewer_state.snapshot_loop = Some(snapshot_loop);
+    state.set_status(&trf("Snapshot loop running at {} fps", &[&fps]));
+}
+
+/// Handle input for the video viewer
+pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Esc | KeyCode::Char('q') => {
             // Return to main menu
             if let Some(viewer_state) = &mut state.video_viewer {
                 let _ = olympus_udp::stop_udp_receiver(viewer_state);
@@ -123,7 +188,12 @@ pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
             }
             state.set_mode(AppMode::Main);
             state.video_viewer = None;
-            state.set_status("Returned to main menu");
+            state.set_status(tr("Returned to main menu"));
+
+            // A camera opened from the dashboard goes back to it
+            if state.return_home_camera() {
+                handlers::open_dashboard(state);
+            }
         }
         KeyCode::Enter => {
             // Restart stream
@@ -139,7 +209,7 @@ pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
                 drop(viewer_state);
 
                 // Restart with a status update
-                state.set_status("Restarting stream...");
+                state.set_status(tr("Restarting stream..."));
 
                 // Add delay for better recovery
                 std::thread::sleep(std::time::Duration::from_millis(1000));
@@ -150,98 +220,277 @@ pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
                         Ok(_) => {
                             std::thread::sleep(std::time::Duration::from_millis(500));
                             if let Err(e) = olympus_udp::start_udp_receiver(viewer_state) {
-                                state.set_status(&format!("Failed to restart stream: {}", e));
+                                state.set_status(&trf("Failed to restart stream: {}", &[&e]));
+                                alert::notify(&state.config.alerts, AlertEvent::StreamError);
                             } else {
-                                state.set_status("Stream restarted successfully");
+                                state.set_status(tr("Stream restarted successfully"));
                             }
                         }
-                        Err(e) => state.set_status(&format!("Failed to restart live view: {}", e)),
+                        Err(e) => {
+                            state.set_status(&trf("Failed to restart live view: {}", &[&e]));
+                            alert::notify(&state.config.alerts, AlertEvent::StreamError);
+                        }
                     }
                 }
             }
         }
         KeyCode::Char(' ') => {
-            // Toggle play/pause
+            // Freeze or unfreeze output while the receiver keeps running
+            if let Some(viewer_state) = &mut state.video_viewer {
+                if !viewer_state.is_playing {
+                    start_stream(state);
+                } else if viewer_state.is_paused() {
+                    viewer_state.set_paused(false);
+                    state.set_status(tr("Playback resumed"));
+                } else {
+                    viewer_state.set_paused(true);
+                    state.set_status(tr("Playback paused"));
+                }
+            }
+        }
+        KeyCode::Char('x') => {
+            // Stop or start the camera stream and player entirely
             if let Some(viewer_state) = &mut state.video_viewer {
                 if viewer_state.is_playing {
                     let _ = olympus_udp::stop_udp_receiver(viewer_state);
                     let _ = olympus_udp::stop_live_view(&state.camera);
-
-                    // Drop the borrow of viewer_state
-                    drop(viewer_state);
-
-                    state.set_status("Playback paused");
+                    state.set_status(tr("Stream stopped"));
                 } else {
-                    // Store the UDP port for later use
-                    let udp_port = if let Some(vs) = &state.video_viewer {
-                        vs.udp_port
-                    } else {
-                        65001 // Default port
-                    };
-
-                    match olympus_udp::initialize_camera(&state.camera, udp_port) {
-                        Ok(_) => {
-                            std::thread::sleep(std::time::Duration::from_millis(500));
-
-                            if let Some(viewer_state) = &mut state.video_viewer {
-                                if let Err(e) = olympus_udp::start_udp_receiver(viewer_state) {
-                                    state.set_status(&format!("Failed to resume: {}", e));
-                                } else {
-                                    state.set_status("Playback resumed");
-                                }
-                            }
-                        }
-                        Err(e) => state.set_status(&format!("Failed to restart live view: {}", e)),
-                    }
+                    start_stream(state);
                 }
             }
         }
         KeyCode::Char('r') => {
             // Toggle recording
             if let Some(viewer_state) = &mut state.video_viewer {
-                if viewer_state.is_recording {
+                if viewer_state.is_recording() {
                     viewer_state.stop_recording();
-
-                    // Drop the borrow of viewer_state
-                    drop(viewer_state);
-
-                    state.set_status("Recording stopped");
+                    state.set_status(tr("Recording stopped"));
                 } else {
-                    // Create recordings directory if it doesn't exist
-                    let recordings_dir = Path::new("./recordings");
-                    if !recordings_dir.exists() {
-                        if let Err(e) = std::fs::create_dir_all(recordings_dir) {
-                            state.set_status(&format!(
-                                "Failed to create recordings directory: {}",
-                                e
-                            ));
-                            return Ok(false);
+                    // Generate filename based on current time
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs();
+                    let config = &state.config.recording;
+                    let recording_stem = paths::resolve_output(&config.directory)
+                        .join(format!("olympus_recording_{}", now));
+
+                    let disk = &state.config.disk;
+                    // Refuse to start rather than run out of space partway through
+                    let started =
+                        disk::check_free_space(&recording_stem, 0, disk).and_then(|space| {
+                            viewer_state
+                                .start_recording(&recording_stem, config, disk)
+                                .map(|_| space)
+                        });
+
+                    match started {
+                        Ok(SpaceCheck::Low(free)) => state.set_status(&trf(
+                            "Recording started with only {} MB of disk space free",
+                            &[&free],
+                        )),
+                        Ok(_) if config.segment_minutes > 0 => state.set_status(&trf(
+                            "Recording started in {}-minute segments - note: requires manual encoding later",
+                            &[&config.segment_minutes],
+                        )),
+                        Ok(_) => state.set_status(tr(
+                            "Recording started - note: requires manual encoding later",
+                        )),
+                        Err(e) => state.set_status(&trf("Failed to start recording: {}", &[&e])),
+                    }
+                }
+            }
+        }
+        KeyCode::Char('h') => {
+            // Toggle the MJPEG HTTP server
+            if let Some(viewer_state) = &mut state.video_viewer {
+                if viewer_state.http_port.is_some() {
+                    viewer_state.stop_http_server();
+                    state.set_status(tr("MJPEG server stopped"));
+                } else {
+                    match viewer_state.start_http_server(DEFAULT_HTTP_PORT) {
+                        Ok(_) => {
+                            let url = viewer_state.http_url().unwrap_or_default();
+                            state.set_status(&trf("Serving MJPEG stream on {}", &[&url]))
                         }
+                        Err(e) => state.set_status(&trf("Failed to start MJPEG server: {}", &[&e])),
                     }
-
-                    // Generate filename based on current time
+                }
+            }
+        }
+        KeyCode::Char('o') => {
+            // Toggle pushing the stream to a streaming platform
+            if let Some(viewer_state) = &mut state.video_viewer {
+                if viewer_state.is_streaming_rtmp() {
+                    viewer_state.stop_rtmp();
+                    state.set_status(tr("RTMP stream stopped"));
+                } else {
+                    match viewer_state.start_rtmp(&state.config.rtmp) {
+                        Ok(_) => {
+                            state.set_status(&trf("Streaming to {}", &[&state.config.rtmp.url]))
+                        }
+                        Err(e) => state.set_status(&trf("Failed to start RTMP stream: {}", &[&e])),
+                    }
+                }
+            }
+        }
+        KeyCode::Char('t') => {
+            // Toggle saving stills from the stream at an interval
+            if let Some(viewer_state) = &mut state.video_viewer {
+                let config = &state.config.stills;
+                if viewer_state.stills_saved().is_some() {
+                    let saved = viewer_state.stop_stills();
+                    if config.assemble_mp4 && !saved.is_empty() {
+                        match stills::assemble_mp4(saved, config.assemble_fps) {
+                            Ok(path) => state.set_status(&trf(
+                                "Interval stills stopped, assembling {} in the background",
+                                &[&path.display()],
+                            )),
+                            Err(e) => {
+                                state.set_status(&trf("Failed to assemble stills: {}", &[&e]))
+                            }
+                        }
+                    } else {
+                        state.set_status(&trf(
+                            "Interval stills stopped after {} frames",
+                            &[&saved.len()],
+                        ));
+                    }
+                } else {
+                    match viewer_state.start_stills(config) {
+                        Ok(_) => state.set_status(&trf(
+                            "Saving a still every {}s to {}",
+                            &[&config.interval_secs, &config.directory],
+                        )),
+                        Err(e) => {
+                            state.set_status(&trf("Failed to start interval stills: {}", &[&e]))
+                        }
+                    }
+                }
+            }
+        }
+        KeyCode::Char('s') => {
+            // Save the latest frame
+            if let Some(viewer_state) = &state.video_viewer {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                let snapshot_path = paths::output_dir(DataDir::Snapshots)
+                    .join(format!("olympus_snapshot_{}.jpg", now));
+
+                match viewer_state.save_snapshot(&snapshot_path) {
+                    Ok(_) => {
+                        state.set_status(&trf("Snapshot saved to {}", &[&snapshot_path.display()]))
+                    }
+                    Err(e) => state.set_status(&trf("Failed to save snapshot: {}", &[&e])),
+                }
+            }
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Char('-') => {
+            // Adjust exposure compensation while the feed shows the result
+            let steps = if key == KeyCode::Char('-') { -1 } else { 1 };
+            if let Some(viewer_state) = &mut state.video_viewer {
+                match viewer_state.adjustments.step_ev(&state.camera, steps) {
+                    Ok(ev) => state.set_status(&trf("Exposure compensation: {} EV", &[&ev])),
+                    Err(e) => {
+                        state.set_status(&trf("Failed to set exposure compensation: {}", &[&e]))
+                    }
+                }
+            }
+        }
+        KeyCode::Char('w') => {
+            // Cycle white balance presets
+            if let Some(viewer_state) = &mut state.video_viewer {
+                match viewer_state.adjustments.next_wb(&state.camera) {
+                    Ok(wb) => state.set_status(&trf("White balance: {}", &[&tr(wb)])),
+                    Err(e) => state.set_status(&trf("Failed to set white balance: {}", &[&e])),
+                }
+            }
+        }
+        KeyCode::Char('i') => {
+            // Export the last few seconds as an instant replay clip
+            if let Some(viewer_state) = &state.video_viewer {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+                let config = &state.config.replay;
+                let replay_stem = paths::resolve_output(&config.directory)
+                    .join(format!("olympus_replay_{}", now));
+
+                match viewer_state.export_replay(config, &replay_stem) {
+                    Ok(path) => state.set_status(&trf("Replay saved to {}", &[&path.display()])),
+                    Err(e) => state.set_status(&trf("Failed to export replay: {}", &[&e])),
+                }
+            }
+        }
+        KeyCode::Char('c') => {
+            // Toggle CSV export of per-second stream statistics
+            if let Some(viewer_state) = &mut state.video_viewer {
+                if viewer_state.stats_export_path().is_some() {
+                    viewer_state.stop_stats_export();
+                    state.set_status(tr("Statistics export stopped"));
+                } else {
                     let now = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
                         .unwrap()
                         .as_secs();
-                    let recording_path =
-                        recordings_dir.join(format!("olympus_recording_{}.mjpeg", now));
-
-                    if let Some(viewer_state) = &mut state.video_viewer {
-                        viewer_state.start_recording(recording_path);
-
-                        // Drop the borrow of viewer_state
-                        drop(viewer_state);
-
-                        state
-                            .set_status("Recording started - note: requires manual encoding later");
+                    let stats_path =
+                        paths::dir(DataDir::Logs).join(format!("stream_stats_{}.csv", now));
+
+                    match viewer_state.start_stats_export(&stats_path) {
+                        Ok(_) => state.set_status(&trf(
+                            "Exporting statistics to {}",
+                            &[&stats_path.display()],
+                        )),
+                        Err(e) => {
+                            state.set_status(&trf("Failed to start statistics export: {}", &[&e]))
+                        }
                     }
                 }
             }
         }
+        KeyCode::Char('p') => {
+            // Bring back a player window that was closed
+            #[cfg(feature = "player")]
+            if let Some(viewer_state) = &mut state.video_viewer {
+                if !viewer_state.is_playing {
+                    start_stream(state);
+                } else if viewer_state.player.is_some() {
+                    state.set_status(tr("The player is already running"));
+                } else {
+                    match olympus_udp::relaunch_player(viewer_state) {
+                        Ok(_) => state.set_status(tr("Player relaunched")),
+                        Err(e) => {
+                            state.set_status(&trf("Failed to relaunch the player: {}", &[&e]))
+                        }
+                    }
+                }
+            }
+            #[cfg(not(feature = "player"))]
+            state.set_status(tr("Built without the external player"));
+        }
+        KeyCode::Char('v') => {
+            // Draw the live view in the terminal, or go back to the stream details
+            #[cfg(feature = "graphics")]
+            if let Some(viewer_state) = &mut state.video_viewer {
+                viewer_state.terminal_view = !viewer_state.terminal_view;
+                if viewer_state.terminal_view {
+                    state.set_status(tr("Live view drawn in the terminal"));
+                } else {
+                    state.set_status(tr("Showing stream details"));
+                }
+            }
+            #[cfg(not(feature = "graphics"))]
+            state.set_status(tr(
+                "Drawing the live view in the terminal needs the graphics feature",
+            ));
+        }
         KeyCode::Char('d') => {
             // Run diagnostics
-            state.set_status("Running diagnostics...");
+            state.set_status(tr("Running diagnostics..."));
 
             // First store any data we need from viewer_state to use later
             let udp_port = if let Some(vs) = &state.video_viewer {
@@ -262,12 +511,12 @@ pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
             // Check camera connection
             match state.camera.connect() {
                 Ok(_) => {
-                    state.set_status("Camera connection verified");
+                    state.set_status(tr("Camera connection verified"));
 
                     // Test camera initialization
                     match olympus_udp::initialize_camera(&state.camera, udp_port) {
                         Ok(_) => {
-                            state.set_status("Camera initialized successfully");
+                            state.set_status(tr("Camera initialized successfully"));
                             std::thread::sleep(std::time::Duration::from_millis(500));
 
                             // Now we can re-borrow viewer_state for UDP streaming
@@ -276,23 +525,25 @@ pub fn handle_video_viewer_input(state: &mut AppState, key: KeyCode) -> Result<b
                                     Ok(_) => {
                                         // Don't forget to drop before status update
                                         drop(viewer_state);
-                                        state.set_status("Diagnostics complete, stream restarted");
+                                        state.set_status(tr(
+                                            "Diagnostics complete, stream restarted",
+                                        ));
                                     }
                                     Err(e) => {
                                         // Don't need to drop here since we didn't mutate
                                         drop(viewer_state);
-                                        state.set_status(&format!(
+                                        state.set_status(&trf(
                                             "Failed to start UDP receiver: {}",
-                                            e
+                                            &[&e],
                                         ));
                                     }
                                 }
                             }
                         }
-                        Err(e) => state.set_status(&format!("Failed to initialize camera: {}", e)),
+                        Err(e) => state.set_status(&trf("Failed to initialize camera: {}", &[&e])),
                     }
                 }
-                Err(e) => state.set_status(&format!("Camera connection failed: {}", e)),
+                Err(e) => state.set_status(&trf("Camera connection failed: {}", &[&e])),
             }
         }
         _ => {}
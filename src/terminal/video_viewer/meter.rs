@@ -0,0 +1,188 @@
+// src/terminal/video_viewer/meter.rs
+// Scene brightness for timelapse exposure ramping. Live view keeps running
+// between shots towards a receiver of its own, which assembles frames but only
+// hands one over, to be measured from its histogram, when a reading is asked for.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use crate::net::address;
+use crate::terminal::video_viewer::olympus_udp;
+use crate::terminal::video_viewer::pool::{FramePool, ReceiveArena};
+use crate::terminal::video_viewer::rtp::{FrameBuilder, RtpPacket};
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use log::{info, warn};
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Time to wait for a live view frame before restarting live view
+const FRAME_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Live view running between timelapse shots to meter the scene
+pub struct LiveViewMeter {
+    camera: OlympusCamera,
+    udp_port: u16,
+    /// Start time a frame needs to be handed over, while a reading is wanted
+    wanted: Arc<Mutex<Option<Instant>>>,
+    frames: Receiver<Bytes>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LiveViewMeter {
+    /// Start live view towards a receiver on `udp_port`
+    pub fn start(camera: &OlympusCamera, udp_port: u16) -> Result<Self> {
+        olympus_udp::initialize_camera(camera, udp_port)?;
+
+        let socket = UdpSocket::bind(address::receiver_address(camera.base_url(), udp_port))?;
+        socket.set_read_timeout(Some(Duration::from_millis(500)))?;
+
+        let wanted = Arc::new(Mutex::new(None));
+        let running = Arc::new(AtomicBool::new(true));
+        let (sender, frames) = mpsc::sync_channel(1);
+        let receiver = MeterReceiver {
+            socket,
+            wanted: Arc::clone(&wanted),
+            sender,
+            running: Arc::clone(&running),
+        };
+
+        info!("Metering live view on port {}", udp_port);
+        Ok(Self {
+            camera: camera.clone(),
+            udp_port,
+            wanted,
+            frames,
+            running,
+            handle: Some(thread::spawn(move || receiver.run())),
+        })
+    }
+
+    /// Mean brightness, 0 to 255, of a live view frame that started after `since`
+    pub fn brightness_after(&self, since: Instant) -> Option<f64> {
+        let frame = self.frame_after(since).or_else(|| {
+            // Some firmware ends live view when a picture is taken
+            warn!("No live view frame to meter, restarting live view");
+            self.restart()
+                .map_err(|e| warn!("Could not restart live view: {}", e))
+                .ok()?;
+            self.frame_after(since)
+        })?;
+
+        let brightness = mean_brightness(&frame);
+        match brightness {
+            Some(value) => info!("Scene brightness: {:.1}", value),
+            None => warn!("Could not measure scene brightness"),
+        }
+        brightness
+    }
+
+    /// Wait for the receiver to hand over a frame that started after `since`
+    fn frame_after(&self, since: Instant) -> Option<Bytes> {
+        // A frame left over from an earlier reading that timed out is stale
+        while self.frames.try_recv().is_ok() {}
+        self.set_wanted(Some(since));
+
+        let frame = self.frames.recv_timeout(FRAME_TIMEOUT).ok();
+        self.set_wanted(None);
+        frame
+    }
+
+    fn set_wanted(&self, since: Option<Instant>) {
+        if let Ok(mut wanted) = self.wanted.lock() {
+            *wanted = since;
+        }
+    }
+
+    /// Ask the camera to stream to the receiver again
+    fn restart(&self) -> Result<()> {
+        let start_command = format!("exec_takemisc.cgi?com=startliveview&port={}", self.udp_port);
+        self.camera
+            .get_page_with_timeout(&start_command, self.camera.timeouts.liveview_init())
+            .map_err(|e| anyhow!("Failed to start live view: {}", e))
+    }
+}
+
+impl Drop for LiveViewMeter {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        let _ = olympus_udp::stop_live_view(&self.camera);
+    }
+}
+
+/// State of the receiving thread
+struct MeterReceiver {
+    socket: UdpSocket,
+    wanted: Arc<Mutex<Option<Instant>>>,
+    sender: SyncSender<Bytes>,
+    running: Arc<AtomicBool>,
+}
+
+impl MeterReceiver {
+    fn run(self) {
+        let mut arena = ReceiveArena::default();
+        let mut frame = FrameBuilder::default();
+        let mut pool = FramePool::default();
+
+        while self.running.load(Ordering::Relaxed) {
+            // Times out regularly so the running flag is checked
+            let Some(packet) = arena.recv(&self.socket).ok().and_then(RtpPacket::parse) else {
+                continue;
+            };
+
+            if packet.starts_frame() {
+                frame.start(packet);
+            } else if packet.continues_frame() && frame.follows(&packet) {
+                let last = packet.marker;
+                frame.push(packet);
+                if last {
+                    let started = Instant::now() - frame.elapsed();
+                    if self.take_wanted(started) {
+                        let _ = self.sender.try_send(frame.finish(&mut pool));
+                    } else {
+                        frame.reset();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether a frame that started at `started` answers the pending reading
+    fn take_wanted(&self, started: Instant) -> bool {
+        let Ok(mut wanted) = self.wanted.lock() else {
+            return false;
+        };
+        if wanted.is_some_and(|since| started >= since) {
+            *wanted = None;
+            return true;
+        }
+        false
+    }
+}
+
+/// Mean luma of a JPEG, computed from its histogram
+fn mean_brightness(jpeg: &[u8]) -> Option<f64> {
+    let luma = image::load_from_memory(jpeg).ok()?.to_luma8();
+
+    let mut histogram = [0u64; 256];
+    for pixel in luma.pixels() {
+        histogram[pixel.0[0] as usize] += 1;
+    }
+
+    let count: u64 = histogram.iter().sum();
+    if count == 0 {
+        return None;
+    }
+    let total: u64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(level, n)| level as u64 * n)
+        .sum();
+    Some(total as f64 / count as f64)
+}
@@ -1,5 +1,25 @@
 // src/terminal/video_viewer/mod.rs
+pub mod access;
+pub mod adjust;
+pub mod dashboard;
 pub mod handlers;
+#[cfg(feature = "ramping")]
+pub mod meter;
 pub mod olympus_udp;
+pub mod orientation;
+pub mod output;
+pub mod pool;
+#[cfg(feature = "graphics")]
+pub mod preview;
+pub mod privacy;
+pub mod quality;
 pub mod renderer;
+pub mod replay;
+pub mod rtmp;
+pub mod rtp;
+pub mod sinks;
+pub mod snapshot;
 pub mod state;
+pub mod stats;
+pub mod stills;
+pub mod timing;
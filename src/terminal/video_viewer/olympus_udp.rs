@@ -1,14 +1,26 @@
 // src/terminal/video_viewer/olympus_udp.rs
 use crate::camera::client::basic::ClientOperations;
+use crate::camera::connection::{CameraMode, ModeManager};
+use crate::terminal::video_viewer::output;
+#[cfg(feature = "player")]
+use crate::terminal::video_viewer::output::{PipeWriter, StreamOutput};
+use crate::terminal::video_viewer::pool::{FramePool, ReceiveArena};
+use crate::terminal::video_viewer::rtp::{FrameBuilder, RtpPacket};
+use crate::terminal::video_viewer::sinks::{PLAYER_SINK, SinkRegistry};
 use crate::terminal::video_viewer::state::VideoViewerState;
+use crate::terminal::video_viewer::stats::StreamStats;
+use crate::terminal::video_viewer::timing::{FrameStamp, FrameTiming};
+#[cfg(feature = "player")]
+use crate::utils::exec::find_program;
+#[cfg(feature = "player")]
+use crate::utils::paths::{self, DataDir};
 use anyhow::{Result, anyhow};
 use log::{debug, error, info, warn};
-use std::process::{Command, Stdio};
+use std::process::Command;
+#[cfg(feature = "player")]
+use std::process::{ExitStatus, Stdio};
 use std::{
-    fs,
-    io::Write,
     net::UdpSocket,
-    path::Path,
     sync::{Arc, Mutex},
     thread,
     time::{Duration, Instant},
@@ -24,17 +36,27 @@ pub fn initialize_camera(
         udp_port
     );
 
+    let timeouts = &camera.timeouts;
+
+    // Live view needs rec mode; skip the switch if the camera is already there
+    if let Err(e) = camera.ensure_mode(CameraMode::Rec) {
+        error!(
+            "Camera initialization step failed: switch to rec mode - {}",
+            e
+        );
+        return Err(anyhow!("Failed to initialize camera: {}", e));
+    }
+
     // Full initialization sequence for Olympus camera
     let init_steps = [
         "get_connectmode.cgi",
-        "switch_cameramode.cgi?mode=rec",
         "get_state.cgi",
         "exec_takemisc.cgi?com=stopliveview", // Stop any existing stream first
     ];
 
     // Run initialization steps
     for step in &init_steps {
-        match camera.get_page(step) {
+        match camera.get_page_with_timeout(step, timeouts.liveview_init()) {
             Ok(_) => info!("Camera initialization step successful: {}", step),
             Err(e) => {
                 error!("Camera initialization step failed: {} - {}", step, e);
@@ -42,17 +64,17 @@ pub fn initialize_camera(
             }
         }
         // Add delay between commands
-        thread::sleep(Duration::from_millis(300));
+        thread::sleep(timeouts.step_delay(Duration::from_millis(300)));
     }
 
     // Start the live view stream with the specified port
     let start_command = format!("exec_takemisc.cgi?com=startliveview&port={}", udp_port);
 
-    match camera.get_page(&start_command) {
+    match camera.get_page_with_timeout(&start_command, timeouts.liveview_init()) {
         Ok(_) => {
             info!("Live view started successfully on port {}", udp_port);
             // Wait for camera to initialize streaming
-            thread::sleep(Duration::from_secs(1));
+            thread::sleep(timeouts.step_delay(Duration::from_secs(1)));
             Ok(())
         }
         Err(e) => {
@@ -78,6 +100,31 @@ pub fn stop_live_view(camera: &crate::camera::olympus::OlympusCamera) -> Result<
     }
 }
 
+/// Restart the camera's live view at another size, leaving the receiver running
+pub fn change_live_view_size(
+    camera: &crate::camera::olympus::OlympusCamera,
+    udp_port: u16,
+    size: &str,
+) -> Result<()> {
+    let timeouts = &camera.timeouts;
+
+    // The size can only be changed while live view is stopped
+    let steps = [
+        "exec_takemisc.cgi?com=stopliveview".to_string(),
+        format!("switch_cameramode.cgi?mode=rec&lvqty={}", size),
+        format!("exec_takemisc.cgi?com=startliveview&port={}", udp_port),
+    ];
+    for step in &steps {
+        camera
+            .get_page_with_timeout(step, timeouts.liveview_init())
+            .map_err(|e| anyhow!("Failed to change live view size: {}", e))?;
+        thread::sleep(timeouts.step_delay(Duration::from_millis(300)));
+    }
+
+    info!("Live view size changed to {}", size);
+    Ok(())
+}
+
 /// Start the UDP receiver for Olympus streaming
 pub fn start_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
     info!(
@@ -85,10 +132,12 @@ pub fn start_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
         viewer_state.udp_port
     );
 
-    // Bind to UDP port
-    let socket = match UdpSocket::bind(format!("0.0.0.0:{}", viewer_state.udp_port)) {
+    // Bind to UDP port on the address the camera sends to
+    let mut address = viewer_state.listen_address;
+    address.set_port(viewer_state.udp_port);
+    let socket = match UdpSocket::bind(address) {
         Ok(s) => {
-            info!("Successfully bound to UDP port {}", viewer_state.udp_port);
+            info!("Successfully bound to UDP address {}", address);
             s
         }
         Err(e) => {
@@ -101,7 +150,8 @@ pub fn start_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
             viewer_state.udp_port = 65002;
             info!("Trying alternate port: {}", viewer_state.udp_port);
 
-            match UdpSocket::bind(format!("0.0.0.0:{}", viewer_state.udp_port)) {
+            address.set_port(viewer_state.udp_port);
+            match UdpSocket::bind(address) {
                 Ok(s) => {
                     info!(
                         "Successfully bound to alternate UDP port {}",
@@ -127,18 +177,12 @@ pub fn start_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
     let socket_arc = Arc::new(Mutex::new(socket));
     *viewer_state.udp_running.lock().unwrap() = true;
 
-    // Setup for MPlayer
-    setup_pipe_for_player()?;
-
-    // Try starting MPlayer first, fallback to FFplay if it fails
-    let mplayer_result = start_mplayer_process(viewer_state);
-    if let Err(e) = mplayer_result {
-        warn!(
-            "Failed to start MPlayer: {}. Trying FFplay as fallback...",
-            e
-        );
-        if let Err(e) = start_ffplay_process(viewer_state) {
-            return Err(anyhow!("Failed to start video players: {}", e));
+    // Launch the external player and register it alongside the other sinks
+    #[cfg(feature = "player")]
+    {
+        let output = start_player(viewer_state)?;
+        if let Ok(mut sinks) = viewer_state.sinks.lock() {
+            sinks.add(Box::new(PipeWriter::start(output)));
         }
     }
 
@@ -155,122 +199,111 @@ pub fn start_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
     if let Ok(mut size) = viewer_state.last_frame_size.lock() {
         *size = 0;
     }
+    if let Ok(mut stats) = viewer_state.stream_stats.lock() {
+        stats.reset();
+    }
 
     // Pass viewer state stats counters as Arc<Mutex> to allow updating from thread
-    let packets_received = Arc::clone(&viewer_state.packets_received);
-    let jpeg_frames = Arc::clone(&viewer_state.jpeg_frames);
-    let last_frame_time = Arc::clone(&viewer_state.last_frame_time);
-    let last_frame_size = Arc::clone(&viewer_state.last_frame_size);
+    let shared = StreamShared {
+        sinks: Arc::clone(&viewer_state.sinks),
+        socket: Arc::clone(&socket_arc),
+        running: Arc::clone(&viewer_state.udp_running),
+        packets_received: Arc::clone(&viewer_state.packets_received),
+        jpeg_frames: Arc::clone(&viewer_state.jpeg_frames),
+        last_frame_time: Arc::clone(&viewer_state.last_frame_time),
+        last_frame_size: Arc::clone(&viewer_state.last_frame_size),
+        stream_stats: Arc::clone(&viewer_state.stream_stats),
+    };
 
     // Start UDP processing thread
-    let running_flag = Arc::clone(&viewer_state.udp_running);
-    let socket_clone = Arc::clone(&socket_arc);
-
-    let thread_handle = thread::spawn(move || {
-        process_udp_stream(
-            socket_clone,
-            running_flag,
-            packets_received,
-            jpeg_frames,
-            last_frame_time,
-            last_frame_size,
-        );
-    });
+    let thread_handle = thread::spawn(move || process_udp_stream(shared));
 
     viewer_state.udp_thread_handle = Some(thread_handle);
     viewer_state.is_playing = true;
+    viewer_state.fallback_offered = false;
 
     Ok(())
 }
 
-/// Setup named pipe for MPlayer
-fn setup_pipe_for_player() -> Result<()> {
-    let pipe_path = Path::new("olympus_stream.pipe");
-
-    // Log the current directory to ensure we know where to look for the pipe
-    info!(
-        "Current directory: {:?}",
-        std::env::current_dir().unwrap_or_default()
-    );
-
-    if pipe_path.exists() {
-        info!("Removing existing pipe");
-        match fs::remove_file(pipe_path) {
-            Ok(_) => info!("Successfully removed existing pipe"),
-            Err(e) => warn!("Failed to remove existing pipe: {}", e),
-        }
+/// Launch the external player again after its window was closed, feeding it the running stream
+#[cfg(feature = "player")]
+pub fn relaunch_player(viewer_state: &mut VideoViewerState) -> Result<()> {
+    let output = start_player(viewer_state)?;
+    if let Ok(mut sinks) = viewer_state.sinks.lock() {
+        sinks.add(Box::new(PipeWriter::start(output)));
     }
+    viewer_state.player_closed = false;
+    Ok(())
+}
 
-    #[cfg(unix)]
-    {
-        info!("Creating named pipe with mkfifo");
-        let output = Command::new("mkfifo")
-            .arg("-m")
-            .arg("0666") // More permissive mode for the pipe
-            .arg("olympus_stream.pipe")
-            .output()?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            warn!("mkfifo error: {}", stderr);
-            return Err(anyhow!("Failed to create pipe: {}", stderr));
-        }
-
-        info!("Successfully created named pipe");
+/// Notice the external player exiting on its own, detaching it from the stream
+///
+/// Returns the exit status once, when the player is found to have closed.
+#[cfg(feature = "player")]
+pub fn check_player(viewer_state: &mut VideoViewerState) -> Option<ExitStatus> {
+    let status = viewer_state.player.as_mut()?.try_wait().ok()??;
+    info!("Player exited with {}", status);
+
+    viewer_state.player = None;
+    viewer_state.external_viewer_pid = None;
+    viewer_state.player_closed = true;
+
+    // Stop feeding the pipe nobody reads any more
+    if let Ok(mut sinks) = viewer_state.sinks.lock() {
+        sinks.remove(PLAYER_SINK);
     }
+    output::remove_pipe();
 
-    #[cfg(windows)]
-    {
-        info!("Creating file for Windows");
-        match std::fs::File::create(pipe_path) {
-            Ok(file) => {
-                let _ = file.set_len(0);
-                info!("Successfully created file for streaming on Windows");
-            }
-            Err(e) => {
-                warn!("Failed to create file: {}", e);
-                return Err(anyhow!("Failed to create file: {}", e));
-            }
-        }
-    }
+    Some(status)
+}
 
-    // Verify pipe exists after creation
-    if pipe_path.exists() {
-        info!(
-            "Pipe exists at {:?}",
-            pipe_path.canonicalize().unwrap_or_default()
-        );
+/// Setup the player's input channel and launch MPlayer, falling back to FFplay
+#[cfg(feature = "player")]
+fn start_player(viewer_state: &mut VideoViewerState) -> Result<StreamOutput> {
+    let output = StreamOutput::create()?;
+    let player_input = output.player_input();
+
+    // Try starting MPlayer first, fallback to FFplay if it fails
+    let mplayer_result = if output.is_pipe() {
+        start_mplayer_process(viewer_state, &player_input)
     } else {
-        warn!("Pipe still doesn't exist after creation attempt");
+        Err(anyhow!(
+            "MPlayer can only read the stream from a named pipe"
+        ))
+    };
+    if let Err(e) = mplayer_result {
+        warn!(
+            "Failed to start MPlayer: {}. Trying FFplay as fallback...",
+            e
+        );
+        if let Err(e) = start_ffplay_process(viewer_state, &player_input) {
+            return Err(anyhow!("Failed to start video players: {}", e));
+        }
     }
 
-    Ok(())
+    Ok(output)
 }
 
 /// Launch MPlayer to display stream
-fn start_mplayer_process(viewer_state: &mut VideoViewerState) -> Result<()> {
+#[cfg(feature = "player")]
+fn start_mplayer_process(viewer_state: &mut VideoViewerState, input: &str) -> Result<()> {
     info!("Attempting to start MPlayer...");
 
     // First check if MPlayer is installed
-    let mplayer_check = Command::new("which").arg("mplayer").output();
-
-    match mplayer_check {
-        Ok(output) if output.status.success() => {
-            let path = String::from_utf8_lossy(&output.stdout);
-            info!("MPlayer found at: {}", path.trim());
-        }
-        _ => {
+    match find_program("mplayer") {
+        Some(path) => info!("MPlayer found at: {}", path.display()),
+        None => {
             error!("MPlayer not found in path!");
             return Err(anyhow!("MPlayer not found. Please install MPlayer first."));
         }
     }
 
     // Create a log file for MPlayer output
-    let log_path = Path::new("mplayer_log.txt");
+    let log_path = paths::dir(DataDir::Logs).join("mplayer_log.txt");
     let log_file = std::fs::File::create(log_path)?;
 
     // MPlayer arguments with more debugging
-    let mplayer_args = [
+    let mut mplayer_args: Vec<String> = [
         "-demuxer",
         "lavf",
         "-lavfdopts",
@@ -279,8 +312,12 @@ fn start_mplayer_process(viewer_state: &mut VideoViewerState) -> Result<()> {
         "-loop",
         "0",
         "-v", // Verbose output
-        "olympus_stream.pipe",
-    ];
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    mplayer_args.extend(viewer_state.player_args("mplayer"));
+    mplayer_args.push(input.to_string());
 
     info!("MPlayer command: mplayer {}", mplayer_args.join(" "));
 
@@ -292,46 +329,36 @@ fn start_mplayer_process(viewer_state: &mut VideoViewerState) -> Result<()> {
 
     let pid = child.id();
     viewer_state.external_viewer_pid = Some(pid);
+    viewer_state.player = Some(child);
     info!("Started MPlayer with PID: {}", pid);
 
     Ok(())
 }
 
 /// Launch FFplay as fallback player
-fn start_ffplay_process(viewer_state: &mut VideoViewerState) -> Result<()> {
+#[cfg(feature = "player")]
+fn start_ffplay_process(viewer_state: &mut VideoViewerState, input: &str) -> Result<()> {
     info!("Attempting to start FFplay...");
 
     // First check if FFplay is installed
-    let ffplay_check = Command::new("which").arg("ffplay").output();
-
-    match ffplay_check {
-        Ok(output) if output.status.success() => {
-            let path = String::from_utf8_lossy(&output.stdout);
-            info!("FFplay found at: {}", path.trim());
-        }
-        _ => {
+    match find_program("ffplay") {
+        Some(path) => info!("FFplay found at: {}", path.display()),
+        None => {
             warn!("FFplay not found in path!");
             return Err(anyhow!("FFplay not found"));
         }
     }
 
     // Create log file for FFplay
-    let log_path = Path::new("ffplay_log.txt");
+    let log_path = paths::dir(DataDir::Logs).join("ffplay_log.txt");
     let log_file = std::fs::File::create(log_path)?;
 
     // FFplay arguments for MJPEG stream
-    let ffplay_args = [
-        "-f",
-        "mjpeg",
-        "-i",
-        "olympus_stream.pipe",
-        "-loglevel",
-        "warning",
-        "-x",
-        "800",
-        "-y",
-        "600",
-    ];
+    let mut ffplay_args: Vec<String> = ["-f", "mjpeg", "-i", input, "-loglevel", "warning"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+    ffplay_args.extend(viewer_state.player_args("ffplay"));
 
     info!("FFplay command: ffplay {}", ffplay_args.join(" "));
 
@@ -343,70 +370,59 @@ fn start_ffplay_process(viewer_state: &mut VideoViewerState) -> Result<()> {
 
     let pid = child.id();
     viewer_state.external_viewer_pid = Some(pid);
+    viewer_state.player = Some(child);
     info!("Started FFplay with PID: {}", pid);
 
     Ok(())
 }
 
-/// Process stream data in a thread
-fn process_udp_stream(
-    socket_clone: Arc<Mutex<UdpSocket>>,
-    running_flag: Arc<Mutex<bool>>,
+/// Viewer state the receiving thread updates, shared with the UI
+struct StreamShared {
+    sinks: Arc<Mutex<SinkRegistry>>,
+    socket: Arc<Mutex<UdpSocket>>,
+    running: Arc<Mutex<bool>>,
     packets_received: Arc<Mutex<u32>>,
     jpeg_frames: Arc<Mutex<u32>>,
     last_frame_time: Arc<Mutex<Instant>>,
     last_frame_size: Arc<Mutex<usize>>,
-) {
+    stream_stats: Arc<Mutex<StreamStats>>,
+}
+
+/// Process stream data in a thread
+fn process_udp_stream(shared: StreamShared) {
+    let StreamShared {
+        sinks,
+        socket: socket_clone,
+        running: running_flag,
+        packets_received,
+        jpeg_frames,
+        last_frame_time,
+        last_frame_size,
+        stream_stats,
+    } = shared;
+
     info!("UDP receiver thread started");
 
     // Get current process ID for debugging
     info!("UDP thread process: {}", std::process::id());
 
-    // Open named pipe for writing
-    let pipe_result = std::fs::OpenOptions::new()
-        .write(true)
-        .open("olympus_stream.pipe");
-
-    let mut pipe = match pipe_result {
-        Ok(file) => {
-            info!("Successfully opened pipe for writing");
-            Some(file)
-        }
-        Err(e) => {
-            error!("Failed to open pipe: {}", e);
-            None
-        }
-    };
-
     // Main receive loop - RTP protocol handling for Olympus camera
-    let mut buffer = [0u8; 65535]; // Max UDP packet size
+    // Packets are received into a shared arena and parsed as views of it
+    let mut arena = ReceiveArena::default();
     let mut local_packets_received = 0;
     let mut local_jpeg_frames = 0;
 
-    // RTP frame assembly variables
-    let mut first_frame_received = false;
-    let mut current_frame_id = 0;
-    let mut current_packet_id = 0;
-    let mut jpeg_data = Vec::with_capacity(524288); // larger capacity for better performance
-
-    // And change the capacity threshold check to be more aggressive
-    if jpeg_data.capacity() > 1048576 {
-        // 1MB
-        jpeg_data = Vec::with_capacity(524288); // Resize to 512KB
-    }
+    // RTP frame assembly
+    let mut frame = FrameBuilder::default();
+    let mut pool = FramePool::default();
 
-    // Frame rate control - increased to 30 FPS for smoother video
-    let mut last_write_time = Instant::now();
-    let frame_interval = Duration::from_millis(16); // ~30 FPS
+    // Last RTP sequence number seen, for packet loss detection
+    let mut last_rtp_seq: Option<u16> = None;
 
     // Last activity tracking for reconnection
     let mut last_activity = Instant::now();
     let mut last_heartbeat = Instant::now();
 
-    // Pipe maintenance - periodically recreate pipe to avoid degradation
-    let mut last_pipe_reset = Instant::now();
-    let pipe_reset_interval = Duration::from_secs(30); // Reset pipe every 30 seconds
-
     // Frame skip counter to handle high frame rates
     let mut frame_counter = 0;
     let frame_skip_rate = 1; // Process every frame (0 = skip none, 1 = process all, 2 = every other)
@@ -414,12 +430,16 @@ fn process_udp_stream(
     while *running_flag.lock().unwrap() {
         // Receive and process data
         if let Ok(socket) = socket_clone.lock() {
-            match socket.recv_from(&mut buffer) {
-                Ok((size, _addr)) => {
+            match arena.recv(&socket) {
+                Ok(datagram) => {
+                    let size = datagram.len();
                     local_packets_received += 1;
                     if let Ok(mut counter) = packets_received.lock() {
                         *counter = local_packets_received;
                     }
+                    if let Ok(mut stats) = stream_stats.lock() {
+                        stats.record_packet(size);
+                    }
                     last_activity = Instant::now();
 
                     // Log every 100th packet for debugging
@@ -430,88 +450,42 @@ fn process_udp_stream(
                         );
                     }
 
-                    if size >= 12 {
-                        // Decode RTP header (based on Python implementation)
-                        let v = (buffer[0] & 0xC0) >> 6;
-                        let p = (buffer[0] & 0x20) >> 5;
-                        let x = (buffer[0] & 0x10) >> 4;
-                        let cc = buffer[0] & 0x0F;
-
-                        let m = (buffer[1] & 0x80) >> 7;
-                        let pt = buffer[1] & 0x7F;
-
-                        let rtp_seq = ((buffer[2] as u16) << 8) | (buffer[3] as u16);
-                        let frame_seq = ((buffer[4] as u32) << 24)
-                            | ((buffer[5] as u32) << 16)
-                            | ((buffer[6] as u32) << 8)
-                            | (buffer[7] as u32);
+                    if let Some(packet) = RtpPacket::parse(datagram) {
+                        // Count packets missing from the RTP sequence
+                        if let Some(previous) = last_rtp_seq {
+                            let gap = packet.sequence.wrapping_sub(previous);
+                            if gap > 1
+                                && gap < 1000
+                                && let Ok(mut stats) = stream_stats.lock()
+                            {
+                                stats.record_loss((gap - 1) as u64);
+                            }
+                        }
+                        last_rtp_seq = Some(packet.sequence);
 
                         // First packet of frame
-                        if v == 2 && p == 0 && x == 1 && m == 0 && pt == 96 && !first_frame_received
-                        {
-                            debug!("First packet of frame received, frame ID: {}", frame_seq);
-
-                            current_packet_id = rtp_seq;
-                            current_frame_id = frame_seq;
-                            first_frame_received = true;
-
-                            // Get extension header length (in 32-bit words)
-                            let ext_header_len = if size >= 16 {
-                                ((buffer[14] as u16) << 8) | (buffer[15] as u16)
-                            } else {
-                                0
-                            };
-
-                            // Skip RTP header (12 bytes) + extension header (4 bytes + extension length)
-                            let header_size = 12 + 4 + (ext_header_len as usize) * 4;
-                            if size > header_size {
-                                jpeg_data.clear();
-                                jpeg_data.extend_from_slice(&buffer[header_size..size]);
-                            }
+                        if packet.starts_frame() && !frame.is_building() {
+                            debug!(
+                                "First packet of frame received, frame ID: {}",
+                                packet.frame_id
+                            );
+                            frame.start(packet);
                         }
                         // Middle packets of frame
-                        else if v == 2
-                            && p == 0
-                            && x == 0
-                            && cc == 0
-                            && m == 0
-                            && pt == 96
-                            && first_frame_received
-                            && current_packet_id + 1 == rtp_seq
-                            && current_frame_id == frame_seq
+                        else if packet.continues_frame()
+                            && !packet.marker
+                            && frame.follows(&packet)
                         {
-                            current_packet_id = rtp_seq;
-                            jpeg_data.extend_from_slice(&buffer[12..size]);
+                            frame.push(packet);
                         }
                         // Last packet of frame
-                        else if v == 2
-                            && p == 0
-                            && x == 0
-                            && cc == 0
-                            && m == 1
-                            && pt == 96
-                            && first_frame_received
-                            && current_packet_id + 1 == rtp_seq
-                            && current_frame_id == frame_seq
-                        {
-                            jpeg_data.extend_from_slice(&buffer[12..size]);
+                        else if packet.continues_frame() && frame.follows(&packet) {
+                            frame.push(packet);
+                            let stamp = FrameStamp::now(frame.frame_id(), frame.elapsed());
+                            let jpeg_data = frame.finish(&mut pool);
 
                             // Check if we have valid JPEG data (starts with FF D8)
-                            if jpeg_data.len() >= 2 && jpeg_data[0] == 0xFF && jpeg_data[1] == 0xD8
-                            {
-                                // Apply adaptive frame skipping when under high load
-                                if last_write_time.elapsed() < Duration::from_millis(20) {
-                                    // If we're processing frames too quickly, skip some frames
-                                    // to avoid overwhelming the player
-                                    if frame_counter % 2 != 0 {
-                                        // Skip every other frame when under pressure
-                                        debug!("Skipping frame under high load");
-                                        first_frame_received = false;
-                                        jpeg_data.clear();
-                                        continue;
-                                    }
-                                }
-
+                            if jpeg_data.starts_with(&[0xFF, 0xD8]) {
                                 // Apply frame skipping if needed
                                 frame_counter += 1;
                                 if frame_counter % frame_skip_rate == 0 {
@@ -527,100 +501,35 @@ fn process_udp_stream(
                                     if let Ok(mut size) = last_frame_size.lock() {
                                         *size = jpeg_data.len();
                                     }
+                                    if let Ok(mut stats) = stream_stats.lock() {
+                                        stats.record_frame();
+                                    }
 
                                     debug!(
                                         "Complete JPEG frame assembled: {} bytes",
                                         jpeg_data.len()
                                     );
 
-                                    // Apply frame rate control to avoid flooding player
-                                    let elapsed = last_write_time.elapsed();
-                                    if elapsed < frame_interval {
-                                        thread::sleep(frame_interval - elapsed);
+                                    // Fan the frame out to every registered sink
+                                    let handoff_started = Instant::now();
+                                    if let Ok(mut sinks) = sinks.lock() {
+                                        sinks.dispatch(&jpeg_data, &stamp);
                                     }
-
-                                    // Check if we need to reset the pipe
-                                    if last_pipe_reset.elapsed() > pipe_reset_interval {
-                                        info!(
-                                            "Performing periodic pipe reset to maintain performance"
-                                        );
-                                        drop(pipe);
-
-                                        // Sleep to let player release the pipe
-                                        thread::sleep(Duration::from_millis(100));
-
-                                        // Reopen pipe
-                                        pipe = std::fs::OpenOptions::new()
-                                            .write(true)
-                                            .open("olympus_stream.pipe")
-                                            .ok();
-
-                                        if pipe.is_some() {
-                                            info!("Successfully reopened pipe");
-                                        } else {
-                                            error!("Failed to reopen pipe during maintenance");
-                                        }
-
-                                        last_pipe_reset = Instant::now();
-                                    }
-
-                                    // Write to pipe with error handling for broken pipe
-                                    if let Some(pipe_file) = pipe.as_mut() {
-                                        match pipe_file.write_all(&jpeg_data) {
-                                            Ok(_) => {
-                                                // Successfully wrote the data, now flush
-                                                if let Err(e) = pipe_file.flush() {
-                                                    warn!("Failed to flush pipe: {}", e);
-                                                }
-                                                last_write_time = Instant::now();
-                                            }
-                                            Err(e) => {
-                                                error!("Failed to write to pipe: {}", e);
-
-                                                // Check if the pipe is broken and try to recover
-                                                if e.kind() == std::io::ErrorKind::BrokenPipe {
-                                                    warn!("Pipe broken, attempting to reopen...");
-                                                    // Drop the broken pipe
-                                                    drop(pipe_file);
-                                                    pipe = None;
-
-                                                    // Reopen pipe after a short delay
-                                                    thread::sleep(Duration::from_millis(100));
-                                                    pipe = std::fs::OpenOptions::new()
-                                                        .write(true)
-                                                        .open("olympus_stream.pipe")
-                                                        .ok();
-
-                                                    if pipe.is_some() {
-                                                        info!("Successfully reopened pipe");
-                                                        last_pipe_reset = Instant::now();
-                                                    } else {
-                                                        error!("Failed to reopen pipe");
-                                                    }
-                                                }
-                                            }
-                                        }
+                                    if let Ok(mut stats) = stream_stats.lock() {
+                                        stats.record_timing(FrameTiming {
+                                            assembly: stamp.assembly,
+                                            handoff: handoff_started.elapsed(),
+                                        });
                                     }
                                 }
                             } else {
                                 warn!("Invalid JPEG data (missing FF D8 header)");
                             }
-
-                            // Reset state and free memory
-                            first_frame_received = false;
-                            jpeg_data.clear();
-
-                            // Keep capacity reasonable
-                            if jpeg_data.capacity() > 524288 {
-                                // 512 KB
-                                jpeg_data = Vec::with_capacity(262144); // Resize to 256 KB
-                            }
                         } else {
                             // Reset on unexpected packet
-                            if first_frame_received {
+                            if frame.is_building() {
                                 debug!("Unexpected packet, resetting frame assembly");
-                                first_frame_received = false;
-                                jpeg_data.clear();
+                                frame.reset();
                             }
                         }
                     }
@@ -633,6 +542,11 @@ fn process_udp_stream(
             }
         }
 
+        // Close out finished seconds even when no packets arrive
+        if let Ok(mut stats) = stream_stats.lock() {
+            stats.roll();
+        }
+
         // Check for inactivity
         if last_activity.elapsed() > Duration::from_secs(10) {
             warn!("No packets received for 10 seconds, stream may be stalled");
@@ -647,13 +561,11 @@ fn process_udp_stream(
                 0
             };
 
-            // Calculate approximate FPS over last 5 seconds
-            let time_window = last_heartbeat.elapsed().as_secs_f32();
-            let frames_per_second = if time_window > 0.0 {
-                local_jpeg_frames as f32 / time_window
-            } else {
-                0.0
-            };
+            // Average FPS over the seconds since the last heartbeat
+            let frames_per_second = stream_stats
+                .lock()
+                .map(|stats| stats.fps_summary(5).avg)
+                .unwrap_or(0.0);
 
             info!(
                 "Stream status: {} packets, {} frames ({:.1} FPS), last frame: {}KB",
@@ -663,7 +575,6 @@ fn process_udp_stream(
                 frame_size / 1024
             );
             last_heartbeat = Instant::now();
-            local_jpeg_frames = 0; // Reset for next FPS calculation
         }
 
         thread::sleep(Duration::from_millis(5)); // Shorter sleep for more responsive processing
@@ -675,11 +586,8 @@ fn process_udp_stream(
     );
 }
 
-/// Stop the UDP receiver
-pub fn stop_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
-    info!("Stopping Olympus UDP receiver");
-
-    // First stop thread to prevent further pipe writes
+/// Stop the receiving thread, leaving the player and sinks running
+pub fn stop_udp_thread(viewer_state: &mut VideoViewerState) {
     if let Ok(mut running) = viewer_state.udp_running.lock() {
         *running = false;
     }
@@ -693,6 +601,15 @@ pub fn stop_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
             Err(e) => warn!("Error joining UDP thread: {:?}", e),
         }
     }
+}
+
+/// Stop the UDP receiver
+pub fn stop_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
+    info!("Stopping Olympus UDP receiver");
+
+    // First stop thread to prevent further pipe writes
+    stop_udp_thread(viewer_state);
+    viewer_state.snapshot_loop = None;
 
     // Send SIGTERM to player process first (gentler than SIGKILL)
     if let Some(pid) = viewer_state.external_viewer_pid {
@@ -750,15 +667,20 @@ pub fn stop_udp_receiver(viewer_state: &mut VideoViewerState) -> Result<()> {
         viewer_state.external_viewer_pid = None;
     }
 
-    // Now clean up pipe after player is stopped
-    let pipe_path = Path::new("olympus_stream.pipe");
-    if pipe_path.exists() {
-        info!("Removing pipe file");
-        match fs::remove_file(pipe_path) {
-            Ok(_) => info!("Pipe file removed successfully"),
-            Err(e) => warn!("Failed to remove pipe file: {}", e),
-        }
+    // Reap the player so it doesn't linger as a zombie
+    if let Some(mut player) = viewer_state.player.take() {
+        let _ = player.kill();
+        let _ = player.wait();
+    }
+    viewer_state.player_closed = false;
+
+    // Stop feeding the player and clean up the pipe after it is stopped
+    if let Ok(mut sinks) = viewer_state.sinks.lock() {
+        sinks.remove(PLAYER_SINK);
+        // A stopped stream always starts again unpaused
+        sinks.set_paused(false);
     }
+    output::remove_pipe();
 
     viewer_state.is_playing = false;
 
@@ -0,0 +1,186 @@
+// src/terminal/video_viewer/orientation.rs
+// Turning live view upright for cameras mounted sideways or upside down on a
+// rig. Frames are stored and recorded as the camera sends them; the turn is
+// only applied where they are shown, by the player's video filters or when
+// drawing the terminal view. Each settings profile can have its own, so
+// selecting the rig's profile also picks the way its camera is mounted.
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Clockwise turn applied to live view
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub enum Rotation {
+    #[default]
+    None,
+    Quarter,
+    Half,
+    ThreeQuarters,
+}
+
+impl Rotation {
+    /// Turn in degrees
+    pub fn degrees(&self) -> u16 {
+        match self {
+            Rotation::None => 0,
+            Rotation::Quarter => 90,
+            Rotation::Half => 180,
+            Rotation::ThreeQuarters => 270,
+        }
+    }
+
+    /// Whether the picture ends up on its side, swapping width and height
+    pub fn is_sideways(&self) -> bool {
+        matches!(self, Rotation::Quarter | Rotation::ThreeQuarters)
+    }
+}
+
+impl TryFrom<u16> for Rotation {
+    type Error = anyhow::Error;
+
+    fn try_from(degrees: u16) -> Result<Self> {
+        match degrees {
+            0 => Ok(Rotation::None),
+            90 => Ok(Rotation::Quarter),
+            180 => Ok(Rotation::Half),
+            270 => Ok(Rotation::ThreeQuarters),
+            _ => Err(anyhow!(
+                "Rotation must be 0, 90, 180 or 270 degrees, not {}",
+                degrees
+            )),
+        }
+    }
+}
+
+impl From<Rotation> for u16 {
+    fn from(rotation: Rotation) -> Self {
+        rotation.degrees()
+    }
+}
+
+/// Display aspect ratio, written `16:9` in the configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AspectRatio {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl TryFrom<String> for AspectRatio {
+    type Error = anyhow::Error;
+
+    fn try_from(text: String) -> Result<Self> {
+        text.split_once(':')
+            .and_then(|(width, height)| {
+                Some(Self {
+                    width: width.trim().parse().ok()?,
+                    height: height.trim().parse().ok()?,
+                })
+            })
+            .filter(|ratio| ratio.width > 0 && ratio.height > 0)
+            .ok_or_else(|| anyhow!("Aspect ratio must look like 16:9, not {}", text))
+    }
+}
+
+impl From<AspectRatio> for String {
+    fn from(ratio: AspectRatio) -> Self {
+        ratio.to_string()
+    }
+}
+
+impl std::fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.width, self.height)
+    }
+}
+
+/// How live view is turned and shaped on screen
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Orientation {
+    /// Clockwise turn in degrees: 0, 90, 180 or 270
+    pub rotation: Rotation,
+    /// Display aspect ratio forced on the picture, such as `16:9`, after turning it
+    pub aspect: Option<AspectRatio>,
+}
+
+impl Orientation {
+    /// Video filter flags for the live view player, `mplayer` or `ffplay`
+    pub fn player_args(&self, player: &str) -> Vec<String> {
+        match player {
+            "mplayer" => {
+                let filter = match self.rotation {
+                    Rotation::None => None,
+                    Rotation::Quarter => Some("rotate=1"),
+                    Rotation::Half => Some("mirror,flip"),
+                    Rotation::ThreeQuarters => Some("rotate=2"),
+                };
+                let mut args = Vec::new();
+                if let Some(filter) = filter {
+                    args.extend(["-vf".to_string(), filter.to_string()]);
+                }
+                if let Some(aspect) = self.aspect {
+                    args.extend(["-aspect".to_string(), aspect.to_string()]);
+                }
+                args
+            }
+            "ffplay" => {
+                let mut filters: Vec<String> = match self.rotation {
+                    Rotation::None => Vec::new(),
+                    Rotation::Quarter => vec!["transpose=1".to_string()],
+                    Rotation::Half => vec!["hflip".to_string(), "vflip".to_string()],
+                    Rotation::ThreeQuarters => vec!["transpose=2".to_string()],
+                };
+                if let Some(aspect) = self.aspect {
+                    filters.push(format!("setdar={}/{}", aspect.width, aspect.height));
+                }
+                if filters.is_empty() {
+                    return Vec::new();
+                }
+                vec!["-vf".to_string(), filters.join(",")]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Turn and reshape a decoded frame for drawing in the terminal
+    #[cfg(feature = "graphics")]
+    pub fn apply(&self, image: image::DynamicImage) -> image::DynamicImage {
+        let image = match self.rotation {
+            Rotation::None => image,
+            Rotation::Quarter => image.rotate90(),
+            Rotation::Half => image.rotate180(),
+            Rotation::ThreeQuarters => image.rotate270(),
+        };
+        match self.aspect {
+            Some(aspect) => {
+                let height = (image.width() as u64 * aspect.height as u64 / aspect.width as u64)
+                    .max(1) as u32;
+                image.resize_exact(image.width(), height, image::imageops::FilterType::Triangle)
+            }
+            None => image,
+        }
+    }
+}
+
+/// Live view orientation, with overrides for settings profiles
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrientationConfig {
+    /// Orientation used when the selected profile has none of its own
+    #[serde(flatten)]
+    pub default: Orientation,
+    /// Orientation by settings profile name
+    pub profiles: BTreeMap<String, Orientation>,
+}
+
+impl OrientationConfig {
+    /// Orientation for live view with `profile` selected
+    pub fn for_profile(&self, profile: Option<&str>) -> Orientation {
+        profile
+            .and_then(|profile| self.profiles.get(profile))
+            .unwrap_or(&self.default)
+            .clone()
+    }
+}
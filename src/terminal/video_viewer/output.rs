@@ -0,0 +1,342 @@
+// src/terminal/video_viewer/output.rs
+// Without the player feature only pipe cleanup is needed
+#![cfg_attr(not(feature = "player"), allow(dead_code))]
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use log::{debug, error, info, warn};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(windows)]
+use std::net::TcpListener;
+
+#[cfg(unix)]
+use std::process::Command;
+
+/// Name of the named pipe used on Unix
+pub const PIPE_NAME: &str = "olympus_stream.pipe";
+
+/// How long to wait for the player to connect to the TCP socket
+#[cfg(windows)]
+const ACCEPT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Frames that may wait for the player before new ones are dropped
+const QUEUE_DEPTH: usize = 2;
+
+/// Minimum time between frames written to the player
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// How often the named pipe is reopened to avoid player degradation
+const PIPE_RESET_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a stopped writer gets to finish before it is assumed to wait for a reader
+const WRITER_STOP_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Channel that carries assembled MJPEG frames to the external player
+pub enum StreamOutput {
+    /// Named pipe created with mkfifo
+    #[cfg(unix)]
+    Pipe,
+    /// Local TCP socket the player reads from, since Windows has no mkfifo
+    #[cfg(windows)]
+    Tcp(TcpListener),
+}
+
+impl StreamOutput {
+    /// Create the platform's output channel
+    pub fn create() -> Result<Self> {
+        #[cfg(unix)]
+        {
+            setup_pipe()?;
+            Ok(StreamOutput::Pipe)
+        }
+
+        #[cfg(windows)]
+        {
+            // Let the OS pick a free loopback port
+            let listener = TcpListener::bind("127.0.0.1:0")?;
+            listener.set_nonblocking(true)?;
+            info!("Listening for player on {}", listener.local_addr()?);
+            Ok(StreamOutput::Tcp(listener))
+        }
+    }
+
+    /// Input argument to hand to the player
+    pub fn player_input(&self) -> String {
+        match self {
+            #[cfg(unix)]
+            StreamOutput::Pipe => PIPE_NAME.to_string(),
+            #[cfg(windows)]
+            StreamOutput::Tcp(listener) => match listener.local_addr() {
+                Ok(addr) => format!("tcp://{}", addr),
+                Err(_) => "tcp://127.0.0.1".to_string(),
+            },
+        }
+    }
+
+    /// Whether the player can only be fed through a named pipe it opens itself
+    pub fn is_pipe(&self) -> bool {
+        match self {
+            #[cfg(unix)]
+            StreamOutput::Pipe => true,
+            #[cfg(windows)]
+            StreamOutput::Tcp(_) => false,
+        }
+    }
+
+    /// Open the writing end, blocking until the player is reading
+    pub fn open_writer(&self) -> Result<Box<dyn Write + Send>> {
+        match self {
+            #[cfg(unix)]
+            StreamOutput::Pipe => {
+                let file = fs::OpenOptions::new().write(true).open(PIPE_NAME)?;
+                Ok(Box::new(file))
+            }
+            #[cfg(windows)]
+            StreamOutput::Tcp(listener) => {
+                let started = Instant::now();
+                loop {
+                    match listener.accept() {
+                        Ok((stream, addr)) => {
+                            info!("Player connected from {}", addr);
+                            stream.set_nonblocking(false)?;
+                            stream.set_nodelay(true)?;
+                            return Ok(Box::new(stream));
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                            if started.elapsed() > ACCEPT_TIMEOUT {
+                                return Err(anyhow!("Player did not connect to the stream"));
+                            }
+                            thread::sleep(Duration::from_millis(50));
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Feeds frames to the player from its own thread so a stalled player never blocks reception
+pub struct PipeWriter {
+    /// Bounded queue of frames waiting to be written, until the writer is stopped
+    sender: Option<SyncSender<Bytes>>,
+    /// Frames dropped because the queue was full
+    dropped: u64,
+    /// Tells the writer thread to stop, even with frames still queued
+    stop: Arc<AtomicBool>,
+    /// Set by the writer thread when it ends
+    finished: Arc<AtomicBool>,
+    /// Whether the writer feeds a named pipe, whose opening blocks until a player reads
+    is_pipe: bool,
+}
+
+impl PipeWriter {
+    /// Start the writer thread for the given output
+    pub fn start(output: StreamOutput) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_DEPTH);
+        let stop = Arc::new(AtomicBool::new(false));
+        let finished = Arc::new(AtomicBool::new(false));
+        let is_pipe = output.is_pipe();
+        let thread_stop = Arc::clone(&stop);
+        let thread_finished = Arc::clone(&finished);
+        thread::spawn(move || {
+            write_frames(output, receiver, &thread_stop);
+            thread_finished.store(true, Ordering::Relaxed);
+        });
+        Self {
+            sender: Some(sender),
+            dropped: 0,
+            stop,
+            finished,
+            is_pipe,
+        }
+    }
+
+    /// Queue a frame without blocking, dropping it if the player is behind
+    pub fn send(&mut self, frame: &Bytes) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+        match sender.try_send(frame.clone()) {
+            Ok(_) => {}
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                debug!("Player is behind, dropping frame");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                debug!("Pipe writer has stopped, discarding frame");
+            }
+        }
+    }
+
+    /// Wait a moment for the writer thread to end, returning whether it did
+    fn wait_finished(&self) -> bool {
+        let started = Instant::now();
+        while !self.finished.load(Ordering::Relaxed) {
+            if started.elapsed() >= WRITER_STOP_TIMEOUT {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        true
+    }
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        if self.dropped > 0 {
+            info!(
+                "Dropped {} frames the player could not keep up with",
+                self.dropped
+            );
+        }
+
+        // The thread ends at its next frame, unless it is reopening the pipe
+        // for a player that is gone; opening the reading end once lets it through
+        self.stop.store(true, Ordering::Relaxed);
+        self.sender = None;
+        if !self.wait_finished() && self.is_pipe {
+            info!("Releasing the pipe writer waiting for a player");
+            thread::spawn(|| {
+                let _ = fs::File::open(PIPE_NAME);
+            });
+            // The pipe has to stay in place until the writer got through
+            self.wait_finished();
+        }
+    }
+}
+
+/// Open the output's writing end, logging the outcome
+fn open_output(output: &StreamOutput) -> Option<Box<dyn Write + Send>> {
+    match output.open_writer() {
+        Ok(writer) => {
+            info!("Successfully opened pipe for writing");
+            Some(writer)
+        }
+        Err(e) => {
+            error!("Failed to open pipe: {}", e);
+            None
+        }
+    }
+}
+
+/// Write queued frames to the player until the sending side is dropped or `stop` is set
+fn write_frames(output: StreamOutput, receiver: Receiver<Bytes>, stop: &AtomicBool) {
+    info!("Pipe writer thread started");
+
+    let mut pipe = open_output(&output);
+    let mut last_pipe_reset = Instant::now();
+    let mut last_write_time = Instant::now();
+
+    while let Ok(mut frame) = receiver.recv() {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+
+        // Apply frame rate control to avoid flooding player
+        let elapsed = last_write_time.elapsed();
+        if elapsed < FRAME_INTERVAL {
+            thread::sleep(FRAME_INTERVAL - elapsed);
+        }
+
+        // Skip ahead to the newest frame if several are waiting
+        while let Ok(newer) = receiver.try_recv() {
+            frame = newer;
+        }
+
+        // Periodically reset the pipe; a socket can't be reopened
+        if output.is_pipe() && last_pipe_reset.elapsed() > PIPE_RESET_INTERVAL {
+            info!("Performing periodic pipe reset to maintain performance");
+            drop(pipe);
+
+            // Sleep to let player release the pipe
+            thread::sleep(Duration::from_millis(100));
+            pipe = open_output(&output);
+            last_pipe_reset = Instant::now();
+        }
+
+        let Some(pipe_file) = pipe.as_mut() else {
+            continue;
+        };
+
+        match pipe_file.write_all(&frame).and_then(|_| pipe_file.flush()) {
+            Ok(_) => last_write_time = Instant::now(),
+            Err(e) => {
+                error!("Failed to write to pipe: {}", e);
+
+                if !output.is_pipe() {
+                    // A player that disconnected from the socket won't reconnect
+                    warn!("Player disconnected from the stream");
+                    pipe = None;
+                } else if e.kind() == std::io::ErrorKind::BrokenPipe {
+                    warn!("Pipe broken, attempting to reopen...");
+
+                    // Reopen pipe after a short delay
+                    thread::sleep(Duration::from_millis(100));
+                    pipe = open_output(&output);
+                    last_pipe_reset = Instant::now();
+                }
+            }
+        }
+    }
+
+    info!("Pipe writer thread finished");
+}
+
+/// Create the named pipe, replacing any stale one
+#[cfg(unix)]
+fn setup_pipe() -> Result<()> {
+    let pipe_path = Path::new(PIPE_NAME);
+
+    // Log the current directory to ensure we know where to look for the pipe
+    info!(
+        "Current directory: {:?}",
+        std::env::current_dir().unwrap_or_default()
+    );
+
+    remove_pipe();
+
+    info!("Creating named pipe with mkfifo");
+    let output = Command::new("mkfifo")
+        .arg("-m")
+        .arg("0666") // More permissive mode for the pipe
+        .arg(PIPE_NAME)
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!("mkfifo error: {}", stderr);
+        return Err(anyhow!("Failed to create pipe: {}", stderr));
+    }
+
+    // Verify pipe exists after creation
+    if pipe_path.exists() {
+        info!(
+            "Pipe exists at {:?}",
+            pipe_path.canonicalize().unwrap_or_default()
+        );
+    } else {
+        warn!("Pipe still doesn't exist after creation attempt");
+    }
+
+    Ok(())
+}
+
+/// Remove the named pipe or a stale file left behind by older versions
+pub fn remove_pipe() {
+    let pipe_path = Path::new(PIPE_NAME);
+    if pipe_path.exists() {
+        info!("Removing pipe file");
+        match fs::remove_file(pipe_path) {
+            Ok(_) => info!("Pipe file removed successfully"),
+            Err(e) => warn!("Failed to remove pipe file: {}", e),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// src/terminal/video_viewer/pool.rs
+use bytes::{Bytes, BytesMut};
+use std::collections::VecDeque;
+use std::io;
+use std::net::UdpSocket;
+
+/// Recent frame sizes the buffer size is judged from
+const SIZE_WINDOW: usize = 32;
+
+/// Buffer size before any frame has been seen
+const INITIAL_CAPACITY: usize = 128 * 1024;
+
+/// Largest datagram the receivers accept
+pub const MAX_DATAGRAM: usize = 65535;
+
+/// Receive buffer size, enough for a few frames of packets between allocations
+const ARENA_CAPACITY: usize = 1024 * 1024;
+
+/// Reusable frame buffer, sized from the frames actually received
+///
+/// Frames from one camera stay within a narrow size range, so a buffer sized a
+/// little above the largest recent frame is almost never grown. Frames handed
+/// out of it are shared with the sinks, and its allocation is reused once
+/// they have all been dropped.
+pub struct FramePool {
+    buffer: BytesMut,
+    sizes: VecDeque<usize>,
+}
+
+impl Default for FramePool {
+    fn default() -> Self {
+        Self {
+            buffer: BytesMut::with_capacity(INITIAL_CAPACITY),
+            sizes: VecDeque::with_capacity(SIZE_WINDOW),
+        }
+    }
+}
+
+impl FramePool {
+    /// Capacity that fits the recent frames with some headroom
+    fn frame_capacity(&self) -> usize {
+        match self.sizes.iter().max() {
+            Some(largest) => largest + largest / 4,
+            None => INITIAL_CAPACITY,
+        }
+    }
+
+    /// The buffer to assemble the next frame of `len` bytes in
+    pub fn buffer(&mut self, len: usize) -> &mut BytesMut {
+        if self.sizes.len() == SIZE_WINDOW {
+            self.sizes.pop_front();
+        }
+        self.sizes.push_back(len);
+
+        let capacity = self.frame_capacity().max(len);
+        self.buffer.clear();
+        self.buffer.reserve(capacity);
+        &mut self.buffer
+    }
+}
+
+/// Receive buffer that datagrams are split off, so packets are views of it
+///
+/// Packets are received straight into the arena and never copied before they
+/// are joined into a frame. Once every packet cut from it has been dropped,
+/// its allocation is reused for the next ones.
+pub struct ReceiveArena {
+    buffer: BytesMut,
+}
+
+impl Default for ReceiveArena {
+    fn default() -> Self {
+        Self {
+            buffer: BytesMut::with_capacity(ARENA_CAPACITY),
+        }
+    }
+}
+
+impl ReceiveArena {
+    /// Receive the next datagram from `socket`
+    pub fn recv(&mut self, socket: &UdpSocket) -> io::Result<Bytes> {
+        if self.buffer.capacity() < MAX_DATAGRAM {
+            self.buffer.clear();
+            self.buffer.reserve(ARENA_CAPACITY);
+        }
+        // Only the bytes split off by the last packet need zeroing again
+        self.buffer.resize(MAX_DATAGRAM, 0);
+
+        let (size, _) = socket.recv_from(&mut self.buffer)?;
+        Ok(self.buffer.split_to(size).freeze())
+    }
+}
@@ -0,0 +1,52 @@
+// src/terminal/video_viewer/preview.rs
+// Live view drawn inside the terminal, for when no external player is running.
+// Each cell shows two pixels stacked with an upper half block, its foreground
+// the upper pixel and its background the lower one, which works in any
+// terminal with true color and needs no image protocol.
+use crate::terminal::video_viewer::orientation::Orientation;
+use image::imageops::FilterType;
+use tui::{
+    style::{Color, Style},
+    text::{Span, Spans},
+};
+
+/// Character whose upper half takes the foreground color
+const UPPER_HALF: &str = "▀";
+
+/// Lines of half blocks showing `jpeg`, turned upright and scaled to fit `width` x `height` cells
+pub fn frame_lines(
+    jpeg: &[u8],
+    orientation: &Orientation,
+    width: u16,
+    height: u16,
+) -> Option<Vec<Spans<'static>>> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let decoded = image::load_from_memory_with_format(jpeg, image::ImageFormat::Jpeg).ok()?;
+    let decoded = orientation.apply(decoded);
+
+    // Cells are about twice as tall as wide, so a cell holds a square pair of pixels
+    let pixels = decoded
+        .resize(width as u32, height as u32 * 2, FilterType::Triangle)
+        .to_rgb8();
+
+    let lines = (0..pixels.height())
+        .step_by(2)
+        .map(|y| {
+            let cells = (0..pixels.width())
+                .map(|x| {
+                    let [r, g, b] = pixels.get_pixel(x, y).0;
+                    let mut style = Style::default().fg(Color::Rgb(r, g, b));
+                    if y + 1 < pixels.height() {
+                        let [r, g, b] = pixels.get_pixel(x, y + 1).0;
+                        style = style.bg(Color::Rgb(r, g, b));
+                    }
+                    Span::styled(UPPER_HALF, style)
+                })
+                .collect::<Vec<_>>();
+            Spans::from(cells)
+        })
+        .collect();
+    Some(lines)
+}
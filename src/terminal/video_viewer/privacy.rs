@@ -0,0 +1,155 @@
+// src/terminal/video_viewer/privacy.rs
+// Blackout rectangles painted over frames that leave the machine, so a
+// permanently mounted camera can keep a neighbour's window or a desk out of
+// the restream. Only the MJPEG server and the RTMP stream are masked; the
+// player, recordings and snapshots keep the full picture. Masking decodes and
+// encodes every frame again, which needs the graphics feature; without it a
+// restream with masks configured refuses to start instead of going out unmasked.
+use crate::terminal::video_viewer::sinks::FrameSink;
+#[cfg(feature = "graphics")]
+use crate::terminal::video_viewer::timing::FrameStamp;
+use anyhow::Result;
+#[cfg(feature = "graphics")]
+use bytes::Bytes;
+#[cfg(feature = "graphics")]
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+
+/// Rectangle blacked out of restreamed frames, in percent of the frame size
+///
+/// Percentages keep a mask on the same spot when the live view size changes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MaskRegion {
+    /// Left edge
+    pub x: f32,
+    /// Top edge
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl MaskRegion {
+    /// Pixels covered in a `width` x `height` frame as (x, y, width, height), if any
+    pub fn pixels(&self, width: u32, height: u32) -> Option<(u32, u32, u32, u32)> {
+        let edge = |percent: f32, size: u32| {
+            (percent.clamp(0.0, 100.0) / 100.0 * size as f32).round() as u32
+        };
+        let (left, top) = (edge(self.x, width), edge(self.y, height));
+        let right = edge(self.x + self.width, width);
+        let bottom = edge(self.y + self.height, height);
+        (right > left && bottom > top).then(|| (left, top, right - left, bottom - top))
+    }
+}
+
+/// Privacy masks for the MJPEG server and RTMP stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrivacyConfig {
+    /// Rectangles painted black, placed on the picture as the camera sends it
+    pub regions: Vec<MaskRegion>,
+    /// JPEG quality masked frames are encoded at, from 1 to 100
+    pub quality: u8,
+}
+
+impl Default for PrivacyConfig {
+    fn default() -> Self {
+        Self {
+            regions: Vec::new(),
+            quality: 85,
+        }
+    }
+}
+
+impl PrivacyConfig {
+    /// Whether any region is masked
+    pub fn is_enabled(&self) -> bool {
+        !self.regions.is_empty()
+    }
+
+    /// Wrap `sink` so the regions are blacked out of every frame it gets
+    ///
+    /// Without regions the sink is returned as it is.
+    pub fn mask(&self, sink: Box<dyn FrameSink>) -> Result<Box<dyn FrameSink>> {
+        if !self.is_enabled() {
+            return Ok(sink);
+        }
+
+        #[cfg(feature = "graphics")]
+        {
+            info!(
+                "Masking {} regions out of the {} sink",
+                self.regions.len(),
+                sink.name()
+            );
+            Ok(Box::new(MaskedSink {
+                inner: sink,
+                config: self.clone(),
+            }))
+        }
+        #[cfg(not(feature = "graphics"))]
+        Err(anyhow::anyhow!(
+            "Privacy masks for the {} sink need the graphics feature to re-encode frames",
+            sink.name()
+        ))
+    }
+}
+
+/// Passes frames on to another sink with the privacy masks painted in
+#[cfg(feature = "graphics")]
+struct MaskedSink {
+    inner: Box<dyn FrameSink>,
+    config: PrivacyConfig,
+}
+
+#[cfg(feature = "graphics")]
+impl FrameSink for MaskedSink {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        self.write_stamped(frame, &FrameStamp::now(0, std::time::Duration::ZERO))
+    }
+
+    fn write_stamped(&mut self, frame: &Bytes, stamp: &FrameStamp) -> Result<()> {
+        match mask_frame(frame, &self.config) {
+            Some(masked) => self.inner.write_stamped(&masked, stamp),
+            // A frame that can't be masked is left out rather than sent as it is
+            None => {
+                debug!(
+                    "Dropped a {} byte frame that could not be masked",
+                    frame.len()
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `jpeg` with the configured regions painted black, encoded again
+#[cfg(feature = "graphics")]
+pub fn mask_frame(jpeg: &[u8], config: &PrivacyConfig) -> Option<Bytes> {
+    use image::codecs::jpeg::JpegEncoder;
+
+    let mut image = image::load_from_memory_with_format(jpeg, image::ImageFormat::Jpeg)
+        .ok()?
+        .to_rgb8();
+    let (width, height) = image.dimensions();
+    for (x, y, w, h) in config
+        .regions
+        .iter()
+        .filter_map(|region| region.pixels(width, height))
+    {
+        for row in y..y + h {
+            for column in x..x + w {
+                image.put_pixel(column, row, image::Rgb([0, 0, 0]));
+            }
+        }
+    }
+
+    let mut encoded = Vec::with_capacity(jpeg.len());
+    JpegEncoder::new_with_quality(&mut encoded, config.quality.clamp(1, 100))
+        .encode_image(&image)
+        .ok()?;
+    Some(Bytes::from(encoded))
+}
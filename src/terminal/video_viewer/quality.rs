@@ -0,0 +1,234 @@
+// src/terminal/video_viewer/quality.rs
+// Adaptive live view size. The per-second stream statistics are checked for
+// packet loss and seconds without frames; a poor link steps the camera down to a
+// smaller live view size and a clean one steps it back up.
+use crate::camera::olympus::OlympusCamera;
+use crate::terminal::video_viewer::olympus_udp;
+use crate::terminal::video_viewer::stats::SecondSample;
+use anyhow::{Result, anyhow};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Live view sizes the camera accepts, smallest first
+pub const LIVE_VIEW_SIZES: [&str; 4] = ["0320x0240", "0640x0480", "0800x0600", "1024x0768"];
+
+/// Time the stream needs after a size change before it is judged again
+const SETTLE: Duration = Duration::from_secs(3);
+
+/// Adaptive live view size settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdaptiveQualityConfig {
+    /// Step the live view size down on a poor link and back up once it recovers
+    pub enabled: bool,
+    /// Size live view starts at, the camera's default, and is never stepped above
+    pub max_size: String,
+    /// Smallest size stepped down to
+    pub min_size: String,
+    /// Seconds of statistics each decision is based on
+    pub window_secs: usize,
+    /// Share of packets lost over the window that counts as a poor link
+    pub loss_percent: f64,
+    /// Seconds without a frame in the window that count as a poor link
+    pub stall_secs: usize,
+    /// Seconds of a clean link before stepping back up
+    pub recover_secs: u64,
+}
+
+impl Default for AdaptiveQualityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_size: "0640x0480".to_string(),
+            min_size: "0320x0240".to_string(),
+            window_secs: 5,
+            loss_percent: 5.0,
+            stall_secs: 2,
+            recover_secs: 30,
+        }
+    }
+}
+
+/// What made the link count as poor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkProblem {
+    PacketLoss,
+    Stalls,
+}
+
+/// A size change decided on by the controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualityStep {
+    Down {
+        size: &'static str,
+        problem: LinkProblem,
+    },
+    Up {
+        size: &'static str,
+    },
+}
+
+/// Judge the link from recent seconds
+pub fn link_problem(
+    recent: &[SecondSample],
+    config: &AdaptiveQualityConfig,
+) -> Option<LinkProblem> {
+    let stalls = recent.iter().filter(|sample| sample.frames == 0).count();
+    if stalls >= config.stall_secs.max(1) {
+        return Some(LinkProblem::Stalls);
+    }
+
+    let lost: u64 = recent.iter().map(|sample| sample.lost).sum();
+    let expected = lost + recent.iter().map(|sample| sample.packets).sum::<u64>();
+    if expected > 0 && lost as f64 * 100.0 / expected as f64 > config.loss_percent {
+        return Some(LinkProblem::PacketLoss);
+    }
+    None
+}
+
+/// Size as shown to the user, `0320x0240` becoming `320x240`
+pub fn display_size(size: &str) -> String {
+    size.split('x')
+        .map(|side| side.trim_start_matches('0'))
+        .collect::<Vec<_>>()
+        .join("x")
+}
+
+/// Steps the live view size between the configured bounds as the link changes
+pub struct QualityController {
+    config: AdaptiveQualityConfig,
+    /// Sizes between the bounds, smallest first
+    sizes: Vec<&'static str>,
+    /// Index of the size the camera streams at
+    index: usize,
+    last_change: Instant,
+    clean_since: Option<Instant>,
+    /// Running size change and the index to go back to if it fails
+    change: Option<(JoinHandle<Result<()>>, usize)>,
+}
+
+impl QualityController {
+    /// Start at the largest configured size
+    ///
+    /// When the camera lists its live view sizes, only those are stepped through.
+    pub fn new(config: &AdaptiveQualityConfig, listed: Option<&[String]>) -> Result<Self> {
+        let position = |size: &str| {
+            LIVE_VIEW_SIZES
+                .iter()
+                .position(|known| *known == size)
+                .ok_or_else(|| anyhow!("Unknown live view size: {}", size))
+        };
+        let (min, max) = (position(&config.min_size)?, position(&config.max_size)?);
+        if min > max {
+            return Err(anyhow!(
+                "Live view size {} is above {}",
+                config.min_size,
+                config.max_size
+            ));
+        }
+
+        let sizes: Vec<&'static str> = LIVE_VIEW_SIZES[min..=max]
+            .iter()
+            .copied()
+            .filter(|size| listed.is_none_or(|listed| listed.iter().any(|l| l == size)))
+            .collect();
+        if sizes.is_empty() {
+            return Err(anyhow!(
+                "Camera lists none of the live view sizes from {} to {}",
+                config.min_size,
+                config.max_size
+            ));
+        }
+        Ok(Self {
+            config: config.clone(),
+            index: sizes.len() - 1,
+            sizes,
+            last_change: Instant::now(),
+            clean_since: None,
+            change: None,
+        })
+    }
+
+    /// Size the camera streams at
+    pub fn current_size(&self) -> &'static str {
+        self.sizes[self.index]
+    }
+
+    /// Seconds of statistics to pass to `evaluate`
+    pub fn window(&self) -> usize {
+        self.config.window_secs.max(1)
+    }
+
+    /// Decide on a step from the most recent completed seconds, oldest first
+    pub fn evaluate(&mut self, recent: &[SecondSample]) -> Option<QualityStep> {
+        let settle = SETTLE + Duration::from_secs(self.window() as u64);
+        if self.change.is_some()
+            || recent.len() < self.window()
+            || self.last_change.elapsed() < settle
+        {
+            return None;
+        }
+
+        match link_problem(recent, &self.config) {
+            Some(problem) => {
+                self.clean_since = None;
+                if self.index == 0 {
+                    return None;
+                }
+                self.index -= 1;
+                self.last_change = Instant::now();
+                Some(QualityStep::Down {
+                    size: self.current_size(),
+                    problem,
+                })
+            }
+            None => {
+                let clean_since = *self.clean_since.get_or_insert_with(Instant::now);
+                let recovered =
+                    clean_since.elapsed() >= Duration::from_secs(self.config.recover_secs);
+                if !recovered || self.index + 1 == self.sizes.len() {
+                    return None;
+                }
+                self.index += 1;
+                self.last_change = Instant::now();
+                self.clean_since = None;
+                Some(QualityStep::Up {
+                    size: self.current_size(),
+                })
+            }
+        }
+    }
+
+    /// Restart the camera's live view at the current size in the background
+    pub fn apply(&mut self, camera: &OlympusCamera, udp_port: u16, step: QualityStep) {
+        let previous = match step {
+            QualityStep::Down { .. } => self.index + 1,
+            QualityStep::Up { .. } => self.index - 1,
+        };
+        let camera = camera.clone();
+        let size = self.current_size();
+        info!("Changing live view size to {}", size);
+
+        let handle =
+            thread::spawn(move || olympus_udp::change_live_view_size(&camera, udp_port, size));
+        self.change = Some((handle, previous));
+    }
+
+    /// Result of a size change once it has finished, going back to the old size if it failed
+    pub fn poll_change(&mut self) -> Option<Result<()>> {
+        if !self.change.as_ref()?.0.is_finished() {
+            return None;
+        }
+        let (handle, previous) = self.change.take()?;
+        let result = handle
+            .join()
+            .unwrap_or_else(|_| Err(anyhow!("Size change thread panicked")));
+        if result.is_err() {
+            self.index = previous;
+        }
+        self.last_change = Instant::now();
+        Some(result)
+    }
+}
@@ -1,14 +1,30 @@
 // src/terminal/video_viewer/renderer.rs
+use crate::terminal::renderer::latency_label;
+use crate::terminal::video_viewer::orientation::{Orientation, Rotation};
+#[cfg(feature = "graphics")]
+use crate::terminal::video_viewer::preview;
+use crate::terminal::video_viewer::quality;
 use crate::terminal::video_viewer::state::VideoViewerState;
+use crate::terminal::video_viewer::stats::{
+    FPS_WINDOW_SECONDS, HISTORY_SECONDS, StallCause, StreamHealth,
+};
+use crate::terminal::video_viewer::timing::{LatencyEstimate, LatencyRating};
+use crate::utils::i18n::{tr, trf};
 use tui::{
     Frame,
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Paragraph, Sparkline, Wrap},
 };
 
+/// Loss below this share of a second's packets is drawn as minor
+const LOSS_MINOR_PERCENT: f64 = 5.0;
+
+/// Loss from this share of a second's packets is drawn as a dropout
+const LOSS_DROPOUT_PERCENT: f64 = 50.0;
+
 /// Render the video viewer interface
 pub fn render<B: Backend>(viewer_state: &VideoViewerState, frame: &mut Frame<B>, area: Rect) {
     // Split area into sections
@@ -17,6 +33,8 @@ pub fn render<B: Backend>(viewer_state: &VideoViewerState, frame: &mut Frame<B>,
         .constraints([
             Constraint::Length(3), // Title
             Constraint::Min(5),    // Video area
+            Constraint::Length(6), // FPS / bandwidth history
+            Constraint::Length(3), // Loss map
             Constraint::Length(3), // Controls
             Constraint::Length(3), // Status bar
         ])
@@ -24,7 +42,7 @@ pub fn render<B: Backend>(viewer_state: &VideoViewerState, frame: &mut Frame<B>,
 
     // Render title
     let title = Paragraph::new(vec![Spans::from(vec![Span::styled(
-        format!("Olympus Video Viewer - {}", viewer_state.stream_name),
+        trf("Olympus Video Viewer - {}", &[&viewer_state.stream_name]),
         Style::default()
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD),
@@ -34,116 +52,452 @@ pub fn render<B: Backend>(viewer_state: &VideoViewerState, frame: &mut Frame<B>,
     frame.render_widget(title, chunks[0]);
 
     // Render video info area
-    let stream_status = if viewer_state.is_playing {
-        "Playing"
+    let is_paused = viewer_state.is_paused();
+    let stream_status = if !viewer_state.is_playing {
+        tr("Stopped")
+    } else if is_paused {
+        tr("Paused")
     } else {
-        "Paused"
+        tr("Playing")
     };
 
-    let recording_status = if viewer_state.is_recording {
-        "Recording"
+    let recording_status = if viewer_state.is_recording() {
+        tr("Recording")
     } else {
-        "Not Recording"
+        tr("Not Recording")
     };
 
     // Get statistics
     let (packets, frames, frame_size) = viewer_state.get_statistics();
     let time_since_last_frame = viewer_state.get_time_since_last_frame();
-    let frame_rate = if time_since_last_frame.as_secs() > 0 {
-        0.0
-    } else {
-        1.0 / time_since_last_frame.as_millis() as f64 * 1000.0
-    };
+    let fps = viewer_state.get_fps_summary();
+    let (replay_frames, replay_span) = viewer_state.replay_buffered();
 
     // Format stats with colors based on health
-    let health_status = if time_since_last_frame.as_secs() < 1 {
-        Span::styled("Good", Style::default().fg(Color::Green))
-    } else if time_since_last_frame.as_secs() < 5 {
-        Span::styled("Degraded", Style::default().fg(Color::Yellow))
-    } else {
-        Span::styled("Poor/Stalled", Style::default().fg(Color::Red))
+    let health = viewer_state.health();
+    let health_color = match health {
+        StreamHealth::Good => Color::Green,
+        StreamHealth::Congested | StreamHealth::Degraded(_) => Color::Yellow,
+        StreamHealth::Stalled(_) => Color::Red,
+    };
+    let health_status = Span::styled(tr(health.label()), Style::default().fg(health_color));
+
+    let health_text = Spans::from(vec![Span::raw(tr("Stream Health: ")), health_status]);
+
+    let latency_text = match &viewer_state.latency {
+        Some(latency) => match latency.average {
+            Some(average) => trf(
+                "Camera round trip: {} (average {} ms)",
+                &[&latency_label(latency), &average.as_millis()],
+            ),
+            None => trf("Camera round trip: {}", &[&latency_label(latency)]),
+        },
+        None => trf("Camera round trip: {}", &[&tr("Not measured")]),
     };
 
-    let health_text = Spans::from(vec![Span::raw("Stream Health: "), health_status]);
+    let estimate = viewer_state.latency_estimate();
+    let estimate_color = match estimate.map(|estimate| estimate.rating()) {
+        Some(LatencyRating::MovingSubjects) => Color::Green,
+        Some(LatencyRating::StillSubjects) => Color::Yellow,
+        Some(LatencyRating::TooSlow) => Color::Red,
+        None => Color::Reset,
+    };
+    let estimate_text = Spans::from(vec![Span::styled(
+        latency_estimate_label(estimate.as_ref()),
+        Style::default().fg(estimate_color),
+    )]);
+
+    // Where frames come from, which changes once the snapshot fallback is taken
+    let transport = match &viewer_state.snapshot_loop {
+        Some(snapshot_loop) => trf(
+            "Snapshot loop: {} fps",
+            &[&format!("{:.1}", snapshot_loop.fps())],
+        ),
+        None => trf("UDP Port: {}", &[&viewer_state.udp_port]),
+    };
 
     // Create full video info content
-    let video_content = vec![
-        Spans::from(vec![Span::styled(
-            "Olympus UDP stream is displayed in a separate player window.",
+    let player_line = if viewer_state.player_closed {
+        Span::styled(
+            tr("Player closed. Press p to relaunch it or v to watch in the terminal."),
+            Style::default().fg(Color::Red),
+        )
+    } else {
+        Span::styled(
+            tr("Olympus UDP stream is displayed in a separate player window."),
             Style::default().fg(Color::Yellow),
-        )]),
-        Spans::from(vec![Span::raw(
+        )
+    };
+    let mut video_content = vec![
+        Spans::from(vec![player_line]),
+        Spans::from(vec![Span::raw(tr(
             "Use the controls below to manage the stream.",
-        )]),
-        Spans::from(vec![Span::raw(format!(
+        ))]),
+        Spans::from(vec![Span::raw(trf(
             "Stream URL: {}",
-            viewer_state.generate_stream_url()
+            &[&viewer_state.generate_stream_url()],
         ))]),
-        Spans::from(vec![Span::raw(format!(
-            "Status: {} | {} | UDP Port: {}",
-            stream_status, recording_status, viewer_state.udp_port
+        Spans::from(vec![Span::raw(trf(
+            "Status: {} | {} | {}",
+            &[&stream_status, &recording_status, &transport],
         ))]),
         health_text,
-        Spans::from(vec![Span::raw(format!(
-            "Statistics: {} packets, {} frames, {:.1} FPS",
-            packets, frames, frame_rate
+        Spans::from(vec![Span::raw(latency_text)]),
+        estimate_text,
+        Spans::from(vec![Span::raw(trf(
+            "Statistics: {} packets, {} frames, {} FPS",
+            &[&packets, &frames, &fps.current],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "FPS over last {}s: min {} / avg {} / max {}",
+            &[
+                &FPS_WINDOW_SECONDS,
+                &fps.min,
+                &format!("{:.1}", fps.avg),
+                &fps.max,
+            ],
         ))]),
-        Spans::from(vec![Span::raw(format!(
-            "Last frame: {} KB, received {:.1}s ago",
-            frame_size / 1024,
-            time_since_last_frame.as_secs_f64()
+        Spans::from(vec![Span::raw(trf(
+            "Last frame: {} KB, received {}s ago",
+            &[
+                &(frame_size / 1024),
+                &format!("{:.1}", time_since_last_frame.as_secs_f64()),
+            ],
         ))]),
-        Spans::from(vec![Span::raw(format!(
+        Spans::from(vec![Span::raw(trf(
+            "Stats CSV: {}",
+            &[&viewer_state
+                .stats_export_path()
+                .map_or(tr("Off").to_string(), |path| path.display().to_string())],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Live view size: {}",
+            &[&viewer_state
+                .quality
+                .as_ref()
+                .map_or(tr("Fixed").to_string(), |controller| {
+                    trf(
+                        "{} (adaptive)",
+                        &[&quality::display_size(controller.current_size())],
+                    )
+                })],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Orientation: {}",
+            &[&orientation_label(&viewer_state.orientation)],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Privacy masks: {}",
+            &[&match viewer_state.privacy.regions.len() {
+                0 => tr("Off").to_string(),
+                regions => trf("{} on restreams", &[&regions]),
+            }],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Exposure: {} EV | White balance: {}",
+            &[
+                &viewer_state.adjustments.ev(),
+                &tr(viewer_state.adjustments.wb_label()),
+            ],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Replay buffer: {}s ({} frames)",
+            &[&format!("{:.1}", replay_span.as_secs_f64()), &replay_frames],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Sinks: {}",
+            &[&viewer_state.sink_names().join(", ")],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "MJPEG server: {}",
+            &[&match viewer_state.http_url() {
+                None => tr("Off").to_string(),
+                Some(url) if viewer_state.http_access.requires_auth() => url,
+                Some(url) => trf("{} (no password)", &[&url]),
+            }],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "Interval stills: {}",
+            &[&viewer_state
+                .stills_saved()
+                .map_or(tr("Off").to_string(), |saved| trf("{} saved", &[&saved]))],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
+            "RTMP: {}",
+            &[&match &viewer_state.rtmp_url {
+                Some(url) if viewer_state.is_streaming_rtmp() => url.clone(),
+                Some(_) => tr("Stopped").to_string(),
+                None => tr("Off").to_string(),
+            }],
+        ))]),
+        Spans::from(vec![Span::raw(trf(
             "Player PID: {}",
-            viewer_state
+            &[&viewer_state
                 .external_viewer_pid
-                .map_or("None".to_string(), |pid| pid.to_string())
+                .map_or(tr("None").to_string(), |pid| pid.to_string())],
         ))]),
     ];
 
+    // Output is frozen on the last frame while paused
+    if is_paused {
+        let clock = viewer_state
+            .last_frame_clock()
+            .unwrap_or_else(|| "--:--:--".to_string());
+        video_content.insert(
+            0,
+            Spans::from(vec![Span::styled(
+                trf("PAUSED - last frame at {} UTC", &[&clock]),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+        );
+    }
+
     let video_area = Paragraph::new(video_content)
         .block(
             Block::default()
-                .title("Olympus Live View")
+                .title(tr("Olympus Live View"))
                 .borders(Borders::ALL),
         )
         .wrap(Wrap { trim: true });
 
-    frame.render_widget(video_area, chunks[1]);
+    // The terminal view takes most of the width, leaving the details beside it
+    #[cfg(feature = "graphics")]
+    let details_area = if viewer_state.terminal_view {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[1]);
+        render_terminal_view(viewer_state, frame, columns[0]);
+        columns[1]
+    } else {
+        chunks[1]
+    };
+    #[cfg(not(feature = "graphics"))]
+    let details_area = chunks[1];
+    frame.render_widget(video_area, details_area);
+
+    // Render FPS and bandwidth sparklines
+    render_history(viewer_state, frame, chunks[2]);
+
+    // Render per-second packet loss
+    render_loss_map(viewer_state, frame, chunks[3]);
 
     // Render controls
     let controls = Paragraph::new(vec![Spans::from(vec![
-        Span::styled("Controls: ", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw("Enter - Restart stream   "),
-        Span::raw("Space - Play/Pause   "),
-        Span::raw("d - Diagnostics   "),
-        Span::raw("r - Toggle recording   "), // Added recording toggle
-        Span::raw("Esc - Return to menu   "),
-        Span::raw("q - Quit"),
+        Span::styled(
+            tr("Controls: "),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(tr("Enter - Restart stream   ")),
+        Span::raw(tr("Space - Pause/Resume   ")),
+        Span::raw(tr("x - Stop/Start stream   ")),
+        Span::raw(tr("d - Diagnostics   ")),
+        Span::raw(tr("r - Toggle recording   ")), // Added recording toggle
+        Span::raw(tr("c - Toggle stats CSV   ")),
+        Span::raw(tr("s - Snapshot   ")),
+        Span::raw(tr("i - Instant replay   ")),
+        Span::raw(tr("t - Interval stills   ")),
+        Span::raw(tr("h - Toggle MJPEG server   ")),
+        Span::raw(tr("o - Toggle RTMP stream   ")),
+        Span::raw(tr("p - Relaunch player   ")),
+        Span::raw(tr("v - Terminal view   ")),
+        Span::raw(tr("+/- - Exposure   ")),
+        Span::raw(tr("w - White balance   ")),
+        Span::raw(tr("Esc/q - Return to menu   ")),
+        Span::raw(tr("Ctrl-q - Quit")),
     ])])
     .block(Block::default().borders(Borders::ALL));
 
-    frame.render_widget(controls, chunks[2]);
+    frame.render_widget(controls, chunks[4]);
 
     // Render status bar - show diagnostic info
-    let status_text = if time_since_last_frame.as_secs() > 5 {
-        "Stream may be stalled. Press Enter to restart stream or d to run diagnostics."
+    let weak_link = viewer_state.link_quality.as_ref().filter(|link| link.weak);
+    let player_closed = viewer_state.player_closed && !viewer_state.terminal_view;
+    let status_text = if is_paused {
+        tr("Output paused on the last frame. Press Space to resume.").to_string()
+    } else if player_closed {
+        tr("Player closed. Press p to relaunch it or v to watch in the terminal.").to_string()
+    } else if let StreamHealth::Stalled(cause) = health {
+        match cause {
+            StallCause::Camera => tr("The camera answers but sends no frames. Press Enter to restart stream or d to run diagnostics."),
+            StallCause::Network => tr("Stream stalled and the camera answers slowly: the network is congested. Move closer to the camera or press Enter to restart stream."),
+            StallCause::Unknown => tr("Stream may be stalled. Press Enter to restart stream or d to run diagnostics."),
+        }
+        .to_string()
     } else if frames == 0 {
-        "Waiting for video data. Check camera connection if this persists."
+        tr("Waiting for video data. Check camera connection if this persists.").to_string()
+    } else if let Some(link) = weak_link {
+        trf(
+            "Wi-Fi signal weak ({}%), live view may stutter",
+            &[&link.percent],
+        )
+    } else if health == StreamHealth::Congested {
+        tr("The camera answers slowly; the network may be congested.").to_string()
     } else {
-        "Stream active. Press q to quit, Esc to return to menu."
+        tr("Stream active. Press Esc or q to return to menu, Ctrl-q to quit.").to_string()
     };
 
-    let status_style = if time_since_last_frame.as_secs() > 5 {
+    let status_style = if is_paused || player_closed {
+        Style::default().fg(Color::Yellow)
+    } else if matches!(health, StreamHealth::Stalled(_)) {
         Style::default().fg(Color::Red)
-    } else if frames == 0 {
+    } else if frames == 0 || weak_link.is_some() || health == StreamHealth::Congested {
         Style::default().fg(Color::Yellow)
     } else {
         Style::default().fg(Color::Green)
     };
 
-    let status_bar = Paragraph::new(Spans::from(Span::styled(status_text, status_style)))
-        .block(Block::default().borders(Borders::ALL));
+    // The Wi-Fi indicator sits in the border, like the main status bar
+    let mut status_block = Block::default().borders(Borders::ALL);
+    if let Some(link) = &viewer_state.link_quality {
+        status_block = status_block.title(trf("Wi-Fi: {}", &[&link.indicator()]));
+    }
+    let status_bar =
+        Paragraph::new(Spans::from(Span::styled(status_text, status_style))).block(status_block);
 
-    frame.render_widget(status_bar, chunks[3]);
+    frame.render_widget(status_bar, chunks[5]);
+}
+
+/// Render rolling FPS and bandwidth histories side by side
+fn render_history<B: Backend>(viewer_state: &VideoViewerState, frame: &mut Frame<B>, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let (fps_history, bandwidth_history) = viewer_state.get_history();
+
+    let fps_data = visible_tail(&fps_history, chunks[0]);
+    let fps_sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .title(trf(
+                    "FPS (now {})",
+                    &[&fps_history.last().copied().unwrap_or(0)],
+                ))
+                .borders(Borders::ALL),
+        )
+        .data(fps_data)
+        .style(Style::default().fg(Color::Green));
+
+    frame.render_widget(fps_sparkline, chunks[0]);
+
+    let bandwidth_data = visible_tail(&bandwidth_history, chunks[1]);
+    let bandwidth_sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .title(trf(
+                    "Bandwidth KB/s (now {})",
+                    &[&bandwidth_history.last().copied().unwrap_or(0)],
+                ))
+                .borders(Borders::ALL),
+        )
+        .data(bandwidth_data)
+        .style(Style::default().fg(Color::Cyan));
+
+    frame.render_widget(bandwidth_sparkline, chunks[1]);
+}
+
+/// Render one cell per second colored by packet loss, so dropouts show as a pattern
+fn render_loss_map<B: Backend>(viewer_state: &VideoViewerState, frame: &mut Frame<B>, area: Rect) {
+    let history = viewer_state.get_loss_history();
+    let width = area.width.saturating_sub(2) as usize;
+    let seconds = HISTORY_SECONDS.min(width);
+
+    // Seconds not yet recorded are drawn dim, so the map fills from the right
+    let recorded = &history[history.len().saturating_sub(seconds)..];
+    let mut cells: Vec<Span> = (recorded.len()..seconds)
+        .map(|_| Span::styled("·", Style::default().fg(Color::DarkGray)))
+        .collect();
+    cells.extend(
+        recorded
+            .iter()
+            .map(|loss| Span::styled("█", Style::default().fg(loss_color(*loss)))),
+    );
+
+    let loss_map = Paragraph::new(Spans::from(cells)).block(
+        Block::default()
+            .title(trf("Packet loss, last {}s", &[&seconds]))
+            .borders(Borders::ALL),
+    );
+
+    frame.render_widget(loss_map, area);
+}
+
+/// Color of a loss map cell
+fn loss_color(loss_percent: f64) -> Color {
+    match loss_percent {
+        loss if loss <= 0.0 => Color::Green,
+        loss if loss < LOSS_MINOR_PERCENT => Color::Yellow,
+        loss if loss < LOSS_DROPOUT_PERCENT => Color::LightRed,
+        _ => Color::Red,
+    }
+}
+
+/// Keep only the most recent samples that fit inside a bordered area
+fn visible_tail(data: &[u64], area: Rect) -> &[u64] {
+    let width = area.width.saturating_sub(2) as usize;
+    &data[data.len().saturating_sub(width)..]
+}
+
+/// Draw the latest frame with half blocks, or say that none has arrived yet
+#[cfg(feature = "graphics")]
+fn render_terminal_view<B: Backend>(
+    viewer_state: &VideoViewerState,
+    frame: &mut Frame<B>,
+    area: Rect,
+) {
+    let block = Block::default()
+        .title(tr("Terminal View"))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let latest = viewer_state
+        .latest_frame
+        .lock()
+        .ok()
+        .and_then(|latest| latest.clone());
+    match latest.and_then(|jpeg| {
+        preview::frame_lines(&jpeg, &viewer_state.orientation, inner.width, inner.height)
+    }) {
+        Some(lines) => frame.render_widget(Paragraph::new(lines), inner),
+        None => frame.render_widget(Paragraph::new(tr("Waiting for a frame...")), inner),
+    }
+}
+
+/// Describe how live view is turned and shaped, e.g. `Rotated 90°, 16:9`
+pub fn orientation_label(orientation: &Orientation) -> String {
+    let rotation = match orientation.rotation {
+        Rotation::None => tr("Upright").to_string(),
+        rotation => trf("Rotated {}°", &[&rotation.degrees()]),
+    };
+    match orientation.aspect {
+        Some(aspect) => format!("{}, {}", rotation, aspect),
+        None => rotation,
+    }
+}
+
+/// Describe the end-to-end latency estimate and what it adds up from
+pub fn latency_estimate_label(estimate: Option<&LatencyEstimate>) -> String {
+    let Some(estimate) = estimate else {
+        return trf("End-to-end latency: {}", &[&tr("Not measured")]);
+    };
+    let network = estimate
+        .network
+        .map_or(tr("not measured").to_string(), |network| {
+            format!("{} ms", network.as_millis())
+        });
+    trf(
+        "End-to-end latency: ~{} ms, {} (network {}, assembly {} ms, hand-off {} ms)",
+        &[
+            &estimate.total().as_millis(),
+            &tr(estimate.rating().label()),
+            &network,
+            &estimate.assembly.as_millis(),
+            &estimate.handoff.as_millis(),
+        ],
+    )
 }
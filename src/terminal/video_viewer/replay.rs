@@ -0,0 +1,129 @@
+// src/terminal/video_viewer/replay.rs
+use crate::terminal::video_viewer::sinks::{FrameSink, REPLAY_SINK};
+use crate::utils::ffmpeg;
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Instant replay settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayConfig {
+    /// Keep recent frames in memory so they can be exported
+    pub enabled: bool,
+    /// Seconds of live view kept in the buffer
+    pub seconds: u64,
+    /// Export format, `gif` or `mp4`
+    pub format: String,
+    /// Width of exported GIFs in pixels; MP4 keeps the stream size
+    pub gif_width: u32,
+    /// Directory exported clips are saved to
+    pub directory: String,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            seconds: 10,
+            format: "gif".to_string(),
+            gif_width: 480,
+            directory: "replays".to_string(),
+        }
+    }
+}
+
+/// Recent frames with their arrival time, oldest first
+pub type ReplayBuffer = Arc<Mutex<VecDeque<(Instant, Bytes)>>>;
+
+/// Keeps the last few seconds of frames for instant replay
+pub struct ReplaySink {
+    frames: ReplayBuffer,
+    window: Duration,
+}
+
+impl ReplaySink {
+    /// Create a sink that keeps `window` worth of frames in the given buffer
+    pub fn new(frames: ReplayBuffer, window: Duration) -> Self {
+        Self { frames, window }
+    }
+}
+
+impl FrameSink for ReplaySink {
+    fn name(&self) -> &str {
+        REPLAY_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        let now = Instant::now();
+        if let Ok(mut frames) = self.frames.lock() {
+            frames.push_back((now, frame.clone()));
+            while frames
+                .front()
+                .is_some_and(|(at, _)| now.duration_since(*at) > self.window)
+            {
+                frames.pop_front();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Encode buffered frames into a GIF or MP4 named after `stem`, using ffmpeg
+pub fn export_replay(
+    frames: &[(Instant, Bytes)],
+    config: &ReplayConfig,
+    stem: &Path,
+) -> Result<PathBuf> {
+    let (first, last) = match (frames.first(), frames.last()) {
+        (Some((first, _)), Some((last, _))) if frames.len() > 1 => (*first, *last),
+        _ => return Err(anyhow!("Not enough frames buffered for a replay")),
+    };
+
+    // Play back at the rate the frames actually arrived
+    let span = last.duration_since(first).as_secs_f64().max(0.1);
+    let fps = (frames.len() - 1) as f64 / span;
+
+    let (extension, codec_args) = match config.format.as_str() {
+        "gif" => (
+            "gif",
+            vec![
+                "-vf".to_string(),
+                format!(
+                    "scale={}:-1:flags=lanczos,split[a][b];[a]palettegen[p];[b][p]paletteuse",
+                    config.gif_width
+                ),
+            ],
+        ),
+        "mp4" => ("mp4", ffmpeg::mp4_args(23)),
+        other => return Err(anyhow!("Unknown replay format '{}'", other)),
+    };
+
+    let path = stem.with_extension(extension);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    info!(
+        "Exporting {} replay frames at {:.2} fps to {:?}",
+        frames.len(),
+        fps,
+        path
+    );
+
+    ffmpeg::encode_frames(
+        frames.iter().map(|(_, frame)| frame.as_ref()),
+        fps,
+        &codec_args,
+        &path,
+    )?;
+
+    info!("Replay saved to {:?}", path);
+    Ok(path)
+}
@@ -0,0 +1,186 @@
+// src/terminal/video_viewer/rtmp.rs
+use crate::terminal::video_viewer::sinks::{FrameSink, RTMP_SINK};
+use crate::utils::ffmpeg;
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread;
+
+/// Frames queued for ffmpeg before new ones are dropped
+const QUEUE_DEPTH: usize = 8;
+
+/// RTMP push settings for streaming platforms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RtmpConfig {
+    /// Ingest URL of the platform, without the stream key
+    pub url: String,
+    /// Stream key appended to the ingest URL
+    pub stream_key: String,
+    /// Constant frame rate sent to the platform
+    pub fps: u32,
+    /// Video bitrate in kbit/s
+    pub bitrate_kbps: u32,
+    /// x264 preset; faster presets keep up on slower machines
+    pub preset: String,
+    /// Add a silent audio track, which most platforms require
+    pub silent_audio: bool,
+}
+
+impl Default for RtmpConfig {
+    fn default() -> Self {
+        Self {
+            url: "rtmp://live.twitch.tv/app".to_string(),
+            stream_key: String::new(),
+            fps: 30,
+            bitrate_kbps: 2500,
+            preset: "veryfast".to_string(),
+            silent_audio: true,
+        }
+    }
+}
+
+impl RtmpConfig {
+    /// Full ingest URL including the stream key
+    fn ingest_url(&self) -> String {
+        if self.stream_key.is_empty() {
+            self.url.clone()
+        } else {
+            format!("{}/{}", self.url.trim_end_matches('/'), self.stream_key)
+        }
+    }
+}
+
+/// Encodes frames to H.264 and pushes them to an RTMP ingest server through ffmpeg
+pub struct RtmpSink {
+    url: String,
+    sender: SyncSender<Bytes>,
+    child: Child,
+    dropped: u64,
+}
+
+impl RtmpSink {
+    /// Start ffmpeg and connect to the configured ingest server
+    pub fn start(config: &RtmpConfig) -> Result<Self> {
+        if config.url.is_empty() {
+            return Err(anyhow!("No RTMP ingest URL is configured"));
+        }
+        if config.stream_key.is_empty() {
+            warn!("No RTMP stream key is configured, pushing to the bare ingest URL");
+        }
+
+        let fps = config.fps.max(1);
+        let bitrate = format!("{}k", config.bitrate_kbps);
+        let buffer = format!("{}k", config.bitrate_kbps * 2);
+
+        let mut command = Command::new(ffmpeg::find_ffmpeg()?);
+        // The camera sends frames at an uneven rate, so time them as they arrive
+        command
+            .args(["-loglevel", "error", "-use_wallclock_as_timestamps", "1"])
+            .args(["-f", "mjpeg", "-i", "-"]);
+        if config.silent_audio {
+            command.args([
+                "-f",
+                "lavfi",
+                "-i",
+                "anullsrc=channel_layout=stereo:sample_rate=44100",
+            ]);
+            command.args(["-map", "0:v", "-map", "1:a", "-c:a", "aac", "-b:a", "128k"]);
+        }
+        command
+            .args([
+                "-c:v",
+                "libx264",
+                "-preset",
+                &config.preset,
+                "-tune",
+                "zerolatency",
+            ])
+            .args(["-b:v", &bitrate, "-maxrate", &bitrate, "-bufsize", &buffer])
+            .args(["-pix_fmt", "yuv420p", "-r", &fps.to_string()])
+            // A keyframe every two seconds, as the platforms recommend
+            .args(["-g", &(fps * 2).to_string(), "-f", "flv"])
+            .arg(config.ingest_url())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        let mut child = command.spawn()?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("ffmpeg input is unavailable"))?;
+
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_DEPTH);
+        thread::spawn(move || write_frames(stdin, receiver));
+
+        // The key is a secret, so only the ingest server is logged
+        info!("Streaming live view to {}", config.url);
+        Ok(Self {
+            url: config.url.clone(),
+            sender,
+            child,
+            dropped: 0,
+        })
+    }
+
+    /// Ingest server being streamed to, without the stream key
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl FrameSink for RtmpSink {
+    fn name(&self) -> &str {
+        RTMP_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        match self.sender.try_send(frame.clone()) {
+            Ok(_) => Ok(()),
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                debug!("RTMP encoder is behind, dropping frame");
+                Ok(())
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                let status = self
+                    .child
+                    .try_wait()
+                    .ok()
+                    .flatten()
+                    .map(|status| status.to_string())
+                    .unwrap_or_else(|| "stopped reading".to_string());
+                Err(anyhow!("ffmpeg {}", status))
+            }
+        }
+    }
+}
+
+impl Drop for RtmpSink {
+    fn drop(&mut self) {
+        if self.dropped > 0 {
+            info!(
+                "Dropped {} frames the RTMP encoder could not keep up with",
+                self.dropped
+            );
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        info!("RTMP stream to {} stopped", self.url);
+    }
+}
+
+/// Write queued frames to ffmpeg until it exits or the sink is dropped
+fn write_frames(mut stdin: ChildStdin, receiver: Receiver<Bytes>) {
+    while let Ok(frame) = receiver.recv() {
+        if let Err(e) = stdin.write_all(&frame) {
+            warn!("RTMP encoder stopped accepting frames: {}", e);
+            return;
+        }
+    }
+}
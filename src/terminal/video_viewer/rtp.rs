@@ -0,0 +1,152 @@
+// src/terminal/video_viewer/rtp.rs
+use crate::terminal::video_viewer::pool::FramePool;
+use bytes::Bytes;
+use std::time::{Duration, Instant};
+
+/// Length of the fixed RTP header
+const HEADER_LEN: usize = 12;
+
+/// Payload type the camera sends JPEG frames with
+const JPEG_PAYLOAD_TYPE: u8 = 96;
+
+/// One live view packet, its payload a view into the received datagram
+#[derive(Debug, Clone)]
+pub struct RtpPacket {
+    pub version: u8,
+    pub padding: bool,
+    pub extension: bool,
+    pub csrc_count: u8,
+    /// Set on the last packet of a frame
+    pub marker: bool,
+    pub payload_type: u8,
+    pub sequence: u16,
+    /// The camera puts the frame number where RTP has the timestamp
+    pub frame_id: u32,
+    /// JPEG data after the header and any header extension
+    pub payload: Bytes,
+}
+
+impl RtpPacket {
+    /// Parse a datagram without copying its payload
+    pub fn parse(mut packet: Bytes) -> Option<Self> {
+        if packet.len() < HEADER_LEN {
+            return None;
+        }
+        let extension = packet[0] & 0x10 != 0;
+
+        // The extension header gives its own length in 32-bit words
+        let mut header_len = HEADER_LEN;
+        if extension && packet.len() >= HEADER_LEN + 4 {
+            let words = u16::from_be_bytes([packet[14], packet[15]]) as usize;
+            header_len += 4 + words * 4;
+        }
+
+        let parsed = Self {
+            version: packet[0] >> 6,
+            padding: packet[0] & 0x20 != 0,
+            extension,
+            csrc_count: packet[0] & 0x0F,
+            marker: packet[1] & 0x80 != 0,
+            payload_type: packet[1] & 0x7F,
+            sequence: u16::from_be_bytes([packet[2], packet[3]]),
+            frame_id: u32::from_be_bytes([packet[4], packet[5], packet[6], packet[7]]),
+            payload: Bytes::new(),
+        };
+        Some(Self {
+            payload: packet.split_off(header_len.min(packet.len())),
+            ..parsed
+        })
+    }
+
+    /// Whether this is a JPEG packet the camera sends
+    fn is_jpeg(&self) -> bool {
+        self.version == 2 && !self.padding && self.payload_type == JPEG_PAYLOAD_TYPE
+    }
+
+    /// Whether this packet opens a frame, which only the first one has an extension for
+    pub fn starts_frame(&self) -> bool {
+        self.is_jpeg() && self.extension && !self.marker
+    }
+
+    /// Whether this packet continues a frame, ending it when the marker is set
+    pub fn continues_frame(&self) -> bool {
+        self.is_jpeg() && !self.extension && self.csrc_count == 0
+    }
+}
+
+/// Collects the payloads of one frame as views, joining them once when it ends
+#[derive(Default)]
+pub struct FrameBuilder {
+    chunks: Vec<Bytes>,
+    len: usize,
+    frame_id: u32,
+    sequence: u16,
+    building: bool,
+    /// When the first packet of the frame arrived
+    started: Option<Instant>,
+}
+
+impl FrameBuilder {
+    /// Whether a frame has been started
+    pub fn is_building(&self) -> bool {
+        self.building
+    }
+
+    /// Start a frame with its first packet, dropping any unfinished one
+    pub fn start(&mut self, packet: RtpPacket) {
+        self.reset();
+        self.frame_id = packet.frame_id;
+        self.sequence = packet.sequence;
+        self.building = true;
+        self.started = Some(Instant::now());
+        self.push_payload(packet.payload);
+    }
+
+    /// Whether a packet is the next one of the frame being built
+    pub fn follows(&self, packet: &RtpPacket) -> bool {
+        self.building
+            && packet.frame_id == self.frame_id
+            && packet.sequence == self.sequence.wrapping_add(1)
+    }
+
+    /// Add the next packet of the frame
+    pub fn push(&mut self, packet: RtpPacket) {
+        self.sequence = packet.sequence;
+        self.push_payload(packet.payload);
+    }
+
+    fn push_payload(&mut self, payload: Bytes) {
+        self.len += payload.len();
+        self.chunks.push(payload);
+    }
+
+    /// Frame number of the frame being built
+    pub fn frame_id(&self) -> u32 {
+        self.frame_id
+    }
+
+    /// Time since the first packet of the frame arrived
+    pub fn elapsed(&self) -> Duration {
+        self.started
+            .map(|started| started.elapsed())
+            .unwrap_or_default()
+    }
+
+    /// Copy the collected payload views into one contiguous frame from the pool
+    pub fn finish(&mut self, pool: &mut FramePool) -> Bytes {
+        let buffer = pool.buffer(self.len);
+        for chunk in &self.chunks {
+            buffer.extend_from_slice(chunk);
+        }
+        self.reset();
+        buffer.split().freeze()
+    }
+
+    /// Drop the frame being built
+    pub fn reset(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+        self.building = false;
+        self.started = None;
+    }
+}
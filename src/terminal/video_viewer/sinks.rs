@@ -0,0 +1,580 @@
+// src/terminal/video_viewer/sinks.rs
+use crate::terminal::video_viewer::access::{ClientConnection, ServerAccess, ServerAccessConfig};
+use crate::terminal::video_viewer::output::PipeWriter;
+use crate::terminal::video_viewer::timing::FrameStamp;
+use crate::utils::atomic::PartFile;
+use crate::utils::disk::{self, DiskConfig};
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Name of the external player sink
+pub const PLAYER_SINK: &str = "player";
+
+/// Name of the MJPEG file recorder sink
+pub const RECORDER_SINK: &str = "recorder";
+
+/// Name of the MJPEG HTTP server sink
+pub const HTTP_SINK: &str = "http";
+
+/// Name of the latest-frame snapshot sink
+pub const SNAPSHOT_SINK: &str = "snapshot";
+
+/// Name of the instant replay buffer sink
+pub const REPLAY_SINK: &str = "replay";
+
+/// Name of the RTMP streaming sink
+pub const RTMP_SINK: &str = "rtmp";
+
+/// Name of the interval stills sink
+pub const STILLS_SINK: &str = "stills";
+
+/// How often a running recording checks the free disk space
+const DISK_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default port for the MJPEG HTTP server
+pub const DEFAULT_HTTP_PORT: u16 = 8081;
+
+/// Frames that may wait for an MJPEG client before new ones are dropped
+const CLIENT_QUEUE_DEPTH: usize = 2;
+
+/// Destination for assembled JPEG frames
+pub trait FrameSink: Send {
+    /// Name used to look the sink up in the registry
+    fn name(&self) -> &str;
+
+    /// Handle one complete JPEG frame
+    ///
+    /// Frames are shared, so sinks that keep or queue one clone the handle
+    /// rather than copying the data.
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()>;
+
+    /// Handle a frame along with when it was received
+    ///
+    /// Sinks that pass the timing on override this; the rest just take the frame.
+    fn write_stamped(&mut self, frame: &Bytes, _stamp: &FrameStamp) -> Result<()> {
+        self.write_frame(frame)
+    }
+}
+
+/// Set of sinks every assembled frame is fanned out to
+#[derive(Default)]
+pub struct SinkRegistry {
+    sinks: Vec<Box<dyn FrameSink>>,
+    /// While paused, frames are discarded so every sink keeps its last frame
+    paused: bool,
+    /// Wall-clock time of the last frame handed to the sinks
+    last_frame_at: Option<SystemTime>,
+}
+
+impl SinkRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a sink, replacing any existing sink with the same name
+    pub fn add(&mut self, sink: Box<dyn FrameSink>) {
+        info!("Adding frame sink: {}", sink.name());
+        self.remove(sink.name());
+        self.sinks.push(sink);
+    }
+
+    /// Remove a sink by name, returning whether it was registered
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.sinks.len();
+        self.sinks.retain(|sink| sink.name() != name);
+        before != self.sinks.len()
+    }
+
+    /// Whether a sink with the given name is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.sinks.iter().any(|sink| sink.name() == name)
+    }
+
+    /// Names of all registered sinks, in registration order
+    pub fn names(&self) -> Vec<String> {
+        self.sinks
+            .iter()
+            .map(|sink| sink.name().to_string())
+            .collect()
+    }
+
+    /// Freeze or unfreeze output without stopping reception
+    pub fn set_paused(&mut self, paused: bool) {
+        if self.paused != paused {
+            info!("Frame output {}", if paused { "paused" } else { "resumed" });
+            self.paused = paused;
+        }
+    }
+
+    /// Whether output is currently frozen
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Wall-clock time of the last frame sent to the sinks
+    pub fn last_frame_at(&self) -> Option<SystemTime> {
+        self.last_frame_at
+    }
+
+    /// Send a frame to every sink, dropping sinks that fail
+    pub fn dispatch(&mut self, frame: &Bytes, stamp: &FrameStamp) {
+        if self.paused {
+            return;
+        }
+        self.last_frame_at = Some(stamp.received);
+
+        self.sinks
+            .retain_mut(|sink| match sink.write_stamped(frame, stamp) {
+                Ok(_) => true,
+                Err(e) => {
+                    warn!("Removing frame sink {}: {}", sink.name(), e);
+                    false
+                }
+            });
+    }
+}
+
+impl FrameSink for PipeWriter {
+    fn name(&self) -> &str {
+        PLAYER_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        self.send(frame);
+        Ok(())
+    }
+}
+
+/// Recording location, segmentation and retention settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecordingConfig {
+    /// Directory recordings are written to
+    pub directory: String,
+    /// Start a new file after this many minutes; 0 records a single file
+    pub segment_minutes: u64,
+    /// Delete the oldest segments of a recording beyond this many; 0 keeps them all
+    pub keep_segments: usize,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            directory: "recordings".to_string(),
+            segment_minutes: 10,
+            keep_segments: 0,
+        }
+    }
+}
+
+/// Appends frames to an MJPEG file, optionally split into time-based segments
+///
+/// Each segment is written as a `.part` file and renamed when it is closed,
+/// together with a `.timestamps.csv` file giving every frame's receive time
+/// and byte offset. Recording stops, keeping what was written, when the disk
+/// runs low.
+pub struct RecorderSink {
+    stem: PathBuf,
+    segment_length: Option<Duration>,
+    keep_segments: usize,
+    segment_index: u32,
+    segment_started: Instant,
+    segments: VecDeque<PathBuf>,
+    path: PathBuf,
+    file: PartFile,
+    timestamps: TimestampLog,
+    disk: DiskConfig,
+    last_space_check: Instant,
+}
+
+impl RecorderSink {
+    /// Start recording to `<stem>.mjpeg`, or to `<stem>_001.mjpeg` onwards when segmenting
+    pub fn create(stem: &Path, config: &RecordingConfig, disk: &DiskConfig) -> Result<Self> {
+        let segment_length =
+            (config.segment_minutes > 0).then(|| Duration::from_secs(config.segment_minutes * 60));
+        let segment_index = 1;
+        let path = segment_path(stem, segment_length.map(|_| segment_index));
+
+        let file = PartFile::create(&path)?;
+        let timestamps = TimestampLog::create(&path)?;
+        info!("Recording stream to {:?}", path);
+
+        Ok(Self {
+            stem: stem.to_path_buf(),
+            segment_length,
+            keep_segments: config.keep_segments,
+            segment_index,
+            segment_started: Instant::now(),
+            segments: VecDeque::from([path.clone()]),
+            path,
+            file,
+            timestamps,
+            disk: disk.clone(),
+            last_space_check: Instant::now(),
+        })
+    }
+
+    /// File currently being written
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Close the current segment, open the next one and apply the retention limit
+    fn rotate(&mut self) -> Result<()> {
+        self.file.commit()?;
+        self.timestamps.commit()?;
+        info!("Recording segment saved to {:?}", self.path);
+
+        self.segment_index += 1;
+        let path = segment_path(&self.stem, Some(self.segment_index));
+        self.file = PartFile::create(&path)?;
+        self.timestamps = TimestampLog::create(&path)?;
+        self.segment_started = Instant::now();
+        self.segments.push_back(path.clone());
+        self.path = path;
+        info!("Recording stream to {:?}", self.path);
+
+        while self.keep_segments > 0 && self.segments.len() > self.keep_segments {
+            if let Some(oldest) = self.segments.pop_front() {
+                match fs::remove_file(&oldest) {
+                    Ok(_) => info!("Removed old recording segment {:?}", oldest),
+                    Err(e) => warn!("Failed to remove old segment {:?}: {}", oldest, e),
+                }
+                let _ = fs::remove_file(timestamps_path(&oldest));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Recording file name for the given segment, or the plain name when not segmenting
+fn segment_path(stem: &Path, segment: Option<u32>) -> PathBuf {
+    let mut name = stem.as_os_str().to_owned();
+    match segment {
+        Some(index) => name.push(format!("_{:03}.mjpeg", index)),
+        None => name.push(".mjpeg"),
+    }
+    PathBuf::from(name)
+}
+
+/// Timestamps file kept next to a recording segment
+fn timestamps_path(segment: &Path) -> PathBuf {
+    segment.with_extension("timestamps.csv")
+}
+
+/// Receive time and position of every frame of one recording segment
+struct TimestampLog {
+    file: PartFile,
+    frames: u64,
+    offset: u64,
+}
+
+impl TimestampLog {
+    fn create(segment: &Path) -> Result<Self> {
+        let mut file = PartFile::create(&timestamps_path(segment))?;
+        writeln!(file, "frame,frame_id,received_us,assembly_us,offset")?;
+        Ok(Self {
+            file,
+            frames: 0,
+            offset: 0,
+        })
+    }
+
+    /// Note a frame of `len` bytes written at the current end of the segment
+    fn record(&mut self, stamp: &FrameStamp, len: usize) -> Result<()> {
+        self.frames += 1;
+        writeln!(
+            self.file,
+            "{},{},{},{},{}",
+            self.frames,
+            stamp.frame_id,
+            stamp.unix_micros(),
+            stamp.assembly.as_micros(),
+            self.offset
+        )?;
+        self.offset += len as u64;
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        self.file.commit()
+    }
+}
+
+impl FrameSink for RecorderSink {
+    fn name(&self) -> &str {
+        RECORDER_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        self.write_stamped(frame, &FrameStamp::now(0, Duration::ZERO))
+    }
+
+    fn write_stamped(&mut self, frame: &Bytes, stamp: &FrameStamp) -> Result<()> {
+        // Segments are cut between frames so every file plays on its own
+        if self
+            .segment_length
+            .is_some_and(|length| self.segment_started.elapsed() >= length)
+        {
+            self.rotate()?;
+        }
+
+        if self.last_space_check.elapsed() >= DISK_CHECK_INTERVAL {
+            self.last_space_check = Instant::now();
+            disk::check_free_space(&self.path, 0, &self.disk)
+                .map_err(|e| anyhow!("Recording stopped: {}", e))?;
+        }
+
+        self.timestamps
+            .record(stamp, frame.len())
+            .map_err(|e| anyhow!("Failed to write timestamps of {:?}: {}", self.path, e))?;
+        self.file
+            .write_all(frame)
+            .map_err(|e| anyhow!("Failed to write {:?}: {}", self.path, e))
+    }
+}
+
+impl Drop for RecorderSink {
+    fn drop(&mut self) {
+        if let Err(e) = self.timestamps.commit() {
+            warn!("Failed to finish timestamps of {:?}: {}", self.path, e);
+        }
+        match self.file.commit() {
+            Ok(_) => info!("Recording saved to {:?}", self.path),
+            Err(e) => warn!("Failed to finish recording {:?}: {}", self.path, e),
+        }
+    }
+}
+
+/// Clients of the MJPEG server the stream is written to
+type MjpegClients = Arc<Mutex<Vec<MjpegClient>>>;
+
+/// Multipart header and JPEG data of one frame sent to MJPEG clients
+type MjpegPart = (Bytes, Bytes);
+
+/// Client of the MJPEG server, written to from its own thread so a slow
+/// viewer never holds up reception or the other sinks
+struct MjpegClient {
+    addr: SocketAddr,
+    /// Bounded queue of parts waiting to be written
+    sender: SyncSender<MjpegPart>,
+    /// Frames dropped because the queue was full
+    dropped: u64,
+}
+
+impl MjpegClient {
+    /// Start the writer thread for an admitted client
+    fn start(connection: Box<dyn ClientConnection>, addr: SocketAddr) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(CLIENT_QUEUE_DEPTH);
+        thread::spawn(move || write_parts(connection, receiver, addr));
+        Self {
+            addr,
+            sender,
+            dropped: 0,
+        }
+    }
+
+    /// Queue a part without blocking, returning false once the client is gone
+    fn send(&mut self, part: &MjpegPart) -> bool {
+        match self.sender.try_send(part.clone()) {
+            Ok(_) => true,
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
+impl Drop for MjpegClient {
+    fn drop(&mut self) {
+        if self.dropped > 0 {
+            info!(
+                "Dropped {} frames MJPEG client {} could not keep up with",
+                self.dropped, self.addr
+            );
+        }
+    }
+}
+
+/// Write queued parts to a client until it fails or the server stops
+fn write_parts(
+    mut connection: Box<dyn ClientConnection>,
+    receiver: Receiver<MjpegPart>,
+    addr: SocketAddr,
+) {
+    while let Ok(mut part) = receiver.recv() {
+        // Skip ahead to the newest frame if several are waiting
+        while let Ok(newer) = receiver.try_recv() {
+            part = newer;
+        }
+
+        let (header, frame) = &part;
+        let result = connection
+            .write_all(header)
+            .and_then(|_| connection.write_all(frame))
+            .and_then(|_| connection.write_all(b"\r\n"))
+            .and_then(|_| connection.flush());
+        if let Err(e) = result {
+            info!("MJPEG client {} disconnected: {}", addr, e);
+            return;
+        }
+    }
+}
+
+/// Serves frames as a multipart MJPEG stream for browsers and network players
+pub struct MjpegHttpSink {
+    port: u16,
+    clients: MjpegClients,
+    running: Arc<AtomicBool>,
+}
+
+impl MjpegHttpSink {
+    /// Start listening for HTTP clients on the given port, admitting those `access` allows
+    ///
+    /// The server only accepts connections from this machine unless `access`
+    /// opts in to the local network.
+    pub fn start(port: u16, access: &ServerAccessConfig) -> Result<Self> {
+        let address = if access.lan {
+            Ipv4Addr::UNSPECIFIED
+        } else {
+            Ipv4Addr::LOCALHOST
+        };
+        let access = Arc::new(ServerAccess::new(access)?);
+        let listener = TcpListener::bind((address, port))?;
+        listener.set_nonblocking(true)?;
+        info!("MJPEG HTTP server listening on {}:{}", address, port);
+
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread_clients = Arc::clone(&clients);
+        let thread_running = Arc::clone(&running);
+        thread::spawn(move || accept_clients(listener, access, thread_clients, thread_running));
+
+        Ok(Self {
+            port,
+            clients,
+            running,
+        })
+    }
+
+    /// Port the server is listening on
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl FrameSink for MjpegHttpSink {
+    fn name(&self) -> &str {
+        HTTP_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        self.write_stamped(frame, &FrameStamp::now(0, Duration::ZERO))
+    }
+
+    fn write_stamped(&mut self, frame: &Bytes, stamp: &FrameStamp) -> Result<()> {
+        // Each part carries its receive time the way other MJPEG servers send it
+        let header = format!(
+            "--frame\r\nContent-Type: image/jpeg\r\nContent-Length: {}\r\nX-Timestamp: {}\r\n\r\n",
+            frame.len(),
+            stamp.header_value()
+        );
+        let part = (Bytes::from(header), frame.clone());
+
+        // Clients whose writer has ended are forgotten
+        if let Ok(mut clients) = self.clients.lock() {
+            clients.retain_mut(|client| client.send(&part));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for MjpegHttpSink {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        info!("MJPEG HTTP server on port {} stopped", self.port);
+    }
+}
+
+/// Accept HTTP clients, check their credentials and send them the multipart response header
+///
+/// Each client is set up on its own thread so a slow TLS handshake or request
+/// doesn't hold up the others.
+fn accept_clients(
+    listener: TcpListener,
+    access: Arc<ServerAccess>,
+    clients: MjpegClients,
+    running: Arc<AtomicBool>,
+) {
+    while running.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, addr)) => {
+                let access = Arc::clone(&access);
+                let clients = Arc::clone(&clients);
+                thread::spawn(move || match access.admit(stream) {
+                    Ok(Some(connection)) => {
+                        info!("MJPEG client connected from {}", addr);
+                        if let Ok(mut clients) = clients.lock() {
+                            clients.push(MjpegClient::start(connection, addr));
+                        }
+                    }
+                    Ok(None) => warn!("Refused MJPEG client {} without valid credentials", addr),
+                    Err(e) => warn!("Failed to set up MJPEG client {}: {}", addr, e),
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(100));
+            }
+            Err(e) => {
+                warn!("MJPEG server accept error: {}", e);
+                thread::sleep(Duration::from_millis(100));
+            }
+        }
+    }
+}
+
+/// Latest frame shared between the snapshot sink and the UI
+pub type SnapshotBuffer = Arc<Mutex<Option<Bytes>>>;
+
+/// Keeps the most recent frame for snapshots
+pub struct SnapshotSink {
+    latest: SnapshotBuffer,
+}
+
+impl SnapshotSink {
+    /// Create a sink that stores frames into the given buffer
+    pub fn new(latest: SnapshotBuffer) -> Self {
+        Self { latest }
+    }
+}
+
+impl FrameSink for SnapshotSink {
+    fn name(&self) -> &str {
+        SNAPSHOT_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        if let Ok(mut latest) = self.latest.lock() {
+            *latest = Some(frame.clone());
+        }
+        Ok(())
+    }
+}
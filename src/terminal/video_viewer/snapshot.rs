@@ -0,0 +1,180 @@
+// src/terminal/video_viewer/snapshot.rs
+// Fallback for networks that block the camera's UDP live view. The Olympus Air
+// only streams live view over UDP and has no live frame request over HTTP, so
+// the fallback polls still requests at a low rate and feeds the results to the
+// same sinks the UDP receiver does.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use crate::terminal::video_viewer::sinks::SinkRegistry;
+use crate::terminal::video_viewer::state::VideoViewerState;
+use crate::terminal::video_viewer::stats::StreamStats;
+use crate::terminal::video_viewer::timing::{FrameStamp, FrameTiming};
+use bytes::Bytes;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Snapshot loop fallback settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SnapshotFallbackConfig {
+    /// Offer the snapshot loop when live view packets never arrive
+    pub enabled: bool,
+    /// Seconds without a single UDP packet before the fallback is offered
+    pub udp_timeout_secs: u64,
+    /// Snapshots requested per second
+    pub fps: f64,
+    /// Camera requests tried for each snapshot, first working one kept
+    pub requests: Vec<String>,
+}
+
+impl Default for SnapshotFallbackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            udp_timeout_secs: 5,
+            fps: 2.0,
+            requests: vec![
+                "exec_takemisc.cgi?com=getrecview".to_string(),
+                "exec_takemisc.cgi?com=getlastjpg".to_string(),
+            ],
+        }
+    }
+}
+
+/// Polls the camera for JPEG snapshots on a background thread until dropped
+pub struct SnapshotLoop {
+    running: Arc<AtomicBool>,
+    fps: f64,
+}
+
+impl SnapshotLoop {
+    /// Start polling, counting and dispatching frames like the UDP receiver
+    pub fn start(
+        camera: &OlympusCamera,
+        config: &SnapshotFallbackConfig,
+        viewer_state: &VideoViewerState,
+    ) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+        let interval = Duration::from_secs_f64(1.0 / config.fps.clamp(0.1, 10.0));
+        let feed = Feed {
+            camera: camera.clone(),
+            requests: config.requests.clone(),
+            interval,
+            running: Arc::clone(&running),
+            viewer: FeedTargets::from(viewer_state),
+        };
+        thread::spawn(move || feed.run());
+
+        info!("Snapshot loop started at {:.1} fps", config.fps);
+        Self {
+            running,
+            fps: config.fps,
+        }
+    }
+
+    /// Snapshots requested per second
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+}
+
+impl Drop for SnapshotLoop {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        info!("Snapshot loop stopped");
+    }
+}
+
+/// Shared viewer counters and sinks the loop updates
+struct FeedTargets {
+    jpeg_frames: Arc<Mutex<u32>>,
+    last_frame_time: Arc<Mutex<Instant>>,
+    last_frame_size: Arc<Mutex<usize>>,
+    stream_stats: Arc<Mutex<StreamStats>>,
+    sinks: Arc<Mutex<SinkRegistry>>,
+}
+
+impl From<&VideoViewerState> for FeedTargets {
+    fn from(viewer_state: &VideoViewerState) -> Self {
+        Self {
+            jpeg_frames: Arc::clone(&viewer_state.jpeg_frames),
+            last_frame_time: Arc::clone(&viewer_state.last_frame_time),
+            last_frame_size: Arc::clone(&viewer_state.last_frame_size),
+            stream_stats: Arc::clone(&viewer_state.stream_stats),
+            sinks: Arc::clone(&viewer_state.sinks),
+        }
+    }
+}
+
+struct Feed {
+    camera: OlympusCamera,
+    requests: Vec<String>,
+    interval: Duration,
+    running: Arc<AtomicBool>,
+    viewer: FeedTargets,
+}
+
+impl Feed {
+    fn run(self) {
+        let mut frames: u32 = 0;
+        while self.running.load(Ordering::Relaxed) {
+            let started = Instant::now();
+            match self.fetch() {
+                Some(frame) => {
+                    frames += 1;
+                    let stamp = FrameStamp::now(frames, started.elapsed());
+                    self.deliver(frame, &stamp);
+                }
+                None => debug!("No snapshot from the camera"),
+            }
+            thread::sleep(self.interval.saturating_sub(started.elapsed()));
+        }
+    }
+
+    /// A JPEG from the first request that gives one
+    fn fetch(&self) -> Option<Bytes> {
+        self.requests
+            .iter()
+            .find_map(|request| match self.camera.get_binary(request) {
+                Ok(data) if data.starts_with(&[0xFF, 0xD8]) => Some(Bytes::from(data)),
+                Ok(_) => None,
+                Err(e) => {
+                    debug!("Snapshot request {} failed: {}", request, e);
+                    None
+                }
+            })
+    }
+
+    fn deliver(&self, frame: Bytes, stamp: &FrameStamp) {
+        let viewer = &self.viewer;
+        if let Ok(mut count) = viewer.jpeg_frames.lock() {
+            *count = stamp.frame_id;
+        }
+        if let Ok(mut time) = viewer.last_frame_time.lock() {
+            *time = Instant::now();
+        }
+        if let Ok(mut size) = viewer.last_frame_size.lock() {
+            *size = frame.len();
+        }
+        if let Ok(mut stats) = viewer.stream_stats.lock() {
+            stats.record_packet(frame.len());
+            stats.record_frame();
+            stats.roll();
+        }
+        let handoff_started = Instant::now();
+        match viewer.sinks.lock() {
+            Ok(mut sinks) => sinks.dispatch(&frame, stamp),
+            Err(_) => warn!("Frame sinks are unavailable"),
+        }
+        if let Ok(mut stats) = viewer.stream_stats.lock() {
+            stats.record_timing(FrameTiming {
+                assembly: stamp.assembly,
+                handoff: handoff_started.elapsed(),
+            });
+        }
+    }
+}
@@ -1,8 +1,36 @@
 // src/terminal/video_viewer/state.rs
+use crate::net::address;
+use crate::net::latency::Latency;
+use crate::net::link::LinkQuality;
+use crate::terminal::player_window::PlayerWindowConfig;
+use crate::terminal::video_viewer::access::ServerAccessConfig;
+use crate::terminal::video_viewer::adjust::PreviewAdjustments;
+use crate::terminal::video_viewer::orientation::Orientation;
+use crate::terminal::video_viewer::privacy::PrivacyConfig;
+use crate::terminal::video_viewer::quality::QualityController;
+use crate::terminal::video_viewer::replay::{self, ReplayBuffer, ReplayConfig, ReplaySink};
+use crate::terminal::video_viewer::rtmp::{RtmpConfig, RtmpSink};
+use crate::terminal::video_viewer::sinks::{
+    HTTP_SINK, MjpegHttpSink, RECORDER_SINK, RTMP_SINK, RecorderSink, RecordingConfig, STILLS_SINK,
+    SinkRegistry, SnapshotBuffer, SnapshotSink,
+};
+use crate::terminal::video_viewer::snapshot::SnapshotLoop;
+use crate::terminal::video_viewer::stats::{
+    FPS_WINDOW_SECONDS, FpsSummary, StreamHealth, StreamStats,
+};
+use crate::terminal::video_viewer::stills::{StillsConfig, StillsLog, StillsSink};
+use crate::terminal::video_viewer::timing::LatencyEstimate;
+use crate::utils::alert::TitleFlash;
+use crate::utils::disk::DiskConfig;
+use anyhow::{Result, anyhow};
 use log::info;
-use std::path::PathBuf;
+use std::collections::VecDeque;
+use std::fs;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::process::Child;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, UNIX_EPOCH};
 
 /// Available streaming modes for video
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +44,9 @@ pub struct VideoViewerState {
     /// Stream URL (camera IP)
     pub stream_url: String,
 
+    /// Local address the UDP receiver listens on; its port is replaced by `udp_port`
+    pub listen_address: SocketAddr,
+
     /// Name of the stream/video
     pub stream_name: String,
 
@@ -25,9 +56,6 @@ pub struct VideoViewerState {
     /// Path to save the stream (if recording)
     pub recording_path: Option<PathBuf>,
 
-    /// Whether stream is being recorded
-    pub is_recording: bool,
-
     /// UDP Local port for receiving stream
     pub udp_port: u16,
 
@@ -54,17 +82,87 @@ pub struct VideoViewerState {
 
     /// Size of last frame (bytes)
     pub last_frame_size: Arc<Mutex<usize>>,
+
+    /// Rolling per-second frame and byte history
+    pub stream_stats: Arc<Mutex<StreamStats>>,
+
+    /// Sinks every assembled frame is sent to
+    pub sinks: Arc<Mutex<SinkRegistry>>,
+
+    /// Most recent frame, kept for snapshots
+    pub latest_frame: SnapshotBuffer,
+
+    /// Port of the MJPEG HTTP server, if running
+    pub http_port: Option<u16>,
+
+    /// RTMP ingest server the stream was last pushed to
+    pub rtmp_url: Option<String>,
+
+    /// Recent frames kept for instant replay
+    pub replay_frames: ReplayBuffer,
+
+    /// Stills saved by the running interval sequence
+    pub stills: StillsLog,
+
+    /// Exposure compensation and white balance set from the viewer
+    pub adjustments: PreviewAdjustments,
+
+    /// Latest wireless link quality, copied in by the app
+    pub link_quality: Option<LinkQuality>,
+
+    /// Latest round trip to the camera, copied in by the app
+    pub latency: Option<Latency>,
+
+    /// Snapshot polling used in place of UDP live view
+    pub snapshot_loop: Option<SnapshotLoop>,
+
+    /// Whether the snapshot fallback has been offered for this stream
+    pub fallback_offered: bool,
+
+    /// Adaptive live view size, when enabled
+    pub quality: Option<QualityController>,
+
+    /// Whether frames have stopped for long enough to raise the freeze alert
+    pub frozen: bool,
+
+    /// Terminal title flashing while frozen, restored when dropped
+    pub title_flash: Option<TitleFlash>,
+
+    /// External player process, kept to notice its window being closed
+    pub player: Option<Child>,
+
+    /// Where the external player opens its window
+    pub player_window: PlayerWindowConfig,
+
+    /// How live view is turned in the player and the terminal view
+    pub orientation: Orientation,
+
+    /// Areas blacked out of frames sent to the MJPEG server and RTMP stream
+    pub privacy: PrivacyConfig,
+
+    /// Token, password and certificate protecting the MJPEG server
+    pub http_access: ServerAccessConfig,
+
+    /// Whether the player was closed while the stream kept running
+    pub player_closed: bool,
+
+    /// Whether the live view is drawn in the terminal
+    pub terminal_view: bool,
 }
 
 impl VideoViewerState {
     /// Create a new video viewer state
     pub fn new(stream_url: &str, stream_name: &str) -> Self {
+        let latest_frame: SnapshotBuffer = Arc::new(Mutex::new(None));
+        let mut sinks = SinkRegistry::new();
+        sinks.add(Box::new(SnapshotSink::new(Arc::clone(&latest_frame))));
+
         Self {
             stream_url: stream_url.to_string(),
+            listen_address: SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0),
             stream_name: stream_name.to_string(),
             is_playing: false,
             recording_path: None,
-            is_recording: false,
             udp_port: 65001, // Default UDP port for Olympus
             external_viewer_pid: None,
             udp_thread_handle: None,
@@ -74,15 +172,36 @@ impl VideoViewerState {
             jpeg_frames: Arc::new(Mutex::new(0)),
             last_frame_time: Arc::new(Mutex::new(Instant::now())),
             last_frame_size: Arc::new(Mutex::new(0)),
+            stream_stats: Arc::new(Mutex::new(StreamStats::new())),
+            sinks: Arc::new(Mutex::new(sinks)),
+            latest_frame,
+            http_port: None,
+            rtmp_url: None,
+            replay_frames: Arc::new(Mutex::new(VecDeque::new())),
+            stills: Arc::new(Mutex::new(Vec::new())),
+            adjustments: PreviewAdjustments::default(),
+            link_quality: None,
+            latency: None,
+            snapshot_loop: None,
+            fallback_offered: false,
+            quality: None,
+            frozen: false,
+            title_flash: None,
+            player: None,
+            player_window: PlayerWindowConfig::default(),
+            orientation: Orientation::default(),
+            privacy: PrivacyConfig::default(),
+            http_access: ServerAccessConfig::default(),
+            player_closed: false,
+            terminal_view: false,
         }
     }
 
     /// Generate URL for display purposes
     pub fn generate_stream_url(&self) -> String {
         let url = format!(
-            "olympus-udp://{}:{}",
-            self.stream_url.split(':').next().unwrap_or("192.168.0.10"),
-            self.udp_port
+            "olympus-udp://{}",
+            address::host_port(&self.stream_url, self.udp_port)
         );
         info!("Generated URL for streaming: {}", url);
         url
@@ -97,6 +216,35 @@ impl VideoViewerState {
         }
     }
 
+    /// Health of the stream, telling camera stalls from network trouble where possible
+    pub fn health(&self) -> StreamHealth {
+        StreamHealth::assess(self.get_time_since_last_frame(), self.latency.as_ref())
+    }
+
+    /// Window and video filter flags for the live view player, `mplayer` or `ffplay`
+    #[cfg(feature = "player")]
+    pub fn player_args(&self, player: &str) -> Vec<String> {
+        let window = if self.orientation.rotation.is_sideways() {
+            self.player_window.turned()
+        } else {
+            self.player_window.clone()
+        };
+        let mut args = window.args(Path::new(player));
+        args.extend(self.orientation.player_args(player));
+        args
+    }
+
+    /// Estimated delay from the camera to the screen, once frames have been timed
+    pub fn latency_estimate(&self) -> Option<LatencyEstimate> {
+        let timing = self.stream_stats.lock().ok()?.frame_timing()?;
+        // Snapshot fetch times already include the trip to the camera
+        let latency = self
+            .latency
+            .as_ref()
+            .filter(|_| self.snapshot_loop.is_none());
+        Some(LatencyEstimate::new(timing, latency))
+    }
+
     /// Get packet and frame statistics
     pub fn get_statistics(&self) -> (u32, u32, usize) {
         let packets = self.packets_received.lock().map(|p| *p).unwrap_or(0);
@@ -106,14 +254,277 @@ impl VideoViewerState {
         (packets, frames, last_size)
     }
 
-    /// Start recording
-    pub fn start_recording(&mut self, path: PathBuf) {
+    /// Get the per-second FPS and bandwidth (KB/s) histories
+    pub fn get_history(&self) -> (Vec<u64>, Vec<u64>) {
+        self.stream_stats
+            .lock()
+            .map(|mut stats| {
+                stats.roll();
+                (stats.fps_history(), stats.bandwidth_history_kb())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the per-second packet loss history
+    pub fn get_loss_history(&self) -> Vec<f64> {
+        self.stream_stats
+            .lock()
+            .map(|mut stats| {
+                stats.roll();
+                stats.loss_history()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the rolling frame rate summary over the last 30 seconds
+    pub fn get_fps_summary(&self) -> FpsSummary {
+        self.stream_stats
+            .lock()
+            .map(|mut stats| {
+                stats.roll();
+                stats.fps_summary(FPS_WINDOW_SECONDS)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Start appending per-second statistics to a CSV file
+    pub fn start_stats_export(&mut self, path: &Path) -> Result<()> {
+        let mut stats = self
+            .stream_stats
+            .lock()
+            .map_err(|_| anyhow!("Stream statistics are unavailable"))?;
+        stats.start_csv_export(path)?;
+        info!("Exporting stream statistics to {:?}", path);
+        Ok(())
+    }
+
+    /// Stop the CSV statistics export
+    pub fn stop_stats_export(&mut self) {
+        if let Ok(mut stats) = self.stream_stats.lock() {
+            stats.stop_csv_export();
+        }
+    }
+
+    /// Path of the active statistics CSV file, if exporting
+    pub fn stats_export_path(&self) -> Option<PathBuf> {
+        self.stream_stats
+            .lock()
+            .ok()
+            .and_then(|stats| stats.csv_path().map(Path::to_path_buf))
+    }
+
+    /// Names of the sinks currently receiving frames
+    pub fn sink_names(&self) -> Vec<String> {
+        self.sinks
+            .lock()
+            .map(|sinks| sinks.names())
+            .unwrap_or_default()
+    }
+
+    /// Whether the recorder sink is writing frames
+    pub fn is_recording(&self) -> bool {
+        self.sinks
+            .lock()
+            .map(|sinks| sinks.contains(RECORDER_SINK))
+            .unwrap_or(false)
+    }
+
+    /// Whether output is frozen while the receiver keeps running
+    pub fn is_paused(&self) -> bool {
+        self.sinks
+            .lock()
+            .map(|sinks| sinks.is_paused())
+            .unwrap_or(false)
+    }
+
+    /// Freeze or unfreeze output to the player and other sinks
+    pub fn set_paused(&mut self, paused: bool) {
+        if let Ok(mut sinks) = self.sinks.lock() {
+            sinks.set_paused(paused);
+        }
+    }
+
+    /// UTC time of day of the last frame sent to the sinks, as HH:MM:SS
+    pub fn last_frame_clock(&self) -> Option<String> {
+        let at = self.sinks.lock().ok()?.last_frame_at()?;
+        let secs = at.duration_since(UNIX_EPOCH).ok()?.as_secs() % 86_400;
+        Some(format!(
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        ))
+    }
+
+    /// Start recording frames to MJPEG files named after `stem`
+    pub fn start_recording(
+        &mut self,
+        stem: &Path,
+        config: &RecordingConfig,
+        disk: &DiskConfig,
+    ) -> Result<()> {
+        let recorder = RecorderSink::create(stem, config, disk)?;
+        let path = recorder.path().to_path_buf();
+        self.sinks
+            .lock()
+            .map_err(|_| anyhow!("Frame sinks are unavailable"))?
+            .add(Box::new(recorder));
         self.recording_path = Some(path);
-        self.is_recording = true;
+        Ok(())
     }
 
     /// Stop recording
     pub fn stop_recording(&mut self) {
-        self.is_recording = false;
+        if let Ok(mut sinks) = self.sinks.lock() {
+            sinks.remove(RECORDER_SINK);
+        }
+    }
+
+    /// Start serving the stream as MJPEG over HTTP, with the privacy masks painted in
+    pub fn start_http_server(&mut self, port: u16) -> Result<()> {
+        let server = MjpegHttpSink::start(port, &self.http_access)?;
+        let port = server.port();
+        let sink = self.privacy.mask(Box::new(server))?;
+        self.sinks
+            .lock()
+            .map_err(|_| anyhow!("Frame sinks are unavailable"))?
+            .add(sink);
+        self.http_port = Some(port);
+        Ok(())
+    }
+
+    /// Address of the running MJPEG server on this machine
+    pub fn http_url(&self) -> Option<String> {
+        self.http_port
+            .map(|port| format!("{}://localhost:{}/", self.http_access.scheme(), port))
+    }
+
+    /// Stop the MJPEG HTTP server
+    pub fn stop_http_server(&mut self) {
+        if let Ok(mut sinks) = self.sinks.lock() {
+            sinks.remove(HTTP_SINK);
+        }
+        self.http_port = None;
+    }
+
+    /// Start pushing the stream to an RTMP ingest server, with the privacy masks painted in
+    pub fn start_rtmp(&mut self, config: &RtmpConfig) -> Result<()> {
+        let sink = RtmpSink::start(config)?;
+        let url = sink.url().to_string();
+        let sink = self.privacy.mask(Box::new(sink))?;
+        self.sinks
+            .lock()
+            .map_err(|_| anyhow!("Frame sinks are unavailable"))?
+            .add(sink);
+        self.rtmp_url = Some(url);
+        Ok(())
+    }
+
+    /// Stop pushing the stream to the RTMP server
+    pub fn stop_rtmp(&mut self) {
+        if let Ok(mut sinks) = self.sinks.lock() {
+            sinks.remove(RTMP_SINK);
+        }
+        self.rtmp_url = None;
+    }
+
+    /// Whether the RTMP sink is still pushing frames
+    pub fn is_streaming_rtmp(&self) -> bool {
+        self.sinks
+            .lock()
+            .map(|sinks| sinks.contains(RTMP_SINK))
+            .unwrap_or(false)
+    }
+
+    /// Start saving a still from the stream at the configured interval
+    pub fn start_stills(&mut self, config: &StillsConfig) -> Result<()> {
+        if let Ok(mut stills) = self.stills.lock() {
+            stills.clear();
+        }
+        let sink = StillsSink::create(config, Arc::clone(&self.stills))?;
+        self.sinks
+            .lock()
+            .map_err(|_| anyhow!("Frame sinks are unavailable"))?
+            .add(Box::new(sink));
+        Ok(())
+    }
+
+    /// Stop saving stills, returning the paths saved in this sequence
+    pub fn stop_stills(&mut self) -> Vec<PathBuf> {
+        if let Ok(mut sinks) = self.sinks.lock() {
+            sinks.remove(STILLS_SINK);
+        }
+        self.stills
+            .lock()
+            .map(|mut stills| std::mem::take(&mut *stills))
+            .unwrap_or_default()
+    }
+
+    /// Whether stills are being saved, and how many so far
+    pub fn stills_saved(&self) -> Option<usize> {
+        let running = self
+            .sinks
+            .lock()
+            .map(|sinks| sinks.contains(STILLS_SINK))
+            .unwrap_or(false);
+        if !running {
+            return None;
+        }
+        self.stills.lock().map(|stills| stills.len()).ok()
+    }
+
+    /// Start keeping the last `window` of frames for instant replay
+    pub fn start_replay_buffer(&mut self, window: Duration) -> Result<()> {
+        let sink = ReplaySink::new(Arc::clone(&self.replay_frames), window);
+        self.sinks
+            .lock()
+            .map_err(|_| anyhow!("Frame sinks are unavailable"))?
+            .add(Box::new(sink));
+        Ok(())
+    }
+
+    /// Number of frames and seconds of video currently buffered for replay
+    pub fn replay_buffered(&self) -> (usize, Duration) {
+        self.replay_frames
+            .lock()
+            .map(|frames| {
+                let span = match (frames.front(), frames.back()) {
+                    (Some((first, _)), Some((last, _))) => last.duration_since(*first),
+                    _ => Duration::ZERO,
+                };
+                (frames.len(), span)
+            })
+            .unwrap_or((0, Duration::ZERO))
+    }
+
+    /// Export the buffered frames as a clip named after `stem`
+    pub fn export_replay(&self, config: &ReplayConfig, stem: &Path) -> Result<PathBuf> {
+        // Take the frames out so reception isn't blocked while encoding
+        let frames: Vec<_> = self
+            .replay_frames
+            .lock()
+            .map_err(|_| anyhow!("Replay buffer is unavailable"))?
+            .iter()
+            .cloned()
+            .collect();
+        replay::export_replay(&frames, config, stem)
+    }
+
+    /// Save the most recent frame as a JPEG file
+    pub fn save_snapshot(&self, path: &Path) -> Result<()> {
+        let latest = self
+            .latest_frame
+            .lock()
+            .map_err(|_| anyhow!("Snapshot buffer is unavailable"))?;
+        let frame = latest
+            .as_ref()
+            .ok_or_else(|| anyhow!("No frame has been received yet"))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, frame)?;
+        info!("Saved snapshot to {:?}", path);
+        Ok(())
     }
 }
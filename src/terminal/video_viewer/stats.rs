@@ -0,0 +1,321 @@
+// src/terminal/video_viewer/stats.rs
+use crate::net::latency::Latency;
+use crate::terminal::video_viewer::timing::FrameTiming;
+use anyhow::Result;
+use log::warn;
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Number of one-second samples kept in the rolling history
+pub const HISTORY_SECONDS: usize = 60;
+
+/// Window used for the min/avg/max frame rate summary
+pub const FPS_WINDOW_SECONDS: usize = 30;
+
+/// Number of recent frames whose timing is averaged
+pub const TIMING_FRAMES: usize = 30;
+
+/// Totals collected during a single one-second window
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SecondSample {
+    /// UDP packets received during the second
+    pub packets: u64,
+    /// JPEG frames completed during the second
+    pub frames: u64,
+    /// UDP payload bytes received during the second
+    pub bytes: u64,
+    /// Packets missing from the RTP sequence during the second
+    pub lost: u64,
+}
+
+impl SecondSample {
+    /// Share of the second's packets that went missing, 100 when none arrived at all
+    pub fn loss_percent(&self) -> f64 {
+        match self.packets + self.lost {
+            0 => 100.0,
+            expected => self.lost as f64 * 100.0 / expected as f64,
+        }
+    }
+}
+
+/// Frame rate figures over a window of completed seconds
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FpsSummary {
+    /// Frames during the most recent completed second
+    pub current: u64,
+    /// Lowest per-second frame count in the window
+    pub min: u64,
+    /// Average frames per second over the window
+    pub avg: f64,
+    /// Highest per-second frame count in the window
+    pub max: u64,
+}
+
+/// CSV file the per-second samples are being appended to
+struct CsvExport {
+    path: PathBuf,
+    file: File,
+}
+
+/// Rolling per-second stream statistics, updated by the receiver thread
+pub struct StreamStats {
+    /// Completed one-second samples, oldest first
+    history: VecDeque<SecondSample>,
+    /// Sample currently being accumulated
+    current: SecondSample,
+    /// Start of the current one-second window
+    window_start: Instant,
+    /// Wall-clock time at the start of the current window, for CSV timestamps
+    window_started_at: SystemTime,
+    /// CSV file that completed samples are appended to, if exporting
+    csv: Option<CsvExport>,
+    /// Assembly and hand-off times of the most recent frames, oldest first
+    timings: VecDeque<FrameTiming>,
+}
+
+impl StreamStats {
+    /// Create an empty statistics history
+    pub fn new() -> Self {
+        Self {
+            history: VecDeque::with_capacity(HISTORY_SECONDS),
+            current: SecondSample::default(),
+            window_start: Instant::now(),
+            window_started_at: SystemTime::now(),
+            csv: None,
+            timings: VecDeque::with_capacity(TIMING_FRAMES),
+        }
+    }
+
+    /// Clear all collected samples
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.current = SecondSample::default();
+        self.window_start = Instant::now();
+        self.window_started_at = SystemTime::now();
+        self.timings.clear();
+    }
+
+    /// Record a received packet of the given size
+    pub fn record_packet(&mut self, bytes: usize) {
+        self.roll();
+        self.current.packets += 1;
+        self.current.bytes += bytes as u64;
+    }
+
+    /// Record packets detected as missing from the RTP sequence
+    pub fn record_loss(&mut self, lost: u64) {
+        self.roll();
+        self.current.lost += lost;
+    }
+
+    /// Record a completed JPEG frame
+    pub fn record_frame(&mut self) {
+        self.roll();
+        self.current.frames += 1;
+    }
+
+    /// Record how long a frame took to assemble and to hand to the sinks
+    pub fn record_timing(&mut self, timing: FrameTiming) {
+        if self.timings.len() == TIMING_FRAMES {
+            self.timings.pop_front();
+        }
+        self.timings.push_back(timing);
+    }
+
+    /// Average timing of the recent frames, if any were timed
+    pub fn frame_timing(&self) -> Option<FrameTiming> {
+        let count = u32::try_from(self.timings.len()).ok().filter(|&n| n > 0)?;
+        let (assembly, handoff) = self
+            .timings
+            .iter()
+            .fold((Duration::ZERO, Duration::ZERO), |(a, h), t| {
+                (a + t.assembly, h + t.handoff)
+            });
+        Some(FrameTiming {
+            assembly: assembly / count,
+            handoff: handoff / count,
+        })
+    }
+
+    /// Close finished one-second windows, padding gaps with empty samples
+    pub fn roll(&mut self) {
+        let one_second = Duration::from_secs(1);
+        while self.window_start.elapsed() >= one_second {
+            if self.csv.is_some() {
+                self.append_csv_row(self.current);
+            }
+            self.history.push_back(self.current);
+            if self.history.len() > HISTORY_SECONDS {
+                self.history.pop_front();
+            }
+            self.current = SecondSample::default();
+            self.window_start += one_second;
+            self.window_started_at += one_second;
+        }
+    }
+
+    /// Frames per second for each completed second, oldest first
+    pub fn fps_history(&self) -> Vec<u64> {
+        self.history.iter().map(|s| s.frames).collect()
+    }
+
+    /// Summarize the frame rate over the last `window` completed seconds
+    pub fn fps_summary(&self, window: usize) -> FpsSummary {
+        let samples: Vec<u64> = self
+            .history
+            .iter()
+            .rev()
+            .take(window)
+            .map(|s| s.frames)
+            .collect();
+
+        if samples.is_empty() {
+            return FpsSummary::default();
+        }
+
+        FpsSummary {
+            current: samples[0],
+            min: samples.iter().copied().min().unwrap_or(0),
+            avg: samples.iter().sum::<u64>() as f64 / samples.len() as f64,
+            max: samples.iter().copied().max().unwrap_or(0),
+        }
+    }
+
+    /// The last `seconds` completed samples, oldest first
+    pub fn recent(&self, seconds: usize) -> Vec<SecondSample> {
+        let skip = self.history.len().saturating_sub(seconds);
+        self.history.iter().skip(skip).copied().collect()
+    }
+
+    /// Packet loss percentage for each completed second, oldest first
+    pub fn loss_history(&self) -> Vec<f64> {
+        self.history
+            .iter()
+            .map(SecondSample::loss_percent)
+            .collect()
+    }
+
+    /// Kilobytes per second for each completed second, oldest first
+    pub fn bandwidth_history_kb(&self) -> Vec<u64> {
+        self.history.iter().map(|s| s.bytes / 1024).collect()
+    }
+
+    /// Start appending per-second samples to a CSV file
+    pub fn start_csv_export(&mut self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // Only write the header when starting a fresh file
+        let needs_header = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if needs_header {
+            writeln!(file, "timestamp,packets,frames,bytes,lost")?;
+        }
+
+        self.csv = Some(CsvExport {
+            path: path.to_path_buf(),
+            file,
+        });
+        Ok(())
+    }
+
+    /// Stop appending samples to the CSV file
+    pub fn stop_csv_export(&mut self) {
+        self.csv = None;
+    }
+
+    /// Path of the CSV file currently being written, if any
+    pub fn csv_path(&self) -> Option<&Path> {
+        self.csv.as_ref().map(|csv| csv.path.as_path())
+    }
+
+    /// Append one sample as a CSV row, disabling export if the file becomes unwritable
+    fn append_csv_row(&mut self, sample: SecondSample) {
+        let Some(csv) = &mut self.csv else {
+            return;
+        };
+
+        // Stamp the row with the second it covers, so gap rows caught up at once stay apart
+        let timestamp = self
+            .window_started_at
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let result = writeln!(
+            csv.file,
+            "{},{},{},{},{}",
+            timestamp, sample.packets, sample.frames, sample.bytes, sample.lost
+        );
+
+        if let Err(e) = result {
+            warn!("Failed to write stream statistics to {:?}: {}", csv.path, e);
+            self.stop_csv_export();
+        }
+    }
+}
+
+impl Default for StreamStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What is holding the stream back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallCause {
+    /// The camera answers promptly but sends no frames
+    Camera,
+    /// Requests to the camera are slow or lost too
+    Network,
+    /// No round-trip measurement to tell them apart
+    Unknown,
+}
+
+/// Overall state of the stream, from frame timing and the camera round trip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamHealth {
+    Good,
+    /// Frames still arrive, but round trips to the camera are slow
+    Congested,
+    /// No frame for over a second
+    Degraded(StallCause),
+    /// No frame for five seconds or more
+    Stalled(StallCause),
+}
+
+impl StreamHealth {
+    /// Judge the stream from the time since the last frame and the latest round trip
+    pub fn assess(since_last_frame: Duration, latency: Option<&Latency>) -> Self {
+        let cause = match latency {
+            Some(latency) if latency.slow => StallCause::Network,
+            Some(_) => StallCause::Camera,
+            None => StallCause::Unknown,
+        };
+
+        match since_last_frame.as_secs() {
+            0 if cause == StallCause::Network => StreamHealth::Congested,
+            0 => StreamHealth::Good,
+            1..5 => StreamHealth::Degraded(cause),
+            _ => StreamHealth::Stalled(cause),
+        }
+    }
+
+    /// Untranslated description
+    pub fn label(&self) -> &'static str {
+        match self {
+            StreamHealth::Good => "Good",
+            StreamHealth::Congested => "Good, but the network is slow",
+            StreamHealth::Degraded(StallCause::Camera) => "Degraded (camera not sending)",
+            StreamHealth::Degraded(StallCause::Network) => "Degraded (network congestion)",
+            StreamHealth::Degraded(StallCause::Unknown) => "Degraded",
+            StreamHealth::Stalled(StallCause::Camera) => "Poor/Stalled (camera not sending)",
+            StreamHealth::Stalled(StallCause::Network) => "Poor/Stalled (network congestion)",
+            StreamHealth::Stalled(StallCause::Unknown) => "Poor/Stalled",
+        }
+    }
+}
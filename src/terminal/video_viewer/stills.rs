@@ -0,0 +1,148 @@
+// src/terminal/video_viewer/stills.rs
+use crate::terminal::video_viewer::sinks::{FrameSink, STILLS_SINK};
+use crate::utils::ffmpeg;
+use crate::utils::paths;
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Settings for saving stills from the live stream at a fixed interval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StillsConfig {
+    /// Seconds between saved frames
+    pub interval_secs: u64,
+    /// Directory the frames are saved to
+    pub directory: String,
+    /// File name template; `{session}`, `{n}` and `{timestamp}` are filled in
+    pub filename: String,
+    /// Assemble the frames into an MP4 when the sequence is stopped
+    pub assemble_mp4: bool,
+    /// Frame rate of the assembled MP4
+    pub assemble_fps: f64,
+}
+
+impl Default for StillsConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 10,
+            directory: "stills".to_string(),
+            filename: "olympus_{session}_{n}.jpg".to_string(),
+            assemble_mp4: false,
+            assemble_fps: 24.0,
+        }
+    }
+}
+
+/// Paths of the stills saved in the current sequence, in order
+pub type StillsLog = Arc<Mutex<Vec<PathBuf>>>;
+
+/// Saves one frame from the stream every interval, without using the shutter
+pub struct StillsSink {
+    directory: PathBuf,
+    template: String,
+    session: u64,
+    interval: Duration,
+    last_saved: Option<Instant>,
+    saved: StillsLog,
+}
+
+impl StillsSink {
+    /// Create a sink that records the paths it writes in `saved`
+    pub fn create(config: &StillsConfig, saved: StillsLog) -> Result<Self> {
+        let directory = paths::resolve_output(&config.directory);
+        fs::create_dir_all(&directory)?;
+
+        let session = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        info!(
+            "Saving a live view still every {}s to {:?}",
+            config.interval_secs, directory
+        );
+
+        Ok(Self {
+            directory,
+            template: config.filename.clone(),
+            session,
+            interval: Duration::from_secs(config.interval_secs.max(1)),
+            last_saved: None,
+            saved,
+        })
+    }
+
+    /// Path for the `n`th still of this sequence
+    fn still_path(&self, n: usize) -> PathBuf {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let name = self
+            .template
+            .replace("{session}", &self.session.to_string())
+            .replace("{n}", &format!("{:05}", n))
+            .replace("{timestamp}", &timestamp.to_string());
+        self.directory.join(name)
+    }
+}
+
+impl FrameSink for StillsSink {
+    fn name(&self) -> &str {
+        STILLS_SINK
+    }
+
+    fn write_frame(&mut self, frame: &Bytes) -> Result<()> {
+        if self
+            .last_saved
+            .is_some_and(|at| at.elapsed() < self.interval)
+        {
+            return Ok(());
+        }
+        self.last_saved = Some(Instant::now());
+
+        let mut saved = self
+            .saved
+            .lock()
+            .map_err(|_| anyhow!("Stills log is unavailable"))?;
+        let path = self.still_path(saved.len() + 1);
+        fs::write(&path, frame).map_err(|e| anyhow!("Failed to write {:?}: {}", path, e))?;
+        info!("Saved live view still {:?}", path);
+        saved.push(path);
+        Ok(())
+    }
+}
+
+/// Encode saved stills into an MP4 on a background thread, returning the output path
+pub fn assemble_mp4(stills: Vec<PathBuf>, fps: f64) -> Result<PathBuf> {
+    let first = stills
+        .first()
+        .ok_or_else(|| anyhow!("No stills were saved"))?;
+    // Fail before spawning so a missing ffmpeg shows up in the status bar
+    ffmpeg::find_ffmpeg()?;
+    let output = first.with_extension("mp4");
+    let path = output.clone();
+
+    thread::spawn(move || {
+        info!("Assembling {} stills into {:?}", stills.len(), path);
+        let frames = stills.iter().filter_map(|still| match fs::read(still) {
+            Ok(data) => Some(data),
+            Err(e) => {
+                warn!("Skipping still {:?}: {}", still, e);
+                None
+            }
+        });
+        match ffmpeg::encode_frames(frames, fps, &ffmpeg::mp4_args(23), &path) {
+            Ok(_) => info!("Stills assembled into {:?}", path),
+            Err(e) => warn!("Failed to assemble stills into {:?}: {}", path, e),
+        }
+    });
+
+    Ok(output)
+}
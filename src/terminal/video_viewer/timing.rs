@@ -0,0 +1,119 @@
+// src/terminal/video_viewer/timing.rs
+// How old a frame is by the time it is shown. Each frame is stamped when its
+// last packet arrives, with how long the frame took to come together; the
+// recorder and the MJPEG server pass the stamps on, and the viewer adds half
+// the camera round trip to estimate the delay from the sensor to the screen.
+use crate::net::latency::Latency;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Estimated delay up to which moving subjects can still be followed
+const MOVING_SUBJECT_LIMIT: Duration = Duration::from_millis(150);
+
+/// Estimated delay up to which the stream is still fine for still subjects
+const STILL_SUBJECT_LIMIT: Duration = Duration::from_millis(400);
+
+/// When a frame was received and how long it took to assemble
+#[derive(Debug, Clone, Copy)]
+pub struct FrameStamp {
+    /// Frame number the camera sent, or the count of fetched snapshots
+    pub frame_id: u32,
+    /// Wall-clock time the frame was complete
+    pub received: SystemTime,
+    /// From the first packet of the frame to the last, or the whole fetch for snapshots
+    pub assembly: Duration,
+}
+
+impl FrameStamp {
+    /// Stamp a frame completed just now
+    pub fn now(frame_id: u32, assembly: Duration) -> Self {
+        Self {
+            frame_id,
+            received: SystemTime::now(),
+            assembly,
+        }
+    }
+
+    /// Receive time in microseconds since the Unix epoch
+    pub fn unix_micros(&self) -> u128 {
+        self.received
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros())
+            .unwrap_or(0)
+    }
+
+    /// Receive time as `seconds.micros`, the form MJPEG servers send in `X-Timestamp`
+    pub fn header_value(&self) -> String {
+        let micros = self.unix_micros();
+        format!("{}.{:06}", micros / 1_000_000, micros % 1_000_000)
+    }
+}
+
+/// Receiver-side delays averaged over recent frames
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameTiming {
+    /// Time from the first packet of a frame to the last
+    pub assembly: Duration,
+    /// Time to hand a complete frame to the player and other sinks
+    pub handoff: Duration,
+}
+
+/// How usable the stream is for focusing at the estimated delay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatencyRating {
+    MovingSubjects,
+    StillSubjects,
+    TooSlow,
+}
+
+impl LatencyRating {
+    /// Untranslated description
+    pub fn label(&self) -> &'static str {
+        match self {
+            LatencyRating::MovingSubjects => "fine for moving subjects",
+            LatencyRating::StillSubjects => "still subjects only",
+            LatencyRating::TooSlow => "too slow to focus by",
+        }
+    }
+}
+
+/// Delay from the camera to the screen, split into the parts that can be measured
+///
+/// Time the camera spends exposing and encoding, and any buffering in the
+/// player, can't be seen from here, so the total is a lower bound.
+#[derive(Debug, Clone, Copy)]
+pub struct LatencyEstimate {
+    /// One-way trip from the camera, half the average round trip, if measured
+    pub network: Option<Duration>,
+    /// Time frames took to arrive in full
+    pub assembly: Duration,
+    /// Time frames took to reach the player
+    pub handoff: Duration,
+}
+
+impl LatencyEstimate {
+    /// Combine recent frame timing with the camera round trip
+    ///
+    /// Snapshot fetches already include the trip to the camera and back, so
+    /// pass no round trip for those.
+    pub fn new(timing: FrameTiming, latency: Option<&Latency>) -> Self {
+        Self {
+            network: latency.and_then(|l| l.average).map(|average| average / 2),
+            assembly: timing.assembly,
+            handoff: timing.handoff,
+        }
+    }
+
+    /// Sum of the known parts
+    pub fn total(&self) -> Duration {
+        self.network.unwrap_or_default() + self.assembly + self.handoff
+    }
+
+    /// Judge the total against the focusing limits
+    pub fn rating(&self) -> LatencyRating {
+        match self.total() {
+            total if total <= MOVING_SUBJECT_LIMIT => LatencyRating::MovingSubjects,
+            total if total <= STILL_SUBJECT_LIMIT => LatencyRating::StillSubjects,
+            _ => LatencyRating::TooSlow,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// src/terminal/wizard.rs
+// First-run setup. Asks for the few settings that differ between machines,
+// checks the camera answers, and writes the configuration file so later
+// launches go straight to the interface.
+use crate::camera::client::basic::ClientOperations;
+use crate::camera::olympus::OlympusCamera;
+use crate::net::address;
+use crate::terminal::image_viewer::renderer::detection;
+use crate::terminal::image_viewer::state::DisplayMethod;
+use crate::terminal::plain;
+use crate::utils::config::{CONFIG_FILE, Config};
+use crate::utils::i18n::{tr, trf};
+use anyhow::Result;
+use log::info;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// How long a connection test waits for the camera
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Display methods offered, in menu order
+const DISPLAY_METHODS: [(DisplayMethod, &str); 5] = [
+    (DisplayMethod::Auto, "Automatic"),
+    (DisplayMethod::Kitty, "Kitty graphics"),
+    (DisplayMethod::ITerm, "iTerm2 inline images"),
+    (DisplayMethod::Sixel, "SIXEL"),
+    (DisplayMethod::Basic, "Basic (text only)"),
+];
+
+/// Whether setup should run: there is no configuration yet and someone is there to answer
+pub fn is_first_run() -> bool {
+    !Path::new(CONFIG_FILE).exists() && io::stdin().is_terminal()
+}
+
+/// Run the setup questions, save the answers and return the resulting configuration
+///
+/// Starts from the existing configuration, so running it again only changes
+/// what is asked. If input ends early nothing is written.
+pub fn run() -> Result<Config> {
+    let mut config = Config::load();
+    println!();
+    println!("{}", tr("Setup"));
+    println!("{}", tr("Press Enter to keep the value shown in brackets."));
+
+    let Some(camera_url) = ask_camera_url(&config)? else {
+        return Ok(config);
+    };
+    config.camera_url = camera_url;
+
+    let camera_dir = Path::new(&config.paths.data_dir).join(&config.paths.camera);
+    let Some(downloads) = ask(&trf(
+        "Download directory, relative to {} unless absolute [{}]: ",
+        &[&camera_dir.display(), &config.paths.downloads],
+    ))?
+    else {
+        return Ok(config);
+    };
+    if !downloads.is_empty() {
+        config.paths.downloads = downloads;
+    }
+
+    let Some(display_method) = ask_display_method()? else {
+        return Ok(config);
+    };
+    config.display_method = display_method;
+
+    config.save(Path::new(CONFIG_FILE))?;
+    println!("{}", trf("Settings saved to {}", &[&CONFIG_FILE]));
+    println!();
+    Ok(config)
+}
+
+/// Find the camera, asking for its address until it answers or the user moves on
+fn ask_camera_url(config: &Config) -> Result<Option<String>> {
+    let mut url = config.camera_url.clone();
+    println!("{}", trf("Looking for the camera at {}...", &[&url]));
+    if probe(&url, config) {
+        println!("{}", tr("Camera found."));
+    } else {
+        println!(
+            "{}",
+            tr(
+                "No camera answered. Type w to join the camera's Wi-Fi network, or enter its address."
+            )
+        );
+    }
+
+    loop {
+        let Some(answer) = ask(&trf("Camera address [{}]: ", &[&url]))? else {
+            return Ok(None);
+        };
+        if answer.is_empty() {
+            return Ok(Some(url));
+        }
+        if answer.eq_ignore_ascii_case("w") {
+            plain::join_camera_wifi(&config.wifi);
+        } else {
+            url = normalize_url(&answer);
+        }
+
+        if probe(&url, config) {
+            println!("{}", tr("Camera found."));
+            return Ok(Some(url));
+        }
+        println!(
+            "{}",
+            trf(
+                "No camera answered at {}. Enter another address, or press Enter to keep it anyway.",
+                &[&url],
+            )
+        );
+    }
+}
+
+/// Ask which display method the image viewer should use, suggesting one for this terminal
+fn ask_display_method() -> Result<Option<DisplayMethod>> {
+    let capabilities = detection::detect_terminal_capabilities();
+    let suggested = if capabilities.supports_kitty {
+        DisplayMethod::Kitty
+    } else if capabilities.supports_iterm {
+        DisplayMethod::ITerm
+    } else if capabilities.supports_sixel {
+        DisplayMethod::Sixel
+    } else {
+        DisplayMethod::Auto
+    };
+
+    println!("{}", tr("Image display method:"));
+    for (i, (method, label)) in DISPLAY_METHODS.iter().enumerate() {
+        let marker = if *method == suggested { "*" } else { " " };
+        println!("{} {}. {}", marker, i + 1, tr(label));
+    }
+
+    loop {
+        let Some(answer) = ask(tr("Choose a number [*]: "))? else {
+            return Ok(None);
+        };
+        if answer.is_empty() {
+            return Ok(Some(suggested));
+        }
+        match answer.parse::<usize>() {
+            Ok(number) if (1..=DISPLAY_METHODS.len()).contains(&number) => {
+                return Ok(Some(DISPLAY_METHODS[number - 1].0));
+            }
+            _ => println!(
+                "{}",
+                trf("Type a number from 1 to {}.", &[&DISPLAY_METHODS.len()])
+            ),
+        }
+    }
+}
+
+/// Whether a camera answers at `url`
+fn probe(url: &str, config: &Config) -> bool {
+    let camera = OlympusCamera::new(url, config.timeouts.clone());
+    match camera.get_page_with_timeout("get_connectmode.cgi", PROBE_TIMEOUT) {
+        Ok(_) => true,
+        Err(e) => {
+            info!("Camera probe at {} failed: {}", url, e);
+            false
+        }
+    }
+}
+
+/// Turn a typed address such as `192.168.0.10` or `fd00::10` into a camera URL
+fn normalize_url(typed: &str) -> String {
+    let typed = typed.trim().trim_end_matches('/');
+    if typed.contains("://") {
+        typed.to_string()
+    } else {
+        format!("http://{}", address::url_host(typed))
+    }
+}
+
+/// Print a question and read the trimmed answer, or `None` at the end of input
+fn ask(question: &str) -> Result<Option<String>> {
+    print!("{}", question);
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim().to_string()))
+}
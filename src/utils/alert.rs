@@ -0,0 +1,165 @@
+// src/utils/alert.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Events that can trigger an audible alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertEvent {
+    /// A photo was captured successfully
+    Capture,
+    /// The live view stream failed and could not be (re)started
+    #[cfg_attr(not(feature = "video"), allow(dead_code))]
+    StreamError,
+    /// A timelapse sequence finished or stopped on an error
+    Timelapse,
+    /// Live view stopped delivering frames
+    #[cfg_attr(not(feature = "video"), allow(dead_code))]
+    FrameFreeze,
+}
+
+/// Audible alert settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertConfig {
+    /// Master switch for all alerts
+    pub enabled: bool,
+    /// Alert after a successful capture
+    pub on_capture: bool,
+    /// Alert on fatal live view stream errors
+    pub on_stream_error: bool,
+    /// Alert when a timelapse sequence ends
+    pub on_timelapse: bool,
+    /// Alert when live view delivers no frames for `freeze_secs`
+    pub on_frame_freeze: bool,
+    /// Seconds without a frame that count as a freeze
+    pub freeze_secs: u64,
+    /// Flash the terminal title while live view is frozen
+    pub flash_title: bool,
+    /// Optional command to play a sound instead of the terminal bell
+    pub sound_command: Option<String>,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            on_capture: true,
+            on_stream_error: true,
+            on_timelapse: true,
+            on_frame_freeze: true,
+            freeze_secs: 10,
+            flash_title: true,
+            sound_command: None,
+        }
+    }
+}
+
+impl AlertConfig {
+    /// Check whether the given event should produce an alert
+    pub fn wants(&self, event: AlertEvent) -> bool {
+        self.enabled
+            && match event {
+                AlertEvent::Capture => self.on_capture,
+                AlertEvent::StreamError => self.on_stream_error,
+                AlertEvent::Timelapse => self.on_timelapse,
+                AlertEvent::FrameFreeze => self.on_frame_freeze,
+            }
+    }
+}
+
+/// Emit an alert for the event if enabled in the configuration
+pub fn notify(config: &AlertConfig, event: AlertEvent) {
+    if !config.wants(event) {
+        return;
+    }
+
+    info!("Audible alert for {:?}", event);
+
+    // Prefer the configured sound command, falling back to the terminal bell
+    if let Some(command) = &config.sound_command {
+        let mut parts = command.split_whitespace();
+        if let Some(program) = parts.next() {
+            match Command::new(program)
+                .args(parts)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+            {
+                Ok(mut child) => {
+                    // Reap the player once it finishes so it doesn't linger as a zombie
+                    std::thread::spawn(move || {
+                        let _ = child.wait();
+                    });
+                    return;
+                }
+                Err(e) => warn!("Failed to run alert sound command '{}': {}", command, e),
+            }
+        }
+    }
+
+    ring_bell();
+}
+
+/// Ring the terminal bell
+pub fn ring_bell() {
+    write_terminal(b"\x07");
+}
+
+/// Flashes a message in the terminal title, restoring the previous title when dropped
+#[cfg_attr(not(feature = "video"), allow(dead_code))]
+pub struct TitleFlash {
+    message: String,
+    shown: bool,
+    last_toggle: Instant,
+}
+
+#[cfg_attr(not(feature = "video"), allow(dead_code))]
+impl TitleFlash {
+    /// Save the current title and show the message in its place
+    pub fn start(message: &str) -> Self {
+        // Push the title onto the terminal's title stack
+        write_terminal(b"\x1b[22;0t");
+        let mut flash = Self {
+            message: message.to_string(),
+            shown: false,
+            last_toggle: Instant::now(),
+        };
+        flash.toggle();
+        flash
+    }
+
+    /// Show or hide the message once a second
+    pub fn tick(&mut self) {
+        if self.last_toggle.elapsed() >= Duration::from_secs(1) {
+            self.toggle();
+        }
+    }
+
+    fn toggle(&mut self) {
+        self.shown = !self.shown;
+        self.last_toggle = Instant::now();
+        let title = if self.shown {
+            self.message.as_str()
+        } else {
+            ""
+        };
+        write_terminal(format!("\x1b]0;{}\x07", title).as_bytes());
+    }
+}
+
+impl Drop for TitleFlash {
+    fn drop(&mut self) {
+        // Pop the title saved when the flash started
+        write_terminal(b"\x1b[23;0t");
+    }
+}
+
+/// Write control sequences straight to the terminal
+fn write_terminal(bytes: &[u8]) {
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(bytes);
+    let _ = stdout.flush();
+}
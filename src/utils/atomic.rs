@@ -0,0 +1,120 @@
+// src/utils/atomic.rs
+// Files are written under a `.part` name and renamed once complete, so an
+// interrupted transfer or recording never looks like a finished file.
+use anyhow::{Context, Result};
+use log::{info, warn};
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Extension added to files that are still being written
+pub const PART_EXTENSION: &str = "part";
+
+/// How long a `.part` file has to sit untouched before it counts as abandoned
+const STALE_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// Path a file is written to until it is complete
+pub fn part_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(PART_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Write `data` to `path` through a `.part` file
+pub fn write_atomic(path: &Path, data: &[u8]) -> Result<()> {
+    let mut file = PartFile::create(path)?;
+    file.write_all(data)
+        .with_context(|| format!("Failed to write {:?}", file.part))?;
+    file.commit()
+}
+
+/// A file that keeps its `.part` name until it is committed
+pub struct PartFile {
+    path: PathBuf,
+    part: PathBuf,
+    file: BufWriter<File>,
+    committed: bool,
+}
+
+impl PartFile {
+    /// Start writing `<path>.part`, creating the parent directory if needed
+    pub fn create(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let part = part_path(path);
+        let file = File::create(&part).with_context(|| format!("Failed to create {:?}", part))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            part,
+            file: BufWriter::new(file),
+            committed: false,
+        })
+    }
+
+    /// Flush everything to disk and give the file its final name
+    pub fn commit(&mut self) -> Result<()> {
+        if self.committed {
+            return Ok(());
+        }
+
+        self.file
+            .flush()
+            .with_context(|| format!("Failed to flush {:?}", self.part))?;
+        self.file
+            .get_ref()
+            .sync_all()
+            .with_context(|| format!("Failed to sync {:?}", self.part))?;
+        fs::rename(&self.part, &self.path)
+            .with_context(|| format!("Failed to rename {:?} to {:?}", self.part, self.path))?;
+
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Write for PartFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Delete `.part` files left under `directory` by writes that never finished
+///
+/// Files written to in the last few minutes are kept, since they may belong
+/// to a download or recording another running instance hasn't finished yet.
+pub fn remove_stale_parts(directory: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return 0;
+    };
+
+    let mut removed = 0;
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        if path.is_dir() {
+            removed += remove_stale_parts(&path);
+        } else if path.extension().is_some_and(|ext| ext == PART_EXTENSION) {
+            let recent = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| modified.elapsed().is_ok_and(|age| age < STALE_AFTER));
+            if recent {
+                info!("Keeping {:?}, which is still being written", path);
+                continue;
+            }
+            match fs::remove_file(&path) {
+                Ok(_) => {
+                    info!("Removed incomplete file {:?}", path);
+                    removed += 1;
+                }
+                Err(e) => warn!("Failed to remove incomplete file {:?}: {}", path, e),
+            }
+        }
+    }
+    removed
+}
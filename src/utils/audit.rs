@@ -0,0 +1,105 @@
+// src/utils/audit.rs
+// Append-only record of what was done to the camera: every capture, download,
+// delete and settings change with when it happened and how it went, so a
+// shoot can be reconstructed afterwards. Each camera directory has its own
+// log, one JSON object per line, and lines are only ever added.
+use crate::utils::paths::{self, DataDir};
+use crate::utils::session;
+use anyhow::Result;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Name of the audit log in the camera's log directory
+pub const AUDIT_FILE: &str = "audit.jsonl";
+
+/// Kind of operation recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Capture,
+    Download,
+    Delete,
+    Setting,
+    Command,
+}
+
+impl AuditAction {
+    /// Untranslated name shown in the audit log screen
+    pub fn label(self) -> &'static str {
+        match self {
+            AuditAction::Capture => "Capture",
+            AuditAction::Download => "Download",
+            AuditAction::Delete => "Delete",
+            AuditAction::Setting => "Setting",
+            AuditAction::Command => "Command",
+        }
+    }
+}
+
+/// One recorded operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Unix seconds
+    pub time: u64,
+    pub action: AuditAction,
+    /// Image, file or `PROPERTY=value` the operation was about
+    pub target: String,
+    /// Why the operation failed, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Audit log of the camera directory in use
+pub fn log_path() -> PathBuf {
+    paths::dir(DataDir::Logs).join(AUDIT_FILE)
+}
+
+/// Append an operation and its outcome to the audit log
+pub fn record<T>(action: AuditAction, target: &str, result: &Result<T>) {
+    let entry = AuditEntry {
+        time: session::unix_now(),
+        action,
+        target: target.to_string(),
+        error: result.as_ref().err().map(|e| e.to_string()),
+    };
+    if let Err(e) = append(&log_path(), &entry) {
+        warn!("Failed to write the audit log: {}", e);
+    }
+}
+
+fn append(path: &Path, entry: &AuditEntry) -> Result<()> {
+    // One write per line, so entries from different threads don't interleave
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Every entry in the audit log, newest first
+pub fn load() -> Vec<AuditEntry> {
+    let path = log_path();
+    let Ok(text) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<AuditEntry> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!("Skipping invalid line in {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect();
+    entries.reverse();
+    entries
+}
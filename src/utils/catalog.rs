@@ -0,0 +1,169 @@
+// src/utils/catalog.rs
+// Content hashes of the files in the downloads directory, so a photo the
+// camera renamed or stored twice is recognised instead of saved again.
+// Hashes are kept in the cache directory and only recomputed for files whose
+// size or modification time changed.
+use crate::utils::atomic::{self, PART_EXTENSION};
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Name of the catalog file in the cache directory
+pub const CATALOG_FILE: &str = "catalog.json";
+
+/// Hash identifying a file's content
+pub fn content_hash(data: &[u8]) -> u64 {
+    xxh3_64(data)
+}
+
+/// What the catalog knows about one local file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CatalogEntry {
+    size: u64,
+    modified: u64,
+    hash: u64,
+}
+
+/// Content hashes of every file in a set of directories
+pub struct Catalog {
+    directories: Vec<PathBuf>,
+    file: PathBuf,
+    entries: HashMap<String, CatalogEntry>,
+}
+
+impl Catalog {
+    /// Load the catalog of `directories` saved in `file` and bring it up to date
+    ///
+    /// A name found in more than one directory is catalogued from the first.
+    pub fn load(directories: &[PathBuf], file: &Path) -> Self {
+        let entries = fs::read_to_string(file)
+            .ok()
+            .and_then(|text| match serde_json::from_str(&text) {
+                Ok(entries) => Some(entries),
+                Err(e) => {
+                    warn!("Ignoring invalid catalog {:?}: {}", file, e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        let mut catalog = Self {
+            directories: directories.to_vec(),
+            file: file.to_path_buf(),
+            entries,
+        };
+        catalog.scan();
+        catalog
+    }
+
+    /// Hash new or changed files and forget the ones that were removed
+    fn scan(&mut self) {
+        let files: Vec<PathBuf> = self
+            .directories
+            .iter()
+            .filter_map(|directory| fs::read_dir(directory).ok())
+            .flat_map(|dir_entries| {
+                dir_entries.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            })
+            .collect();
+
+        let mut entries = HashMap::new();
+        let mut hashed = 0;
+        for path in files {
+            if !path.is_file() || path.extension().is_some_and(|ext| ext == PART_EXTENSION) {
+                continue;
+            }
+            let Some(name) = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+            else {
+                continue;
+            };
+            if entries.contains_key(&name) {
+                continue;
+            }
+            let Some((size, modified)) = file_stamp(&path) else {
+                continue;
+            };
+
+            let entry = match self.entries.remove(&name) {
+                Some(entry) if entry.size == size && entry.modified == modified => entry,
+                _ => match fs::read(&path) {
+                    Ok(data) => {
+                        hashed += 1;
+                        CatalogEntry {
+                            size,
+                            modified,
+                            hash: content_hash(&data),
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to hash {:?}: {}", path, e);
+                        continue;
+                    }
+                },
+            };
+            entries.insert(name, entry);
+        }
+
+        info!(
+            "Catalog of {} directories: {} files, {} newly hashed",
+            self.directories.len(),
+            entries.len(),
+            hashed
+        );
+        self.entries = entries;
+    }
+
+    /// Whether a file with this name is already present
+    pub fn contains_name(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Name of a local file with the given content hash, if any
+    pub fn find(&self, hash: u64) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(_, entry)| entry.hash == hash)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Record a file that was just written to `path`
+    pub fn insert(&mut self, path: &Path, hash: u64) {
+        let (Some(name), Some((size, modified))) = (path.file_name(), file_stamp(path)) else {
+            return;
+        };
+        self.entries.insert(
+            name.to_string_lossy().to_string(),
+            CatalogEntry {
+                size,
+                modified,
+                hash,
+            },
+        );
+    }
+
+    /// Save the catalog so the next load only hashes new files
+    pub fn save(&self) -> Result<()> {
+        let text = serde_json::to_string(&self.entries)?;
+        atomic::write_atomic(&self.file, text.as_bytes())
+            .with_context(|| format!("Failed to save catalog {:?}", self.file))
+    }
+}
+
+/// Size and modification time (Unix seconds) of a file
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    Some((metadata.len(), modified))
+}
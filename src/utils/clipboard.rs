@@ -0,0 +1,35 @@
+// src/utils/clipboard.rs
+// Copying text to the system clipboard. On X11 and Wayland the copied text is
+// served by this process, so the clipboard stays open for the whole session
+// rather than being dropped right after copying.
+use anyhow::{Result, anyhow};
+
+/// The system clipboard, opened on first use
+#[derive(Default)]
+pub struct SystemClipboard {
+    #[cfg(feature = "clipboard")]
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl SystemClipboard {
+    /// Put `text` on the clipboard
+    #[cfg(feature = "clipboard")]
+    pub fn copy(&mut self, text: &str) -> Result<()> {
+        let mut clipboard = match self.clipboard.take() {
+            Some(clipboard) => clipboard,
+            None => arboard::Clipboard::new()
+                .map_err(|e| anyhow!("Clipboard is not available: {}", e))?,
+        };
+        let result = clipboard
+            .set_text(text)
+            .map_err(|e| anyhow!("Failed to set clipboard text: {}", e));
+        self.clipboard = Some(clipboard);
+        result
+    }
+
+    /// Put `text` on the clipboard
+    #[cfg(not(feature = "clipboard"))]
+    pub fn copy(&mut self, _text: &str) -> Result<()> {
+        Err(anyhow!("Clipboard support is not available in this build"))
+    }
+}
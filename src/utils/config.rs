@@ -0,0 +1,312 @@
+// src/utils/config.rs
+use crate::camera::client::breaker::BreakerConfig;
+use crate::camera::client::protocol::ProtocolLogConfig;
+use crate::camera::client::timeouts::TimeoutConfig;
+use crate::camera::events::EventConfig;
+use crate::camera::image::prefetch::PrefetchConfig;
+use crate::camera::pairing::PairingConfig;
+use crate::camera::photo::bracket::FocusBracketConfig;
+use crate::camera::photo::queue::CaptureQueueConfig;
+use crate::camera::photo::tether::TetherConfig;
+use crate::camera::photo::timelapse::TimelapseConfig;
+use crate::camera::profiles::ProfileConfig;
+#[cfg(feature = "ble")]
+use crate::net::ble::BleWakeConfig;
+use crate::net::latency::LatencyConfig;
+use crate::net::link::LinkConfig;
+use crate::net::wifi::WifiConfig;
+use crate::plugins::PluginsConfig;
+use crate::terminal::image_viewer::state::DisplayMethod;
+use crate::terminal::macros::MacroConfig;
+use crate::terminal::movie::MoviePlayerConfig;
+use crate::terminal::player_window::PlayerWindowConfig;
+use crate::terminal::refresh::ListRefreshConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::access::ServerAccessConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::dashboard::DashboardConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::orientation::OrientationConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::privacy::PrivacyConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::quality::AdaptiveQualityConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::replay::ReplayConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::rtmp::RtmpConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::sinks::RecordingConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::snapshot::SnapshotFallbackConfig;
+#[cfg(feature = "video")]
+use crate::terminal::video_viewer::stills::StillsConfig;
+use crate::utils::alert::AlertConfig;
+use crate::utils::atomic;
+use crate::utils::disk::DiskConfig;
+use crate::utils::dng::DngConfig;
+#[cfg(feature = "video")]
+use crate::utils::ffmpeg::TranscodeConfig;
+use crate::utils::paths::PathsConfig;
+use crate::utils::resize::WebSizeConfig;
+use crate::utils::session::SessionConfig;
+use crate::utils::xmp::XmpConfig;
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Default location of the configuration file
+pub const CONFIG_FILE: &str = "olympus_config.json";
+
+/// Application configuration loaded from `olympus_config.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Base URL of the camera
+    pub camera_url: String,
+
+    /// Audible alert settings
+    pub alerts: AlertConfig,
+
+    /// Interface language code, e.g. `en` or `de`
+    pub locale: String,
+
+    /// Per-camera data directory layout
+    pub paths: PathsConfig,
+
+    /// Free space required before downloading or recording
+    pub disk: DiskConfig,
+
+    /// Display method the image viewer starts with
+    pub display_method: DisplayMethod,
+
+    /// Fetch a sharper version of a viewed image in the background right away
+    pub progressive_images: bool,
+
+    /// Start streaming live view as soon as the camera is connected
+    pub auto_liveview: bool,
+
+    /// Only allow browsing, downloading and live view; refuse capture, deletion and settings
+    pub read_only: bool,
+
+    /// Camera request timeouts and slow camera profile
+    pub timeouts: TimeoutConfig,
+
+    /// Pausing automatic requests while the camera isn't answering
+    pub breaker: BreakerConfig,
+
+    /// Full request and response logging for protocol debugging
+    pub protocol_log: ProtocolLogConfig,
+
+    /// Finding and joining the camera's Wi-Fi network
+    pub wifi: WifiConfig,
+
+    /// Waking a sleeping camera over Bluetooth
+    #[cfg(feature = "ble")]
+    pub ble_wake: BleWakeConfig,
+
+    /// Wireless link quality indicator
+    pub link: LinkConfig,
+
+    /// Round-trip time measurement to the camera
+    pub latency: LatencyConfig,
+
+    /// Background polling for changes made on the camera
+    pub events: EventConfig,
+
+    /// Refreshing the image list on a timer while it is shown
+    pub list_refresh: ListRefreshConfig,
+
+    /// Background fetching of thumbnails around the shown page
+    pub prefetch: PrefetchConfig,
+
+    /// Tethered capture straight to the host
+    pub tether: TetherConfig,
+
+    /// Focus bracketing for focus stacking
+    pub focus_bracket: FocusBracketConfig,
+
+    /// Intervalometer and exposure ramping
+    pub timelapse: TimelapseConfig,
+
+    /// Shots taken in turn with their own settings
+    pub capture_queue: CaptureQueueConfig,
+
+    /// Optional features compiled in as plugins
+    pub plugins: PluginsConfig,
+
+    /// Named camera settings profiles
+    pub profiles: ProfileConfig,
+
+    /// Recorded key macros
+    pub macros: MacroConfig,
+
+    /// Connect-mode and access point requests for pairing
+    pub pairing: PairingConfig,
+
+    /// Converting synced raw files to DNG
+    pub dng: DngConfig,
+
+    /// Small copies of photos for sharing
+    pub web_size: WebSizeConfig,
+
+    /// External player for movies on the card
+    pub movies: MoviePlayerConfig,
+
+    /// Size and position of the live view and movie player windows
+    pub player_window: PlayerWindowConfig,
+
+    /// Ratings, tags and location written to XMP sidecars
+    pub xmp: XmpConfig,
+
+    /// Folders for shoot sessions
+    pub session: SessionConfig,
+
+    /// Live view recording segmentation and retention
+    #[cfg(feature = "video")]
+    pub recording: RecordingConfig,
+
+    /// Instant replay of the last seconds of live view
+    #[cfg(feature = "video")]
+    pub replay: ReplayConfig,
+
+    /// Stills saved from the live stream at an interval
+    #[cfg(feature = "video")]
+    pub stills: StillsConfig,
+
+    /// Pushing live view to a streaming platform
+    #[cfg(feature = "video")]
+    pub rtmp: RtmpConfig,
+
+    /// Areas blacked out of the MJPEG server and RTMP stream
+    #[cfg(feature = "video")]
+    pub privacy: PrivacyConfig,
+
+    /// Token, password and HTTPS for the MJPEG server
+    #[cfg(feature = "video")]
+    pub http_access: ServerAccessConfig,
+
+    /// Conversion of recordings to MP4
+    #[cfg(feature = "video")]
+    pub transcode: TranscodeConfig,
+
+    /// Snapshot loop offered when UDP live view is blocked
+    #[cfg(feature = "video")]
+    pub snapshot_fallback: SnapshotFallbackConfig,
+
+    /// Live view size stepped down on a poor link
+    #[cfg(feature = "video")]
+    pub adaptive_quality: AdaptiveQualityConfig,
+
+    /// Cameras shown side by side on the dashboard
+    #[cfg(feature = "video")]
+    pub dashboard: DashboardConfig,
+
+    /// Live view turned for cameras mounted sideways or upside down
+    #[cfg(feature = "video")]
+    pub orientation: OrientationConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            camera_url: "http://192.168.0.10".to_string(),
+            alerts: AlertConfig::default(),
+            locale: "en".to_string(),
+            paths: PathsConfig::default(),
+            disk: DiskConfig::default(),
+            display_method: DisplayMethod::default(),
+            progressive_images: true,
+            auto_liveview: false,
+            read_only: false,
+            timeouts: TimeoutConfig::default(),
+            breaker: BreakerConfig::default(),
+            protocol_log: ProtocolLogConfig::default(),
+            wifi: WifiConfig::default(),
+            #[cfg(feature = "ble")]
+            ble_wake: BleWakeConfig::default(),
+            link: LinkConfig::default(),
+            latency: LatencyConfig::default(),
+            events: EventConfig::default(),
+            list_refresh: ListRefreshConfig::default(),
+            prefetch: PrefetchConfig::default(),
+            tether: TetherConfig::default(),
+            focus_bracket: FocusBracketConfig::default(),
+            timelapse: TimelapseConfig::default(),
+            capture_queue: CaptureQueueConfig::default(),
+            plugins: PluginsConfig::default(),
+            profiles: ProfileConfig::default(),
+            macros: MacroConfig::default(),
+            pairing: PairingConfig::default(),
+            dng: DngConfig::default(),
+            web_size: WebSizeConfig::default(),
+            movies: MoviePlayerConfig::default(),
+            player_window: PlayerWindowConfig::default(),
+            xmp: XmpConfig::default(),
+            session: SessionConfig::default(),
+            #[cfg(feature = "video")]
+            recording: RecordingConfig::default(),
+            #[cfg(feature = "video")]
+            replay: ReplayConfig::default(),
+            #[cfg(feature = "video")]
+            stills: StillsConfig::default(),
+            #[cfg(feature = "video")]
+            rtmp: RtmpConfig::default(),
+            #[cfg(feature = "video")]
+            privacy: PrivacyConfig::default(),
+            #[cfg(feature = "video")]
+            http_access: ServerAccessConfig::default(),
+            #[cfg(feature = "video")]
+            transcode: TranscodeConfig::default(),
+            #[cfg(feature = "video")]
+            snapshot_fallback: SnapshotFallbackConfig::default(),
+            #[cfg(feature = "video")]
+            adaptive_quality: AdaptiveQualityConfig::default(),
+            #[cfg(feature = "video")]
+            dashboard: DashboardConfig::default(),
+            #[cfg(feature = "video")]
+            orientation: OrientationConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Load the configuration file, falling back to defaults if it is missing or invalid
+    pub fn load() -> Self {
+        let path = Path::new(CONFIG_FILE);
+        if !path.exists() {
+            info!("No configuration file found, using defaults");
+            return Self::default();
+        }
+
+        match Self::load_from(path) {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("Failed to load configuration: {}. Using defaults.", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Load configuration from a specific file
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut config: Self = serde_json::from_str(&text)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        config.timeouts.clamp_zero();
+        info!("Loaded configuration from {}", path.display());
+        Ok(config)
+    }
+
+    /// Write the configuration to a file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+        atomic::write_atomic(path, text.as_bytes())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        info!("Saved configuration to {}", path.display());
+        Ok(())
+    }
+}
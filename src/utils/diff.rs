@@ -0,0 +1,57 @@
+// src/utils/diff.rs
+// Line diff of two texts through their longest common subsequence. The table
+// grows with the product of the line counts, which is fine for the short
+// responses compared by hand but not for whole image listings, so those are
+// refused.
+
+/// Largest table of line pairs compared
+const MAX_CELLS: usize = 4_000_000;
+
+/// What happened to a line between the two texts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Same,
+    Removed,
+    Added,
+}
+
+/// Lines of `before` and `after` in order, each marked as kept, removed or added
+///
+/// Returns `None` when the texts are too long to compare.
+pub fn diff_lines<'a>(before: &[&'a str], after: &[&'a str]) -> Option<Vec<(Change, &'a str)>> {
+    let (rows, columns) = (before.len(), after.len());
+    if (rows + 1).saturating_mul(columns + 1) > MAX_CELLS {
+        return None;
+    }
+
+    // common[i][j] is the longest common run of before[i..] and after[j..]
+    let mut common = vec![vec![0usize; columns + 1]; rows + 1];
+    for i in (0..rows).rev() {
+        for j in (0..columns).rev() {
+            common[i][j] = if before[i] == after[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(rows.max(columns));
+    let (mut i, mut j) = (0, 0);
+    while i < rows && j < columns {
+        if before[i] == after[j] {
+            lines.push((Change::Same, before[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            lines.push((Change::Removed, before[i]));
+            i += 1;
+        } else {
+            lines.push((Change::Added, after[j]));
+            j += 1;
+        }
+    }
+    lines.extend(before[i..].iter().map(|line| (Change::Removed, *line)));
+    lines.extend(after[j..].iter().map(|line| (Change::Added, *line)));
+    Some(lines)
+}
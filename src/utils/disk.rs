@@ -0,0 +1,83 @@
+// src/utils/disk.rs
+// Free space is checked before downloads and recordings start, so a full disk
+// stops them cleanly instead of failing halfway through a write.
+use anyhow::{Result, anyhow};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Bytes in a megabyte, the unit thresholds are configured in
+const MB: u64 = 1024 * 1024;
+
+/// Free space thresholds for transfers and recordings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiskConfig {
+    /// Refuse to start, or stop a recording, below this many MB free
+    pub min_free_mb: u64,
+    /// Warn when less than this many MB are free
+    pub warn_free_mb: u64,
+}
+
+impl Default for DiskConfig {
+    fn default() -> Self {
+        Self {
+            min_free_mb: 200,
+            warn_free_mb: 1024,
+        }
+    }
+}
+
+impl DiskConfig {
+    /// Free space below which writing is refused, in bytes
+    pub fn min_free_bytes(&self) -> u64 {
+        self.min_free_mb * MB
+    }
+}
+
+/// Result of a successful free space check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceCheck {
+    /// Comfortably above the warning threshold
+    Ok,
+    /// Enough to proceed, but below the warning threshold; holds the free MB
+    Low(u64),
+}
+
+/// Bytes available at `path`, measured on the nearest directory that exists
+pub fn free_space(path: &Path) -> Result<u64> {
+    // The destination may not have been created yet
+    let existing = path
+        .ancestors()
+        .find(|dir| dir.exists())
+        .unwrap_or(Path::new("."));
+    fs2::available_space(existing)
+        .map_err(|e| anyhow!("Failed to read free space at {:?}: {}", existing, e))
+}
+
+/// Check that `needed` more bytes fit at `path` without dropping below the minimum
+pub fn check_free_space(path: &Path, needed: u64, config: &DiskConfig) -> Result<SpaceCheck> {
+    let free = match free_space(path) {
+        Ok(free) => free,
+        Err(e) => {
+            // Not every filesystem reports free space; don't block writes over it
+            warn!("{}", e);
+            return Ok(SpaceCheck::Ok);
+        }
+    };
+
+    let remaining = free.saturating_sub(needed);
+    if remaining < config.min_free_bytes() {
+        return Err(anyhow!(
+            "Only {} MB free at {:?}, at least {} MB are required",
+            free / MB,
+            path,
+            config.min_free_mb
+        ));
+    }
+    if remaining < config.warn_free_mb * MB {
+        warn!("Low disk space: {} MB free at {:?}", free / MB, path);
+        return Ok(SpaceCheck::Low(free / MB));
+    }
+    Ok(SpaceCheck::Ok)
+}
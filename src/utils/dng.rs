@@ -0,0 +1,247 @@
+// src/utils/dng.rs
+// Olympus raw files (ORF) are converted to DNG after a sync by handing them to
+// dnglab or Adobe DNG Converter, whichever is installed. Conversion runs on a
+// worker thread, one file at a time, and can be followed through its progress.
+use crate::utils::exec;
+use anyhow::{Result, anyhow};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Where Adobe DNG Converter installs itself, since it isn't put on the PATH
+#[cfg(target_os = "macos")]
+const ADOBE_INSTALL_PATHS: &[&str] =
+    &["/Applications/Adobe DNG Converter.app/Contents/MacOS/Adobe DNG Converter"];
+#[cfg(target_os = "windows")]
+const ADOBE_INSTALL_PATHS: &[&str] = &[
+    r"C:\Program Files\Adobe\Adobe DNG Converter\Adobe DNG Converter.exe",
+    r"C:\Program Files (x86)\Adobe\Adobe DNG Converter.exe",
+];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const ADOBE_INSTALL_PATHS: &[&str] = &[];
+
+/// Settings for converting synced raw files to DNG
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DngConfig {
+    /// Settings profiles whose syncs convert raw files; empty turns conversion off
+    pub profiles: Vec<String>,
+    /// Converter to run instead of searching for dnglab and Adobe DNG Converter
+    pub converter: Option<String>,
+    /// Keep the ORF file once its DNG has been written
+    pub keep_raw: bool,
+}
+
+impl Default for DngConfig {
+    fn default() -> Self {
+        Self {
+            profiles: Vec::new(),
+            converter: None,
+            keep_raw: true,
+        }
+    }
+}
+
+impl DngConfig {
+    /// Whether syncing with `profile` selected converts raw files
+    pub fn enabled_for(&self, profile: Option<&str>) -> bool {
+        profile.is_some_and(|profile| self.profiles.iter().any(|name| name == profile))
+    }
+}
+
+/// An installed raw to DNG converter
+#[derive(Debug, Clone)]
+pub enum DngConverter {
+    /// dnglab, the open source converter
+    Dnglab(PathBuf),
+    /// Adobe DNG Converter's command line mode
+    Adobe(PathBuf),
+}
+
+impl DngConverter {
+    /// Find the configured converter, or else dnglab, or else Adobe DNG Converter
+    pub fn find(config: &DngConfig) -> Result<Self> {
+        if let Some(program) = &config.converter {
+            let path = exec::find_program(program).unwrap_or_else(|| PathBuf::from(program));
+            return Ok(Self::from_path(path));
+        }
+
+        exec::find_program("dnglab")
+            .map(Self::Dnglab)
+            .or_else(|| {
+                ADOBE_INSTALL_PATHS
+                    .iter()
+                    .map(PathBuf::from)
+                    .find(|path| path.exists())
+                    .map(Self::Adobe)
+            })
+            .ok_or_else(|| anyhow!("Neither dnglab nor Adobe DNG Converter was found"))
+    }
+
+    /// Tell the converters apart by their program name
+    fn from_path(path: PathBuf) -> Self {
+        let is_dnglab = path
+            .file_stem()
+            .is_some_and(|stem| stem.to_string_lossy().eq_ignore_ascii_case("dnglab"));
+        if is_dnglab {
+            Self::Dnglab(path)
+        } else {
+            Self::Adobe(path)
+        }
+    }
+
+    /// Convert `input` to a DNG next to it
+    pub fn convert(&self, input: &Path) -> Result<PathBuf> {
+        let output = input.with_extension("dng");
+        let mut command = match self {
+            Self::Dnglab(program) => {
+                let mut command = Command::new(program);
+                command.arg("convert").arg(input).arg(&output);
+                command
+            }
+            Self::Adobe(program) => {
+                let directory = input.parent().unwrap_or(Path::new("."));
+                let mut command = Command::new(program);
+                command
+                    .args(["-c", "-d"])
+                    .arg(directory)
+                    .arg("-o")
+                    .arg(output.file_name().unwrap_or_default())
+                    .arg(input);
+                command
+            }
+        };
+
+        let result = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()?;
+        // Adobe DNG Converter exits successfully even when it writes nothing
+        if !result.status.success() || !output.exists() {
+            let stderr = String::from_utf8_lossy(&result.stderr).trim().to_string();
+            return Err(if stderr.is_empty() {
+                anyhow!("Converter exited with {}", result.status)
+            } else {
+                anyhow!("Converter failed: {}", stderr)
+            });
+        }
+        Ok(output)
+    }
+}
+
+/// Progress of a background DNG conversion
+#[derive(Debug, Clone, Default)]
+pub struct DngProgress {
+    /// Files converted so far
+    pub converted: usize,
+    /// Files that failed to convert
+    pub failed: usize,
+    /// Files queued for conversion
+    pub total: usize,
+    /// Whether every file has been handled or the job was cancelled
+    pub finished: bool,
+}
+
+impl DngProgress {
+    /// Files handled so far, converted or not
+    pub fn done(&self) -> usize {
+        self.converted + self.failed
+    }
+}
+
+/// Raw files being converted to DNG in the background
+pub struct DngJob {
+    progress: Arc<Mutex<DngProgress>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl DngJob {
+    /// Start converting `files` one at a time on a worker thread
+    pub fn start(converter: DngConverter, files: Vec<PathBuf>, keep_raw: bool) -> Self {
+        let progress = Arc::new(Mutex::new(DngProgress {
+            total: files.len(),
+            ..DngProgress::default()
+        }));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        info!("Converting {} raw files with {:?}", files.len(), converter);
+
+        let thread_progress = Arc::clone(&progress);
+        let thread_cancelled = Arc::clone(&cancelled);
+        thread::spawn(move || {
+            for file in files {
+                if thread_cancelled.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let result = converter.convert(&file);
+                match &result {
+                    Ok(output) => {
+                        info!("Converted {:?} to {:?}", file, output);
+                        let removed = if keep_raw {
+                            Ok(())
+                        } else {
+                            fs::remove_file(&file)
+                        };
+                        if let Err(e) = removed {
+                            warn!("Failed to remove {:?}: {}", file, e);
+                        }
+                    }
+                    Err(e) => warn!("Failed to convert {:?}: {}", file, e),
+                }
+                if let Ok(mut progress) = thread_progress.lock() {
+                    match result {
+                        Ok(_) => progress.converted += 1,
+                        Err(_) => progress.failed += 1,
+                    }
+                }
+            }
+
+            if let Ok(mut progress) = thread_progress.lock() {
+                progress.finished = true;
+            }
+        });
+
+        Self {
+            progress,
+            cancelled,
+        }
+    }
+
+    /// Snapshot of the conversion progress
+    pub fn progress(&self) -> DngProgress {
+        self.progress
+            .lock()
+            .map(|progress| progress.clone())
+            .unwrap_or_default()
+    }
+
+    /// Stop after the file being converted
+    pub fn cancel(&self) {
+        info!("Cancelling DNG conversion");
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// ORF files in a directory that have no DNG next to them yet
+pub fn unconverted_raw_files(directory: &Path) -> Result<Vec<PathBuf>> {
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("orf"))
+        })
+        .filter(|path| !path.with_extension("dng").exists())
+        .collect();
+    files.sort();
+    Ok(files)
+}
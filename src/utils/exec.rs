@@ -0,0 +1,22 @@
+// src/utils/exec.rs
+use log::{debug, info};
+use std::path::PathBuf;
+
+/// Locate an external program on the PATH, on any platform
+pub fn find_program(name: &str) -> Option<PathBuf> {
+    match which::which(name) {
+        Ok(path) => {
+            info!("Found {} at {}", name, path.display());
+            Some(path)
+        }
+        Err(e) => {
+            debug!("{} not found: {}", name, e);
+            None
+        }
+    }
+}
+
+/// Check whether an external program is available on the PATH
+pub fn has_program(name: &str) -> bool {
+    find_program(name).is_some()
+}
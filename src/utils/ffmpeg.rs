@@ -0,0 +1,304 @@
+// src/utils/ffmpeg.rs
+// Transcoding is only reachable through the live view recordings screen
+#![cfg_attr(not(feature = "video"), allow(dead_code))]
+use crate::utils::exec;
+use anyhow::{Result, anyhow};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Settings for turning raw MJPEG recordings into MP4
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TranscodeConfig {
+    /// Frame rate the raw recording is played back at, since MJPEG files carry no timing
+    pub input_fps: f64,
+    /// Frame rate of the output; `None` keeps the input rate
+    pub output_fps: Option<f64>,
+    /// Output width in pixels, keeping the aspect ratio; `None` keeps the recorded size
+    pub width: Option<u32>,
+    /// x264 quality, lower is better
+    pub crf: u8,
+}
+
+impl Default for TranscodeConfig {
+    fn default() -> Self {
+        Self {
+            input_fps: 30.0,
+            output_fps: None,
+            width: None,
+            crf: 23,
+        }
+    }
+}
+
+/// Locate the ffmpeg executable
+pub fn find_ffmpeg() -> Result<PathBuf> {
+    exec::find_program("ffmpeg").ok_or_else(|| anyhow!("ffmpeg was not found on the PATH"))
+}
+
+/// Encode JPEG frames to `output` by piping them through ffmpeg's MJPEG demuxer
+pub fn encode_frames<F: AsRef<[u8]>>(
+    frames: impl IntoIterator<Item = F>,
+    fps: f64,
+    output_args: &[String],
+    output: &Path,
+) -> Result<()> {
+    let mut child = Command::new(find_ffmpeg()?)
+        .args(["-y", "-loglevel", "error", "-f", "mjpeg", "-framerate"])
+        .arg(format!("{:.2}", fps))
+        .args(["-i", "-"])
+        .args(output_args)
+        .arg(output)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        for frame in frames {
+            stdin.write_all(frame.as_ref())?;
+        }
+        // Dropping stdin tells ffmpeg the input is complete
+    }
+
+    let result = child.wait_with_output()?;
+    if !result.status.success() {
+        return Err(anyhow!(
+            "ffmpeg failed: {}",
+            String::from_utf8_lossy(&result.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Output arguments for H.264 MP4 files that play everywhere
+pub fn mp4_args(crf: u8) -> Vec<String> {
+    [
+        "-c:v",
+        "libx264",
+        "-crf",
+        &crf.to_string(),
+        "-pix_fmt",
+        "yuv420p",
+        "-movflags",
+        "+faststart",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect()
+}
+
+/// Progress of a background transcode
+#[derive(Debug, Clone, Default)]
+pub struct TranscodeProgress {
+    /// Frames ffmpeg has written so far
+    pub frames_done: u64,
+    /// Frames in the input, once counted
+    pub frames_total: Option<u64>,
+    /// Whether ffmpeg has exited
+    pub finished: bool,
+    /// Why the transcode failed, if it did
+    pub error: Option<String>,
+}
+
+impl TranscodeProgress {
+    /// Completion from 0 to 100, once the input has been counted
+    pub fn percent(&self) -> Option<u16> {
+        let total = self.frames_total.filter(|total| *total > 0)?;
+        Some((self.frames_done.min(total) * 100 / total) as u16)
+    }
+}
+
+/// An MJPEG to MP4 transcode running in the background
+pub struct TranscodeJob {
+    /// Recording being transcoded
+    pub input: PathBuf,
+    /// File being written
+    pub output: PathBuf,
+    progress: Arc<Mutex<TranscodeProgress>>,
+    child: Arc<Mutex<Option<Child>>>,
+}
+
+impl TranscodeJob {
+    /// Start transcoding a raw MJPEG recording to an MP4 next to it
+    pub fn start(input: &Path, config: &TranscodeConfig) -> Result<Self> {
+        let ffmpeg = find_ffmpeg()?;
+        let output = input.with_extension("mp4");
+
+        let mut filters = Vec::new();
+        if let Some(width) = config.width {
+            // Height must stay even for yuv420p
+            filters.push(format!("scale={}:-2", width));
+        }
+        if let Some(fps) = config.output_fps {
+            filters.push(format!("fps={:.2}", fps));
+        }
+
+        let mut command = Command::new(ffmpeg);
+        command
+            .args([
+                "-y",
+                "-loglevel",
+                "error",
+                "-nostats",
+                "-progress",
+                "pipe:1",
+            ])
+            .args(["-f", "mjpeg", "-framerate"])
+            .arg(format!("{:.2}", config.input_fps))
+            .arg("-i")
+            .arg(input);
+        if !filters.is_empty() {
+            command.arg("-vf").arg(filters.join(","));
+        }
+        command
+            .args(mp4_args(config.crf))
+            .arg(&output)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = command.spawn()?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("ffmpeg progress output is unavailable"))?;
+        info!("Transcoding {:?} to {:?}", input, output);
+
+        let progress = Arc::new(Mutex::new(TranscodeProgress::default()));
+        let child = Arc::new(Mutex::new(Some(child)));
+
+        // Counting frames reads the whole file, so it runs alongside ffmpeg
+        let count_progress = Arc::clone(&progress);
+        let count_input = input.to_path_buf();
+        thread::spawn(move || match count_jpeg_frames(&count_input) {
+            Ok(total) => {
+                if let Ok(mut progress) = count_progress.lock() {
+                    progress.frames_total = Some(total);
+                }
+            }
+            Err(e) => warn!("Failed to count frames in {:?}: {}", count_input, e),
+        });
+
+        let thread_progress = Arc::clone(&progress);
+        let thread_child = Arc::clone(&child);
+        thread::spawn(move || track_progress(stdout, thread_progress, thread_child));
+
+        Ok(Self {
+            input: input.to_path_buf(),
+            output,
+            progress,
+            child,
+        })
+    }
+
+    /// Snapshot of the transcode progress
+    pub fn progress(&self) -> TranscodeProgress {
+        self.progress
+            .lock()
+            .map(|progress| progress.clone())
+            .unwrap_or_default()
+    }
+
+    /// Stop ffmpeg, leaving a partial output file
+    pub fn cancel(&self) {
+        let Ok(mut child) = self.child.lock() else {
+            return;
+        };
+        if let Some(child) = child.as_mut() {
+            info!("Cancelling transcode of {:?}", self.input);
+            let _ = child.kill();
+        }
+    }
+}
+
+/// Read ffmpeg's `-progress` output until it exits, then record the result
+fn track_progress(
+    stdout: impl Read,
+    progress: Arc<Mutex<TranscodeProgress>>,
+    child: Arc<Mutex<Option<Child>>>,
+) {
+    for line in BufReader::new(stdout).lines().map_while(|line| line.ok()) {
+        let Some(frames) = line
+            .strip_prefix("frame=")
+            .and_then(|value| value.trim().parse().ok())
+        else {
+            continue;
+        };
+        if let Ok(mut progress) = progress.lock() {
+            progress.frames_done = frames;
+        }
+    }
+
+    // Progress output closes when ffmpeg exits
+    let result = match child.lock().ok().and_then(|mut child| child.take()) {
+        Some(child) => child.wait_with_output().map_err(anyhow::Error::from),
+        None => Err(anyhow!("ffmpeg process was lost")),
+    };
+
+    if let Ok(mut progress) = progress.lock() {
+        progress.finished = true;
+        match result {
+            Ok(output) if output.status.success() => info!("Transcode finished"),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                let message = if stderr.is_empty() {
+                    format!("ffmpeg exited with {}", output.status)
+                } else {
+                    stderr
+                };
+                warn!("Transcode failed: {}", message);
+                progress.error = Some(message);
+            }
+            Err(e) => progress.error = Some(e.to_string()),
+        }
+    }
+}
+
+/// Count JPEG start-of-image markers in an MJPEG file
+fn count_jpeg_frames(path: &Path) -> Result<u64> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut buffer = vec![0u8; 1 << 20];
+    // Carry the tail of each chunk over so markers split across reads still count
+    let mut carry: Vec<u8> = Vec::new();
+    let mut count = 0;
+
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+
+        let mut chunk = std::mem::take(&mut carry);
+        chunk.extend_from_slice(&buffer[..read]);
+        count += chunk
+            .windows(3)
+            .filter(|window| window == &[0xFF, 0xD8, 0xFF])
+            .count() as u64;
+        carry = chunk[chunk.len().saturating_sub(2)..].to_vec();
+    }
+
+    Ok(count)
+}
+
+/// Raw MJPEG recordings in a directory, newest first
+pub fn list_recordings(directory: &Path) -> Result<Vec<PathBuf>> {
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut recordings: Vec<PathBuf> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "mjpeg"))
+        .collect();
+    // Names carry a timestamp and segment number, so they sort chronologically
+    recordings.sort();
+    recordings.reverse();
+    Ok(recordings)
+}
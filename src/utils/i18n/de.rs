@@ -0,0 +1,913 @@
+// src/utils/i18n/de.rs
+// German message catalog
+
+/// Look up the German translation of an English message
+pub fn translate(msg: &str) -> Option<&'static str> {
+    Some(match msg {
+        "(Resolution: {} - Loading higher... {}s)" => {
+            "(Auflösung: {} - Höhere wird geladen... {} s)"
+        }
+        "(Resolution: {} - Press 'r' for higher)" => "(Auflösung: {} - 'r' für höhere drücken)",
+        "(Resolution: {})" => "(Auflösung: {})",
+        "(empty response)" => "(leere Antwort)",
+        "+/- - Exposure   " => "+/- - Belichtung   ",
+        "+/- - Zoom in/out   " => "+/- - Vergrößern/Verkleinern   ",
+        "0 - Reset zoom   " => "0 - Zoom zurücksetzen   ",
+        "1-5 - Rate, 0 - Clear rating, t - Tags" => {
+            "1-5 - Bewerten, 0 - Bewertung löschen, t - Schlagwörter"
+        }
+        "1. Camera is powered on" => "1. Die Kamera ist eingeschaltet",
+        "2. WiFi connection is active" => "2. Die WLAN-Verbindung ist aktiv",
+        "3. Camera IP address is correct" => "3. Die IP-Adresse der Kamera ist korrekt",
+        "A macro is playing, wait for it to finish before recording" => {
+            "Ein Makro läuft, vor dem Aufzeichnen auf sein Ende warten"
+        }
+        "A movie is already downloading" => "Es wird bereits ein Film heruntergeladen",
+        "A transcode is already running" => "Es läuft bereits eine Umwandlung",
+        "API exploration complete: {} of {} endpoints supported. Check logs for details." => {
+            "API-Erkundung abgeschlossen: {} von {} Endpunkten unterstützt. Details stehen im Protokoll."
+        }
+        "Access Point" => "Zugangspunkt",
+        "Access point changed to {}, join that network to reconnect" => {
+            "Zugangspunkt in {} geändert, zum erneuten Verbinden diesem Netz beitreten"
+        }
+        "Access point: not reported by this camera" => {
+            "Zugangspunkt: von dieser Kamera nicht gemeldet"
+        }
+        "Access point: {} (password {})" => "Zugangspunkt: {} (Passwort {})",
+        "All thumbnail formats failed, trying direct image download" => {
+            "Alle Miniaturformate sind fehlgeschlagen, das Bild wird direkt heruntergeladen"
+        }
+        "Already at {} resolution (maximum available)" => {
+            "Bereits bei Auflösung {} (höchste verfügbare)"
+        }
+        "Alternative display method failed: {}" => "Andere Anzeigemethode fehlgeschlagen: {}",
+        "Alternative display method succeeded" => "Andere Anzeigemethode erfolgreich",
+        "Another instance of the app (process {}) is controlling this camera. This window is read-only.\n\nTake control?" => {
+            "Eine andere Instanz der App (Prozess {}) steuert diese Kamera. Dieses Fenster ist schreibgeschützt.\n\nSteuerung übernehmen?"
+        }
+        "Another instance of the app (process {}) took control of the camera. This window is now read-only.\n\nTake control back?" => {
+            "Eine andere Instanz der App (Prozess {}) hat die Steuerung der Kamera übernommen. Dieses Fenster ist jetzt schreibgeschützt.\n\nSteuerung zurückholen?"
+        }
+        "Aperture" => "Blende",
+        "Applied profile {} ({} settings)" => "Profil {} angewendet ({} Einstellungen)",
+        "Aspect ratio preservation disabled" => "Seitenverhältnis wird nicht beibehalten",
+        "Aspect ratio preservation enabled" => "Seitenverhältnis wird beibehalten",
+        "Attempting basic image rendering methods..." => {
+            "Probiere einfache Methoden zur Bilddarstellung..."
+        }
+        "Attempting to delete: {}..." => "Versuche zu löschen: {}...",
+        "Audit Log" => "Protokoll",
+        "Audit log ({} operations, newest first) - Page {}/{}" => {
+            "Protokoll ({} Vorgänge, neueste zuerst) - Seite {}/{}"
+        }
+        "Auto" => "Automatisch",
+        "Automatic" => "Automatisch",
+        "Bandwidth KB/s (now {})" => "Bandbreite KB/s (aktuell {})",
+        "Basic (text only)" => "Einfach (nur Text)",
+        "Battery: {}" => "Akku: {}",
+        "Bluetooth wake failed: {}" => "Wecken über Bluetooth fehlgeschlagen: {}",
+        "Built without the external player" => "Ohne externen Player gebaut",
+        "Camera Connection Error" => "Kamera-Verbindungsfehler",
+        "Camera In Use" => "Kamera wird verwendet",
+        "Camera Settings" => "Kameraeinstellungen",
+        "Camera Wi-Fi networks:" => "WLAN-Netze der Kamera:",
+        "Camera address [{}]: " => "Kameraadresse [{}]: ",
+        "Camera battery low: {}" => "Kamera-Akku schwach: {}",
+        "Camera capabilities" => "Kamerafähigkeiten",
+        "Camera connection" => "Kameraverbindung",
+        "Camera connection failed: {}" => "Kameraverbindung fehlgeschlagen: {}",
+        "Camera connection verified" => "Kameraverbindung überprüft",
+        "Camera doesn't support WiFi deletion. Try using camera's menu." => {
+            "Die Kamera unterstützt kein Löschen über WLAN. Bitte das Kameramenü verwenden."
+        }
+        "Camera found." => "Kamera gefunden.",
+        "Camera initialized successfully" => "Kamera erfolgreich initialisiert",
+        "Camera requests" => "Kamera-Anfragen",
+        "Camera round trip: {}" => "Antwortzeit der Kamera: {}",
+        "Camera round trip: {} (average {} ms)" => "Antwortzeit der Kamera: {} (Mittel {} ms)",
+        "Camera settings reloaded" => "Kameraeinstellungen neu geladen",
+        "Camera switched to {} mode" => "Kamera in den Verbindungsmodus {} geschaltet",
+        "Camera unresponsive - automatic requests paused, checking again in {}s" => {
+            "Kamera reagiert nicht - automatische Anfragen pausiert, erneuter Versuch in {}s"
+        }
+        "Camera unresponsive - checking whether it answers again" => {
+            "Kamera reagiert nicht - es wird geprüft, ob sie wieder antwortet"
+        }
+        "Camera {} changed to {}" => "Kamera {} geändert auf {}",
+        "Camera: connected ({} mode)" => "Kamera: verbunden ({}-Modus)",
+        "Camera: connecting..." => "Kamera: verbinde...",
+        "Camera: disconnected" => "Kamera: getrennt",
+        "Camera: error - {}" => "Kamera: Fehler - {}",
+        "Cameras ({} total)" => "Kameras ({} insgesamt)",
+        "Cameras ({} total) - Page {}/{}" => "Kameras ({} insgesamt) - Seite {}/{}",
+        "Cancelling transcode..." => "Umwandlung wird abgebrochen...",
+        "Capabilities exported to {}" => "Fähigkeiten nach {} exportiert",
+        "Capture" => "Aufnahme",
+        "Capture Queue" => "Aufnahmewarteschlange",
+        "Capture is disabled in read-only mode" => "Aufnehmen ist im Nur-Lese-Modus gesperrt",
+        "Capture queue cancelled after {}/{} shots" => {
+            "Aufnahmewarteschlange nach {}/{} Aufnahmen abgebrochen"
+        }
+        "Capture queue complete: {} shots" => "Aufnahmewarteschlange abgeschlossen: {} Aufnahmen",
+        "Capture queue started: {} shots" => "Aufnahmewarteschlange gestartet: {} Aufnahmen",
+        "Capture queue stopped after {}/{} shots: {}" => {
+            "Aufnahmewarteschlange nach {}/{} Aufnahmen gestoppt: {}"
+        }
+        "Capturing focus bracket..." => "Fokusreihe wird aufgenommen...",
+        "Changes since {}" => "Änderungen seit {}",
+        "Checking whether the camera is awake..." => "Prüfe, ob die Kamera wach ist...",
+        "Choose a number [*]: " => "Nummer wählen [*]: ",
+        "Cleared the rating of {}" => "Bewertung von {} gelöscht",
+        "Cloudy" => "Bewölkt",
+        "Command" => "Befehl",
+        "Command Console" => "Befehlskonsole",
+        "Connect Mode" => "Verbindungsmodus",
+        "Connect mode: {}" => "Verbindungsmodus: {}",
+        "Connect settings reloaded" => "Verbindungseinstellungen neu geladen",
+        "Connecting to Olympus camera..." => "Verbinde mit Olympus-Kamera...",
+        "Connection Status" => "Verbindungsstatus",
+        "Controls" => "Steuerung",
+        "Controls: " => "Steuerung: ",
+        "Converted {} raw files to DNG" => "{} Raw-Dateien in DNG umgewandelt",
+        "Converted {} raw files to DNG, {} failed" => {
+            "{} Raw-Dateien in DNG umgewandelt, {} fehlgeschlagen"
+        }
+        "Converting {} raw files to DNG" => "{} Raw-Dateien werden in DNG umgewandelt",
+        "Copied to clipboard: {}" => "In die Zwischenablage kopiert: {}",
+        "Could not change the live view size: {}" => {
+            "Live-View-Größe konnte nicht geändert werden: {}"
+        }
+        "Could not display image. Your terminal may not support image display." => {
+            "Bild konnte nicht angezeigt werden. Das Terminal unterstützt eventuell keine Bildanzeige."
+        }
+        "Ctrl-q - Quit" => "Strg-q - Beenden",
+        "DNG conversion" => "DNG-Umwandlung",
+        "DNG conversion skipped: {}" => "DNG-Umwandlung übersprungen: {}",
+        "Dashboard" => "Übersicht",
+        "Daylight" => "Tageslicht",
+        "Degraded" => "Beeinträchtigt",
+        "Degraded (camera not sending)" => "Beeinträchtigt (Kamera sendet nicht)",
+        "Degraded (network congestion)" => "Beeinträchtigt (Netzwerk überlastet)",
+        "Delete" => "Löschen",
+        "Delete - Delete selected image" => "Entf - Ausgewähltes Bild löschen",
+        "Delete Image" => "Bild löschen",
+        "Delete {} from the camera?\n\nThis cannot be undone." => {
+            "{} von der Kamera löschen?\n\nDies kann nicht rückgängig gemacht werden."
+        }
+        "Deleting images is disabled in read-only mode" => {
+            "Löschen von Bildern ist im Nur-Lese-Modus gesperrt"
+        }
+        "Deleting: {}..." => "Lösche: {}...",
+        "Deletion attempt for {} completed" => "Löschversuch für {} abgeschlossen",
+        "Deletion failed: {}" => "Löschen fehlgeschlagen: {}",
+        "Diagnostics complete, stream restarted" => "Diagnose abgeschlossen, Stream neu gestartet",
+        "Display method: {}" => "Anzeigemethode: {}",
+        "Download" => "Download",
+        "Download Image" => "Bild herunterladen",
+        "Download directory, relative to {} unless absolute [{}]: " => {
+            "Download-Verzeichnis, relativ zu {}, sofern nicht absolut [{}]: "
+        }
+        "Download failed: {}" => "Herunterladen fehlgeschlagen: {}",
+        "Download {} to the downloads directory?" => {
+            "{} in das Download-Verzeichnis herunterladen?"
+        }
+        "Downloaded: {} to {}" => "Heruntergeladen: {} nach {}",
+        "Downloaded: {} to {} - only {} MB of disk space left" => {
+            "Heruntergeladen: {} nach {} - nur noch {} MB Speicherplatz frei"
+        }
+        "Downloading image: {}..." => "Lade Bild herunter: {}...",
+        "Downloading {} to play it" => "{} wird zum Abspielen heruntergeladen",
+        "Downloading: {} to downloads directory..." => {
+            "Lade herunter: {} in das Download-Verzeichnis..."
+        }
+        "Drawing the live view in the terminal needs the graphics feature" => {
+            "Die Live-Ansicht im Terminal braucht das Feature graphics"
+        }
+        "End-to-end latency: {}" => "Gesamtverzögerung: {}",
+        "End-to-end latency: ~{} ms, {} (network {}, assembly {} ms, hand-off {} ms)" => {
+            "Gesamtverzögerung: ~{} ms, {} (Netzwerk {}, Zusammensetzen {} ms, Übergabe {} ms)"
+        }
+        "Enter - Restart stream   " => "Enter - Stream neu starten   ",
+        "Enter - Send a request, d - Compare with the last response to it" => {
+            "Enter - Anfrage senden, d - Mit der letzten Antwort darauf vergleichen"
+        }
+        "Enter - Transcode selected recording to MP4" => {
+            "Enter - Ausgewählte Aufnahme in MP4 umwandeln"
+        }
+        "Enter - View selected image" => "Enter - Ausgewähltes Bild anzeigen",
+        "Enter or the key shown - Run, Up/Down, PgUp/PgDn - Browse" => {
+            "Enter oder angezeigte Taste - Ausführen, Hoch/Runter, Bild↑/↓ - Blättern"
+        }
+        "Error connecting to camera: {}" => "Fehler beim Verbinden mit der Kamera: {}",
+        "Error refreshing images: {}" => "Fehler beim Aktualisieren der Bilder: {}",
+        "Error: {}" => "Fehler: {}",
+        "Esc - Return to main menu" => "Esc - Zurück zum Hauptmenü",
+        "Esc/q - Return to image list   " => "Esc/q - Zurück zur Bildliste   ",
+        "Esc/q - Return to menu   " => "Esc/q - Zurück zum Menü   ",
+        "Exploring camera API endpoints..." => "Erkunde API-Endpunkte der Kamera...",
+        "Export capabilities to:" => "Fähigkeiten exportieren nach:",
+        "Exporting statistics to {}" => "Exportiere Statistiken nach {}",
+        "Exposure compensation" => "Belichtungskorrektur",
+        "Exposure compensation: {} EV" => "Belichtungskorrektur: {} EV",
+        "Exposure settings" => "Belichtungseinstellungen",
+        "Exposure: {} EV | White balance: {}" => "Belichtung: {} EV | Weißabgleich: {}",
+        "FPS (now {})" => "FPS (aktuell {})",
+        "FPS over last {}s: min {} / avg {} / max {}" => {
+            "FPS der letzten {}s: min {} / Ø {} / max {}"
+        }
+        "Failed to apply profile: {}" => "Profil konnte nicht angewendet werden: {}",
+        "Failed to assemble stills: {}" => "Standbilder konnten nicht zusammengesetzt werden: {}",
+        "Failed to change the access point: {}" => "Ändern des Zugangspunkts fehlgeschlagen: {}",
+        "Failed to change {}: {}" => "{} konnte nicht geändert werden: {}",
+        "Failed to connect to camera" => "Verbindung zur Kamera fehlgeschlagen",
+        "Failed to connect to camera: {}" => "Verbindung zur Kamera fehlgeschlagen: {}",
+        "Failed to connect: {}" => "Verbindung fehlgeschlagen: {}",
+        "Failed to copy to clipboard: {}" => "Kopieren in die Zwischenablage fehlgeschlagen: {}",
+        "Failed to display image with all available methods." => {
+            "Das Bild konnte mit keiner verfügbaren Methode angezeigt werden."
+        }
+        "Failed to display image: {}" => "Bild konnte nicht angezeigt werden: {}",
+        "Failed to download {}: {}" => "{} konnte nicht heruntergeladen werden: {}",
+        "Failed to export capabilities: {}" => "Export der Fähigkeiten fehlgeschlagen: {}",
+        "Failed to export replay: {}" => "Wiederholung konnte nicht exportiert werden: {}",
+        "Failed to import capabilities: {}" => "Import der Fähigkeiten fehlgeschlagen: {}",
+        "Failed to initialize camera: {}" => "Kamera konnte nicht initialisiert werden: {}",
+        "Failed to join {}: {}" => "Verbindung mit {} fehlgeschlagen: {}",
+        "Failed to load higher resolution: {}" => {
+            "Höhere Auflösung konnte nicht geladen werden: {}"
+        }
+        "Failed to load image {} after trying multiple formats.\n\nTry exploring the camera API or refreshing the image list." => {
+            "Bild {} konnte mit keinem der Formate geladen werden.\n\nDie Kamera-API erkunden oder die Bildliste aktualisieren."
+        }
+        "Failed to load image: All URL formats failed" => {
+            "Bild konnte nicht geladen werden: Alle URL-Formate sind fehlgeschlagen"
+        }
+        "Failed to load image: {}\n\nCheck the logs for more details." => {
+            "Bild konnte nicht geladen werden: {}\n\nDetails stehen im Protokoll."
+        }
+        "Failed to open folder {}: {}" => "Ordner {} konnte nicht geöffnet werden: {}",
+        "Failed to open {}: {}" => "{} konnte nicht geöffnet werden: {}",
+        "Failed to play {}: {}" => "{} konnte nicht abgespielt werden: {}",
+        "Failed to read the camera settings: {}" => {
+            "Kameraeinstellungen konnten nicht gelesen werden: {}"
+        }
+        "Failed to read the connect settings: {}" => {
+            "Lesen der Verbindungseinstellungen fehlgeschlagen: {}"
+        }
+        "Failed to relaunch the player: {}" => "Player konnte nicht neu gestartet werden: {}",
+        "Failed to restart live view: {}" => "Live-Ansicht konnte nicht neu gestartet werden: {}",
+        "Failed to restart stream: {}" => "Stream konnte nicht neu gestartet werden: {}",
+        "Failed to save profile: {}" => "Profil konnte nicht gespeichert werden: {}",
+        "Failed to save rating: {}" => "Bewertung konnte nicht gespeichert werden: {}",
+        "Failed to save snapshot: {}" => "Schnappschuss konnte nicht gespeichert werden: {}",
+        "Failed to save tags: {}" => "Schlagwörter konnten nicht gespeichert werden: {}",
+        "Failed to save the macro: {}" => "Makro konnte nicht gespeichert werden: {}",
+        "Failed to save web size copy: {}" => "Web-Kopie konnte nicht gespeichert werden: {}",
+        "Failed to set exposure compensation: {}" => {
+            "Belichtungskorrektur konnte nicht gesetzt werden: {}"
+        }
+        "Failed to set white balance: {}" => "Weißabgleich konnte nicht gesetzt werden: {}",
+        "Failed to start MJPEG server: {}" => "MJPEG-Server konnte nicht gestartet werden: {}",
+        "Failed to start RTMP stream: {}" => "RTMP-Stream konnte nicht gestartet werden: {}",
+        "Failed to start UDP receiver: {}" => "UDP-Empfänger konnte nicht gestartet werden: {}",
+        "Failed to start capture queue: {}" => {
+            "Aufnahmewarteschlange konnte nicht gestartet werden: {}"
+        }
+        "Failed to start interval stills: {}" => {
+            "Intervall-Standbilder konnten nicht gestartet werden: {}"
+        }
+        "Failed to start live view: {}" => "Live-Ansicht konnte nicht gestartet werden: {}",
+        "Failed to start recording: {}" => "Aufnahme konnte nicht gestartet werden: {}",
+        "Failed to start session: {}" => "Sitzung konnte nicht gestartet werden: {}",
+        "Failed to start statistics export: {}" => {
+            "Statistikexport konnte nicht gestartet werden: {}"
+        }
+        "Failed to start timelapse: {}" => "Zeitraffer konnte nicht gestartet werden: {}",
+        "Failed to start transcode: {}" => "Umwandlung konnte nicht gestartet werden: {}",
+        "Failed to start video recording: {}" => "Videoaufnahme konnte nicht gestartet werden: {}",
+        "Failed to start video stream: {}" => "Videostream konnte nicht gestartet werden: {}",
+        "Failed to stop video recording: {}" => "Videoaufnahme konnte nicht beendet werden: {}",
+        "Failed to switch the connect mode: {}" => {
+            "Wechsel des Verbindungsmodus fehlgeschlagen: {}"
+        }
+        "Failed to view image: {}" => "Bild konnte nicht angezeigt werden: {}",
+        "Failed to view {}: {}" => "Anzeige von {} fehlgeschlagen: {}",
+        "Failed to write session summary: {}" => {
+            "Sitzungszusammenfassung konnte nicht geschrieben werden: {}"
+        }
+        "Finished" => "Fertig",
+        "Fixed" => "Fest",
+        "Flash" => "Blitz",
+        "Fluorescent" => "Leuchtstofflampe",
+        "Focus bracket complete: {} frames" => "Fokusreihe abgeschlossen: {} Bilder",
+        "Focus bracket failed: {}" => "Fokusreihe fehlgeschlagen: {}",
+        "Folder {} - {} images" => "Ordner {} - {} Bilder",
+        "Found {} images" => "{} Bilder gefunden",
+        "Found {} images on camera" => "{} Bilder auf der Kamera gefunden",
+        "Found {} recordings" => "{} Aufnahmen gefunden",
+        "Go To Image" => "Gehe zu Bild",
+        "Good" => "Gut",
+        "Good, but the network is slow" => "Gut, aber das Netzwerk ist langsam",
+        "High" => "Hoch",
+        "Higher resolution image is already loading..." => {
+            "Bild in höherer Auflösung wird bereits geladen..."
+        }
+        "Higher resolution version loaded. Press Enter to view it." => {
+            "Höhere Auflösung geladen. Enter zum Anzeigen drücken."
+        }
+        "ISO" => "ISO",
+        "Image List" => "Bildliste",
+        "Image Loading Failed" => "Bild konnte nicht geladen werden",
+        "Image Preview" => "Bildvorschau",
+        "Image Viewer" => "Bildbetrachter",
+        "Image Viewer - {}" => "Bildbetrachter - {}",
+        "Image display method:" => "Bildanzeigemethode:",
+        "Image displayed successfully" => "Bild erfolgreich angezeigt",
+        "Image list refreshed - {} images found, {} new, {} removed" => {
+            "Bildliste aktualisiert - {} Bilder gefunden, {} neu, {} entfernt"
+        }
+        "Image list unchanged - {} images" => "Bildliste unverändert - {} Bilder",
+        "Image list updated - {} new, {} removed" => "Bildliste aktualisiert - {} neu, {} entfernt",
+        "Image loaded from prefetched thumbnail" => "Bild aus vorab geladener Miniatur geladen",
+        "Image loaded successfully (direct method)" => "Bild erfolgreich geladen (direkt)",
+        "Image loaded successfully using format #{}" => "Bild erfolgreich mit Format #{} geladen",
+        "Image number (1-{}):" => "Bildnummer (1-{}):",
+        "Image resolution increased to {}" => "Bildauflösung auf {} erhöht",
+        "Images ({} total) - Page {}/{}" => "Bilder ({} insgesamt) - Seite {}/{}",
+        "Import capabilities from:" => "Fähigkeiten importieren aus:",
+        "Imported {} capability entries" => "{} Fähigkeitseinträge importiert",
+        "Initializing camera for live view..." => "Initialisiere Kamera für die Live-Ansicht...",
+        "Interval stills stopped after {} frames" => {
+            "Intervall-Standbilder nach {} Bildern gestoppt"
+        }
+        "Interval stills stopped, assembling {} in the background" => {
+            "Intervall-Standbilder gestoppt, {} wird im Hintergrund erstellt"
+        }
+        "Interval stills: {}" => "Intervall-Standbilder: {}",
+        "Joined {}" => "Verbunden mit {}",
+        "Kitty graphics" => "Kitty-Grafik",
+        "Last frame: {} KB, received {}s ago" => "Letztes Bild: {} KB, vor {}s empfangen",
+        "Link recovered: live view back up to {}" => "Verbindung erholt: Live-View wieder auf {}",
+        "Live View" => "Live-Ansicht",
+        "Live view drawn in the terminal" => "Live-Ansicht im Terminal",
+        "Live view frames arriving again" => "Live-View-Bilder kommen wieder an",
+        "Live view frozen" => "Live-View eingefroren",
+        "Live view frozen: no frames for {} seconds" => {
+            "Live-View eingefroren: seit {} Sekunden keine Bilder"
+        }
+        "Live view is not available in this build" => {
+            "Live-Ansicht ist in diesem Build nicht verfügbar"
+        }
+        "Live view size: {}" => "Live-View-Größe: {}",
+        "Live view started on port {}" => "Live-Ansicht auf Port {} gestartet",
+        "Live view stream started" => "Live-Stream gestartet",
+        "Loading higher resolution image... (Current: {})" => {
+            "Lade Bild in höherer Auflösung... (Aktuell: {})"
+        }
+        "Loading image list..." => "Lade Bildliste...",
+        "Loading image: {} (Trying format #{}/{})" => "Lade Bild: {} (Probiere Format #{}/{})",
+        "Loading image: {} (Trying multiple formats...)" => {
+            "Lade Bild: {} (Probiere mehrere Formate...)"
+        }
+        "Logging camera protocol to {}" => "Kamera-Protokoll wird in {} aufgezeichnet",
+        "Looking for the camera at {}..." => "Suche Kamera unter {}...",
+        "Looking for the camera's Wi-Fi network..." => "Suche das WLAN der Kamera...",
+        "Low" => "Niedrig",
+        "MJPEG server stopped" => "MJPEG-Server gestoppt",
+        "MJPEG server: {}" => "MJPEG-Server: {}",
+        "MP4 ready" => "MP4 vorhanden",
+        "Macro not saved: no name given" => "Makro nicht gespeichert: kein Name angegeben",
+        "Macro recording stopped, no keys were recorded" => {
+            "Makroaufzeichnung beendet, keine Tasten aufgezeichnet"
+        }
+        "Macro to play ({}):" => "Abzuspielendes Makro ({}):",
+        "Macro {} finished" => "Makro {} beendet",
+        "Macro {} saved with {} keys, Ctrl-p plays it" => {
+            "Makro {} mit {} Tasten gespeichert, Strg-p spielt es ab"
+        }
+        "Macro {} stopped after {}/{} keys" => "Makro {} nach {}/{} Tasten angehalten",
+        "Macro {} stopped: {}" => "Makro {} angehalten: {}",
+        "Macro {} {}/{}" => "Makro {} {}/{}",
+        "Main Menu" => "Hauptmenü",
+        "Main Menu (f: focus bracket, t: timelapse, c: capture queue, p/s/r: profiles, n: session, e: transcode, m: dashboard, w: pairing, g: settings, x: console, u: plugins, i: requests, l: audit log, d: explore API)" => {
+            "Hauptmenü (f: Fokusreihe, t: Zeitraffer, c: Aufnahmewarteschlange, p/s/r: Profile, n: Sitzung, e: Umwandeln, m: Übersicht, w: Kopplung, g: Einstellungen, x: Konsole, u: Plugins, i: Anfragen, l: Protokoll, d: API erkunden)"
+        }
+        "Medium" => "Mittel",
+        "Monitoring {} cameras" => "Überwache {} Kameras",
+        "Most Olympus cameras do not support deleting images over WiFi. This is a limitation of the camera's firmware.\n\nAlternatives:\n1. Use the camera's menu to delete images\n2. Connect the SD card to your computer\n3. Format the SD card (will delete ALL images)" => {
+            "Die meisten Olympus-Kameras unterstützen das Löschen von Bildern über WLAN nicht. Dies ist eine Einschränkung der Kamera-Firmware.\n\nAlternativen:\n1. Bilder über das Kameramenü löschen\n2. Die SD-Karte mit dem Computer verbinden\n3. Die SD-Karte formatieren (löscht ALLE Bilder)"
+        }
+        "Movie download" => "Film-Download",
+        "NEW" => "NEU",
+        "Name for the {} keys recorded:" => "Name für die {} aufgezeichneten Tasten:",
+        "Network name:" => "Netzwerkname:",
+        "New photo on camera: {}" => "Neues Foto auf der Kamera: {}",
+        "No Live View Packets" => "Keine Live-View-Pakete",
+        "No URL available for higher resolution" => "Keine URL für höhere Auflösung verfügbar",
+        "No camera Wi-Fi network found" => "Kein WLAN der Kamera gefunden",
+        "No camera answered at {}. Enter another address, or press Enter to keep it anyway." => {
+            "Unter {} hat keine Kamera geantwortet. Andere Adresse eingeben oder Enter drücken, um sie trotzdem zu übernehmen."
+        }
+        "No camera answered. Type w to join the camera's Wi-Fi network, or enter its address." => {
+            "Keine Kamera hat geantwortet. w eingeben, um sich mit dem WLAN der Kamera zu verbinden, oder ihre Adresse eingeben."
+        }
+        "No connect modes configured" => "Keine Verbindungsmodi konfiguriert",
+        "No dashboard cameras configured" => "Keine Kameras für die Übersicht konfiguriert",
+        "No entry numbered {}" => "Kein Eintrag mit der Nummer {}",
+        "No image selected for a web size copy" => "Kein Bild für eine Web-Kopie ausgewählt",
+        "No image selected for deletion" => "Kein Bild zum Löschen ausgewählt",
+        "No image selected for download" => "Kein Bild zum Herunterladen ausgewählt",
+        "No image selected to copy" => "Kein Bild zum Kopieren ausgewählt",
+        "No image selected to open" => "Kein Bild zum Öffnen ausgewählt",
+        "No image selected to rate" => "Kein Bild zum Bewerten ausgewählt",
+        "No image selected to tag" => "Kein Bild zum Verschlagworten ausgewählt",
+        "No image selected to view" => "Kein Bild zum Anzeigen ausgewählt",
+        "No images to go to" => "Keine Bilder zum Anspringen",
+        "No images to sync" => "Keine Bilder zum Abgleichen",
+        "No live view packets arrived in {} seconds. A VPN or firewall may be blocking UDP.\n\nSwitch to a snapshot loop at {} fps?" => {
+            "In {} Sekunden sind keine Live-View-Pakete angekommen. Ein VPN oder eine Firewall blockiert möglicherweise UDP.\n\nAuf eine Schnappschuss-Schleife mit {} fps umschalten?"
+        }
+        "No macros recorded yet, Ctrl-r starts recording one" => {
+            "Noch keine Makros aufgezeichnet, Strg-r startet die Aufzeichnung"
+        }
+        "No network joined" => "Mit keinem Netz verbunden",
+        "No next folder" => "Kein nächster Ordner",
+        "No plugins in this build" => "Keine Plugins in diesem Build",
+        "No plugins in this build; they are compiled in with cargo features such as astro" => {
+            "Keine Plugins in diesem Build; sie werden mit Cargo-Features wie astro eingebaut"
+        }
+        "No previous folder" => "Kein voriger Ordner",
+        "No recording selected" => "Keine Aufnahme ausgewählt",
+        "No requests have been made yet" => "Es wurden noch keine Anfragen gestellt",
+        "No requests sent yet" => "Noch keine Anfragen gesendet",
+        "No settings profiles configured" => "Keine Einstellungsprofile konfiguriert",
+        "No transcode running" => "Keine Umwandlung aktiv",
+        "None" => "Keine",
+        "Not Recording" => "Keine Aufnahme",
+        "Not an image number: {} (1-{})" => "Keine gültige Bildnummer: {} (1-{})",
+        "Not measured" => "Nicht gemessen",
+        "Nothing new in that capability file" => "Nichts Neues in dieser Fähigkeitsdatei",
+        "Off" => "Aus",
+        "Olympus Camera Control - Audit Log" => "Olympus-Kamerasteuerung - Protokoll",
+        "Olympus Camera Control - Camera Dashboard" => "Olympus-Kamerasteuerung - Kameraübersicht",
+        "Olympus Camera Control - Camera Settings" => {
+            "Olympus-Kamerasteuerung - Kameraeinstellungen"
+        }
+        "Olympus Camera Control - Command Console" => "Olympus-Kamerasteuerung - Befehlskonsole",
+        "Olympus Camera Control - Image List" => "Olympus-Kamerasteuerung - Bildliste",
+        "Olympus Camera Control - Image Viewer" => "Olympus-Kamerasteuerung - Bildbetrachter",
+        "Olympus Camera Control - Main Menu" => "Olympus-Kamerasteuerung - Hauptmenü",
+        "Olympus Camera Control - OFFLINE MODE" => "Olympus-Kamerasteuerung - OFFLINE-MODUS",
+        "Olympus Camera Control - Pairing" => "Olympus-Kamerasteuerung - Kopplung",
+        "Olympus Camera Control - Plugins" => "Olympus-Kamerasteuerung - Plugins",
+        "Olympus Camera Control - Transcode Recordings" => {
+            "Olympus-Kamerasteuerung - Aufnahmen umwandeln"
+        }
+        "Olympus Camera Control - Video Viewer" => "Olympus-Kamerasteuerung - Videobetrachter",
+        "Olympus Camera Control is still running. Type 'exit' to return. Shell in {}" => {
+            "Olympus Camera Control läuft weiter. Mit 'exit' geht es zurück. Shell in {}"
+        }
+        "Olympus Camera Control terminated successfully." => {
+            "Olympus-Kamerasteuerung erfolgreich beendet."
+        }
+        "Olympus Camera Delete Limitation" => "Einschränkung beim Löschen",
+        "Olympus Live View" => "Olympus Live-Ansicht",
+        "Olympus UDP stream is displayed in a separate player window." => {
+            "Der Olympus-UDP-Stream wird in einem separaten Player-Fenster angezeigt."
+        }
+        "Olympus Video Viewer - {}" => "Olympus Videobetrachter - {}",
+        "Opened {} in the system viewer" => "{} im Systembetrachter geöffnet",
+        "Opening folder {}..." => "Öffne Ordner {}...",
+        "Orientation: {}" => "Ausrichtung: {}",
+        "Output paused on the last frame. Press Space to resume." => {
+            "Ausgabe beim letzten Bild angehalten. Leertaste setzt fort."
+        }
+        "PAUSED - last frame at {} UTC" => "PAUSIERT - letztes Bild um {} UTC",
+        "Packet loss, last {}s" => "Paketverlust, letzte {}s",
+        "Packet loss: live view reduced to {}" => "Paketverlust: Live-View auf {} verkleinert",
+        "Pairing" => "Kopplung",
+        "Password for {} (8 to 63 characters):" => "Passwort für {} (8 bis 63 Zeichen):",
+        "Paused" => "Pausiert",
+        "Photo captured successfully" => "Foto erfolgreich aufgenommen",
+        "Photo saved to {}" => "Foto gespeichert unter {}",
+        "Play Macro" => "Makro abspielen",
+        "Playback paused" => "Wiedergabe pausiert",
+        "Playback resumed" => "Wiedergabe fortgesetzt",
+        "Player PID: {}" => "Player-PID: {}",
+        "Player closed. Press p to relaunch it or v to watch in the terminal." => {
+            "Player geschlossen. p startet ihn neu, v zeigt das Bild im Terminal."
+        }
+        "Player relaunched" => "Player neu gestartet",
+        "Playing" => "Wiedergabe",
+        "Playing macro {}, any key stops it" => "Makro {} läuft, jede Taste hält es an",
+        "Playing {} in {}" => "{} wird in {} abgespielt",
+        "Please check:" => "Bitte prüfen:",
+        "Plugins" => "Plugins",
+        "Plugins ({} loaded)" => "Plugins ({} geladen)",
+        "Poor/Stalled" => "Schlecht/Angehalten",
+        "Poor/Stalled (camera not sending)" => "Schlecht/Angehalten (Kamera sendet nicht)",
+        "Poor/Stalled (network congestion)" => "Schlecht/Angehalten (Netzwerk überlastet)",
+        "Press 'r' to attempt reconnection, 'w' to find the camera's Wi-Fi or 'q' to quit" => {
+            "'r' für erneuten Verbindungsversuch, 'w' zum Suchen des Kamera-WLANs oder 'q' zum Beenden drücken"
+        }
+        "Press Enter to close" => "Enter zum Schließen drücken",
+        "Press Enter to confirm or Esc to cancel" => {
+            "Enter zum Bestätigen oder Esc zum Abbrechen drücken"
+        }
+        "Press Enter to keep the value shown in brackets." => {
+            "Enter drücken, um den Wert in Klammern zu übernehmen."
+        }
+        "Press Enter to refresh statistics. Type s to restart, p to pause/resume, x to stop/start the stream, l to relaunch a closed player, r to toggle recording, g to grab a snapshot, i to export an instant replay, t to start or stop interval stills, h to toggle the MJPEG server, o to toggle RTMP streaming, c to toggle stats CSV, + or - to adjust exposure, w to change white balance, d for diagnostics, b to go back." => {
+            "Enter aktualisiert die Statistik. s für Neustart, p für Pause/Fortsetzen, x zum Stoppen/Starten des Streams, l startet einen geschlossenen Player neu, r für Aufnahme, g für Schnappschuss, i exportiert eine Sofortwiederholung, t für Intervall-Standbilder, h für MJPEG-Server, o für RTMP-Streaming, c für Statistik-CSV, + oder - für Belichtung, w für Weißabgleich, d für Diagnose, b für zurück."
+        }
+        "Press Esc to hide; it keeps running in the background" => {
+            "Esc zum Ausblenden drücken; läuft im Hintergrund weiter"
+        }
+        "Press any key to return to the application after viewing, or Esc to go back to the image list." => {
+            "Nach dem Ansehen eine beliebige Taste drücken, um zur Anwendung zurückzukehren, oder Esc für die Bildliste."
+        }
+        "Press any key to return to the application, or Esc to go back to the image list..." => {
+            "Eine beliebige Taste drücken, um zur Anwendung zurückzukehren, oder Esc für die Bildliste..."
+        }
+        "Preview file: {}" => "Vorschaudatei: {}",
+        "Privacy masks: {}" => "Sichtschutzmasken: {}",
+        "Profile: {}" => "Profil: {}",
+        "Progress" => "Fortschritt",
+        "Queue {}/{}" => "Warteschlange {}/{}",
+        "Quit" => "Beenden",
+        "RTMP stream" => "RTMP-Stream",
+        "RTMP stream stopped" => "RTMP-Stream gestoppt",
+        "RTMP: {}" => "RTMP: {}",
+        "RTT timeout" => "RTT Zeitüberschreitung",
+        "RTT {} ms" => "RTT {} ms",
+        "Rated {} {} stars" => "{} mit {} Sternen bewertet",
+        "Read-only mode: capture, deleting images and settings changes are disabled" => {
+            "Nur-Lese-Modus: Aufnehmen, Löschen von Bildern und Einstellungsänderungen sind gesperrt"
+        }
+        "Read-only: the camera is being controlled elsewhere" => {
+            "Nur lesen: Die Kamera wird anderswo gesteuert"
+        }
+        "Recent failures:" => "Letzte Fehlschläge:",
+        "Record Video" => "Video aufnehmen",
+        "Recording" => "Aufnahme",
+        "Recording a macro, Ctrl-r again to stop" => {
+            "Makro wird aufgezeichnet, erneut Strg-r zum Beenden"
+        }
+        "Recording macro: {} keys" => "Makroaufzeichnung: {} Tasten",
+        "Recording started - note: requires manual encoding later" => {
+            "Aufnahme gestartet - Hinweis: muss später manuell kodiert werden"
+        }
+        "Recording started in {}-minute segments - note: requires manual encoding later" => {
+            "Aufnahme in {}-Minuten-Segmenten gestartet - Hinweis: muss später manuell kodiert werden"
+        }
+        "Recording started with only {} MB of disk space free" => {
+            "Aufnahme gestartet, nur {} MB Speicherplatz frei"
+        }
+        "Recording stopped" => "Aufnahme beendet",
+        "Recording video is disabled in read-only mode" => {
+            "Videoaufnahme ist im Nur-Lese-Modus deaktiviert"
+        }
+        "Recording video, choose Record Video again to stop" => {
+            "Video wird aufgenommen, zum Beenden erneut Video aufnehmen wählen"
+        }
+        "Recordings ({} total)" => "Aufnahmen ({} insgesamt)",
+        "Recordings ({} total) - Page {}/{}" => "Aufnahmen ({} insgesamt) - Seite {}/{}",
+        "Refresh Image List" => "Bildliste aktualisieren",
+        "Refreshing image count..." => "Aktualisiere Bildanzahl...",
+        "Replay buffer: {}s ({} frames)" => "Wiederholungspuffer: {}s ({} Bilder)",
+        "Replay saved to {}" => "Wiederholung gespeichert unter {}",
+        "Request, e.g. get_caminfo.cgi:" => "Anfrage, z. B. get_caminfo.cgi:",
+        "Requests sent ({}, newest first)" => "Gesendete Anfragen ({}, neueste zuerst)",
+        "Response" => "Antwort",
+        "Restarting stream..." => "Starte Stream neu...",
+        "Returned to image list" => "Zurück zur Bildliste",
+        "Returned to main menu" => "Zurück zum Hauptmenü",
+        "Returning to Olympus Camera Control..." => "Zurück zu Olympus Camera Control...",
+        "Rotated {}°" => "Um {}° gedreht",
+        "Running diagnostics..." => "Führe Diagnose aus...",
+        "SIXEL" => "SIXEL",
+        "Save Macro" => "Makro speichern",
+        "Saved camera settings as profile {}" => "Kameraeinstellungen als Profil {} gespeichert",
+        "Saved web size copy of {} to {}" => "Web-Kopie von {} unter {} gespeichert",
+        "Saving a still every {}s to {}" => "Speichere alle {}s ein Standbild in {}",
+        "Saving web size copy of {}..." => "Web-Kopie von {} wird gespeichert...",
+        "Selected image {}" => "Bild {} ausgewählt",
+        "Selected profile: {}" => "Ausgewähltes Profil: {}",
+        "Selected: {}" => "Ausgewählt: {}",
+        "Send {} again to compare its responses" => {
+            "{} erneut senden, um die Antworten zu vergleichen"
+        }
+        "Serving MJPEG stream on {}" => "MJPEG-Stream wird unter {} bereitgestellt",
+        "Session name (may be left empty):" => "Name der Sitzung (darf leer bleiben):",
+        "Session {} ended with {} files" => "Sitzung {} mit {} Dateien beendet",
+        "Session {} started, new files go to {}" => {
+            "Sitzung {} gestartet, neue Dateien landen in {}"
+        }
+        "Session: {}" => "Sitzung: {}",
+        "Setting" => "Einstellung",
+        "Settings saved to {}" => "Einstellungen gespeichert in {}",
+        "Setup" => "Einrichtung",
+        "Shade" => "Schatten",
+        "Showing stream details" => "Stream-Details werden angezeigt",
+        "Showing the full response" => "Vollständige Antwort wird angezeigt",
+        "Shutter speed" => "Verschlusszeit",
+        "Sinks: {}" => "Ausgaben: {}",
+        "Snapshot loop running at {} fps" => "Schnappschuss-Schleife läuft mit {} fps",
+        "Snapshot loop: {} fps" => "Schnappschuss-Schleife: {} fps",
+        "Snapshot saved to {}" => "Schnappschuss gespeichert unter {}",
+        "Space - Pause/Resume   " => "Leertaste - Pause/Fortsetzen   ",
+        "Start Session" => "Sitzung starten",
+        "Starting in offline mode. Press any key to continue." => {
+            "Start im Offline-Modus. Beliebige Taste zum Fortfahren drücken."
+        }
+        "Starting live view stream..." => "Starte Live-Stream...",
+        "Starting terminal interface..." => "Starte Terminal-Oberfläche...",
+        "Starts at {} UTC" => "Beginnt um {} UTC",
+        "Statistics export stopped" => "Statistikexport beendet",
+        "Statistics: {} packets, {} frames, {} FPS" => "Statistik: {} Pakete, {} Bilder, {} FPS",
+        "Stats CSV: {}" => "Statistik-CSV: {}",
+        "Status: {} | {} | {}" => "Status: {} | {} | {}",
+        "Still running: {}\n\nQuitting now stops them. Quit anyway?" => {
+            "Läuft noch: {}\n\nBeim Beenden wird das abgebrochen. Trotzdem beenden?"
+        }
+        "Stop recording with Ctrl-r before playing a macro" => {
+            "Vor dem Abspielen eines Makros die Aufzeichnung mit Strg-r beenden"
+        }
+        "Stopped" => "Gestoppt",
+        "Stopping capture queue after the current shot..." => {
+            "Aufnahmewarteschlange wird nach der aktuellen Aufnahme gestoppt..."
+        }
+        "Stopping timelapse after the current frame..." => {
+            "Zeitraffer wird nach dem aktuellen Bild beendet..."
+        }
+        "Stream Health: " => "Stream-Zustand: ",
+        "Stream Health: {}" => "Stream-Zustand: {}",
+        "Stream URL: {}" => "Stream-URL: {}",
+        "Stream active. Press Esc or q to return to menu, Ctrl-q to quit." => {
+            "Stream aktiv. Esc oder q kehrt zum Menü zurück, Strg-q beendet."
+        }
+        "Stream may be stalled. Press Enter to restart stream or d to run diagnostics." => {
+            "Der Stream hängt möglicherweise. Enter für Neustart oder d für Diagnose drücken."
+        }
+        "Stream restarted successfully" => "Stream erfolgreich neu gestartet",
+        "Stream stalled and the camera answers slowly: the network is congested. Move closer to the camera or press Enter to restart stream." => {
+            "Stream angehalten und die Kamera antwortet langsam: das Netzwerk ist überlastet. Näher an die Kamera gehen oder Enter zum Neustarten des Streams drücken."
+        }
+        "Stream stalling: live view reduced to {}" => "Stream stockt: Live-View auf {} verkleinert",
+        "Stream started" => "Stream gestartet",
+        "Stream stopped" => "Stream gestoppt",
+        "Streaming to {}" => "Streaming an {}",
+        "Successfully deleted: {}" => "Erfolgreich gelöscht: {}",
+        "Switch the camera to {} mode? It may restart its Wi-Fi and drop this connection." => {
+            "Kamera in den Modus {} schalten? Sie startet dabei eventuell ihr WLAN neu und trennt diese Verbindung."
+        }
+        "Sync complete: {} downloaded, {} already present, {} duplicates skipped, {} failed" => {
+            "Abgleich abgeschlossen: {} heruntergeladen, {} bereits vorhanden, {} Duplikate übersprungen, {} fehlgeschlagen"
+        }
+        "Sync stopped: {}" => "Abgleich abgebrochen: {}",
+        "Syncing {} images..." => "Gleiche {} Bilder ab...",
+        "Tag Image" => "Bild verschlagworten",
+        "Tagged {} with {} tags" => "{} mit {} Schlagwörtern versehen",
+        "Tags for {}, separated by commas:" => "Schlagwörter für {}, durch Kommas getrennt:",
+        "Take Photo" => "Foto aufnehmen",
+        "Taking photo with warm-up..." => "Nehme Foto mit Aufwärmphase auf...",
+        "Taking tethered photo..." => "Tethering-Foto wird aufgenommen...",
+        "Terminal View" => "Terminal-Ansicht",
+        "Terminal too small" => "Terminal zu klein",
+        "Thank you for using the application!" => "Vielen Dank für die Nutzung der Anwendung!",
+        "The camera answers but sends no frames. Press Enter to restart stream or d to run diagnostics." => {
+            "Die Kamera antwortet, sendet aber keine Bilder. Enter zum Neustarten des Streams oder d für die Diagnose drücken."
+        }
+        "The camera answers slowly; the network may be congested." => {
+            "Die Kamera antwortet langsam; das Netzwerk ist möglicherweise überlastet."
+        }
+        "The camera doesn't report its access point, so it can't be changed here" => {
+            "Die Kamera meldet ihren Zugangspunkt nicht, er kann hier daher nicht geändert werden"
+        }
+        "The camera switched to {} mode without this app asking; another app such as OI.Share may be controlling it. This window is now read-only.\n\nTake control?" => {
+            "Die Kamera ist ohne Auftrag dieser App in den Modus {} gewechselt; vermutlich steuert sie eine andere App wie OI.Share. Dieses Fenster ist jetzt schreibgeschützt.\n\nSteuerung übernehmen?"
+        }
+        "The dashboard is not available in this build" => {
+            "Die Übersicht ist in dieser Version nicht verfügbar"
+        }
+        "The image is located at: {}" => "Das Bild liegt unter: {}",
+        "The player is already running" => "Der Player läuft bereits",
+        "The responses are the same" => "Die Antworten sind gleich",
+        "The responses are too long to compare" => "Die Antworten sind zu lang zum Vergleichen",
+        "The terminal UI will be temporarily suspended while viewing the image." => {
+            "Die Terminal-Oberfläche wird während der Bildanzeige vorübergehend ausgeblendet."
+        }
+        "This camera can't delete images over WiFi" => {
+            "Diese Kamera kann über WLAN keine Bilder löschen"
+        }
+        "Timelapse" => "Zeitraffer",
+        "Timelapse cancelled after {}/{} frames" => "Zeitraffer nach {}/{} Bildern abgebrochen",
+        "Timelapse complete" => "Zeitraffer abgeschlossen",
+        "Timelapse scheduled: {} frames every {}s from {} UTC" => {
+            "Zeitraffer geplant: {} Bilder alle {}s ab {} UTC"
+        }
+        "Timelapse started: {} frames every {}s" => "Zeitraffer gestartet: {} Bilder alle {}s",
+        "Timelapse stopped after {}/{} frames: {}" => "Zeitraffer nach {}/{} Bildern gestoppt: {}",
+        "Timelapse {}/{}" => "Zeitraffer {}/{}",
+        "To view the image, press Enter. The image will be displayed using viuer." => {
+            "Enter drücken, um das Bild anzuzeigen. Die Anzeige erfolgt mit viuer."
+        }
+        "Took control of the camera" => "Steuerung der Kamera übernommen",
+        "Transcode" => "Umwandeln",
+        "Transcode failed: {}" => "Umwandlung fehlgeschlagen: {}",
+        "Transcoded to {}" => "Umgewandelt in {}",
+        "Transcoding is not available in this build" => {
+            "Umwandeln ist in diesem Build nicht verfügbar"
+        }
+        "Transcoding to {}..." => "Umwandlung in {}...",
+        "Transcoding {}: {}" => "Umwandlung von {}: {}",
+        "Trying alternative display method..." => "Probiere eine andere Anzeigemethode...",
+        "Tungsten" => "Kunstlicht",
+        "Type a number from 1 to {}." => "Eine Nummer von 1 bis {} eingeben.",
+        "Type a number to change that setting, r to reload, b to go back." => {
+            "Nummer eingeben, um diese Einstellung zu ändern, r zum Neuladen, b für zurück."
+        }
+        "Type a number to choose, f for a focus bracket, t to start or stop a timelapse, c to start or stop the capture queue, p/s/r to select, save or restore a settings profile, n to start or end a shoot session, e to transcode recordings, m for the camera dashboard, w for pairing and the camera's access point, g for ISO, shutter, aperture, white balance and exposure compensation, x for the command console, u for plugins, i for camera request statistics, l for the audit log, a to explore the camera API, play NAME to play a recorded macro, or q to quit." => {
+            "Nummer zur Auswahl eingeben, f für eine Fokusreihe, t zum Starten oder Stoppen eines Zeitraffers, c zum Starten oder Stoppen der Aufnahmewarteschlange, p/s/r zum Auswählen, Speichern oder Wiederherstellen eines Einstellungsprofils, n zum Starten oder Beenden einer Shooting-Sitzung, e zum Umwandeln von Aufnahmen, m für die Kameraübersicht, w für die Kopplung und den Zugangspunkt der Kamera, g für ISO, Verschlusszeit, Blende, Weißabgleich und Belichtungskorrektur, x für die Befehlskonsole, u für Plugins, i für Statistiken der Kamera-Anfragen, l für das Protokoll, a zum Erkunden der Kamera-API, play NAME zum Abspielen eines aufgezeichneten Makros oder q zum Beenden."
+        }
+        "Type a number to open that camera's live view, b to go back. Press Enter to refresh." => {
+            "Nummer eingeben, um das Livebild dieser Kamera zu öffnen, b für zurück. Enter zum Aktualisieren."
+        }
+        "Type a number to run that action, b to go back." => {
+            "Nummer eingeben, um diese Aktion auszuführen, b für zurück."
+        }
+        "Type a number to select a setting, + or - to change it, r to reload, b to go back." => {
+            "Nummer eingeben, um eine Einstellung auszuwählen, + oder - zum Ändern, r zum Neuladen, b für zurück."
+        }
+        "Type a number to select, v to view, d to download, o to open in the system viewer, c to copy its path, w to save a web size copy, *1-*5 to rate (*0 clears), t to tag, s to sync all new images, x to delete, n/p for next/previous page, [/] for previous/next folder, r to refresh, b to go back." => {
+            "Nummer zur Auswahl eingeben, v zum Anzeigen, d zum Herunterladen, o zum Öffnen im Systembetrachter, c zum Kopieren des Pfads, w zum Speichern einer Web-Kopie, *1-*5 zum Bewerten (*0 löscht), t zum Verschlagworten, s zum Abgleichen aller neuen Bilder, x zum Löschen, n/p für nächste/vorige Seite, [/] für vorigen/nächsten Ordner, r zum Aktualisieren, b für zurück."
+        }
+        "Type a number to transcode that recording to MP4 (* means an MP4 exists), c to cancel, r to refresh, b to go back. Press Enter to refresh progress." => {
+            "Nummer eingeben, um diese Aufnahme in MP4 umzuwandeln (* bedeutet, dass eine MP4 existiert), c zum Abbrechen, r zum Aktualisieren, b für zurück. Enter aktualisiert den Fortschritt."
+        }
+        "Type a value, or an empty line to cancel." => {
+            "Wert eingeben oder leere Zeile zum Abbrechen."
+        }
+        "Type c to send a request, d to compare it with the last response to the same request, e/i to export/import capabilities, n/p for the next/previous one sent, b to go back." => {
+            "c sendet eine Anfrage, d vergleicht sie mit der letzten Antwort auf dieselbe Anfrage, e/i exportiert/importiert Fähigkeiten, n/p für die nächste/vorige gesendete, b für zurück."
+        }
+        "Type n/p for next/previous page, r to reload, b to go back." => {
+            "n/p für nächste/vorherige Seite, r zum Neuladen, b für zurück eingeben."
+        }
+        "Type r to retry, w to join the camera's Wi-Fi or q to quit: " => {
+            "r für erneuten Versuch, w zum Verbinden mit dem Kamera-WLAN oder q zum Beenden: "
+        }
+        "Type the number of the network to join: " => "Nummer des Netzes eingeben: ",
+        "Type v to display the image, r for higher resolution, o to open in the system viewer, c to copy its path, w to save a web size copy, +/- to zoom, 0 to reset zoom, a to toggle aspect ratio, z to toggle the zebra clipping overlay, m to cycle display modes, b to go back." => {
+            "v zeigt das Bild an, r für höhere Auflösung, o zum Öffnen im Systembetrachter, c zum Kopieren des Pfads, w zum Speichern einer Web-Kopie, +/- zum Zoomen, 0 setzt den Zoom zurück, a schaltet das Seitenverhältnis um, z schaltet die Zebra-Überbelichtungsanzeige um, m wechselt den Anzeigemodus, b für zurück."
+        }
+        "Type y to confirm or n to cancel." => "y zum Bestätigen oder n zum Abbrechen.",
+        "Type, then press Enter to confirm or Esc to cancel; Up/Down recall earlier values" => {
+            "Eingeben, dann Enter zum Bestätigen oder Esc zum Abbrechen drücken; Auf/Ab ruft frühere Werte auf"
+        }
+        "UDP Port: {}" => "UDP-Port: {}",
+        "Underwater" => "Unterwasser",
+        "Unknown command: {}" => "Unbekannter Befehl: {}",
+        "Unknown error" => "Unbekannter Fehler",
+        "Unreachable: {}" => "Nicht erreichbar: {}",
+        "Up/Down - Select, Enter - Change the selected setting, r - Reload" => {
+            "Hoch/Runter - Auswählen, Enter - Gewählte Einstellung ändern, r - Neu laden"
+        }
+        "Up/Down - Select, Enter - Open live view of the selected camera" => {
+            "Hoch/Runter - Auswählen, Enter - Livebild der gewählten Kamera öffnen"
+        }
+        "Up/Down - Select, Left/Right - Change the selected setting, r - Reload" => {
+            "Hoch/Runter - Auswählen, Links/Rechts - Ausgewählte Einstellung ändern, r - Neu laden"
+        }
+        "Up/Down, PgUp/PgDn - Browse, r - Reload" => {
+            "Auf/Ab, Bild auf/ab - Blättern, r - Neu laden"
+        }
+        "Upright" => "Aufrecht",
+        "Use the controls below to manage the stream." => {
+            "Mit den Tasten unten wird der Stream gesteuert."
+        }
+        "Video player not found. Please install MPlayer or FFplay" => {
+            "Kein Videoplayer gefunden. Bitte MPlayer oder FFplay installieren"
+        }
+        "Video recording stopped after {}" => "Videoaufnahme nach {} beendet",
+        "Video stream started successfully" => "Videostream erfolgreich gestartet",
+        "View Images" => "Bilder anzeigen",
+        "Viewing image: {}" => "Zeige Bild: {}",
+        "Viewing image: {} (Resolution: {})" => "Zeige Bild: {} (Auflösung: {})",
+        "Viewing video stream: {}" => "Zeige Videostream: {}",
+        "Waiting for a frame..." => "Warte auf ein Bild...",
+        "Waiting for frames" => "Warte auf Bilder",
+        "Waiting for video data. Check camera connection if this persists." => {
+            "Warte auf Videodaten. Bei längerem Ausbleiben die Kameraverbindung prüfen."
+        }
+        "White balance" => "Weißabgleich",
+        "White balance: {}" => "Weißabgleich: {}",
+        "Wi-Fi password, or Enter if this computer already knows it: " => {
+            "WLAN-Passwort, oder Enter, wenn dieser Computer es schon kennt: "
+        }
+        "Wi-Fi scan failed: {}" => "WLAN-Suche fehlgeschlagen: {}",
+        "Wi-Fi signal weak ({}%), live view may stutter" => {
+            "WLAN-Signal schwach ({}%), die Live-Ansicht kann ruckeln"
+        }
+        "Wi-Fi: {}" => "WLAN: {}",
+        "Woke {} over Bluetooth" => "{} über Bluetooth geweckt",
+        "Zebra clipping overlay disabled" => "Zebra-Überbelichtungsanzeige deaktiviert",
+        "Zebra clipping overlay enabled" => "Zebra-Überbelichtungsanzeige aktiviert",
+        "Zebra overlay is not available in this build" => {
+            "Die Zebra-Anzeige ist in diesem Build nicht verfügbar"
+        }
+        "Zebra: Off " => "Zebra: Aus ",
+        "Zebra: On " => "Zebra: An ",
+        "Zoom reset to 1.0x" => "Zoom auf 1,0x zurückgesetzt",
+        "Zoom: {}x" => "Zoom: {}x",
+        "Zoom: {}x " => "Zoom: {}x ",
+        "[ / ] - Previous/next folder" => "[ / ] - Voriger/nächster Ordner",
+        "a - Toggle aspect ratio   " => "a - Seitenverhältnis umschalten   ",
+        "c - Cancel running transcode" => "c - Laufende Umwandlung abbrechen",
+        "c - Copy path   " => "c - Pfad kopieren   ",
+        "c - Toggle stats CSV   " => "c - Statistik-CSV umschalten   ",
+        "capture queue" => "Aufnahmewarteschlange",
+        "d - Cycle display modes   " => "d - Anzeigemodus wechseln   ",
+        "d - Diagnostics   " => "d - Diagnose   ",
+        "d - Download selected image, w - Web size copy" => {
+            "d - Ausgewähltes Bild herunterladen, w - Web-Kopie"
+        }
+        "e/i - Export/import capabilities, Up/Down, PgUp/PgDn - Browse, Esc - Back" => {
+            "e/i - Fähigkeiten export./import., Hoch/Runter, Bild↑/↓ - Blättern, Esc - Zurück"
+        }
+        "failed" => "fehlgeschlagen",
+        "failed: {}" => "fehlgeschlagen: {}",
+        "fine for moving subjects" => "gut für bewegte Motive",
+        "fixed" => "fest",
+        "g - Go to image number" => "g - Zu Bildnummer springen",
+        "h - Toggle MJPEG server   " => "h - MJPEG-Server umschalten   ",
+        "i - Instant replay   " => "i - Sofortwiederholung   ",
+        "iTerm2 inline images" => "iTerm2-Inline-Bilder",
+        "interval stills" => "Intervall-Standbilder",
+        "new" => "neu",
+        "no response" => "keine Antwort",
+        "not measured" => "nicht gemessen",
+        "o - Open in system viewer   " => "o - Im Systembetrachter öffnen   ",
+        "o - Open in system viewer, c - Copy path" => {
+            "o - Im Systembetrachter öffnen, c - Pfad kopieren"
+        }
+        "o - Toggle RTMP stream   " => "o - RTMP-Stream ein/aus   ",
+        "ok" => "ok",
+        "p - Relaunch player   " => "p - Player neu starten   ",
+        "q - Quit application" => "q - Anwendung beenden",
+        "r - Attempt reconnection   " => "r - Erneut verbinden   ",
+        "r - Higher resolution   " => "r - Höhere Auflösung   ",
+        "r - Refresh image list" => "r - Bildliste aktualisieren",
+        "r - Refresh recording list" => "r - Aufnahmeliste aktualisieren",
+        "r - Toggle recording   " => "r - Aufnahme umschalten   ",
+        "read-only" => "nur lesen",
+        "recording" => "Aufnahme",
+        "recording {}" => "Aufnahme {}",
+        "s - Snapshot   " => "s - Schnappschuss   ",
+        "s - Sync all new images to downloads" => "s - Alle neuen Bilder in Downloads abgleichen",
+        "still subjects only" => "nur für ruhende Motive",
+        "t - Interval stills   " => "t - Intervall-Standbilder   ",
+        "timelapse" => "Zeitraffer",
+        "too slow to focus by" => "zu langsam zum Fokussieren",
+        "transcode" => "Umwandlung",
+        "v - Terminal view   " => "v - Terminal-Ansicht   ",
+        "w - Find camera Wi-Fi   " => "w - Kamera-WLAN suchen   ",
+        "w - Web size copy   " => "w - Web-Kopie   ",
+        "w - White balance   " => "w - Weißabgleich   ",
+        "weak" => "schwach",
+        "x - Stop/Start stream   " => "x - Stream stoppen/starten   ",
+        "z - Zebra clipping overlay   " => "z - Zebra-Überbelichtungsanzeige   ",
+        "{} (adaptive)" => "{} (adaptiv)",
+        "{} (folder {} of {}) - {}" => "{} (Ordner {} von {}) - {}",
+        "{} (no password)" => "{} (ohne Passwort)",
+        "{} - {} frames" => "{} - {} Bilder",
+        "{} MB" => "{} MB",
+        "{} answered {} in {} ms" => "{} antwortete {} in {} ms",
+        "{} failed: {}" => "{} fehlgeschlagen: {}",
+        "{} frames" => "{} Bilder",
+        "{} is already at its limit" => "{} ist bereits am Ende des Bereichs",
+        "{} is fixed by the current exposure mode" => {
+            "{} wird vom aktuellen Belichtungsmodus festgelegt"
+        }
+        "{} lines changed" => "{} Zeilen geändert",
+        "{} new" => "{} neu",
+        "{} on restreams" => "{} beim Weiterstreamen",
+        "{} operations in the audit log" => "{} Vorgänge im Protokoll",
+        "{} saved" => "{} gespeichert",
+        "{} set to {}" => "{} auf {} gesetzt",
+        "{} | {} fps, {}% loss | last frame {} | battery {} | {}" => {
+            "{} | {} fps, {}% Verlust | letztes Bild {} | Akku {} | {}"
+        }
+        "{}, saved to {}" => "{}, gespeichert unter {}",
+        "{}/{} MB" => "{}/{} MB",
+        "{}/{} files" => "{}/{} Dateien",
+        "{}/{} frames" => "{}/{} Bilder",
+        "{}/{} shots" => "{}/{} Aufnahmen",
+        "{}: {} requests, {} failed, avg {} ms, max {} ms" => {
+            "{}: {} Anfragen, {} fehlgeschlagen, Mittel {} ms, Maximum {} ms"
+        }
+        "{}x{}, need at least {}x{}" => "{}x{}, mindestens {}x{} nötig",
+        _ => return None,
+    })
+}
@@ -0,0 +1,79 @@
+// src/utils/i18n/mod.rs
+// Message catalog for user-facing strings. Messages are identified by their
+// English text, so untranslated strings fall back to English. Placeholders
+// are written as `{}` and filled in order by `trf`.
+mod de;
+
+use log::{info, warn};
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// Supported interface languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// English (built in, used as message ids)
+    English,
+    /// German
+    German,
+}
+
+impl Locale {
+    /// Parse a locale code such as `en`, `de` or `de_DE.UTF-8`
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+
+        match language.as_str() {
+            "en" => Some(Locale::English),
+            "de" => Some(Locale::German),
+            _ => None,
+        }
+    }
+}
+
+/// Locale selected at startup
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Select the interface language from a locale code
+pub fn init(code: &str) {
+    let locale = Locale::from_code(code).unwrap_or_else(|| {
+        warn!("Unsupported locale '{}', falling back to English", code);
+        Locale::English
+    });
+
+    info!("Using locale {:?}", locale);
+    let _ = LOCALE.set(locale);
+}
+
+/// Get the active locale
+pub fn locale() -> Locale {
+    LOCALE.get().copied().unwrap_or(Locale::English)
+}
+
+/// Translate a message into the active locale
+pub fn tr(msg: &'static str) -> &'static str {
+    match locale() {
+        Locale::English => msg,
+        Locale::German => de::translate(msg).unwrap_or(msg),
+    }
+}
+
+/// Translate a message and fill its `{}` placeholders in order
+pub fn trf(msg: &'static str, args: &[&dyn Display]) -> String {
+    let mut output = String::new();
+    let mut args = args.iter();
+
+    for (i, part) in tr(msg).split("{}").enumerate() {
+        if i > 0
+            && let Some(arg) = args.next()
+        {
+            output.push_str(&arg.to_string());
+        }
+        output.push_str(part);
+    }
+
+    output
+}
@@ -0,0 +1,114 @@
+// src/utils/instance.rs
+// Only one instance of the app should drive a camera at a time. The camera
+// directory holds a lock file naming the instance in control, refreshed by a
+// heartbeat: an instance that finds a fresh lock from another one starts
+// read-only, and one whose lock was taken over notices on its next heartbeat.
+use crate::utils::session::unix_now;
+use crate::utils::{atomic, paths};
+use anyhow::Result;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Name of the lock file in the camera directory
+pub const LOCK_FILE: &str = "instance.lock";
+
+/// How often the instance in control refreshes the lock
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Seconds without a heartbeat after which a lock is treated as abandoned
+const STALE_SECS: u64 = 30;
+
+/// Instance named in a lock file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockOwner {
+    pub pid: u32,
+    /// When the instance took control, in Unix seconds
+    pub since: u64,
+    /// Last heartbeat of the instance, in Unix seconds
+    pub heartbeat: u64,
+}
+
+impl LockOwner {
+    fn is_us(&self) -> bool {
+        self.pid == process::id()
+    }
+
+    fn is_fresh(&self) -> bool {
+        unix_now().saturating_sub(self.heartbeat) < STALE_SECS
+    }
+}
+
+/// Lock file of the camera directory
+fn lock_path() -> PathBuf {
+    paths::camera_root().join(LOCK_FILE)
+}
+
+fn read_owner() -> Option<LockOwner> {
+    let text = fs::read_to_string(lock_path()).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+/// Another running instance in control of this camera, if there is one
+pub fn other_owner() -> Option<LockOwner> {
+    read_owner().filter(|owner| !owner.is_us() && owner.is_fresh())
+}
+
+/// Control of the camera held by this instance
+pub struct InstanceLock {
+    owner: LockOwner,
+    last_beat: Instant,
+}
+
+impl InstanceLock {
+    /// Take control of the camera, replacing any other instance's lock
+    pub fn claim() -> Result<Self> {
+        let now = unix_now();
+        let lock = Self {
+            owner: LockOwner {
+                pid: process::id(),
+                since: now,
+                heartbeat: now,
+            },
+            last_beat: Instant::now(),
+        };
+        lock.write()?;
+        info!("Took control of the camera as instance {}", lock.owner.pid);
+        Ok(lock)
+    }
+
+    fn write(&self) -> Result<()> {
+        let text = serde_json::to_string_pretty(&self.owner)?;
+        atomic::write_atomic(&lock_path(), text.as_bytes())
+    }
+
+    /// Refresh the lock when due, returning the instance that took over if one did
+    pub fn heartbeat(&mut self) -> Option<LockOwner> {
+        if self.last_beat.elapsed() < HEARTBEAT_INTERVAL {
+            return None;
+        }
+        self.last_beat = Instant::now();
+
+        if let Some(owner) = read_owner().filter(|owner| !owner.is_us()) {
+            warn!("Instance {} took control of the camera", owner.pid);
+            return Some(owner);
+        }
+        self.owner.heartbeat = unix_now();
+        if let Err(e) = self.write() {
+            warn!("Failed to refresh the instance lock: {}", e);
+        }
+        None
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // A lock another instance has taken over is theirs to remove
+        if read_owner().is_some_and(|owner| owner.is_us()) {
+            let _ = fs::remove_file(lock_path());
+        }
+    }
+}
@@ -1,2 +1,22 @@
 // src/utils/mod.rs
+pub mod alert;
+pub mod atomic;
+pub mod audit;
+pub mod catalog;
+pub mod clipboard;
+pub mod config;
+pub mod diff;
+pub mod disk;
+pub mod dng;
+pub mod exec;
+pub mod ffmpeg;
+pub mod i18n;
+pub mod instance;
 pub mod logging;
+pub mod notes;
+pub mod open;
+pub mod paths;
+pub mod resize;
+pub mod session;
+pub mod solar;
+pub mod xmp;
@@ -0,0 +1,113 @@
+// src/utils/notes.rs
+// Ratings and tags given to images while culling in the image list. They are
+// kept per camera, keyed by image name, so they survive between sessions and
+// can be written into XMP sidecars once the images are downloaded.
+use crate::utils::atomic;
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Highest star rating
+pub const MAX_RATING: u8 = 5;
+
+/// Rating and tags of one image
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImageNotes {
+    /// Stars from 0 (unrated) to 5
+    pub rating: u8,
+    /// Keywords, in the order they were entered
+    pub tags: Vec<String>,
+}
+
+impl ImageNotes {
+    /// Whether the image has neither a rating nor tags
+    pub fn is_empty(&self) -> bool {
+        self.rating == 0 && self.tags.is_empty()
+    }
+
+    /// Stars and tags as shown next to the image name
+    pub fn summary(&self) -> String {
+        let stars = "★".repeat(self.rating as usize);
+        match (stars.is_empty(), self.tags.is_empty()) {
+            (_, true) => stars,
+            (true, false) => format!("[{}]", self.tags.join(", ")),
+            (false, false) => format!("{} [{}]", stars, self.tags.join(", ")),
+        }
+    }
+}
+
+/// Split a comma separated list of tags, dropping blanks and repeats
+pub fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in text.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Ratings and tags of every image that has any, saved after each change
+pub struct NotesStore {
+    path: PathBuf,
+    notes: BTreeMap<String, ImageNotes>,
+}
+
+impl NotesStore {
+    /// Load the notes saved in `path`, starting empty if the file is missing or invalid
+    pub fn load(path: &Path) -> Self {
+        let notes = match fs::read_to_string(path) {
+            Ok(text) => match serde_json::from_str(&text) {
+                Ok(notes) => {
+                    info!("Loaded image ratings and tags from {}", path.display());
+                    notes
+                }
+                Err(e) => {
+                    warn!("Ignoring invalid image notes {}: {}", path.display(), e);
+                    BTreeMap::new()
+                }
+            },
+            Err(_) => BTreeMap::new(),
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            notes,
+        }
+    }
+
+    /// Rating and tags of an image, if it has any
+    pub fn get(&self, image: &str) -> Option<&ImageNotes> {
+        self.notes.get(image)
+    }
+
+    /// Give an image a star rating; 0 clears it
+    pub fn set_rating(&mut self, image: &str, rating: u8) -> Result<()> {
+        self.update(image, |notes| notes.rating = rating.min(MAX_RATING))
+    }
+
+    /// Replace the tags of an image
+    pub fn set_tags(&mut self, image: &str, tags: Vec<String>) -> Result<()> {
+        self.update(image, |notes| notes.tags = tags)
+    }
+
+    fn update(&mut self, image: &str, change: impl FnOnce(&mut ImageNotes)) -> Result<()> {
+        let mut notes = self.notes.remove(image).unwrap_or_default();
+        change(&mut notes);
+        // Images with nothing left to say are dropped rather than kept empty
+        if !notes.is_empty() {
+            self.notes.insert(image.to_string(), notes);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let text = serde_json::to_string_pretty(&self.notes)?;
+        atomic::write_atomic(&self.path, text.as_bytes())
+            .with_context(|| format!("Failed to save image notes to {}", self.path.display()))
+    }
+}
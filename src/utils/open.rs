@@ -0,0 +1,43 @@
+// src/utils/open.rs
+// Hands files to the desktop, for when the terminal can't show them well.
+use anyhow::{Context, Result};
+use log::info;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Open `path` with the system's default application for its type
+pub fn open_with_default_app(path: &Path) -> Result<()> {
+    let mut command = opener_command(path);
+    info!("Opening {:?} with {:?}", path, command.get_program());
+
+    // The viewer outlives the request, so it isn't waited for
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn opener_command(path: &Path) -> Command {
+    let mut command = Command::new("open");
+    command.arg(path);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn opener_command(path: &Path) -> Command {
+    // `start` is built into cmd; the empty title keeps a quoted path from being taken as one
+    let mut command = Command::new("cmd");
+    command.args(["/C", "start", ""]).arg(path);
+    command
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn opener_command(path: &Path) -> Command {
+    let mut command = Command::new("xdg-open");
+    command.arg(path);
+    command
+}
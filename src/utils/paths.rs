@@ -0,0 +1,165 @@
+// src/utils/paths.rs
+// Everything the app writes lives under one directory per camera, so files
+// from different cameras and sessions don't pile up in the working directory.
+// Directories in the configuration are relative to that camera directory
+// unless they are absolute. While a shoot session runs, new downloads,
+// recordings and captures go into the session's folder instead.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Location of the per-camera data directories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PathsConfig {
+    /// Directory holding one subdirectory per camera
+    pub data_dir: String,
+    /// Name of this camera's subdirectory
+    pub camera: String,
+    /// Directory for downloaded images, relative to the camera directory unless absolute
+    pub downloads: String,
+}
+
+impl Default for PathsConfig {
+    fn default() -> Self {
+        Self {
+            data_dir: "olympus_data".to_string(),
+            camera: "default".to_string(),
+            downloads: DataDir::Downloads.name().to_string(),
+        }
+    }
+}
+
+/// Standard directories inside a camera directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataDir {
+    /// Images downloaded from the camera
+    Downloads,
+    /// Live view recordings
+    Recordings,
+    /// Frames saved from the live view
+    Snapshots,
+    /// Files that can be recreated, such as decoded previews
+    Cache,
+    /// Player output and stream statistics
+    Logs,
+}
+
+impl DataDir {
+    /// Every standard directory, created together
+    pub const ALL: [DataDir; 5] = [
+        DataDir::Downloads,
+        DataDir::Recordings,
+        DataDir::Snapshots,
+        DataDir::Cache,
+        DataDir::Logs,
+    ];
+
+    /// Name of the directory on disk
+    pub fn name(self) -> &'static str {
+        match self {
+            DataDir::Downloads => "downloads",
+            DataDir::Recordings => "recordings",
+            DataDir::Snapshots => "snapshots",
+            DataDir::Cache => "cache",
+            DataDir::Logs => "logs",
+        }
+    }
+}
+
+/// Camera directory selected at startup
+static CAMERA_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Download directory selected at startup
+static DOWNLOADS_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Folder of the running shoot session, if any
+static SESSION_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Select the camera directory and create its standard layout
+pub fn init(config: &PathsConfig) {
+    // The camera name becomes a single path component
+    let camera: String = config
+        .camera
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+        .collect();
+    let root = Path::new(&config.data_dir).join(camera);
+
+    let downloads = root.join(&config.downloads);
+    for dir in DataDir::ALL {
+        let path = match dir {
+            DataDir::Downloads => downloads.clone(),
+            _ => root.join(dir.name()),
+        };
+        if let Err(e) = fs::create_dir_all(&path) {
+            warn!("Failed to create data directory {:?}: {}", path, e);
+        }
+    }
+
+    info!("Using camera data directory {:?}", root);
+    let _ = CAMERA_ROOT.set(root);
+    let _ = DOWNLOADS_DIR.set(downloads);
+}
+
+/// Directory holding this camera's data
+pub fn camera_root() -> PathBuf {
+    CAMERA_ROOT
+        .get()
+        .cloned()
+        .unwrap_or_else(|| Path::new(&PathsConfig::default().data_dir).join("default"))
+}
+
+/// Route new files into a shoot session's folder, or back to the camera directory with `None`
+pub fn set_session_dir(directory: Option<PathBuf>) {
+    *SESSION_DIR.lock().unwrap() = directory;
+}
+
+/// Folder of the running shoot session, if any
+pub fn session_dir() -> Option<PathBuf> {
+    SESSION_DIR.lock().unwrap().clone()
+}
+
+/// Path of one of the standard directories in the camera directory
+///
+/// Sessions don't change it, so lookups of files written earlier keep working
+/// while one runs. New files are written to [`output_dir`].
+pub fn dir(kind: DataDir) -> PathBuf {
+    match (kind, DOWNLOADS_DIR.get()) {
+        (DataDir::Downloads, Some(downloads)) => downloads.clone(),
+        _ => camera_root().join(kind.name()),
+    }
+}
+
+/// Directory new files of `kind` are written to
+///
+/// Downloads, recordings and snapshots go into the session folder while a session runs.
+pub fn output_dir(kind: DataDir) -> PathBuf {
+    match (kind, session_dir()) {
+        (DataDir::Downloads | DataDir::Recordings | DataDir::Snapshots, Some(session)) => {
+            session.join(kind.name())
+        }
+        _ => dir(kind),
+    }
+}
+
+/// Resolve a configured path against the camera directory, keeping absolute paths
+pub fn resolve(configured: &str) -> PathBuf {
+    let path = Path::new(configured);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        camera_root().join(path)
+    }
+}
+
+/// Resolve a configured output directory, against the session folder while a session runs
+pub fn resolve_output(configured: &str) -> PathBuf {
+    let path = Path::new(configured);
+    match session_dir() {
+        Some(session) if !path.is_absolute() => session.join(path),
+        _ => resolve(configured),
+    }
+}
@@ -0,0 +1,119 @@
+// src/utils/resize.rs
+// Shareable "web size" copies of photos. The camera can resize on its side,
+// which saves most of the transfer over Wi-Fi; either way the result is
+// scaled here to the configured size, keeping the original's EXIF data.
+#![cfg_attr(not(feature = "resize"), allow(dead_code))]
+use anyhow::Result;
+#[cfg(not(feature = "resize"))]
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+/// Sizes `get_resized_img.cgi` accepts, smallest first
+pub const CAMERA_RESIZE_SIZES: [u32; 4] = [1024, 1600, 1920, 2048];
+
+/// JPEG marker of the segment EXIF data is stored in
+const APP1: u8 = 0xE1;
+
+/// JPEG marker after which the compressed image data starts
+const SOS: u8 = 0xDA;
+
+/// Settings for web size copies of photos
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebSizeConfig {
+    /// Longest side of the copy in pixels
+    pub max_dimension: u32,
+    /// JPEG quality of the copy, from 1 to 100
+    pub quality: u8,
+    /// Ask the camera for a resized image instead of downloading the full file
+    pub on_camera: bool,
+    /// Directory the copies are saved to
+    pub directory: String,
+}
+
+impl Default for WebSizeConfig {
+    fn default() -> Self {
+        Self {
+            max_dimension: 2048,
+            quality: 85,
+            on_camera: true,
+            directory: "web".to_string(),
+        }
+    }
+}
+
+/// Largest size the camera can resize to without going over `max_dimension`
+pub fn camera_resize_size(max_dimension: u32) -> u32 {
+    CAMERA_RESIZE_SIZES
+        .iter()
+        .rev()
+        .copied()
+        .find(|size| *size <= max_dimension)
+        .unwrap_or(CAMERA_RESIZE_SIZES[0])
+}
+
+/// Scale a JPEG down so its longest side is at most `max_dimension`, keeping its EXIF data
+///
+/// Images that already fit are returned unchanged.
+#[cfg(feature = "resize")]
+pub fn resize_jpeg(data: &[u8], max_dimension: u32, quality: u8) -> Result<Vec<u8>> {
+    use image::GenericImageView;
+    use image::codecs::jpeg::JpegEncoder;
+    use image::imageops::FilterType;
+
+    let decoded = image::load_from_memory(data)?;
+    let (width, height) = decoded.dimensions();
+    if width.max(height) <= max_dimension {
+        return Ok(data.to_vec());
+    }
+
+    // Fits the image inside the square, so the aspect ratio is kept
+    let resized = decoded
+        .resize(max_dimension, max_dimension, FilterType::Lanczos3)
+        .to_rgb8();
+    let mut encoded = Vec::new();
+    JpegEncoder::new_with_quality(&mut encoded, quality.clamp(1, 100)).encode_image(&resized)?;
+
+    Ok(match exif_segment(data) {
+        Some(exif) => insert_segment(&encoded, exif),
+        None => encoded,
+    })
+}
+
+/// Without an image codec, resizing isn't possible
+#[cfg(not(feature = "resize"))]
+pub fn resize_jpeg(_data: &[u8], _max_dimension: u32, _quality: u8) -> Result<Vec<u8>> {
+    Err(anyhow!("Resizing images needs the resize feature"))
+}
+
+/// The whole APP1 segment holding a JPEG's EXIF data, marker included
+pub fn exif_segment(jpeg: &[u8]) -> Option<&[u8]> {
+    if !jpeg.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    let mut offset = 2;
+    while offset + 4 <= jpeg.len() && jpeg[offset] == 0xFF {
+        let marker = jpeg[offset + 1];
+        if marker == SOS {
+            break;
+        }
+        // The length covers itself but not the marker
+        let length = u16::from_be_bytes([jpeg[offset + 2], jpeg[offset + 3]]) as usize;
+        let end = (offset + 2 + length).min(jpeg.len());
+        if marker == APP1 && jpeg[offset + 4..end].starts_with(b"Exif\0\0") {
+            return Some(&jpeg[offset..end]);
+        }
+        offset = end;
+    }
+    None
+}
+
+/// Put a segment right after a JPEG's start of image marker
+fn insert_segment(jpeg: &[u8], segment: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(jpeg.len() + segment.len());
+    output.extend_from_slice(&jpeg[..2]);
+    output.extend_from_slice(segment);
+    output.extend_from_slice(&jpeg[2..]);
+    output
+}
@@ -0,0 +1,233 @@
+// src/utils/session.rs
+// Shoot sessions: from the moment one starts, downloads, recordings and
+// captures go into a folder named after the session's start time, and ending
+// it writes a summary of what the shoot produced next to the files.
+use crate::utils::paths::DataDir;
+use crate::utils::{atomic, paths};
+use anyhow::{Context, Result};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Name of the summary written into the session folder when it ends
+pub const SUMMARY_FILE: &str = "session.json";
+
+/// Where session folders are created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionConfig {
+    /// Directory holding one folder per session
+    pub directory: String,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            directory: "sessions".to_string(),
+        }
+    }
+}
+
+/// Files written into one folder of a session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FolderSummary {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// What a session produced, written to its folder when it ends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub name: String,
+    /// Start and end of the session in Unix seconds
+    pub started: u64,
+    pub ended: u64,
+    /// Files by the folder they were written to, such as `downloads`
+    pub folders: BTreeMap<String, FolderSummary>,
+}
+
+impl SessionSummary {
+    /// Files written during the whole session
+    pub fn total_files(&self) -> usize {
+        self.folders.values().map(|folder| folder.files).sum()
+    }
+}
+
+/// A running shoot session
+pub struct ShootSession {
+    /// Start time, followed by the label given to the session
+    pub name: String,
+    /// Folder the session's files go to
+    pub directory: PathBuf,
+    started: u64,
+}
+
+impl ShootSession {
+    /// Start a session labelled `label` and route new files into its folder
+    pub fn start(config: &SessionConfig, label: &str) -> Result<Self> {
+        let started = unix_now();
+        let label = sanitize(label);
+        let name = if label.is_empty() {
+            timestamp(started)
+        } else {
+            format!("{}_{}", timestamp(started), label)
+        };
+
+        let directory = paths::resolve(&config.directory).join(&name);
+        fs::create_dir_all(&directory)
+            .with_context(|| format!("Failed to create session folder {:?}", directory))?;
+        paths::set_session_dir(Some(directory.clone()));
+        info!("Started shoot session {} in {:?}", name, directory);
+
+        Ok(Self {
+            name,
+            directory,
+            started,
+        })
+    }
+
+    /// End the session, send new files back to the camera directory and write the summary
+    pub fn finish(self) -> Result<SessionSummary> {
+        paths::set_session_dir(None);
+
+        let mut folders = BTreeMap::new();
+        for entry in fs::read_dir(&self.directory)?.filter_map(|entry| entry.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                folders.insert(name, summarize_folder(&path));
+            }
+        }
+
+        let summary = SessionSummary {
+            name: self.name,
+            started: self.started,
+            ended: unix_now(),
+            folders,
+        };
+        let text = serde_json::to_string_pretty(&summary)?;
+        atomic::write_atomic(&self.directory.join(SUMMARY_FILE), text.as_bytes())?;
+        info!(
+            "Ended shoot session {} with {} files",
+            summary.name,
+            summary.total_files()
+        );
+        Ok(summary)
+    }
+}
+
+/// Downloads directory of the camera, followed by the downloads folder of every session
+///
+/// Sync and lookups of local copies search all of them, so an image
+/// downloaded during a session isn't fetched again once it has ended.
+pub fn download_dirs(config: &SessionConfig) -> Vec<PathBuf> {
+    let mut dirs = vec![paths::dir(DataDir::Downloads)];
+    if let Ok(entries) = fs::read_dir(paths::resolve(&config.directory)) {
+        let mut sessions: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .map(|path| path.join(DataDir::Downloads.name()))
+            .filter(|path| path.is_dir())
+            .collect();
+        sessions.sort();
+        dirs.extend(sessions);
+    }
+    dirs
+}
+
+/// Local copy of a downloaded image, wherever it was downloaded to
+pub fn find_download(config: &SessionConfig, name: &str) -> Option<PathBuf> {
+    download_dirs(config)
+        .into_iter()
+        .map(|dir| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Count the files below a folder and their size
+fn summarize_folder(path: &Path) -> FolderSummary {
+    let mut summary = FolderSummary::default();
+    let Ok(entries) = fs::read_dir(path) else {
+        return summary;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            let inner = summarize_folder(&path);
+            summary.files += inner.files;
+            summary.bytes += inner.bytes;
+        } else if let Ok(metadata) = entry.metadata() {
+            summary.files += 1;
+            summary.bytes += metadata.len();
+        }
+    }
+    summary
+}
+
+/// Keep a label usable as part of a folder name
+fn sanitize(label: &str) -> String {
+    label
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Seconds since the Unix epoch
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// `YYYY-MM-DD_HHMM` in UTC, so session folders sort by start time
+fn timestamp(secs: u64) -> String {
+    let (year, month, day) = civil_date(secs);
+    let minutes = (secs % 86_400) / 60;
+    format!(
+        "{:04}-{:02}-{:02}_{:02}{:02}",
+        year,
+        month,
+        day,
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/// `YYYY-MM-DD HH:MM:SS` in UTC
+pub fn datetime(secs: u64) -> String {
+    let (year, month, day) = civil_date(secs);
+    let seconds = secs % 86_400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60
+    )
+}
+
+/// Year, month and day of a Unix time (Howard Hinnant's algorithm)
+fn civil_date(secs: u64) -> (i64, i64, i64) {
+    let days = (secs / 86_400) as i64;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
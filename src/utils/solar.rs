@@ -0,0 +1,63 @@
+// src/utils/solar.rs
+// Sunrise and sunset for a location and day from the sunrise equation, with
+// the usual allowance for refraction and the size of the sun's disc. The result
+// is good to a minute or two, plenty for starting and ending a timelapse.
+use serde::{Deserialize, Serialize};
+
+/// Julian day of the Unix epoch
+const UNIX_EPOCH_JULIAN: f64 = 2_440_587.5;
+
+/// Julian day of the J2000 epoch
+const J2000: f64 = 2_451_545.0;
+
+/// Altitude of the sun's centre at sunrise and sunset, in degrees
+const HORIZON_DEGREES: f64 = -0.833;
+
+/// Tilt of the Earth's axis, in degrees
+const OBLIQUITY_DEGREES: f64 = 23.4397;
+
+/// Seconds in a day
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Sun event a schedule is tied to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SolarEvent {
+    Sunrise,
+    Sunset,
+}
+
+/// Unix time of sunrise or sunset on `day`, counted in days since the Unix epoch
+///
+/// Latitude is in degrees north and longitude in degrees east. Returns `None` on days the sun
+/// doesn't rise or set there, during polar night or midnight sun.
+pub fn sun_event(day: i64, latitude: f64, longitude: f64, event: SolarEvent) -> Option<i64> {
+    // Days since J2000 of the mean solar noon nearest the given day
+    let day_number = (day as f64 + UNIX_EPOCH_JULIAN - J2000 + 0.0008).ceil();
+    let mean_noon = day_number - longitude / 360.0;
+
+    let anomaly = (357.5291 + 0.985_600_28 * mean_noon).rem_euclid(360.0);
+    let anomaly_rad = anomaly.to_radians();
+    let center = 1.9148 * anomaly_rad.sin()
+        + 0.0200 * (2.0 * anomaly_rad).sin()
+        + 0.0003 * (3.0 * anomaly_rad).sin();
+    let ecliptic = (anomaly + center + 180.0 + 102.9372)
+        .rem_euclid(360.0)
+        .to_radians();
+    let transit = J2000 + mean_noon + 0.0053 * anomaly_rad.sin() - 0.0069 * (2.0 * ecliptic).sin();
+
+    let declination = (ecliptic.sin() * OBLIQUITY_DEGREES.to_radians().sin()).asin();
+    let latitude = latitude.to_radians();
+    let cos_hour_angle = (HORIZON_DEGREES.to_radians().sin() - latitude.sin() * declination.sin())
+        / (latitude.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+
+    let half_day = cos_hour_angle.acos().to_degrees() / 360.0;
+    let julian = match event {
+        SolarEvent::Sunrise => transit - half_day,
+        SolarEvent::Sunset => transit + half_day,
+    };
+    Some(((julian - UNIX_EPOCH_JULIAN) * SECONDS_PER_DAY as f64).round() as i64)
+}
@@ -0,0 +1,130 @@
+// src/utils/xmp.rs
+// XMP sidecars next to downloaded images, carrying the ratings and tags given
+// in the image list and the shoot location, so Lightroom and darktable pick
+// up the culling work on import. The Air has no GPS, so the location is the
+// one configured for the shoot.
+use crate::utils::atomic;
+use crate::utils::notes::ImageNotes;
+use anyhow::Result;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Sidecar settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct XmpConfig {
+    /// Write sidecars for downloaded images
+    pub enabled: bool,
+    /// File the ratings and tags are kept in
+    pub notes_file: String,
+    /// Where the photos were taken, written as their GPS position
+    pub location: Option<GpsLocation>,
+    /// Name sidecars `P1010001.JPG.xmp` as darktable does instead of `P1010001.xmp`
+    pub append_extension: bool,
+}
+
+impl Default for XmpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            notes_file: "image_notes.json".to_string(),
+            location: None,
+            append_extension: false,
+        }
+    }
+}
+
+/// Position in decimal degrees, north and east positive
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GpsLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Sidecar file for the image at `image`
+pub fn sidecar_path(image: &Path, config: &XmpConfig) -> PathBuf {
+    if config.append_extension {
+        let mut name = image.as_os_str().to_owned();
+        name.push(".xmp");
+        PathBuf::from(name)
+    } else {
+        image.with_extension("xmp")
+    }
+}
+
+/// Write the sidecar of a downloaded image, if there is anything to put in it
+///
+/// Returns where the sidecar was written.
+pub fn write_sidecar(
+    image: &Path,
+    notes: Option<&ImageNotes>,
+    config: &XmpConfig,
+) -> Result<Option<PathBuf>> {
+    let notes = notes.filter(|notes| !notes.is_empty());
+    if !config.enabled || (notes.is_none() && config.location.is_none()) {
+        return Ok(None);
+    }
+
+    let path = sidecar_path(image, config);
+    atomic::write_atomic(&path, render(notes, config.location).as_bytes())?;
+    info!("Wrote XMP sidecar {:?}", path);
+    Ok(Some(path))
+}
+
+/// XMP packet holding the rating, keywords and position
+fn render(notes: Option<&ImageNotes>, location: Option<GpsLocation>) -> String {
+    let mut attributes = String::new();
+    if let Some(notes) = notes.filter(|notes| notes.rating > 0) {
+        attributes.push_str(&format!("\n    xmp:Rating=\"{}\"", notes.rating));
+    }
+    if let Some(location) = location {
+        attributes.push_str(&format!(
+            "\n    exif:GPSVersionID=\"2.3.0.0\"\n    exif:GPSLatitude=\"{}\"\n    exif:GPSLongitude=\"{}\"",
+            coordinate(location.latitude, 'N', 'S'),
+            coordinate(location.longitude, 'E', 'W')
+        ));
+    }
+
+    let mut subject = String::new();
+    if let Some(notes) = notes.filter(|notes| !notes.tags.is_empty()) {
+        subject.push_str("   <dc:subject>\n    <rdf:Bag>\n");
+        for tag in &notes.tags {
+            subject.push_str(&format!("     <rdf:li>{}</rdf:li>\n", escape(tag)));
+        }
+        subject.push_str("    </rdf:Bag>\n   </dc:subject>\n");
+    }
+
+    format!(
+        r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:exif="http://ns.adobe.com/exif/1.0/"{}>
+{}  </rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>
+"#,
+        attributes, subject
+    )
+}
+
+/// Decimal degrees in XMP's `DDD,MM.mmmmK` form
+fn coordinate(value: f64, positive: char, negative: char) -> String {
+    let direction = if value < 0.0 { negative } else { positive };
+    let value = value.abs();
+    let degrees = value.trunc();
+    let minutes = (value - degrees) * 60.0;
+    format!("{},{:.4}{}", degrees as u32, minutes, direction)
+}
+
+/// Escape text for use in XML content
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}